@@ -1,64 +1,234 @@
 // App state and main event loop.
 // Manages tabs, navigation state, and keyboard input handling.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures::stream::{self, StreamExt};
 use ratatui::prelude::*;
 use ratatui::widgets::ListState;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
+use crate::bulk::{BulkAction, BulkItems};
 use crate::cache;
-use crate::github::GitHubClient;
+use crate::changelog::{self, CURRENT_VERSION, Release};
+use crate::clipboard::{self, CopyMode, LogContext, LogExcerpt};
+use crate::config::{CacheKind, Config, IgnoreRule, SettingsField, WindowTitle};
+use crate::diag;
+use crate::error::{JoltError, ViewError};
+use crate::export::{self, ExportFormat, ExportRow};
+use crate::github::{
+    Branch, Capability, GitHubClient, InboxPullRequest, Job, OwnerType, RunConclusion, RunStatus,
+    Runner, RunnerGroupVisibility, RunnerStatus, Workflow, WorkflowRun, WorkflowState,
+};
+use crate::keymap::{
+    Action, CONSOLE_ACTIONS, FILTER_INPUT_ACTIONS, HOME_ACTIONS, INBOX_ACTIONS, LIVE_ACTIONS,
+    SEARCH_INPUT_ACTIONS,
+};
+use crate::message::{Command, ListView, Loaded, Message, Rows, RowsQuery};
+use crate::metrics;
+use crate::state::branches::BranchPicker;
+use crate::state::caches::CachesView;
+use crate::state::checks::{ChecksView, sort_checks};
+use crate::state::churn::ChurnHistory;
+use crate::state::concurrency::{QUEUE_STATUSES, RunQueue};
+use crate::state::flaky::{FLAKY_REPORT_RUNS, FlakyReport, flaky_job_names};
+use crate::state::home::{HOME_RECENT, HomeItem, failing_runs, is_failure};
+use crate::state::ignore::is_ignored;
+use crate::state::inbox::{inbox_id, sort_inbox};
+use crate::state::job_matrix::{group_jobs, matrix_parts, ungroup_jobs};
+use crate::state::job_summary::{JobSummaryView, summary_markdown};
+use crate::state::latest_runs::{latest_by_workflow, missing_workflows};
+use crate::state::live::{LiveRun, sort_live};
+use crate::state::log_archive::{LogArchiveView, extract_archive, list_archive};
+use crate::state::log_stream::LogStream;
+use crate::state::log_time::TimestampMode;
+use crate::state::log_view::{LogViewState, prune_log_views};
+use crate::state::log_viewer::LogViewerState;
+use crate::state::permalink::Permalink;
+use crate::state::policy::{PolicyParts, PolicySection, PolicyView};
+use crate::state::popup::PopupKey;
+use crate::state::prefetch::LogPrefetch;
+use crate::state::queue::load_queue_times;
+use crate::state::recent::{RecentItem, RecentJob, record_recent};
+use crate::state::run_groups::{group_runs, ungroup_runs};
+use crate::state::runners::{RunnerJob, RunnerRow, group_runners, jobs_by_runner};
+use crate::state::schedule::{UpcomingRun, WorkflowSchedule};
+use crate::state::secrets::{Inventory, SecretsView};
+use crate::state::usage::UsageHistory;
+use crate::state::utilization::RunnerUtilization;
+use crate::state::workspace::{Workspace, load_workspaces, save_workspaces, upsert_workspace};
 use crate::state::{
-    LoadingState, NavigationStack, RunnersNavStack, RunnersTabState, RunnersViewLevel, ViewLevel,
-    WorkflowsTabState,
+    JobHistory, LoadingState, NavContext, NavigationStack, RunnersNavStack, RunnersTabState,
+    RunnersViewLevel, SelectableList, ViewLevel, WorkflowsTabState,
 };
+use crate::tasks::{TaskKind, Tasks};
+use crate::title;
 use crate::ui;
+use crate::ui::caps::TermCaps;
+use crate::ui::highlight::Highlighter;
+
+/// How long the Runs selection must stay put before its preview loads.
+const PREVIEW_DELAY: Duration = Duration::from_millis(250);
+
+/// Pages of 100 branches fetched for the branch picker.
+const MAX_BRANCH_PAGES: u32 = 10;
+
+/// Hours of runner churn shown under the runners list.
+const CHURN_WINDOW_HOURS: i64 = 24;
+
+/// Time buckets in the runner churn chart.
+const CHURN_BUCKETS: usize = 24;
+
+/// How often the Live tab refreshes when auto-refresh is off.
+const LIVE_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Upcoming runs listed per favorite workflow in the schedule overview.
+const SCHEDULE_OVERVIEW_RUNS: usize = 3;
+
+/// Deployments listed per environment, each costing a status request.
+const RECENT_DEPLOYMENTS: u32 = 20;
+
+/// Lines scrolled by a page in the help overlay.
+const HELP_PAGE: u16 = 10;
+
+/// How often unfinished jobs in a Jobs list are refetched to update their steps.
+const JOB_TICK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Time spent reading a streaming log between redraws.
+const LOG_STREAM_BUDGET: Duration = Duration::from_millis(50);
+
+/// Job lists fetched at once when looking up what busy runners are running.
+const ENRICHMENT_CONCURRENCY: usize = 4;
+
+/// How often the runners of a shown Runners list are sampled for their
+/// utilization when auto-refresh is off.
+const RUNNER_SAMPLE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Requests per hour GitHub allows without a token.
+const UNAUTHENTICATED_RATE_LIMIT: u32 = 60;
 
 /// Active tab in the application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Tab {
-    Runners,
     #[default]
+    Home,
+    Runners,
     Workflows,
+    Inbox,
     Console,
+    Live,
 }
 
 impl Tab {
     pub fn title(&self) -> &'static str {
         match self {
+            Tab::Home => "Home",
             Tab::Runners => "Runners",
             Tab::Workflows => "Workflows",
+            Tab::Inbox => "Inbox",
             Tab::Console => "Console",
+            Tab::Live => "Live",
         }
     }
 
     pub fn next(&self) -> Self {
         match self {
+            Tab::Home => Tab::Runners,
             Tab::Runners => Tab::Workflows,
-            Tab::Workflows => Tab::Console,
-            Tab::Console => Tab::Runners,
+            Tab::Workflows => Tab::Inbox,
+            Tab::Inbox => Tab::Console,
+            Tab::Console => Tab::Live,
+            Tab::Live => Tab::Home,
         }
     }
 
     pub fn prev(&self) -> Self {
         match self {
-            Tab::Runners => Tab::Console,
+            Tab::Home => Tab::Live,
+            Tab::Runners => Tab::Home,
             Tab::Workflows => Tab::Runners,
-            Tab::Console => Tab::Workflows,
+            Tab::Inbox => Tab::Workflows,
+            Tab::Console => Tab::Inbox,
+            Tab::Live => Tab::Console,
         }
     }
 }
 
+/// Run awaiting confirmation before being re-run with debug logging.
+#[derive(Debug, Clone)]
+pub struct PendingRerun {
+    pub owner: String,
+    pub repo: String,
+    pub run_id: u64,
+    pub run_number: u64,
+    /// Attempt number the re-run will get.
+    pub attempt: u64,
+}
+
+/// Popup or text input that reads key presses ahead of the list and log
+/// views, in the order they take focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Overlay {
+    Help,
+    DebugLog,
+    Recent,
+    Permalink,
+    Workspaces,
+    Path,
+    CopyMenu,
+    ExportMenu,
+    BulkMenu,
+    BulkConfirm,
+    WhatsNew,
+    RestoreOffer,
+    RepoOffer,
+    FlakyReport,
+    RunQueue,
+    Policy,
+    Schedule,
+    Branches,
+    Caches,
+    LogArchive,
+    JobSummary,
+    Checks,
+    Secrets,
+    RerunConfirm,
+    Settings,
+    SearchInput,
+    FilterInput,
+}
+
+/// Bulk action awaiting confirmation before it's applied to marked items.
+#[derive(Debug, Clone)]
+pub struct PendingBulk {
+    pub action: BulkAction,
+    pub owner: String,
+    pub repo: String,
+    pub items: BulkItems,
+}
+
 /// Console message for the Console tab.
 #[derive(Debug, Clone)]
 pub struct ConsoleMessage {
     pub level: ConsoleLevel,
+    pub kind: ConsoleKind,
     pub message: String,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Run the message is about, opened with Enter.
+    pub target: Option<RecentItem>,
+}
+
+/// What a console message reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleKind {
+    /// Something jolt did, or failed to do.
+    Note,
+    /// A run of a favorite repository failed.
+    RunFailed,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -72,31 +242,75 @@ impl ConsoleMessage {
     pub fn error(message: impl Into<String>) -> Self {
         Self {
             level: ConsoleLevel::Error,
+            kind: ConsoleKind::Note,
             message: message.into(),
             timestamp: chrono::Utc::now(),
+            target: None,
         }
     }
 
     pub fn warn(message: impl Into<String>) -> Self {
         Self {
             level: ConsoleLevel::Warn,
+            kind: ConsoleKind::Note,
             message: message.into(),
             timestamp: chrono::Utc::now(),
+            target: None,
         }
     }
 
     pub fn info(message: impl Into<String>) -> Self {
         Self {
             level: ConsoleLevel::Info,
+            kind: ConsoleKind::Note,
             message: message.into(),
             timestamp: chrono::Utc::now(),
+            target: None,
+        }
+    }
+
+    /// A run that failed in `owner/repo`, opened with Enter.
+    pub fn run_failed(owner: &str, repo: &str, run: &WorkflowRun) -> Self {
+        let workflow_name = run.name.clone().unwrap_or_default();
+        Self {
+            level: ConsoleLevel::Error,
+            kind: ConsoleKind::RunFailed,
+            message: format!(
+                "Run #{} of {} failed in {}/{}",
+                run.run_number, workflow_name, owner, repo
+            ),
+            timestamp: chrono::Utc::now(),
+            target: Some(RecentItem {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                workflow_id: run.workflow_id,
+                workflow_name,
+                run_id: run.id,
+                run_number: run.run_number,
+                job: None,
+                viewed_at: chrono::Utc::now(),
+            }),
         }
     }
 }
 
+/// Format version of the persisted state, bumped when a change needs a migration.
+const STATE_VERSION: u32 = 1;
+
+/// Migrations of persisted state to the next version, indexed by the version
+/// they upgrade from.
+const STATE_MIGRATIONS: [fn(&mut serde_json::Value); STATE_VERSION as usize] = [
+    // 0 → 1: the version field was added; nothing else changed
+    |_| {},
+];
+
 /// Persisted application state saved between sessions.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct PersistedState {
+    /// Format version the state was saved with; 0 for state saved before
+    /// versioning.
+    #[serde(default)]
+    pub version: u32,
     /// Last active tab.
     pub active_tab: Tab,
     /// Workflows tab navigation stack.
@@ -117,32 +331,90 @@ pub struct PersistedState {
     /// Favorite runners (as "owner/repo/runner_name").
     #[serde(default)]
     pub favorite_runners: HashSet<String>,
+    /// Repositories hidden from the Runners tab (as "owner/repo").
+    #[serde(default)]
+    pub hidden_runner_repos: HashSet<String>,
+    /// Whether the Runs list shows a preview pane.
+    #[serde(default)]
+    pub split_view: bool,
+    /// Run attempts started with debug logging (as "run_id/attempt").
+    #[serde(default)]
+    pub debug_runs: HashSet<String>,
+    /// Whether Runs lists only show runs the user triggered.
+    #[serde(default)]
+    pub my_runs_only: bool,
+    /// Whether Runs lists are grouped by trigger event.
+    #[serde(default)]
+    pub group_runs_by_event: bool,
+    /// Whether Jobs lists nest matrix variants under their base job.
+    #[serde(default)]
+    pub group_jobs_by_matrix: bool,
+    /// Recently viewed runs and jobs, most recent first.
+    #[serde(default)]
+    pub recent: Vec<RecentItem>,
+    /// Version of jolt that last saved this state, to show what's new after upgrading.
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    /// How log line timestamps are shown.
+    #[serde(default)]
+    pub log_timestamps: TimestampMode,
+    /// Log viewer state (e.g., bookmarks) by job ID.
+    #[serde(default)]
+    pub log_views: HashMap<u64, LogViewState>,
+    /// Default branches of repositories, by "owner/repo".
+    #[serde(default)]
+    pub default_branches: HashMap<String, String>,
 }
 
 impl PersistedState {
-    /// Load persisted state from disk.
-    #[allow(clippy::collapsible_if)]
+    /// Load persisted state from disk, falling back to the backup of the
+    /// previous save when the state file is missing or unreadable.
     pub fn load() -> Self {
-        if let Some(path) = cache::state_path() {
-            if let Ok(contents) = std::fs::read_to_string(&path) {
-                if let Ok(state) = serde_json::from_str(&contents) {
-                    return state;
-                }
-            }
+        [cache::state_path(), cache::state_backup_path()]
+            .into_iter()
+            .flatten()
+            .find_map(|path| Self::read(&path))
+            .unwrap_or_default()
+    }
+
+    /// Read a state file, migrating state saved by older versions.
+    fn read(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        let mut value: serde_json::Value = serde_json::from_str(&contents).ok()?;
+        let version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        for migrate in STATE_MIGRATIONS.iter().skip(version as usize) {
+            migrate(&mut value);
         }
-        Self::default()
+        serde_json::from_value(value).ok()
     }
 
-    /// Save persisted state to disk.
-    pub fn save(&self) {
-        if let Some(path) = cache::state_path() {
-            if let Some(parent) = path.parent() {
-                let _ = std::fs::create_dir_all(parent);
-            }
-            if let Ok(json) = serde_json::to_string_pretty(self) {
-                let _ = std::fs::write(&path, json);
-            }
+    /// Take the state of a session that crashed, if the last one did.
+    pub fn take_crashed() -> Option<Self> {
+        let path = cache::crash_state_path()?;
+        let state = Self::read(&path);
+        let _ = std::fs::remove_file(&path);
+        state.filter(|state| state.workflows_nav.is_some() || state.runners_nav.is_some())
+    }
+
+    /// Save persisted state to disk, keeping the previous save as a backup.
+    pub fn save(&self) -> crate::error::Result<()> {
+        let (Some(path), Some(backup)) = (cache::state_path(), cache::state_backup_path()) else {
+            return Ok(());
+        };
+        self.save_to(&path, &backup)
+    }
+
+    /// Save to `path`, first copying a readable previous save to `backup`.
+    /// The file is replaced atomically so a crash mid-write can't corrupt it.
+    fn save_to(&self, path: &Path, backup: &Path) -> crate::error::Result<()> {
+        if Self::read(path).is_some() {
+            std::fs::copy(path, backup)?;
         }
+        let json = serde_json::to_string_pretty(self)?;
+        cache::write_text(path, &json)
     }
 }
 
@@ -150,6 +422,14 @@ impl PersistedState {
 pub struct App {
     /// Currently active tab.
     pub active_tab: Tab,
+    /// Styling the terminal supports, detected at startup.
+    pub term_caps: TermCaps,
+    /// Whether --ascii was given, keeping glyphs plain for the session
+    /// whatever the ascii setting says.
+    pub ascii_flag: bool,
+    /// Where the frame being drawn shows log text, whose glyphs stay as the
+    /// log has them in ASCII mode.
+    pub log_text_areas: Vec<Rect>,
     /// Number of unread console errors (for badge).
     pub console_unread: usize,
     /// Console messages.
@@ -160,10 +440,117 @@ pub struct App {
     pub should_quit: bool,
     /// Whether to show the help overlay.
     pub show_help: bool,
+    /// Lines the help overlay's shortcuts are scrolled down by.
+    pub help_scroll: u16,
+    /// Whether to show the debug overlay tailing the application log.
+    pub show_debug_log: bool,
+    /// Runtime settings from the config file.
+    pub config: Config,
+    /// Log highlight rules compiled from the config.
+    pub log_highlighter: Highlighter,
+    /// Whether to show the settings overlay.
+    pub show_settings: bool,
+    /// Whether to show the full breadcrumb path popup.
+    pub show_path: bool,
+    /// Level highlighted in the path popup (0 = root).
+    pub path_selected: usize,
+    /// Recently viewed runs and jobs, most recent first.
+    pub recent: Vec<RecentItem>,
+    /// Whether to show the Recent picker.
+    pub show_recent: bool,
+    /// Row highlighted in the Recent picker.
+    pub recent_selected: usize,
+    /// Saved workspaces, most recent first, loaded when the picker opens.
+    pub workspaces: Vec<Workspace>,
+    /// Whether to show the Workspaces picker.
+    pub show_workspaces: bool,
+    /// Row highlighted in the Workspaces picker.
+    pub workspace_selected: usize,
+    /// Name being typed to save the current workspace.
+    pub workspace_name: Option<String>,
+    /// GitHub Actions URL being typed or pasted to open.
+    pub permalink_input: Option<String>,
+    /// Whether to show the copy mode picker for the current log line.
+    pub show_copy_menu: bool,
+    /// Whether to show the format picker for exporting the current list.
+    pub show_export_menu: bool,
+    /// Whether to show the bulk action picker for marked items.
+    pub show_bulk_menu: bool,
+    /// Bulk action waiting for the user to confirm it.
+    pub pending_bulk: Option<PendingBulk>,
+    /// Run waiting for the user to confirm a debug re-run.
+    pub pending_rerun: Option<PendingRerun>,
+    /// Capabilities added since the last version run, shown once after upgrading.
+    pub whats_new: Option<Vec<Release>>,
+    /// Repository of the current directory, offered at startup (owner, repo).
+    pub repo_offer: Option<(String, String)>,
+    /// Session that crashed, offered for restoring at startup.
+    pub crashed_session: Option<PersistedState>,
+    /// Flaky jobs report for the current workflow, when shown.
+    pub flaky_report: Option<FlakyReport>,
+    /// Unfinished runs of the current repository or workflow, when shown.
+    pub run_queue: Option<RunQueue>,
+    /// Upcoming scheduled runs of favorite workflows, when shown.
+    pub schedule_overview: Option<Vec<UpcomingRun>>,
+    /// Actions cache entries of the current repository, when shown.
+    pub caches_view: Option<CachesView>,
+    /// Secrets and variables of the current repository, when shown.
+    pub secrets_view: Option<SecretsView>,
+    /// Actions permissions and policy of the current repository, when shown.
+    pub policy_view: Option<PolicyView>,
+    /// Check runs of a run's commit from every CI provider, shown in a popup.
+    pub checks_view: Option<ChecksView>,
+    /// Extracted log archive of a run, browsed in a popup.
+    pub log_archive: Option<LogArchiveView>,
+    /// Summary a job published, shown in a popup.
+    pub job_summary: Option<JobSummaryView>,
+    /// Job log still downloading in a tab, shown as far as it has arrived.
+    pub log_stream: Option<(Tab, LogStream)>,
+    /// Requests running in the background, such as list revalidation.
+    tasks: Tasks<Loaded>,
+    /// Branches to narrow the Runs list to, when shown.
+    pub branch_picker: Option<BranchPicker>,
+    /// Default branches of repositories, by "owner/repo", kept across
+    /// sessions so Runs lists open narrowed without asking GitHub first.
+    pub default_branches: HashMap<String, String>,
+    /// Run attempts started with debug logging (as "run_id/attempt").
+    pub debug_runs: HashSet<String>,
+    /// Selected row in the settings overlay.
+    pub settings_selected: usize,
+    /// When the current view was last auto-refreshed.
+    last_auto_refresh: Instant,
+    /// When unfinished jobs in the Jobs list were last refetched.
+    last_job_tick: Instant,
+    /// Whether the Runs list shows the selected run's jobs in a preview pane.
+    pub split_view: bool,
+    /// How log line timestamps are shown.
+    pub log_timestamps: TimestampMode,
+    /// Log viewer state (e.g., bookmarks) by job ID.
+    pub log_views: HashMap<u64, LogViewState>,
+    /// When the last key was pressed, to let the selection settle before previewing.
+    last_input: Instant,
+    /// Whether Runs lists only show runs the user triggered.
+    pub my_runs_only: bool,
+    /// Whether Runs lists are grouped by trigger event.
+    pub group_runs_by_event: bool,
+    /// Terminal title last set and where, so it's only sent when either changes.
+    window_title: (String, WindowTitle),
+    /// Trigger events whose group is folded to its latest run.
+    pub collapsed_events: HashSet<String>,
+    /// Whether Jobs lists nest matrix variants under their base job.
+    pub group_jobs_by_matrix: bool,
+    /// Matrix jobs whose variants are folded to one.
+    pub collapsed_matrix: HashSet<String>,
+    /// Whether Jobs lists only show matrix variants that failed.
+    pub failed_variants_only: bool,
+    /// Login of the authenticated user, fetched when first needed.
+    current_user: Option<String>,
     /// Whether search input is active.
     pub search_active: bool,
     /// Current search query.
     pub search_query: String,
+    /// Whether typing edits the current list's filter.
+    pub filter_active: bool,
     /// Line numbers containing search matches.
     pub search_matches: Vec<usize>,
     /// Index of current match in search_matches.
@@ -174,24 +561,61 @@ pub struct App {
     pub workflows: WorkflowsTabState,
     /// Runners tab state.
     pub runners: RunnersTabState,
+    /// Pull requests awaiting the user, failing checks first.
+    pub inbox: SelectableList<InboxPullRequest>,
+    /// In-progress runs across favorite repositories, longest running first.
+    pub live: SelectableList<LiveRun>,
+    /// Home dashboard of favorites needing attention, by section.
+    pub home: SelectableList<HomeItem>,
+    /// Busy samples of runners whose lists were fetched this session.
+    pub runner_utilization: RunnerUtilization,
+    /// Requests and log downloads per day, across sessions.
+    pub usage: UsageHistory,
+    /// The client's session totals of requests and log downloads already
+    /// added to `usage`.
+    usage_counted: (u64, u64),
     /// Favorite owners.
     pub favorite_owners: HashSet<String>,
     /// Favorite repositories.
     pub favorite_repos: HashSet<String>,
+    /// Repositories the metrics exporter collects, when it's running.
+    pub metrics_repos: Option<tokio::sync::watch::Sender<Vec<String>>>,
     /// Favorite workflows.
     pub favorite_workflows: HashSet<String>,
     /// Favorite runners.
     pub favorite_runners: HashSet<String>,
+    /// Repositories hidden from the Runners tab.
+    pub hidden_runner_repos: HashSet<String>,
 }
 
 impl App {
     pub fn new() -> Self {
         // Load persisted state from previous session
+        let upgraded = cache::state_path().is_some_and(|path| path.exists());
         let persisted = PersistedState::load();
+        // Fresh installs skip the panel; states saved before versions were tracked see everything
+        let whats_new = upgraded
+            .then(|| {
+                let last_seen = persisted.last_seen_version.as_deref().unwrap_or("0.0.0");
+                changelog::new_since(changelog::releases(), last_seen, CURRENT_VERSION)
+            })
+            .filter(|releases| !releases.is_empty());
+        let (config, config_error) = match Config::load() {
+            Ok(config) => (config, None),
+            Err(e) => (Config::default(), Some(e)),
+        };
 
-        // Try to create GitHub client from env
-        let github_client = match GitHubClient::from_env() {
-            Ok(client) => Some(client),
+        // Try to create GitHub client from env, browsing public repositories
+        // unauthenticated without a token
+        let mut anonymous = false;
+        let github_client = match GitHubClient::from_env_with_config(&config.http) {
+            Ok(client) => Some(client.interactive()),
+            Err(JoltError::MissingToken) => {
+                anonymous = true;
+                GitHubClient::anonymous(&config.http)
+                    .ok()
+                    .map(GitHubClient::interactive)
+            }
             Err(e) => {
                 // Will show error in console tab
                 eprintln!("GitHub client error: {}", e);
@@ -199,6 +623,35 @@ impl App {
             }
         };
 
+        let term_caps = TermCaps {
+            ascii: config.ascii,
+            ..TermCaps::detect()
+        };
+        let mut app = Self::with_state(persisted, config, github_client, whats_new, term_caps);
+        app.crashed_session = PersistedState::take_crashed();
+        app.usage = UsageHistory::load();
+        if let Some(e) = config_error {
+            app.log_warn(format!("Using default settings: {}", e));
+        }
+        if anonymous {
+            app.log_warn(format!(
+                "No GITHUB_TOKEN set: browsing public repositories unauthenticated, \
+                 limited to {} requests/hour",
+                UNAUTHENTICATED_RATE_LIMIT
+            ));
+        }
+        app
+    }
+
+    /// Create the app from already loaded state, without touching the
+    /// environment or disk (used directly by rendering tests).
+    pub fn with_state(
+        persisted: PersistedState,
+        config: Config,
+        github_client: Option<GitHubClient>,
+        whats_new: Option<Vec<Release>>,
+        term_caps: TermCaps,
+    ) -> Self {
         // Create tab states and restore navigation if available
         let mut workflows = WorkflowsTabState::new();
         if let Some(nav) = persisted.workflows_nav {
@@ -208,31 +661,121 @@ impl App {
         if let Some(nav) = persisted.runners_nav {
             runners.nav = nav;
         }
+        let (log_highlighter, highlight_errors) = Highlighter::new(&config.log_highlights);
+        let mut log_views = persisted.log_views;
+        prune_log_views(&mut log_views);
 
         Self {
-            active_tab: persisted.active_tab,
+            active_tab: config.default_tab.unwrap_or(persisted.active_tab),
+            term_caps,
+            ascii_flag: false,
+            log_text_areas: Vec::new(),
             console_unread: 0,
-            console_messages: Vec::new(),
+            console_messages: highlight_errors
+                .into_iter()
+                .map(ConsoleMessage::warn)
+                .collect(),
             console_list_state: ListState::default(),
             should_quit: false,
             show_help: false,
+            help_scroll: 0,
+            show_debug_log: false,
+            config,
+            log_highlighter,
+            show_settings: false,
+            show_path: false,
+            path_selected: 0,
+            recent: persisted.recent,
+            show_recent: false,
+            recent_selected: 0,
+            workspaces: Vec::new(),
+            show_workspaces: false,
+            workspace_selected: 0,
+            workspace_name: None,
+            permalink_input: None,
+            show_copy_menu: false,
+            show_export_menu: false,
+            show_bulk_menu: false,
+            metrics_repos: None,
+            pending_bulk: None,
+            pending_rerun: None,
+            whats_new,
+            repo_offer: None,
+            crashed_session: None,
+            flaky_report: None,
+            run_queue: None,
+            schedule_overview: None,
+            caches_view: None,
+            secrets_view: None,
+            policy_view: None,
+            checks_view: None,
+            log_archive: None,
+            job_summary: None,
+            log_stream: None,
+            tasks: Tasks::default(),
+            branch_picker: None,
+            default_branches: persisted.default_branches,
+            debug_runs: persisted.debug_runs,
+            settings_selected: 0,
+            last_auto_refresh: Instant::now(),
+            last_job_tick: Instant::now(),
+            split_view: persisted.split_view,
+            log_timestamps: persisted.log_timestamps,
+            log_views,
+            last_input: Instant::now(),
+            my_runs_only: persisted.my_runs_only,
+            group_runs_by_event: persisted.group_runs_by_event,
+            window_title: (String::new(), WindowTitle::Off),
+            collapsed_events: HashSet::new(),
+            group_jobs_by_matrix: persisted.group_jobs_by_matrix,
+            collapsed_matrix: HashSet::new(),
+            failed_variants_only: false,
+            current_user: None,
             search_active: false,
+            filter_active: false,
             search_query: String::new(),
             search_matches: Vec::new(),
             search_match_index: 0,
             github_client,
             workflows,
             runners,
+            inbox: SelectableList::with_id_of(inbox_id),
+            live: SelectableList::with_id_of(|live| live.run.id),
+            home: SelectableList::with_id_of(HomeItem::id),
+            runner_utilization: RunnerUtilization::default(),
+            usage: UsageHistory::default(),
+            usage_counted: (0, 0),
             favorite_owners: persisted.favorite_owners,
             favorite_repos: persisted.favorite_repos,
             favorite_workflows: persisted.favorite_workflows,
             favorite_runners: persisted.favorite_runners,
+            hidden_runner_repos: persisted.hidden_runner_repos,
         }
     }
 
     /// Save application state for next session.
-    pub fn save_state(&self) {
-        let state = PersistedState {
+    pub fn save_state(&self) -> crate::error::Result<()> {
+        self.usage.save();
+        self.persisted_state().save()
+    }
+
+    /// Save state after a crash. The next session starts fresh and offers to
+    /// restore where this one was, in case going back there crashes again.
+    pub fn save_crash_state(&self) -> crate::error::Result<()> {
+        self.usage.save();
+        let mut state = self.persisted_state();
+        if let Some(path) = cache::crash_state_path() {
+            cache::write_text(&path, &serde_json::to_string_pretty(&state)?)?;
+        }
+        state.workflows_nav = None;
+        state.runners_nav = None;
+        state.save()
+    }
+
+    /// State to save for the next session.
+    fn persisted_state(&self) -> PersistedState {
+        PersistedState {
+            version: STATE_VERSION,
             active_tab: self.active_tab,
             workflows_nav: Some(self.workflows.nav.clone()),
             runners_nav: Some(self.runners.nav.clone()),
@@ -240,253 +783,1185 @@ impl App {
             favorite_repos: self.favorite_repos.clone(),
             favorite_workflows: self.favorite_workflows.clone(),
             favorite_runners: self.favorite_runners.clone(),
-        };
-        state.save();
+            hidden_runner_repos: self.hidden_runner_repos.clone(),
+            split_view: self.split_view,
+            debug_runs: self.debug_runs.clone(),
+            my_runs_only: self.my_runs_only,
+            group_runs_by_event: self.group_runs_by_event,
+            group_jobs_by_matrix: self.group_jobs_by_matrix,
+            recent: self.recent.clone(),
+            last_seen_version: Some(CURRENT_VERSION.to_string()),
+            log_timestamps: self.log_timestamps,
+            log_views: self.log_views.clone(),
+            default_branches: self.default_branches.clone(),
+        }
     }
 
     /// Main event loop.
     pub async fn run(&mut self, terminal: &mut Terminal<impl Backend>) -> io::Result<()> {
         // Initial data load for active tab
+        self.check_token_scopes().await;
+        self.start_metrics_exporter().await;
         self.on_tab_change().await;
 
         while !self.should_quit {
             terminal.draw(|frame| ui::draw(frame, self))?;
+            self.update_window_title();
+            self.enrich_runners();
             self.handle_events().await?;
+            self.record_log_state();
+            self.auto_refresh().await;
+            self.revalidate().await;
+            self.apply_tasks().await;
+            self.update_preview().await;
+            self.prefetch_logs();
+            self.stream_logs().await;
+            self.tick_running_jobs();
+            self.report_slow_endpoints();
+            self.report_retries();
+            self.count_usage();
         }
 
         // Save state for next session
-        self.save_state();
-        Ok(())
+        self.save_state()
+            .map_err(|e| io::Error::other(format!("Failed to save state: {}", e)))
+    }
+
+    /// Add the requests and log downloads made since the last call to
+    /// today's usage.
+    fn count_usage(&mut self) {
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        let counts = (client.session_usage().0, client.log_downloads());
+        let (requests, log_downloads) = (
+            counts.0.saturating_sub(self.usage_counted.0),
+            counts.1.saturating_sub(self.usage_counted.1),
+        );
+        if requests > 0 || log_downloads > 0 {
+            let today = chrono::Local::now().date_naive();
+            self.usage.record(requests, log_downloads, today);
+            self.usage_counted = counts;
+        }
     }
 
     /// Handle keyboard and other events.
-    #[allow(clippy::collapsible_if)]
     async fn handle_events(&mut self) -> io::Result<()> {
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    // When help is shown, only handle close keys
-                    if self.show_help {
-                        match key.code {
-                            KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => {
-                                self.show_help = false;
-                            }
-                            _ => {}
-                        }
-                        return Ok(());
-                    }
+        // Don't wait for input while a log is streaming in
+        let timeout = if self.log_stream.is_some() {
+            Duration::ZERO
+        } else {
+            Duration::from_millis(100)
+        };
+        if event::poll(timeout)? {
+            let message = match event::read()? {
+                Event::Paste(text) => Message::Paste(text),
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    self.last_input = Instant::now();
+                    Message::Key(key)
+                }
+                _ => return Ok(()),
+            };
+            if let Some(command) = self.update(message) {
+                self.run_command(command).await;
+            }
+        }
+        Ok(())
+    }
 
-                    // When search input is active, capture text input
-                    if self.search_active {
-                        match key.code {
-                            KeyCode::Esc => {
-                                self.search_active = false;
-                                self.search_query.clear();
-                                self.search_matches.clear();
-                            }
-                            KeyCode::Enter => {
-                                self.search_active = false;
-                                self.execute_search();
-                            }
-                            KeyCode::Backspace => {
-                                self.search_query.pop();
-                            }
-                            KeyCode::Char(c) => {
-                                self.search_query.push(c);
-                            }
-                            _ => {}
-                        }
-                        return Ok(());
-                    }
+    /// Apply a message to the state, returning the async work it leaves, if any.
+    fn update(&mut self, message: Message) -> Option<Command> {
+        match message {
+            Message::Key(key) => match self.focused_overlay() {
+                Some(overlay) => self.update_overlay(overlay, key),
+                None => Action::from_key(key).and_then(|action| self.update_action(action)),
+            },
+            // Pasted text only goes to the permalink input
+            Message::Paste(text) => {
+                if let Some(input) = self.permalink_input.as_mut() {
+                    input.push_str(text.trim());
+                }
+                None
+            }
+        }
+    }
 
-                    // Handle Ctrl modifier keys first
-                    if key.modifiers.contains(KeyModifiers::CONTROL) {
-                        match key.code {
-                            KeyCode::Char('d') => self.handle_page_down(),
-                            KeyCode::Char('u') => self.handle_page_up(),
-                            KeyCode::Char('f') => self.handle_page_down(),
-                            KeyCode::Char('b') => self.handle_page_up(),
-                            _ => {}
-                        }
-                        return Ok(());
-                    }
+    /// Overlay reading key presses ahead of the list and log views, if one is open.
+    fn focused_overlay(&self) -> Option<Overlay> {
+        [
+            (self.show_help, Overlay::Help),
+            (self.show_debug_log, Overlay::DebugLog),
+            (self.show_recent, Overlay::Recent),
+            (self.permalink_input.is_some(), Overlay::Permalink),
+            (self.show_workspaces, Overlay::Workspaces),
+            (self.show_path, Overlay::Path),
+            (self.show_copy_menu, Overlay::CopyMenu),
+            (self.show_export_menu, Overlay::ExportMenu),
+            (self.show_bulk_menu, Overlay::BulkMenu),
+            (self.pending_bulk.is_some(), Overlay::BulkConfirm),
+            (self.whats_new.is_some(), Overlay::WhatsNew),
+            (self.crashed_session.is_some(), Overlay::RestoreOffer),
+            (self.repo_offer.is_some(), Overlay::RepoOffer),
+            (self.flaky_report.is_some(), Overlay::FlakyReport),
+            (self.run_queue.is_some(), Overlay::RunQueue),
+            (self.policy_view.is_some(), Overlay::Policy),
+            (self.schedule_overview.is_some(), Overlay::Schedule),
+            (self.branch_picker.is_some(), Overlay::Branches),
+            (self.caches_view.is_some(), Overlay::Caches),
+            (self.log_archive.is_some(), Overlay::LogArchive),
+            (self.job_summary.is_some(), Overlay::JobSummary),
+            (self.checks_view.is_some(), Overlay::Checks),
+            (self.secrets_view.is_some(), Overlay::Secrets),
+            (self.pending_rerun.is_some(), Overlay::RerunConfirm),
+            (self.show_settings, Overlay::Settings),
+            (self.search_active, Overlay::SearchInput),
+            (self.filter_active, Overlay::FilterInput),
+        ]
+        .into_iter()
+        .find(|(open, _)| *open)
+        .map(|(_, overlay)| overlay)
+    }
 
-                    match key.code {
-                        KeyCode::Char('q') => self.should_quit = true,
-                        KeyCode::Char('?') => self.show_help = true,
-                        KeyCode::Tab => {
-                            self.active_tab = self.active_tab.next();
-                            self.clear_console_badge_if_viewing();
-                            self.on_tab_change().await;
-                        }
-                        KeyCode::BackTab => {
-                            self.active_tab = self.active_tab.prev();
-                            self.clear_console_badge_if_viewing();
-                            self.on_tab_change().await;
-                        }
-                        // Direct tab selection
-                        KeyCode::Char('1') => {
-                            self.active_tab = Tab::Runners;
-                            self.clear_console_badge_if_viewing();
-                            self.on_tab_change().await;
-                        }
-                        KeyCode::Char('2') => {
-                            self.active_tab = Tab::Workflows;
-                            self.clear_console_badge_if_viewing();
-                            self.on_tab_change().await;
-                        }
-                        KeyCode::Char('3') => {
-                            self.active_tab = Tab::Console;
-                            self.clear_console_badge_if_viewing();
-                            self.on_tab_change().await;
-                        }
-                        // Arrow keys
-                        KeyCode::Up => self.handle_up(),
-                        KeyCode::Down => self.handle_down(),
-                        KeyCode::Left => self.handle_left(),
-                        KeyCode::Right => self.handle_right(),
-                        // Vim navigation
-                        KeyCode::Char('k') => self.handle_up(),
-                        KeyCode::Char('j') => self.handle_down(),
-                        KeyCode::Char('h') => self.handle_left(),
-                        KeyCode::Char('l') => self.handle_right(),
-                        // Page navigation
-                        KeyCode::PageUp => self.handle_page_up(),
-                        KeyCode::PageDown => self.handle_page_down(),
-                        // Jump to start/end
-                        KeyCode::Home => self.handle_home(),
-                        KeyCode::End => self.handle_end(),
-                        KeyCode::Char('g') => self.handle_home(),
-                        KeyCode::Char('G') => self.handle_end(),
-                        // Actions
-                        KeyCode::Enter => self.handle_enter().await,
-                        KeyCode::Esc => self.handle_escape().await,
-                        KeyCode::Char('r') => self.handle_refresh().await,
-                        KeyCode::Char('/') => self.handle_search_start(),
-                        KeyCode::Char('o') => self.handle_open_in_browser(),
-                        KeyCode::Char('f') => self.toggle_favorite(),
-                        // Search navigation
-                        KeyCode::Char('n') => self.search_next(),
-                        KeyCode::Char('N') => self.search_prev(),
-                        _ => {}
+    /// Apply a key press to the focused overlay.
+    fn update_overlay(&mut self, overlay: Overlay, key: KeyEvent) -> Option<Command> {
+        let code = overlay_key(key);
+        match overlay {
+            // Only close keys close help, and the others scroll it
+            Overlay::Help => match code {
+                KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => self.show_help = false,
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.help_scroll = self.help_scroll.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.help_scroll = self.help_scroll.saturating_add(1)
+                }
+                KeyCode::PageUp => self.help_scroll = self.help_scroll.saturating_sub(HELP_PAGE),
+                KeyCode::PageDown => self.help_scroll = self.help_scroll.saturating_add(HELP_PAGE),
+                KeyCode::Home | KeyCode::Char('g') => self.help_scroll = 0,
+                // Clamped to the last page when drawn
+                KeyCode::End | KeyCode::Char('G') => self.help_scroll = u16::MAX,
+                _ => {}
+            },
+            Overlay::DebugLog => self.show_debug_log = false,
+            Overlay::Recent => return self.update_recent_picker(code),
+            Overlay::Permalink => return self.update_permalink_input(key),
+            Overlay::Workspaces => return self.update_workspaces_picker(code),
+            Overlay::Path => return self.update_path_popup(code),
+            // A mode key copies and any other key cancels
+            Overlay::CopyMenu => {
+                self.show_copy_menu = false;
+                let mode = match code {
+                    KeyCode::Char(c) => CopyMode::ALL.into_iter().find(|m| m.key() == c),
+                    _ => None,
+                };
+                if let Some(mode) = mode {
+                    self.copy_log_excerpt(mode);
+                }
+            }
+            // A format key exports and any other key cancels
+            Overlay::ExportMenu => {
+                self.show_export_menu = false;
+                let format = match code {
+                    KeyCode::Char(c) => ExportFormat::ALL.into_iter().find(|f| f.key() == c),
+                    _ => None,
+                };
+                if let Some(format) = format {
+                    self.export_list(format);
+                }
+            }
+            // An action key picks it and any other key cancels
+            Overlay::BulkMenu => {
+                self.show_bulk_menu = false;
+                if let (KeyCode::Char(c), Some((owner, repo, items))) = (code, self.marked_items())
+                {
+                    let action = BulkAction::available(&items)
+                        .into_iter()
+                        .find(|a| a.key() == c);
+                    if let Some(action) = action {
+                        return self.choose_bulk_action(action, owner, repo, items);
                     }
                 }
             }
+            // Only `y` applies a bulk action awaiting confirmation
+            Overlay::BulkConfirm => {
+                let bulk = self.pending_bulk.take()?;
+                if code == KeyCode::Char('y') {
+                    return Some(Command::ApplyBulk(Box::new(bulk)));
+                }
+            }
+            Overlay::WhatsNew => self.whats_new = None,
+            // Enter restores the session that crashed and any other key starts fresh
+            Overlay::RestoreOffer => {
+                let session = self.crashed_session.take()?;
+                if code == KeyCode::Enter {
+                    self.restore_session(session);
+                    return Some(Command::LoadTab);
+                }
+            }
+            // Enter opens the current directory's repository and any other key
+            // dismisses the offer
+            Overlay::RepoOffer => {
+                let (owner, repo) = self.repo_offer.take()?;
+                if code == KeyCode::Enter {
+                    self.show_repo_workflows(&owner, &repo);
+                    return Some(Command::LoadTab);
+                }
+            }
+            Overlay::FlakyReport => self.flaky_report = None,
+            Overlay::RunQueue => self.run_queue = None,
+            Overlay::Policy => self.policy_view = None,
+            Overlay::Schedule => self.schedule_overview = None,
+            Overlay::Branches => match self.branch_picker.as_mut()?.update(code) {
+                PopupKey::Stay => {}
+                PopupKey::Close => self.branch_picker = None,
+                PopupKey::Act(branch) => {
+                    self.branch_picker = None;
+                    return Some(Command::SetBranch(branch));
+                }
+            },
+            Overlay::Caches => match self.caches_view.as_mut()?.update(code) {
+                PopupKey::Stay => {}
+                PopupKey::Close => self.caches_view = None,
+                PopupKey::Act(()) => return Some(Command::DeleteCache),
+            },
+            Overlay::LogArchive => match self.log_archive.as_mut()?.update(code) {
+                Ok(PopupKey::Stay | PopupKey::Act(())) => {}
+                Ok(PopupKey::Close) => self.log_archive = None,
+                Err(e) => self.log_error(format!("Failed to read archived log: {}", e)),
+            },
+            Overlay::JobSummary => {
+                if self.job_summary.as_mut()?.update(code) == PopupKey::Close {
+                    self.job_summary = None;
+                }
+            }
+            Overlay::Checks => match self.checks_view.as_mut()?.update(code) {
+                PopupKey::Stay => {}
+                PopupKey::Close => self.checks_view = None,
+                PopupKey::Act(url) => self.open_url(&url),
+            },
+            Overlay::Secrets => {
+                if self.secrets_view.as_mut()?.update(code) == PopupKey::Close {
+                    self.secrets_view = None;
+                }
+            }
+            // Only `y` confirms a debug re-run
+            Overlay::RerunConfirm => {
+                let rerun = self.pending_rerun.take()?;
+                if code == KeyCode::Char('y') {
+                    return Some(Command::DebugRerun(rerun));
+                }
+            }
+            Overlay::Settings => self.handle_settings_key(code),
+            Overlay::SearchInput => self.update_search_input(key),
+            Overlay::FilterInput => self.update_filter_input(key),
         }
-        Ok(())
+        None
     }
 
-    /// Handle up arrow key.
-    fn handle_up(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.select_prev(),
-            Tab::Runners => self.runners.select_prev(),
-            Tab::Console => self.console_select_prev(),
+    /// Handle a key in the Recent picker: arrows move, Enter reopens, and any
+    /// other key closes it.
+    fn update_recent_picker(&mut self, code: KeyCode) -> Option<Command> {
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.recent_selected = self.recent_selected.saturating_sub(1);
+                return None;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.recent_selected =
+                    (self.recent_selected + 1).min(self.recent.len().saturating_sub(1));
+                return None;
+            }
+            _ => {}
         }
+        self.show_recent = false;
+        let item = self.recent.get(self.recent_selected).cloned();
+        item.filter(|_| code == KeyCode::Enter)
+            .map(Command::OpenRecent)
     }
 
-    /// Handle down arrow key.
-    fn handle_down(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.select_next(),
-            Tab::Runners => self.runners.select_next(),
-            Tab::Console => self.console_select_next(),
+    /// Handle a key in the path popup: a level's number or Enter jumps to it,
+    /// arrows move the highlight, and any other key closes it.
+    fn update_path_popup(&mut self, code: KeyCode) -> Option<Command> {
+        let last = self.nav_depth().saturating_sub(1);
+        let level = match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.path_selected = self.path_selected.saturating_sub(1);
+                return None;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.path_selected = (self.path_selected + 1).min(last);
+                return None;
+            }
+            KeyCode::Enter => Some(self.path_selected),
+            KeyCode::Char(c @ '1'..='9') => Some(c as usize - '1' as usize),
+            _ => None,
+        };
+        self.show_path = false;
+        level.map(Command::JumpToLevel)
+    }
+
+    /// Handle a key while a search query is typed.
+    fn update_search_input(&mut self, key: KeyEvent) {
+        if is_clear_input(key) {
+            self.search_query.clear();
+            return;
+        }
+        match overlay_key(key) {
+            KeyCode::Esc => {
+                self.search_active = false;
+                self.search_query.clear();
+                self.search_matches.clear();
+            }
+            KeyCode::Enter => {
+                self.search_active = false;
+                self.execute_search();
+            }
+            KeyCode::Backspace => {
+                self.search_query.pop();
+            }
+            KeyCode::Char(c) => {
+                self.search_query.push(c);
+            }
+            _ => {}
         }
     }
 
-    /// Handle left arrow key.
-    fn handle_left(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.scroll_left(),
-            Tab::Runners => self.runners.scroll_left(),
-            Tab::Console => {}
+    /// Handle a key while a list filter is typed.
+    fn update_filter_input(&mut self, key: KeyEvent) {
+        if is_clear_input(key) {
+            self.set_list_filter(Some(String::new()));
+            return;
+        }
+        match overlay_key(key) {
+            KeyCode::Esc => {
+                self.filter_active = false;
+                self.set_list_filter(None);
+            }
+            KeyCode::Enter => {
+                self.filter_active = false;
+                if self.list_filter().is_some_and(str::is_empty) {
+                    self.set_list_filter(None);
+                }
+            }
+            KeyCode::Backspace => {
+                let mut query = self.list_filter().unwrap_or_default().to_string();
+                query.pop();
+                self.set_list_filter(Some(query));
+            }
+            KeyCode::Char(c) => {
+                let mut query = self.list_filter().unwrap_or_default().to_string();
+                query.push(c);
+                self.set_list_filter(Some(query));
+            }
+            KeyCode::Up => self.handle_up(),
+            KeyCode::Down => self.handle_down(),
+            _ => {}
         }
     }
 
-    /// Handle right arrow key.
-    fn handle_right(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.scroll_right(),
-            Tab::Runners => self.runners.scroll_right(),
-            Tab::Console => {}
+    /// Apply an action asked for in a list or log view.
+    fn update_action(&mut self, action: Action) -> Option<Command> {
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::Help => {
+                self.show_help = true;
+                self.help_scroll = 0;
+            }
+            Action::DebugLog => self.show_debug_log = true,
+            Action::Settings => self.show_settings = true,
+            Action::ShowPath => {
+                self.show_path = matches!(self.active_tab, Tab::Workflows | Tab::Runners);
+                self.path_selected = self.nav_depth().saturating_sub(1);
+            }
+            Action::Recent => {
+                self.show_recent = !self.recent.is_empty();
+                self.recent_selected = 0;
+            }
+            Action::Workspaces => {
+                self.workspaces = load_workspaces();
+                self.workspace_selected = 0;
+                self.show_workspaces = true;
+            }
+            Action::Permalink => self.permalink_input = Some(String::new()),
+            Action::TogglePreview => self.split_view = !self.split_view,
+            Action::FocusAnnotations => self.toggle_annotations_focus(),
+            Action::FocusTests => self.toggle_tests_focus(),
+            Action::Timestamps => self.cycle_log_timestamps(),
+            Action::MyRuns => {
+                if self.log_job().is_some() {
+                    self.toggle_log_bookmark();
+                } else {
+                    return Some(Command::ToggleMyRuns);
+                }
+            }
+            Action::Branch => return Some(Command::OpenBranches),
+            Action::AllBranches => return Some(Command::ToggleAllBranches),
+            Action::GroupRuns if self.current_jobs_mut().is_some() => self.toggle_jobs_grouping(),
+            Action::GroupRuns => self.toggle_runs_grouping(),
+            Action::Fold if self.current_jobs_mut().is_some() => self.toggle_matrix_fold(),
+            Action::Fold => self.toggle_event_fold(),
+            Action::FailedVariants => self.toggle_failed_variants(),
+            Action::DiagLogs => return Some(Command::OpenDiagLogs),
+            Action::RunnerGroups => {
+                if self.require_capability(Action::RunnerGroups) {
+                    return Some(Command::OpenRunnerGroups);
+                }
+            }
+            Action::DebugRerun => {
+                if self.require_capability(Action::DebugRerun) {
+                    self.pending_rerun = self.selected_run_for_rerun();
+                }
+            }
+            Action::FlakyReport => return Some(Command::ShowFlakyReport),
+            Action::RunQueue => return Some(Command::ShowRunQueue),
+            Action::Schedule => return Some(Command::ShowSchedule),
+            Action::Environments => return Some(Command::OpenEnvironments),
+            Action::Caches => return Some(Command::ShowCaches),
+            Action::Secrets => return Some(Command::ShowSecrets),
+            Action::Policy => return Some(Command::ShowPolicy),
+            Action::Checks => return Some(Command::ShowChecks),
+            Action::LogArchive => return Some(Command::ShowLogArchive),
+            Action::JobSummary => return Some(Command::ShowJobSummary),
+            Action::Mark => self.toggle_mark(),
+            Action::Export => self.show_export_menu = self.export_rows().is_some(),
+            Action::Bulk => {
+                if self.require_capability(Action::Bulk) {
+                    self.show_bulk_menu = self.marked_items().is_some();
+                }
+            }
+            Action::Copy => self.show_copy_menu = self.current_log_excerpt().is_some(),
+            Action::NextTab => return self.select_tab(self.active_tab.next()),
+            Action::PrevTab => return self.select_tab(self.active_tab.prev()),
+            Action::SelectTab(tab) => return self.select_tab(tab),
+            Action::Up => self.handle_up(),
+            Action::Down => self.handle_down(),
+            Action::Left => self.handle_left(),
+            Action::Right => self.handle_right(),
+            Action::PageUp => self.handle_page_up(),
+            Action::PageDown => self.handle_page_down(),
+            Action::Top => self.handle_home(),
+            Action::Bottom => self.handle_end(),
+            Action::Select => return Some(Command::DrillDown),
+            Action::Back => return Some(Command::GoBack),
+            Action::Refresh => return Some(Command::Refresh),
+            Action::HardRefresh => return Some(Command::HardRefresh),
+            Action::Search => self.handle_search_start(),
+            Action::OpenInBrowser => self.handle_open_in_browser(),
+            Action::Favorite => self.toggle_favorite(),
+            Action::HideRepo => return Some(Command::HideRunnerRepo),
+            Action::UnhideRepos => self.unhide_runner_repos(),
+            Action::NextMatch => self.search_next(),
+            Action::PrevMatch => self.search_prev(),
+            Action::NextBookmark => self.jump_to_bookmark(true),
+            Action::PrevBookmark => self.jump_to_bookmark(false),
+            // Hints standing for other actions, and keys read by text inputs
+            Action::Navigate
+            | Action::Scroll
+            | Action::Page
+            | Action::Jump
+            | Action::SwitchTab
+            | Action::Filter
+            | Action::Bookmark
+            | Action::CancelSearch
+            | Action::ConfirmSearch
+            | Action::ConfirmFilter
+            | Action::ClearFilter => {}
         }
+        None
     }
 
-    /// Handle Page Up key.
-    fn handle_page_up(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.page_up(),
-            Tab::Runners => self.runners.page_up(),
-            Tab::Console => {}
+    /// Switch to a tab, leaving its data to be loaded.
+    fn select_tab(&mut self, tab: Tab) -> Option<Command> {
+        self.active_tab = tab;
+        self.clear_console_badge_if_viewing();
+        Some(Command::LoadTab)
+    }
+
+    /// Run the async work left by a message.
+    async fn run_command(&mut self, command: Command) {
+        match command {
+            Command::LoadTab => self.on_tab_change().await,
+            Command::ToggleMyRuns => self.toggle_my_runs().await,
+            Command::OpenBranches => self.open_branch_picker(),
+            Command::ToggleAllBranches => self.toggle_all_branches().await,
+            Command::OpenDiagLogs => self.open_diag_logs().await,
+            Command::OpenRunnerGroups => self.open_runner_groups().await,
+            Command::ShowFlakyReport => self.show_flaky_report(),
+            Command::ShowRunQueue => self.show_run_queue().await,
+            Command::HideRunnerRepo => self.hide_runner_repo().await,
+            Command::ShowSchedule => self.show_schedule_overview().await,
+            Command::OpenEnvironments => self.open_environments().await,
+            Command::ShowCaches => self.show_caches().await,
+            Command::ShowSecrets => self.show_secrets().await,
+            Command::ShowPolicy => self.show_policy().await,
+            Command::ShowChecks => self.show_checks().await,
+            Command::ShowLogArchive => self.show_log_archive().await,
+            Command::ShowJobSummary => self.show_job_summary().await,
+            Command::DrillDown => self.handle_enter().await,
+            Command::GoBack => self.handle_escape().await,
+            Command::Refresh => self.handle_refresh().await,
+            Command::HardRefresh => self.handle_hard_refresh().await,
+            Command::OpenRecent(item) => self.open_recent(item).await,
+            Command::OpenPermalink(link) => self.open_permalink(link).await,
+            Command::RestoreWorkspace(workspace) => self.restore_workspace(workspace).await,
+            Command::JumpToLevel(level) => self.jump_to_level(level).await,
+            Command::SetBranch(branch) => self.set_branch_filter(branch).await,
+            Command::DeleteCache => self.delete_selected_cache().await,
+            Command::ApplyBulk(bulk) => {
+                let PendingBulk {
+                    action,
+                    owner,
+                    repo,
+                    items,
+                } = *bulk;
+                self.apply_bulk_action(action, &owner, &repo, items).await;
+            }
+            Command::DebugRerun(rerun) => self.rerun_with_debug(rerun).await,
         }
     }
 
-    /// Handle Page Down key.
-    fn handle_page_down(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.page_down(),
-            Tab::Runners => self.runners.page_down(),
-            Tab::Console => {}
+    /// Handle a key press while the settings overlay is shown.
+    fn handle_settings_key(&mut self, code: KeyCode) {
+        let field = SettingsField::ALL[self.settings_selected];
+        match code {
+            KeyCode::Esc | KeyCode::Char(',') | KeyCode::Char('q') => {
+                self.show_settings = false;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.settings_selected = self.settings_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.settings_selected =
+                    (self.settings_selected + 1).min(SettingsField::ALL.len() - 1);
+            }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter => {
+                self.cycle_setting(field, true);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.cycle_setting(field, false);
+            }
+            _ => {}
         }
     }
 
-    /// Handle Home key.
-    fn handle_home(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.scroll_to_start(),
-            Tab::Runners => self.runners.scroll_to_start(),
-            Tab::Console => {}
+    /// Step a setting and apply it right away.
+    fn cycle_setting(&mut self, field: SettingsField, forward: bool) {
+        field.cycle(&mut self.config, forward);
+        if field == SettingsField::Ascii {
+            self.term_caps.ascii = self.config.ascii || self.ascii_flag;
         }
+        self.save_config();
     }
 
-    /// Handle End key.
-    fn handle_end(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.workflows.scroll_to_end(),
-            Tab::Runners => self.runners.scroll_to_end(),
-            Tab::Console => {}
+    /// Persist settings after an edit.
+    fn save_config(&mut self) {
+        if let Err(e) = self.config.save() {
+            self.log_error(format!("Failed to save settings: {}", e));
         }
     }
 
-    /// Handle search start (/ key).
-    fn handle_search_start(&mut self) {
-        // Only activate search when viewing logs
+    /// Refresh the current list view when the auto-refresh interval elapses.
+    async fn auto_refresh(&mut self) {
+        // The Home and Live tabs keep updating even with auto-refresh off,
+        // unless requests are rationed without a token
+        let interval = match (self.config.refresh_interval(), self.active_tab) {
+            (Some(interval), _) => interval,
+            (None, Tab::Home | Tab::Live) if !self.anonymous() => LIVE_REFRESH_INTERVAL,
+            (None, Tab::Runners) => {
+                // Runners lists stay as they are, but which runners are busy
+                // is still sampled
+                if self.last_auto_refresh.elapsed() >= RUNNER_SAMPLE_INTERVAL {
+                    self.last_auto_refresh = Instant::now();
+                    self.sample_runners();
+                }
+                return;
+            }
+            (None, _) => return,
+        };
+        if self.last_auto_refresh.elapsed() < interval {
+            return;
+        }
+        self.last_auto_refresh = Instant::now();
+        tracing::debug!(tab = ?self.active_tab, "auto refresh");
+
+        // Logs don't change once loaded and overlays shouldn't be disturbed
         let in_logs = match self.active_tab {
             Tab::Workflows => matches!(self.workflows.nav.current(), ViewLevel::Logs { .. }),
-            Tab::Runners => matches!(self.runners.nav.current(), RunnersViewLevel::Logs { .. }),
-            Tab::Console => false,
+            Tab::Runners => self.runners.nav.current().is_log_view(),
+            Tab::Home | Tab::Inbox | Tab::Live => false,
+            Tab::Console => true,
         };
-        if in_logs {
-            self.search_active = true;
-            self.search_query.clear();
-            self.search_matches.clear();
-            self.search_match_index = 0;
+        if !in_logs && !self.show_help && !self.show_settings && !self.search_active {
+            // Keep the current rows on screen while the fresh list is fetched
+            match self.active_tab {
+                Tab::Workflows => self.workflows.mark_current_stale(),
+                Tab::Runners => self.runners.mark_current_stale(),
+                Tab::Inbox => self.inbox.mark_stale(),
+                Tab::Live => self.live.mark_stale(),
+                Tab::Home => self.home.mark_stale(),
+                Tab::Console => {}
+            }
         }
     }
 
-    /// Execute search on current log content.
-    fn execute_search(&mut self) {
-        if self.search_query.is_empty() {
-            self.search_matches.clear();
-            return;
+    /// Show the state of the run open in the active tab in the terminal title.
+    fn update_window_title(&mut self) {
+        let now = chrono::Utc::now();
+        let (item, jobs) = match self.active_tab {
+            Tab::Workflows => (
+                RecentItem::from_levels(self.workflows.nav.levels(), now),
+                &self.workflows.jobs,
+            ),
+            Tab::Runners => (self.runners_recent_item(now), &self.runners.jobs),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => (None, &self.workflows.jobs),
+        };
+        let window_title = match item {
+            Some(item) => title::run_title(
+                &item.workflow_name,
+                item.run_number,
+                jobs.data.data().map(|list| list.items.as_slice()),
+            ),
+            None => title::IDLE_TITLE.to_string(),
+        };
+        let shown = (window_title, self.config.window_title);
+        if shown != self.window_title {
+            let _ = title::set(&shown.0, shown.1);
+            self.window_title = shown;
         }
+    }
 
-        let logs = match self.active_tab {
-            Tab::Workflows => {
-                if let LoadingState::Loaded(ref logs) = self.workflows.log_content {
-                    logs.clone()
-                } else {
-                    return;
-                }
-            }
-            Tab::Runners => {
-                if let LoadingState::Loaded(ref logs) = self.runners.log_content {
-                    logs.clone()
-                } else {
-                    return;
-                }
+    /// Actions available in the current view, for the status bar hints.
+    pub fn available_actions(&self) -> Vec<Action> {
+        if self.search_active {
+            return SEARCH_INPUT_ACTIONS.to_vec();
+        }
+        if self.filter_active {
+            return FILTER_INPUT_ACTIONS.to_vec();
+        }
+        let mut actions = match self.active_tab {
+            Tab::Workflows => self.workflows.nav.current().actions().to_vec(),
+            Tab::Runners => self.runners.nav.current().actions().to_vec(),
+            Tab::Inbox => INBOX_ACTIONS.to_vec(),
+            Tab::Live => LIVE_ACTIONS.to_vec(),
+            Tab::Home => HOME_ACTIONS.to_vec(),
+            Tab::Console => CONSOLE_ACTIONS.to_vec(),
+        };
+        let search = actions.iter().position(|a| *a == Action::Search);
+        if let Some(i) = search.filter(|_| !self.search_matches.is_empty()) {
+            actions.insert(i + 1, Action::NextMatch);
+        }
+        actions
+    }
+
+    /// Whether the token's scopes allow an action.
+    pub fn action_allowed(&self, action: Action) -> bool {
+        match (action.capability(), &self.github_client) {
+            (Some(capability), Some(client)) => client.can(capability),
+            _ => true,
+        }
+    }
+
+    /// Whether requests are made without a token. Their rate limit of 60 an
+    /// hour leaves no room for requests the user didn't ask for, such as
+    /// badges and timed refreshes.
+    fn anonymous(&self) -> bool {
+        self.github_client
+            .as_ref()
+            .is_some_and(|client| !client.is_authenticated())
+    }
+
+    /// Status bar note on the scope the current view is missing, if any.
+    pub fn missing_scope_note(&self) -> Option<String> {
+        let client = self.github_client.as_ref()?;
+        if !client.is_authenticated() {
+            return Some("No token: public repositories only".to_string());
+        }
+        let capability = self
+            .available_actions()
+            .into_iter()
+            .find_map(|action| action.capability().filter(|c| !client.can(*c)))
+            .or_else(|| {
+                (self.active_tab == Tab::Runners && !client.can(Capability::ReadRunners))
+                    .then_some(Capability::ReadRunners)
+            })?;
+        Some(format!(
+            "Token needs the {} scope to {}",
+            capability.scopes()[0],
+            capability.label()
+        ))
+    }
+
+    /// Check the token's scopes up front and note what it can't do.
+    async fn check_token_scopes(&mut self) {
+        // Any response reports the scopes; the user is needed for "my runs" anyway
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        if !client.is_authenticated() {
+            return;
+        }
+        match client.get_current_user().await {
+            Ok(user) => self.current_user = Some(user.login),
+            Err(e) => {
+                self.log_error(format!("Failed to load current user: {}", e));
+                return;
+            }
+        }
+        let missing = self
+            .github_client
+            .as_ref()
+            .map(|client| client.missing_capabilities())
+            .unwrap_or_default();
+        for capability in missing {
+            self.log_warn(format!(
+                "Token lacks the {} scope, so jolt can't {}",
+                capability.scopes()[0],
+                capability.label()
+            ));
+        }
+    }
+
+    /// Serve Prometheus gauges of the favorite repositories if configured.
+    async fn start_metrics_exporter(&mut self) {
+        let Some(addr) = self.config.metrics_addr.clone() else {
+            return;
+        };
+        let client = match GitHubClient::from_env_with_config(&self.config.http) {
+            Ok(client) => client,
+            Err(e) => {
+                self.log_error(format!("Metrics exporter not started: {}", e));
+                return;
+            }
+        };
+        let listener = match tokio::net::TcpListener::bind(&addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                self.log_error(format!("Metrics exporter can't listen on {}: {}", addr, e));
+                return;
+            }
+        };
+        let (repos, watched) = tokio::sync::watch::channel(self.sorted_favorite_repos());
+        self.metrics_repos = Some(repos);
+        tokio::spawn(metrics::serve(listener, client, watched));
+        self.log_info(format!("Serving metrics on http://{}/metrics", addr));
+    }
+
+    /// Favorite repositories as "owner/repo", sorted.
+    fn sorted_favorite_repos(&self) -> Vec<String> {
+        let mut repos: Vec<String> = self.favorite_repos.iter().cloned().collect();
+        repos.sort();
+        repos
+    }
+
+    /// Have the metrics exporter collect the current favorite repositories.
+    fn update_metrics_repos(&self) {
+        if let Some(repos) = &self.metrics_repos {
+            repos.send_replace(self.sorted_favorite_repos());
+        }
+    }
+
+    /// Log why an action is unavailable to the token. Returns whether it's allowed.
+    fn require_capability(&mut self, action: Action) -> bool {
+        if self.action_allowed(action) {
+            return true;
+        }
+        let authenticated = self
+            .github_client
+            .as_ref()
+            .is_some_and(|client| client.is_authenticated());
+        if !authenticated {
+            self.log_warn(format!("{} needs a GitHub token", action.label()));
+        } else if let Some(capability) = action.capability() {
+            self.log_warn(format!(
+                "{} needs a token with the {} scope",
+                action.label(),
+                capability.scopes()[0]
+            ));
+        }
+        false
+    }
+
+    /// Repository, branch, and run for the status bar context segment.
+    pub fn nav_context(&self) -> Option<NavContext> {
+        let (mut context, runs) = match self.active_tab {
+            Tab::Workflows => (self.workflows.nav.context(), &self.workflows.runs),
+            Tab::Runners => (self.runners.nav.context(), &self.runners.runs),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return None,
+        };
+        // The runs list keeps its selection while drilled into a run
+        if context.run_number.is_some() {
+            context.branch = runs.selected_item().and_then(|run| run.head_branch.clone());
+        }
+        Some(context)
+    }
+
+    /// Whether the current list is showing stale data awaiting a refresh.
+    pub fn is_revalidating(&self) -> bool {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.current_is_stale(),
+            Tab::Runners => self.runners.current_is_stale(),
+            Tab::Inbox => self.inbox.stale,
+            Tab::Live => self.live.stale,
+            Tab::Home => self.home.stale,
+            Tab::Console => false,
+        }
+    }
+
+    /// Refresh a current list that was rendered from an expired cache entry.
+    /// The fresh rows are fetched in the background, so the stale ones stay
+    /// on screen and keys keep working meanwhile.
+    async fn revalidate(&mut self) {
+        if !self.is_revalidating() || self.tasks.is_running(TaskKind::Revalidate) {
+            return;
+        }
+        let view = match self.active_tab {
+            Tab::Live => return self.load_live(),
+            Tab::Home => return self.load_home(),
+            _ => self.current_list_view(),
+        };
+        let Some(view) = view else {
+            return;
+        };
+        let query = self.rows_query(&view).await;
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        self.tasks
+            .spawn(TaskKind::Revalidate, client, move |mut fork| async move {
+                let rows = fetch_rows(&mut fork, &view, &query).await;
+                (fork, Loaded::Revalidated { view, query, rows })
+            });
+    }
+
+    /// Apply what background tasks sent back since the last frame.
+    async fn apply_tasks(&mut self) {
+        while let Some(done) = self.tasks.next() {
+            if let (Some(fork), Some(client)) = (done.fork, self.github_client.as_mut()) {
+                client.absorb(fork);
+            }
+            if done.panicked {
+                self.log_warn(format!("Background task stopped: {:?}", done.kind));
+            }
+            match done.message {
+                Some(Loaded::Revalidated { view, query, rows }) => {
+                    self.show_revalidated(view, query, rows).await
+                }
+                Some(Loaded::Preview {
+                    run_id,
+                    path,
+                    result,
+                }) => self.show_preview(run_id, path, result),
+                Some(Loaded::FlakyReport(result)) => match result {
+                    Ok(report) => self.flaky_report = Some(report),
+                    Err(e) => self.log_error(format!("Failed to load flaky jobs: {}", e)),
+                },
+                Some(Loaded::Schedules {
+                    owner,
+                    repo,
+                    schedules,
+                    failures,
+                }) => self.show_schedules(&owner, &repo, schedules, failures),
+                Some(Loaded::Live {
+                    runs,
+                    finished,
+                    failures,
+                }) => self.show_live(runs, finished, failures),
+                Some(Loaded::Branches {
+                    owner,
+                    repo,
+                    page,
+                    result,
+                }) => self.show_branches(&owner, &repo, page, result),
+                Some(Loaded::DefaultBranch {
+                    owner,
+                    repo,
+                    result,
+                }) => self.show_default_branch(&owner, &repo, result).await,
+                Some(Loaded::LatestRuns {
+                    owner,
+                    repo,
+                    result,
+                }) => self.show_latest_runs(&owner, &repo, result),
+                Some(Loaded::RunnerJobs {
+                    owner,
+                    repo,
+                    result,
+                }) => self.show_runner_jobs(&owner, &repo, result),
+                Some(Loaded::JobTick {
+                    tab,
+                    run_id,
+                    result,
+                }) => self.show_job_tick(tab, run_id, result),
+                Some(Loaded::LogPrefetch {
+                    job_name,
+                    result: Err(e),
+                }) => self.log_warn(format!("Failed to prefetch log of {}: {}", job_name, e)),
+                Some(Loaded::Home { items, failures }) => self.show_home(items, failures),
+                Some(Loaded::RunnerSample(result)) => match result {
+                    Ok(runners) => self.runner_utilization.record(&runners, chrono::Utc::now()),
+                    Err(e) => tracing::warn!(error = %e, "runner sample failed"),
+                },
+                Some(Loaded::RunnerHealth(name, health)) => {
+                    self.runners.health.insert(name, health);
+                }
+                Some(Loaded::LogPrefetch { result: Ok(()), .. }) | None => {}
+            }
+        }
+    }
+
+    /// Show fresh rows for a stale list, unless the user has since left it or
+    /// reloaded it.
+    async fn show_revalidated(&mut self, view: ListView, query: RowsQuery, rows: Option<Rows>) {
+        if self.current_list_view().as_ref() != Some(&view) || !self.is_revalidating() {
+            return;
+        }
+        let Some(rows) = rows else {
+            return;
+        };
+        self.show_rows(&view, &query, rows, true);
+        match view {
+            ListView::Workflows(_) => self.load_current_view().await,
+            ListView::Runners(_) => self.load_runners_view().await,
+            ListView::Inbox => {}
+        }
+    }
+
+    /// The list the active tab shows, if its rows come from the API.
+    fn current_list_view(&self) -> Option<ListView> {
+        match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Logs { .. } => None,
+                level => Some(ListView::Workflows(level.clone())),
+            },
+            Tab::Runners => {
+                let level = self.runners.nav.current();
+                (!level.is_log_view()).then(|| ListView::Runners(level.clone()))
+            }
+            Tab::Inbox => Some(ListView::Inbox),
+            Tab::Home | Tab::Live | Tab::Console => None,
+        }
+    }
+
+    /// Warn in the Console about endpoints that became consistently slow.
+    fn report_slow_endpoints(&mut self) {
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        for timing in client.take_slow_endpoints() {
+            self.log_warn(format!(
+                "Slow endpoint {}: p50 {:.1}s, p95 {:.1}s over {} requests",
+                timing.endpoint,
+                timing.p50.as_secs_f64(),
+                timing.p95.as_secs_f64(),
+                timing.count
+            ));
+        }
+    }
+
+    /// Warn in the Console about requests that failed and were retried.
+    fn report_retries(&mut self) {
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        for retry in client.take_retries() {
+            self.log_warn(retry);
+        }
+    }
+
+    /// Load jobs for the run selected in the Runs list into the preview pane.
+    /// Waits for the selection to settle so scrolling doesn't fetch every run.
+    async fn update_preview(&mut self) {
+        if !self.split_view
+            || self.active_tab != Tab::Workflows
+            || self.last_input.elapsed() < PREVIEW_DELAY
+        {
+            return;
+        }
+        let ViewLevel::Runs {
+            owner,
+            repo,
+            workflow_id,
+            ..
+        } = self.workflows.nav.current().clone()
+        else {
+            return;
+        };
+        let Some(run_id) = self.workflows.runs.selected_item().map(|run| run.id) else {
+            return;
+        };
+        // A preview still loading is finished first, then the settled selection is fetched
+        if self.workflows.preview_run_id == Some(run_id) || self.tasks.is_running(TaskKind::Preview)
+        {
+            return;
+        }
+        self.workflows.preview_run_id = Some(run_id);
+        self.workflows.job_history = JobHistory::load(&owner, &repo, workflow_id, run_id);
+
+        let path = cache::jobs_list_path(&owner, &repo, workflow_id, run_id);
+        let ttl = self.config.cache_ttl(CacheKind::Jobs);
+        let preview = &mut self.workflows.preview_jobs;
+        let cached = load_cached_list(preview, path.clone(), ttl);
+        // The preview isn't navigable, so don't highlight a row
+        preview.list_state.select(None);
+        if cached && !preview.stale {
+            return;
+        }
+        if !cached {
+            preview.set_loading();
+        }
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        let per_page = self.config.per_page;
+        self.tasks
+            .spawn(TaskKind::Preview, client, move |mut fork| async move {
+                let result = fork.get_jobs(&owner, &repo, run_id, 1, per_page).await;
+                (
+                    fork,
+                    Loaded::Preview {
+                        run_id,
+                        path,
+                        result,
+                    },
+                )
+            });
+    }
+
+    /// Show the jobs fetched for the run preview, if that run is still selected.
+    fn show_preview(
+        &mut self,
+        run_id: u64,
+        path: Option<PathBuf>,
+        result: crate::error::Result<(Vec<Job>, u64)>,
+    ) {
+        if self.workflows.preview_run_id != Some(run_id) {
+            return;
+        }
+        match result {
+            Ok((jobs, count)) => {
+                if let Some(path) = path {
+                    let _ = cache::write_cached(&path, &jobs, false);
+                }
+                self.workflows.preview_jobs.set_loaded(jobs, count);
+                self.workflows.preview_jobs.list_state.select(None);
+            }
+            Err(e) => {
+                self.workflows.preview_jobs.set_error(&e);
+                self.log_warn(format!("Failed to load run preview: {}", e));
             }
-            Tab::Console => return,
+        }
+    }
+
+    /// Handle up arrow key.
+    fn handle_up(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.select_prev(),
+            Tab::Runners => self.runners.select_prev(),
+            Tab::Inbox => self.inbox.select_prev(),
+            Tab::Live => self.live.select_prev(),
+            Tab::Home => self.home.select_prev(),
+            Tab::Console => self.console_select_prev(),
+        }
+    }
+
+    /// Handle down arrow key.
+    fn handle_down(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.select_next(),
+            Tab::Runners => self.runners.select_next(),
+            Tab::Inbox => self.inbox.select_next(),
+            Tab::Live => self.live.select_next(),
+            Tab::Home => self.home.select_next(),
+            Tab::Console => self.console_select_next(),
+        }
+    }
+
+    /// Handle left arrow key.
+    fn handle_left(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.scroll_left(),
+            Tab::Runners => self.runners.scroll_left(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle right arrow key.
+    fn handle_right(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.scroll_right(),
+            Tab::Runners => self.runners.scroll_right(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle Page Up key.
+    fn handle_page_up(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.page_up(),
+            Tab::Runners => self.runners.page_up(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle Page Down key.
+    fn handle_page_down(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.page_down(),
+            Tab::Runners => self.runners.page_down(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle Home key.
+    fn handle_home(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.scroll_to_start(),
+            Tab::Runners => self.runners.scroll_to_start(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle End key.
+    fn handle_end(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.scroll_to_end(),
+            Tab::Runners => self.runners.scroll_to_end(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle search start (/ key).
+    /// Searches logs, or starts filtering in list views.
+    fn handle_search_start(&mut self) {
+        let in_logs = match self.active_tab {
+            Tab::Workflows => matches!(self.workflows.nav.current(), ViewLevel::Logs { .. }),
+            Tab::Runners => self.runners.nav.current().is_log_view(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => false,
+        };
+        if !in_logs {
+            let query = self.list_filter().unwrap_or_default().to_string();
+            self.filter_active = self.set_list_filter(Some(query));
+        } else {
+            self.search_active = true;
+            self.search_query.clear();
+            self.search_matches.clear();
+            self.search_match_index = 0;
+        }
+    }
+
+    /// Execute search on current log content.
+    fn execute_search(&mut self) {
+        if self.search_query.is_empty() {
+            self.search_matches.clear();
+            return;
+        }
+
+        let Some(logs) = self
+            .log_viewer(self.active_tab)
+            .and_then(|log| log.content.data())
+            .cloned()
+        else {
+            return;
         };
 
         // Find all matching line numbers (0-indexed)
@@ -529,16 +2004,86 @@ impl App {
 
     /// Scroll log view to current search match.
     fn scroll_to_match(&mut self) {
-        if let Some(&line) = self.search_matches.get(self.search_match_index) {
-            match self.active_tab {
-                Tab::Workflows => {
-                    self.workflows.log_scroll_y = line as u16;
-                }
-                Tab::Runners => {
-                    self.runners.log_scroll_y = line as u16;
-                }
-                Tab::Console => {}
-            }
+        let line = self.search_matches.get(self.search_match_index).copied();
+        if let (Some(line), Some(log)) = (line, self.log_viewer_mut(self.active_tab)) {
+            log.scroll_y = line as u16;
+        }
+    }
+
+    /// Log viewer of `tab`, if it has one.
+    pub fn log_viewer(&self, tab: Tab) -> Option<&LogViewerState> {
+        match tab {
+            Tab::Workflows => Some(&self.workflows.log),
+            Tab::Runners => Some(&self.runners.log),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Log viewer of `tab` to load into or scroll, if it has one.
+    fn log_viewer_mut(&mut self, tab: Tab) -> Option<&mut LogViewerState> {
+        match tab {
+            Tab::Workflows => Some(&mut self.workflows.log),
+            Tab::Runners => Some(&mut self.runners.log),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Job of the log being viewed, with its top visible line.
+    fn log_job(&self) -> Option<(u64, u16)> {
+        self.log_job_in(self.active_tab)
+    }
+
+    /// Job of the log open in `tab`, with its top visible line.
+    fn log_job_in(&self, tab: Tab) -> Option<(u64, u16)> {
+        match tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Logs { job_id, .. } => Some((*job_id, self.workflows.log.scroll_y)),
+                _ => None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Logs { job_id, .. } => Some((*job_id, self.runners.log.scroll_y)),
+                _ => None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Bookmarks of the log being viewed, if any.
+    pub fn log_bookmarks(&self) -> Option<&LogViewState> {
+        let (job_id, _) = self.log_job()?;
+        self.log_views.get(&job_id)
+    }
+
+    /// Bookmark the top visible log line, or remove its bookmark. Jumping to
+    /// a search match scrolls it to the top, so that bookmarks the match.
+    fn toggle_log_bookmark(&mut self) {
+        let Some((job_id, scroll_y)) = self.log_job() else {
+            return;
+        };
+        let state = self.log_views.entry(job_id).or_default();
+        state.toggle_bookmark(scroll_y as usize);
+        state.viewed_at = Some(chrono::Utc::now());
+        if state.is_empty() {
+            self.log_views.remove(&job_id);
+        }
+        prune_log_views(&mut self.log_views);
+    }
+
+    /// Scroll the log to the next (or previous) bookmark, wrapping around.
+    fn jump_to_bookmark(&mut self, forward: bool) {
+        let Some((job_id, scroll_y)) = self.log_job() else {
+            return;
+        };
+        let Some(state) = self.log_views.get(&job_id) else {
+            return;
+        };
+        let line = if forward {
+            state.next_bookmark(scroll_y as usize)
+        } else {
+            state.prev_bookmark(scroll_y as usize)
+        };
+        if let (Some(line), Some(log)) = (line, self.log_viewer_mut(self.active_tab)) {
+            log.scroll_y = line as u16;
         }
     }
 
@@ -547,845 +2092,3354 @@ impl App {
         let url = match self.active_tab {
             Tab::Workflows => self.get_workflows_github_url(),
             Tab::Runners => self.get_runners_github_url(),
+            Tab::Inbox => self.inbox.selected_item().map(|pr| pr.url.clone()),
+            Tab::Live => self
+                .live
+                .selected_item()
+                .map(|live| live.run.html_url.clone()),
+            Tab::Home => self.home.selected_item().map(HomeItem::url),
             Tab::Console => None,
         };
 
-        #[allow(clippy::collapsible_if)]
         if let Some(url) = url {
-            if let Err(e) = std::process::Command::new("open").arg(&url).spawn() {
-                self.log_error(format!("Failed to open browser: {}", e));
-            }
+            self.open_url(&url);
         }
     }
 
-    /// Toggle favorite status for the currently selected item.
-    fn toggle_favorite(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.toggle_workflows_favorite(),
-            Tab::Runners => self.toggle_runners_favorite(),
-            Tab::Console => {}
+    /// Open a URL in the browser.
+    fn open_url(&mut self, url: &str) {
+        if let Err(e) = std::process::Command::new("open").arg(url).spawn() {
+            self.log_error(format!("Failed to open browser: {}", e));
         }
     }
 
-    /// Toggle favorite in Workflows tab.
-    fn toggle_workflows_favorite(&mut self) {
-        match self.workflows.nav.current().clone() {
-            ViewLevel::Owners => {
-                // Get selected index and sort data the same way as rendering
-                let index = match self.workflows.owners.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.workflows.owners.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_fav = self.favorite_owners.contains(&a.login);
-                    let b_fav = self.favorite_owners.contains(&b.login);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.login.cmp(&b.login),
-                    }
-                });
-                if let Some(owner) = sorted.get(index) {
-                    let key = owner.login.clone();
-                    if self.favorite_owners.contains(&key) {
-                        self.favorite_owners.remove(&key);
-                    } else {
-                        self.favorite_owners.insert(key);
-                    }
-                }
-            }
-            ViewLevel::Repositories { ref owner } => {
-                let index = match self.workflows.repositories.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.workflows.repositories.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", owner, a.name);
-                    let b_key = format!("{}/{}", owner, b.name);
-                    let a_fav = self.favorite_repos.contains(&a_key);
-                    let b_fav = self.favorite_repos.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                if let Some(repo) = sorted.get(index) {
-                    let key = format!("{}/{}", owner, repo.name);
-                    if self.favorite_repos.contains(&key) {
-                        self.favorite_repos.remove(&key);
-                    } else {
-                        self.favorite_repos.insert(key);
-                    }
+    /// Log line to copy: the current search match, or else the top visible line.
+    pub fn current_log_excerpt(&self) -> Option<LogExcerpt> {
+        let (logs, scroll_y, jobs, owner, repo, run_id, job_id, job_name) = match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Logs {
+                    owner,
+                    repo,
+                    run_id,
+                    job_id,
+                    job_name,
+                    ..
+                } => (
+                    self.workflows.log.content.data()?,
+                    self.workflows.log.scroll_y,
+                    &self.workflows.jobs,
+                    owner,
+                    repo,
+                    run_id,
+                    job_id,
+                    job_name,
+                ),
+                _ => return None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Logs {
+                    owner,
+                    repo,
+                    run_id,
+                    job_id,
+                    job_name,
+                    ..
+                } => (
+                    self.runners.log.content.data()?,
+                    self.runners.log.scroll_y,
+                    &self.runners.jobs,
+                    owner,
+                    repo,
+                    run_id,
+                    job_id,
+                    job_name,
+                ),
+                _ => return None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return None,
+        };
+
+        let line_index = self
+            .search_matches
+            .get(self.search_match_index)
+            .copied()
+            .unwrap_or(scroll_y as usize);
+        let text = logs.lines().nth(line_index)?.to_string();
+        let job = jobs
+            .sorted_items()
+            .into_iter()
+            .find(|job| job.id == *job_id);
+        let steps = job.map(|job| job.steps.as_slice()).unwrap_or_default();
+        let run = match self.active_tab {
+            Tab::Runners => self.runners_recent_item(chrono::Utc::now()),
+            _ => RecentItem::from_levels(self.workflows.nav.levels(), chrono::Utc::now()),
+        };
+        let run_url = format!(
+            "https://github.com/{}/{}/actions/runs/{}",
+            owner, repo, run_id
+        );
+        let context = LogContext {
+            owner: owner.clone(),
+            repo: repo.clone(),
+            workflow: run.as_ref().map(|run| run.workflow_name.clone()),
+            run_id: *run_id,
+            run_number: run.as_ref().map(|run| run.run_number),
+            job_url: format!("{}/job/{}", run_url, job_id),
+            run_url,
+            job_id: *job_id,
+            job_name: job_name.clone(),
+            head_sha: job
+                .map(|job| job.head_sha.clone())
+                .filter(|sha| !sha.is_empty()),
+            head_branch: job.and_then(|job| job.head_branch.clone()),
+            runner_name: job.and_then(|job| job.runner_name.clone()),
+        };
+        Some(LogExcerpt {
+            text,
+            anchor: clipboard::step_anchor(logs, line_index, steps),
+            context,
+        })
+    }
+
+    /// Mark or unmark the selected run or job for bulk actions, then move down.
+    fn toggle_mark(&mut self) {
+        let (in_runs, in_jobs) = match self.active_tab {
+            Tab::Workflows => (
+                matches!(self.workflows.nav.current(), ViewLevel::Runs { .. }),
+                matches!(self.workflows.nav.current(), ViewLevel::Jobs { .. }),
+            ),
+            Tab::Runners => (
+                matches!(self.runners.nav.current(), RunnersViewLevel::Runs { .. }),
+                matches!(self.runners.nav.current(), RunnersViewLevel::Jobs { .. }),
+            ),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return,
+        };
+        let (runs, jobs) = match self.active_tab {
+            Tab::Workflows => (&mut self.workflows.runs, &mut self.workflows.jobs),
+            _ => (&mut self.runners.runs, &mut self.runners.jobs),
+        };
+        if let Some(id) = runs.selected_item().map(|run| run.id).filter(|_| in_runs) {
+            runs.toggle_mark(id);
+            runs.select_next();
+        } else if let Some(id) = jobs.selected_item().map(|job| job.id).filter(|_| in_jobs) {
+            jobs.toggle_mark(id);
+            jobs.select_next();
+        }
+    }
+
+    /// Rows of the current Runs or Jobs list as shown (filtered and sorted), with the
+    /// repository name and kind of rows.
+    pub fn export_rows(&self) -> Option<(String, &'static str, Vec<ExportRow>)> {
+        let runs = |list: &SelectableList<WorkflowRun>| -> Vec<ExportRow> {
+            list.sorted_items()
+                .into_iter()
+                .map(ExportRow::from_run)
+                .collect()
+        };
+        // The runs list keeps its selection while drilled into a run
+        let jobs = |list: &SelectableList<Job>, run: Option<&WorkflowRun>| -> Vec<ExportRow> {
+            list.sorted_items()
+                .into_iter()
+                .map(|job| ExportRow::from_job(job, run))
+                .collect()
+        };
+        let (repo, kind, rows) = match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Runs { repo, .. } => (repo, "runs", runs(&self.workflows.runs)),
+                ViewLevel::Jobs { repo, .. } => {
+                    let run = self.workflows.runs.selected_item();
+                    (repo, "jobs", jobs(&self.workflows.jobs, run))
                 }
-            }
-            ViewLevel::Workflows {
-                ref owner,
-                ref repo,
-            } => {
-                let index = match self.workflows.workflows.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.workflows.workflows.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                let repo = repo.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.id);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.id);
-                    let a_fav = self.favorite_workflows.contains(&a_key);
-                    let b_fav = self.favorite_workflows.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                if let Some(workflow) = sorted.get(index) {
-                    let key = format!("{}/{}/{}", owner, repo, workflow.id);
-                    if self.favorite_workflows.contains(&key) {
-                        self.favorite_workflows.remove(&key);
-                    } else {
-                        self.favorite_workflows.insert(key);
-                    }
+                _ => return None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Runs { repo, .. } => (repo, "runs", runs(&self.runners.runs)),
+                RunnersViewLevel::Jobs { repo, .. } => {
+                    let run = self.runners.runs.selected_item();
+                    (repo, "jobs", jobs(&self.runners.jobs, run))
                 }
-            }
-            _ => {} // Can't favorite runs, jobs, or logs
+                _ => return None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return None,
+        };
+        (!rows.is_empty()).then(|| (repo.clone(), kind, rows))
+    }
+
+    /// Write the current runs or jobs list to a file in the working directory.
+    fn export_list(&mut self, format: ExportFormat) {
+        let Some((repo, kind, rows)) = self.export_rows() else {
+            return;
+        };
+        let path = PathBuf::from(export::file_name(&repo, kind, format, chrono::Utc::now()));
+        match std::fs::write(&path, export::render(&rows, format)) {
+            Ok(()) => self.log_info(format!(
+                "Exported {} {} to {}",
+                rows.len(),
+                kind,
+                path.display()
+            )),
+            Err(e) => self.log_error(format!("Failed to export {}: {}", kind, e)),
         }
     }
 
-    /// Toggle favorite in Runners tab.
-    fn toggle_runners_favorite(&mut self) {
-        match self.runners.nav.current().clone() {
-            RunnersViewLevel::Repositories => {
-                let index = match self.runners.repositories.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.runners.repositories.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", a.owner.login, a.name);
-                    let b_key = format!("{}/{}", b.owner.login, b.name);
-                    let a_fav = self.favorite_repos.contains(&a_key);
-                    let b_fav = self.favorite_repos.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a_key.cmp(&b_key),
-                    }
-                });
-                if let Some(repo) = sorted.get(index) {
-                    let key = format!("{}/{}", repo.owner.login, repo.name);
-                    if self.favorite_repos.contains(&key) {
-                        self.favorite_repos.remove(&key);
-                    } else {
-                        self.favorite_repos.insert(key);
+    /// Marked items of the current Runs or Jobs list, with their repository.
+    pub fn marked_items(&self) -> Option<(String, String, BulkItems)> {
+        let marked_runs = |list: &SelectableList<WorkflowRun>| -> Vec<WorkflowRun> {
+            list.sorted_items()
+                .into_iter()
+                .filter(|run| list.marked.contains(&run.id))
+                .cloned()
+                .collect()
+        };
+        let marked_jobs = |list: &SelectableList<Job>| -> Vec<Job> {
+            list.sorted_items()
+                .into_iter()
+                .filter(|job| list.marked.contains(&job.id))
+                .cloned()
+                .collect()
+        };
+        let (owner, repo, items) = match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Runs { owner, repo, .. } => (
+                    owner,
+                    repo,
+                    BulkItems::Runs(marked_runs(&self.workflows.runs)),
+                ),
+                ViewLevel::Jobs {
+                    owner,
+                    repo,
+                    workflow_id,
+                    ..
+                } => (
+                    owner,
+                    repo,
+                    BulkItems::Jobs {
+                        workflow_id: *workflow_id,
+                        jobs: marked_jobs(&self.workflows.jobs),
+                    },
+                ),
+                _ => return None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Runs { owner, repo, .. } => (
+                    owner,
+                    repo,
+                    BulkItems::Runs(marked_runs(&self.runners.runs)),
+                ),
+                RunnersViewLevel::Jobs { owner, repo, .. } => (
+                    owner,
+                    repo,
+                    BulkItems::Jobs {
+                        // The runs list keeps its selection while drilled into a run
+                        workflow_id: self.runners.runs.selected_item()?.workflow_id,
+                        jobs: marked_jobs(&self.runners.jobs),
+                    },
+                ),
+                _ => return None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return None,
+        };
+        (items.len() > 0).then(|| (owner.clone(), repo.clone(), items))
+    }
+
+    /// Apply a bulk action picked for marked items, asking first if it
+    /// changes runs on GitHub.
+    fn choose_bulk_action(
+        &mut self,
+        action: BulkAction,
+        owner: String,
+        repo: String,
+        items: BulkItems,
+    ) -> Option<Command> {
+        let items = items.for_action(action);
+        if items.len() == 0 {
+            self.log_warn("No failed jobs marked");
+            return None;
+        }
+        let bulk = PendingBulk {
+            action,
+            owner,
+            repo,
+            items,
+        };
+        if action.needs_confirmation() {
+            self.pending_bulk = Some(bulk);
+            None
+        } else {
+            Some(Command::ApplyBulk(Box::new(bulk)))
+        }
+    }
+
+    /// Apply a bulk action to marked items, then clear the marks.
+    async fn apply_bulk_action(
+        &mut self,
+        action: BulkAction,
+        owner: &str,
+        repo: &str,
+        items: BulkItems,
+    ) {
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        let description = items.describe();
+        let mut failures = Vec::new();
+        match &items {
+            BulkItems::Runs(runs) => {
+                for run in runs {
+                    let result = match action {
+                        BulkAction::Cancel => client.cancel_workflow_run(owner, repo, run.id).await,
+                        BulkAction::RerunFailed => {
+                            client.rerun_failed_jobs(owner, repo, run.id).await
+                        }
+                        BulkAction::Delete => client.delete_workflow_run(owner, repo, run.id).await,
+                        BulkAction::DownloadLogs => {
+                            download_run_logs(client, owner, repo, run, self.config.per_page).await
+                        }
+                    };
+                    if let Err(e) = result {
+                        failures.push(format!("#{}: {}", run.run_number, e));
                     }
                 }
             }
-            RunnersViewLevel::Runners {
-                ref owner,
-                ref repo,
-            } => {
-                let index = match self.runners.runners.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.runners.runners.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                let repo = repo.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.name);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.name);
-                    let a_fav = self.favorite_runners.contains(&a_key);
-                    let b_fav = self.favorite_runners.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                if let Some(runner) = sorted.get(index) {
-                    let key = format!("{}/{}/{}", owner, repo, runner.name);
-                    if self.favorite_runners.contains(&key) {
-                        self.favorite_runners.remove(&key);
-                    } else {
-                        self.favorite_runners.insert(key);
+            BulkItems::Jobs { workflow_id, jobs } => {
+                for job in jobs {
+                    let result = match action {
+                        BulkAction::DownloadLogs => {
+                            download_job_log(client, owner, repo, *workflow_id, job).await
+                        }
+                        BulkAction::RerunFailed
+                            if job.conclusion == Some(RunConclusion::Failure) =>
+                        {
+                            client.rerun_job(owner, repo, job.id).await
+                        }
+                        // Jobs that didn't fail, and actions that only apply to runs
+                        BulkAction::RerunFailed | BulkAction::Cancel | BulkAction::Delete => {
+                            continue;
+                        }
+                    };
+                    if let Err(e) = result {
+                        failures.push(format!("{}: {}", job.name, e));
                     }
                 }
             }
-            _ => {} // Can't favorite runs, jobs, or logs
+        }
+
+        if failures.is_empty() {
+            self.log_info(format!("{}: {}", action.label(), description));
+        } else {
+            self.log_error(format!(
+                "{} failed for {} of {}: {}",
+                action.label(),
+                failures.len(),
+                description,
+                failures.join("; ")
+            ));
+        }
+        for list in [&mut self.workflows.runs, &mut self.runners.runs] {
+            list.marked.clear();
+            list.mark_stale();
+        }
+        for list in [&mut self.workflows.jobs, &mut self.runners.jobs] {
+            list.marked.clear();
+            list.mark_stale();
         }
     }
 
-    /// Get GitHub URL for current Workflows tab view.
-    fn get_workflows_github_url(&self) -> Option<String> {
-        match self.workflows.nav.current().clone() {
-            ViewLevel::Owners => {
-                let index = self.workflows.owners.selected()?;
-                let data = self.workflows.owners.data.data()?;
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_fav = self.favorite_owners.contains(&a.login);
-                    let b_fav = self.favorite_owners.contains(&b.login);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.login.cmp(&b.login),
-                    }
-                });
-                sorted
-                    .get(index)
-                    .map(|owner| format!("https://github.com/{}", owner.login))
-            }
-            ViewLevel::Repositories { ref owner } => {
-                let index = self.workflows.repositories.selected()?;
-                let data = self.workflows.repositories.data.data()?;
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", owner, a.name);
-                    let b_key = format!("{}/{}", owner, b.name);
-                    let a_fav = self.favorite_repos.contains(&a_key);
-                    let b_fav = self.favorite_repos.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                sorted
-                    .get(index)
-                    .map(|repo| format!("https://github.com/{}/{}", owner, repo.name))
-            }
-            ViewLevel::Workflows {
-                ref owner,
-                ref repo,
-            } => {
-                let index = self.workflows.workflows.selected()?;
-                let data = self.workflows.workflows.data.data()?;
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                let repo = repo.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.id);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.id);
-                    let a_fav = self.favorite_workflows.contains(&a_key);
-                    let b_fav = self.favorite_workflows.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                sorted.get(index).map(|workflow| {
-                    format!(
-                        "https://github.com/{}/{}/actions/workflows/{}",
-                        owner,
-                        repo,
-                        workflow.path.rsplit('/').next().unwrap_or(&workflow.path)
-                    )
-                })
-            }
-            ViewLevel::Runs { owner, repo, .. } => self.workflows.runs.selected_item().map(|run| {
-                format!(
-                    "https://github.com/{}/{}/actions/runs/{}",
-                    owner, repo, run.id
-                )
-            }),
-            ViewLevel::Jobs {
-                owner,
-                repo,
-                run_id,
-                ..
-            } => self.workflows.jobs.selected_item().map(|job| {
-                format!(
-                    "https://github.com/{}/{}/actions/runs/{}/job/{}",
-                    owner, repo, run_id, job.id
-                )
-            }),
-            ViewLevel::Logs {
-                owner,
-                repo,
-                run_id,
-                job_id,
-                ..
-            } => Some(format!(
-                "https://github.com/{}/{}/actions/runs/{}/job/{}",
-                owner, repo, run_id, job_id
-            )),
+    /// Copy the current log line to the clipboard in the given format.
+    fn copy_log_excerpt(&mut self, mode: CopyMode) {
+        let Some(excerpt) = self.current_log_excerpt() else {
+            return;
+        };
+        let Some(text) = excerpt.format(mode) else {
+            self.log_warn(format!(
+                "No {} known for this job",
+                mode.label().to_lowercase()
+            ));
+            return;
+        };
+        match clipboard::copy(&text, self.config.clipboard) {
+            Ok(()) => self.log_info(format!("Copied {}", mode.label().to_lowercase())),
+            Err(e) => self.log_error(format!("Failed to copy to clipboard: {}", e)),
         }
     }
 
-    /// Get GitHub URL for current Runners tab view.
-    fn get_runners_github_url(&self) -> Option<String> {
-        match self.runners.nav.current().clone() {
-            RunnersViewLevel::Repositories => {
-                let index = self.runners.repositories.selected()?;
-                let data = self.runners.repositories.data.data()?;
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", a.owner.login, a.name);
-                    let b_key = format!("{}/{}", b.owner.login, b.name);
-                    let a_fav = self.favorite_repos.contains(&a_key);
-                    let b_fav = self.favorite_repos.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a_key.cmp(&b_key),
-                    }
-                });
-                sorted
-                    .get(index)
-                    .map(|repo| format!("https://github.com/{}/{}", repo.owner.login, repo.name))
-            }
-            RunnersViewLevel::Runners { owner, repo } => Some(format!(
-                "https://github.com/{}/{}/settings/actions/runners",
-                owner, repo
-            )),
-            RunnersViewLevel::Runs { owner, repo, .. } => {
-                self.runners.runs.selected_item().map(|run| {
-                    format!(
-                        "https://github.com/{}/{}/actions/runs/{}",
-                        owner, repo, run.id
-                    )
-                })
+    /// Jobs of every attempt of a run, cached once the run completes.
+    async fn job_attempts(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        workflow_id: u64,
+        run_id: u64,
+    ) -> Option<Vec<Job>> {
+        let client = self.github_client.as_mut()?;
+        match fetch_job_attempts(client, owner, repo, workflow_id, run_id).await {
+            Ok(jobs) => Some(jobs),
+            Err(e) => {
+                self.log_warn(format!("Failed to load job attempts: {}", e));
+                None
             }
-            RunnersViewLevel::Jobs {
-                owner,
-                repo,
-                run_id,
-                ..
-            } => self.runners.jobs.selected_item().map(|job| {
-                format!(
-                    "https://github.com/{}/{}/actions/runs/{}/job/{}",
-                    owner, repo, run_id, job.id
-                )
-            }),
-            RunnersViewLevel::Logs {
-                owner,
-                repo,
-                run_id,
-                job_id,
-                ..
-            } => Some(format!(
-                "https://github.com/{}/{}/actions/runs/{}/job/{}",
-                owner, repo, run_id, job_id
-            )),
         }
     }
 
-    /// Handle Enter key (drill down).
-    async fn handle_enter(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.handle_workflows_enter().await,
-            Tab::Runners => self.handle_runners_enter().await,
-            Tab::Console => {}
+    /// Show the jobs most often passing on retry in the current workflow's
+    /// recent runs. The report is built in the background and pops up once ready.
+    fn show_flaky_report(&mut self) {
+        if self.active_tab != Tab::Workflows {
+            return;
         }
+        let ViewLevel::Runs {
+            owner,
+            repo,
+            workflow_id,
+            ..
+        } = self.workflows.nav.current().clone()
+        else {
+            return;
+        };
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        self.tasks.spawn(
+            TaskKind::FlakyReport,
+            client,
+            move |mut client| async move {
+                let report = fetch_flaky_report(&mut client, &owner, &repo, workflow_id).await;
+                (client, Loaded::FlakyReport(report))
+            },
+        );
     }
 
-    /// Handle Enter in Workflows tab.
-    async fn handle_workflows_enter(&mut self) {
-        // Get the next navigation level based on current selection
-        // Note: For views with favorites, we must sort to match the displayed order
-        let next_level = match self.workflows.nav.current().clone() {
-            ViewLevel::Owners => {
-                let index = match self.workflows.owners.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.workflows.owners.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_fav = self.favorite_owners.contains(&a.login);
-                    let b_fav = self.favorite_owners.contains(&b.login);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.login.cmp(&b.login),
-                    }
-                });
-                sorted.get(index).map(|owner| ViewLevel::Repositories {
-                    owner: owner.login.clone(),
-                })
-            }
-            ViewLevel::Repositories { ref owner } => {
-                let index = match self.workflows.repositories.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.workflows.repositories.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", owner, a.name);
-                    let b_key = format!("{}/{}", owner, b.name);
-                    let a_fav = self.favorite_repos.contains(&a_key);
-                    let b_fav = self.favorite_repos.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                sorted.get(index).map(|repo| ViewLevel::Workflows {
-                    owner,
-                    repo: repo.name.clone(),
-                })
-            }
-            ViewLevel::Workflows {
-                ref owner,
-                ref repo,
-            } => {
-                let index = match self.workflows.workflows.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.workflows.workflows.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                let repo = repo.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.id);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.id);
-                    let a_fav = self.favorite_workflows.contains(&a_key);
-                    let b_fav = self.favorite_workflows.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-                sorted.get(index).map(|workflow| ViewLevel::Runs {
-                    owner,
-                    repo,
-                    workflow_id: workflow.id,
-                    workflow_name: workflow.name.clone(),
-                })
-            }
+    /// Show how many runs of the repository (or, from a Runs list, the workflow)
+    /// are in progress, queued, waiting, or pending, and what each waiting run
+    /// is likely queued behind.
+    async fn show_run_queue(&mut self) {
+        if self.active_tab != Tab::Workflows {
+            return;
+        }
+        let (owner, repo, workflow) = match self.workflows.nav.current().clone() {
+            ViewLevel::Workflows { owner, repo } => (owner, repo, None),
             ViewLevel::Runs {
                 owner,
                 repo,
                 workflow_id,
-                ..
-            } => self
-                .workflows
-                .runs
-                .selected_item()
-                .map(|run| ViewLevel::Jobs {
+                workflow_name,
+            } => (owner, repo, Some((workflow_id, workflow_name))),
+            _ => return,
+        };
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        // A workflow's runs are fetched on their own, so busy repositories'
+        // other runs don't crowd them out of the page
+        let mut runs = Vec::new();
+        for status in QUEUE_STATUSES {
+            let result = match &workflow {
+                Some((workflow_id, _)) => {
+                    client
+                        .get_workflow_runs_with_status(&owner, &repo, *workflow_id, status)
+                        .await
+                }
+                None => client.get_runs_with_status(&owner, &repo, status).await,
+            };
+            match result {
+                Ok(found) => runs.extend(found),
+                Err(e) => {
+                    self.log_warn(format!("Failed to load {} runs: {}", status, e));
+                    return;
+                }
+            }
+        }
+        let scope = match workflow {
+            Some((_, name)) => name,
+            None => format!("{}/{}", owner, repo),
+        };
+        self.run_queue = Some(RunQueue::from_runs(scope, runs));
+    }
+
+    /// Open the deployment environments of the repository whose workflows are listed.
+    async fn open_environments(&mut self) {
+        if self.active_tab != Tab::Workflows {
+            return;
+        }
+        let ViewLevel::Workflows { owner, repo } = self.workflows.nav.current().clone() else {
+            return;
+        };
+        self.workflows
+            .nav
+            .push(ViewLevel::Environments { owner, repo });
+        self.load_current_view().await;
+    }
+
+    /// Refetch the open Jobs list's jobs in the background while any is in
+    /// progress, to update their current step and duration in place without
+    /// reloading the list.
+    fn tick_running_jobs(&mut self) {
+        if self.last_job_tick.elapsed() < JOB_TICK_INTERVAL {
+            return;
+        }
+        let Some((tab, owner, repo, run_id)) = self.open_jobs_list() else {
+            return;
+        };
+        let jobs = self.jobs_list(tab);
+        let in_progress = jobs.data.data().is_some_and(|data| {
+            data.items
+                .iter()
+                .any(|job| job.status == RunStatus::InProgress)
+        });
+        if !in_progress || jobs.stale {
+            return;
+        }
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        self.last_job_tick = Instant::now();
+        self.tasks
+            .spawn(TaskKind::JobTick, client, move |mut fork| async move {
+                let result = fork.get_all_jobs(&owner, &repo, run_id).await;
+                (
+                    fork,
+                    Loaded::JobTick {
+                        tab,
+                        run_id,
+                        result,
+                    },
+                )
+            });
+    }
+
+    /// Update the in-progress jobs of a Jobs list still open with the run's
+    /// jobs fetched in the background.
+    fn show_job_tick(&mut self, tab: Tab, run_id: u64, result: crate::error::Result<Vec<Job>>) {
+        let Ok(fetched) = result else {
+            return;
+        };
+        match self.open_jobs_list() {
+            Some((open_tab, _, _, open_run)) if (open_tab, open_run) == (tab, run_id) => {}
+            _ => return,
+        }
+        let jobs = self.jobs_list(tab);
+        for job in fetched {
+            let id = job.id;
+            jobs.replace_item(
+                |existing| existing.id == id && existing.status == RunStatus::InProgress,
+                job,
+            );
+        }
+    }
+
+    /// The tab, repository, and run of the Jobs list the active tab shows, if any.
+    fn open_jobs_list(&self) -> Option<(Tab, String, String, u64)> {
+        match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Jobs {
                     owner,
                     repo,
-                    workflow_id,
-                    run_id: run.id,
-                    run_number: run.run_number,
-                }),
-            ViewLevel::Jobs {
-                owner,
-                repo,
-                workflow_id,
-                run_id,
-                ..
-            } => self
-                .workflows
-                .jobs
-                .selected_item()
-                .map(|job| ViewLevel::Logs {
+                    run_id,
+                    ..
+                } => Some((Tab::Workflows, owner.clone(), repo.clone(), *run_id)),
+                _ => None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Jobs {
                     owner,
                     repo,
-                    workflow_id,
                     run_id,
-                    job_id: job.id,
-                    job_name: job.name.clone(),
-                    job_status: job.status,
-                    job_conclusion: job.conclusion,
-                }),
-            ViewLevel::Logs { .. } => None, // Can't drill down further
-        };
+                    ..
+                } => Some((Tab::Runners, owner.clone(), repo.clone(), *run_id)),
+                _ => None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
 
-        if let Some(level) = next_level {
-            self.workflows.nav.push(level);
-            self.load_current_view().await;
+    /// The Jobs list of the Workflows or Runners tab.
+    fn jobs_list(&mut self, tab: Tab) -> &mut SelectableList<Job> {
+        match tab {
+            Tab::Runners => &mut self.runners.jobs,
+            _ => &mut self.workflows.jobs,
         }
     }
 
-    /// Handle Enter in Runners tab.
-    async fn handle_runners_enter(&mut self) {
-        // Note: For views with favorites, we must sort to match the displayed order
-        let next_level = match self.runners.nav.current().clone() {
-            RunnersViewLevel::Repositories => {
-                let index = match self.runners.repositories.selected() {
-                    Some(i) => i,
-                    None => return,
+    /// Download the next queued failed-job log of the active tab into the cache
+    /// in a background task, one at a time. Waits until keys have been idle like
+    /// the preview does, so requests the user asked for go first.
+    fn prefetch_logs(&mut self) {
+        if self.tasks.is_running(TaskKind::LogPrefetch) {
+            return;
+        }
+        let idle = self.last_input.elapsed() >= PREVIEW_DELAY;
+        let queue = match self.active_tab {
+            Tab::Workflows => &mut self.workflows.log_prefetch,
+            Tab::Runners => &mut self.runners.log_prefetch,
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return,
+        };
+        let Some(prefetch) = queue.next(idle) else {
+            return;
+        };
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        self.tasks
+            .spawn(TaskKind::LogPrefetch, client, move |mut fork| async move {
+                let result = download_job_log(
+                    &mut fork,
+                    &prefetch.owner,
+                    &prefetch.repo,
+                    prefetch.workflow_id,
+                    &prefetch.job,
+                )
+                .await;
+                let job_name = prefetch.job.name;
+                (fork, Loaded::LogPrefetch { job_name, result })
+            });
+    }
+
+    /// Read more of a streaming log and show the lines received so far. Once
+    /// complete, the log is cached and shown in full. Stops when the tab
+    /// leaves the log.
+    async fn stream_logs(&mut self) {
+        let Some((tab, job_id)) = self
+            .log_stream
+            .as_ref()
+            .map(|(tab, stream)| (*tab, stream.job_id))
+        else {
+            return;
+        };
+        if self.log_job_in(tab).map(|(open_job, _)| open_job) != Some(job_id) {
+            self.log_stream = None;
+            return;
+        }
+        let Some((_, stream)) = self.log_stream.as_mut() else {
+            return;
+        };
+        let result = stream.read(LOG_STREAM_BUDGET).await;
+        match result {
+            Ok(false) => {
+                let lines = stream.new_lines();
+                let content = match tab {
+                    Tab::Runners => &mut self.runners.log.content,
+                    _ => &mut self.workflows.log.content,
                 };
-                let data = match self.runners.repositories.data.data() {
-                    Some(d) => d,
-                    None => return,
+                match content {
+                    LoadingState::Loaded(logs) => logs.push_str(&lines),
+                    _ => *content = LoadingState::Loaded(lines),
+                }
+            }
+            Ok(true) => {
+                let Some((_, stream)) = self.log_stream.take() else {
+                    return;
                 };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", a.owner.login, a.name);
-                    let b_key = format!("{}/{}", b.owner.login, b.name);
-                    let a_fav = self.favorite_repos.contains(&a_key);
-                    let b_fav = self.favorite_repos.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a_key.cmp(&b_key),
-                    }
-                });
-                sorted.get(index).map(|repo| RunnersViewLevel::Runners {
-                    owner: repo.owner.login.clone(),
-                    repo: repo.name.clone(),
-                })
+                let jump_to_error = stream.jump_to_error;
+                let cache_path = stream.cache_path.clone();
+                let logs = stream.into_text();
+                if let Some(path) = cache_path {
+                    let _ = cache::write_text(&path, &logs);
+                }
+                self.set_streamed_logs(tab, logs, jump_to_error);
+            }
+            Err(e) => {
+                self.log_stream = None;
+                let error = LoadingState::Error(ViewError::from(&e));
+                match tab {
+                    Tab::Runners => self.runners.log.content = error,
+                    _ => self.workflows.log.content = error,
+                }
+                self.log_error(format!("Failed to load logs: {}", e));
+            }
+        }
+    }
+
+    /// A log from the disk cache, unless its TTL has passed since it was cached.
+    fn read_cached_log(&self, path: Option<&Path>) -> Option<String> {
+        let path = path?;
+        let ttl = self.config.cache_ttl(CacheKind::Logs);
+        let expired = ttl.is_some_and(|ttl| {
+            cache::modified_at(path)
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_none_or(|age| age > ttl)
+        });
+        if expired {
+            return None;
+        }
+        cache::read_text(path).ok().flatten()
+    }
+
+    /// Show a log that finished streaming into a tab.
+    fn set_streamed_logs(&mut self, tab: Tab, logs: String, jump_to_error: bool) {
+        match tab {
+            Tab::Runners => {
+                self.runners.log.content = LoadingState::Loaded(logs);
+                if jump_to_error {
+                    self.runners.log.scroll_to_first_error();
+                }
+            }
+            _ => {
+                self.workflows.set_logs(logs);
+                if jump_to_error {
+                    self.workflows.log.scroll_to_first_error();
+                }
+            }
+        }
+        self.restore_log_state(tab);
+    }
+
+    /// Resume a log just loaded into `tab` where it was left: its scroll
+    /// position and, in the active tab, its search.
+    fn restore_log_state(&mut self, tab: Tab) {
+        let Some((job_id, _)) = self.log_job_in(tab) else {
+            return;
+        };
+        let saved = self.log_views.get(&job_id).cloned();
+        if tab == self.active_tab {
+            self.search_query = saved
+                .as_ref()
+                .map(|state| state.search.clone())
+                .unwrap_or_default();
+            self.search_matches.clear();
+            self.search_match_index = 0;
+            self.execute_search();
+        }
+        let Some(saved) = saved else {
+            return;
+        };
+        let Some(log) = self.log_viewer_mut(tab) else {
+            return;
+        };
+        let line_count = log.content.data().map_or(0, |logs| logs.lines().count());
+        log.scroll_x = saved.scroll_x;
+        log.scroll_y = saved.scroll_y.min(line_count.saturating_sub(1) as u16);
+        // Continue the search from where the log was left
+        let top = log.scroll_y as usize;
+        if tab == self.active_tab {
+            self.search_match_index = self
+                .search_matches
+                .iter()
+                .position(|&line| line >= top)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Remember where the log in view is scrolled and what it was searched
+    /// for. Waits until a streaming log is complete and restored.
+    fn record_log_state(&mut self) {
+        let Some((job_id, scroll_y)) = self.log_job() else {
+            return;
+        };
+        let streaming = self
+            .log_stream
+            .as_ref()
+            .is_some_and(|(tab, _)| *tab == self.active_tab);
+        let Some(log) = self.log_viewer(self.active_tab) else {
+            return;
+        };
+        let (loaded, scroll_x) = (log.content.is_loaded(), log.scroll_x);
+        if streaming || !loaded || self.search_active {
+            return;
+        }
+        let state = self.log_views.entry(job_id).or_default();
+        state.scroll_x = scroll_x;
+        state.scroll_y = scroll_y;
+        state.search.clone_from(&self.search_query);
+        state.viewed_at = Some(chrono::Utc::now());
+        if state.is_empty() {
+            self.log_views.remove(&job_id);
+        }
+        prune_log_views(&mut self.log_views);
+    }
+
+    /// Download progress of a log still streaming into a tab.
+    pub fn log_stream_progress(&self, tab: Tab) -> Option<String> {
+        self.log_stream
+            .as_ref()
+            .filter(|(stream_tab, _)| *stream_tab == tab)
+            .map(|(_, stream)| stream.progress())
+    }
+
+    /// Look up the jobs busy runners are working on. Starts once the Runners
+    /// list has been drawn, fetching the jobs of the repository's in-progress
+    /// runs concurrently in the background.
+    fn enrich_runners(&mut self) {
+        if !self.runners.enrichment_loading {
+            return;
+        }
+        let RunnersViewLevel::Runners { owner, repo } = self.runners.nav.current().clone() else {
+            self.runners.enrichment_loading = false;
+            return;
+        };
+        let any_busy = self.runners.runners.data.data().is_some_and(|rows| {
+            rows.items.iter().any(|row| match row {
+                RunnerRow::Runner(runner) => runner.busy,
+                RunnerRow::ScaleSet { runners, .. } => runners.iter().any(|r| r.busy),
+            })
+        });
+        if !any_busy {
+            self.runners.enrichment_loading = false;
+            self.runners.runner_jobs.clear();
+            return;
+        }
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        // Another repository's lookup still running holds this one until it ends
+        let started = self
+            .tasks
+            .spawn(TaskKind::RunnerJobs, client, move |mut fork| async move {
+                let result = fetch_runner_jobs(&mut fork, &owner, &repo).await;
+                (
+                    fork,
+                    Loaded::RunnerJobs {
+                        owner,
+                        repo,
+                        result,
+                    },
+                )
+            });
+        if started {
+            self.runners.enrichment_loading = false;
+        }
+    }
+
+    /// Show the jobs busy runners are working on, if their repository's
+    /// runners are still listed.
+    fn show_runner_jobs(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        result: crate::error::Result<(HashMap<String, RunnerJob>, usize)>,
+    ) {
+        let (jobs, failures) = match result {
+            Ok(jobs) => jobs,
+            Err(e) => {
+                self.log_warn(format!("Failed to load in-progress runs: {}", e));
+                return;
             }
+        };
+        if failures > 0 {
+            self.log_warn(format!(
+                "Failed to load jobs of {} in-progress runs",
+                failures
+            ));
+        }
+        match self.runners.nav.current() {
             RunnersViewLevel::Runners {
-                ref owner,
-                ref repo,
-            } => {
-                let index = match self.runners.runners.selected() {
-                    Some(i) => i,
-                    None => return,
-                };
-                let data = match self.runners.runners.data.data() {
-                    Some(d) => d,
-                    None => return,
-                };
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                let owner = owner.clone();
-                let repo = repo.clone();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.name);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.name);
-                    let a_fav = self.favorite_runners.contains(&a_key);
-                    let b_fav = self.favorite_runners.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
+                owner: listed_owner,
+                repo: listed_repo,
+            } if (listed_owner.as_str(), listed_repo.as_str()) == (owner, repo) => {
+                self.runners.runner_jobs = jobs;
+            }
+            _ => {}
+        }
+    }
+
+    /// Load the cron triggers of the listed workflows, to forecast their next
+    /// runs. Skipped without a token, as it costs a request per workflow.
+    fn load_schedules(&mut self, owner: &str, repo: &str) {
+        if self.anonymous() {
+            return;
+        }
+        let Some(list) = self.workflows.workflows.data.data() else {
+            return;
+        };
+        let workflows: Vec<Workflow> = list
+            .items
+            .iter()
+            .filter(|w| {
+                w.state == WorkflowState::Active && !self.workflows.schedules.contains_key(&w.id)
+            })
+            .cloned()
+            .collect();
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        if workflows.is_empty() {
+            return;
+        }
+        let (owner, repo) = (owner.to_string(), repo.to_string());
+        self.tasks
+            .spawn(TaskKind::Schedules, client, move |mut client| async move {
+                let results: Vec<_> = stream::iter(workflows)
+                    .map(|workflow| {
+                        let mut fork = client.fork();
+                        let (owner, repo) = (owner.clone(), repo.clone());
+                        async move {
+                            let result =
+                                workflow_schedule(&mut fork, &owner, &repo, &workflow).await;
+                            (workflow.id, result, fork)
+                        }
+                    })
+                    .buffer_unordered(ENRICHMENT_CONCURRENCY)
+                    .collect()
+                    .await;
+                let mut schedules = Vec::new();
+                let mut failures = 0;
+                for (id, result, fork) in results {
+                    client.absorb(fork);
+                    match result {
+                        Ok(schedule) => schedules.push((id, schedule)),
+                        Err(_) => failures += 1,
                     }
-                });
-                sorted.get(index).map(|runner| RunnersViewLevel::Runs {
+                }
+                let loaded = Loaded::Schedules {
                     owner,
                     repo,
-                    runner_name: Some(runner.name.clone()),
-                })
+                    schedules,
+                    failures,
+                };
+                (client, loaded)
+            });
+    }
+
+    /// Keep the cron triggers read in the background. If another repository's
+    /// workflows are listed by now, theirs are read next.
+    fn show_schedules(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        schedules: Vec<(u64, WorkflowSchedule)>,
+        failures: usize,
+    ) {
+        self.workflows.schedules.extend(schedules);
+        if failures > 0 {
+            self.log_warn(format!(
+                "Failed to read {} workflow files for schedules",
+                failures
+            ));
+        }
+        match self.workflows.nav.current().clone() {
+            ViewLevel::Workflows {
+                owner: listed_owner,
+                repo: listed_repo,
+            } if (listed_owner.as_str(), listed_repo.as_str()) != (owner, repo) => {
+                self.load_schedules(&listed_owner, &listed_repo)
             }
-            RunnersViewLevel::Runs { owner, repo, .. } => {
-                self.runners
-                    .runs
-                    .selected_item()
-                    .map(|run| RunnersViewLevel::Jobs {
+            _ => {}
+        }
+    }
+
+    /// Load the latest run of each listed workflow, for the Workflows list's
+    /// badges, from cache or else in the background. The repository's recent
+    /// runs cover most workflows in one request; the rest are fetched a few at
+    /// a time. Without a token, only cached badges are shown.
+    fn load_latest_runs(&mut self, owner: &str, repo: &str) {
+        if !self.workflows.latest_runs.is_empty() {
+            return;
+        }
+        let Some(workflows) = self
+            .workflows
+            .workflows
+            .data
+            .data()
+            .map(|d| d.items.clone())
+        else {
+            return;
+        };
+        let path = cache::latest_runs_path(owner, repo);
+        let ttl = self.config.cache_ttl(CacheKind::Runs);
+        let cached = path
+            .as_ref()
+            .and_then(|path| cache::read_cached::<HashMap<u64, WorkflowRun>>(path).ok())
+            .flatten()
+            .filter(|cached| ttl.is_none_or(|ttl| cached.is_valid(ttl)));
+        if let Some(cached) = cached {
+            self.workflows.latest_runs = cached.data;
+            return;
+        }
+        if self.anonymous() {
+            return;
+        }
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        let (owner, repo) = (owner.to_string(), repo.to_string());
+        self.tasks
+            .spawn(TaskKind::LatestRuns, client, move |mut fork| async move {
+                let result = fetch_latest_runs(&mut fork, &owner, &repo, &workflows).await;
+                (
+                    fork,
+                    Loaded::LatestRuns {
                         owner,
                         repo,
-                        run_id: run.id,
-                        run_number: run.run_number,
-                    })
+                        result,
+                    },
+                )
+            });
+    }
+
+    /// Cache the latest runs fetched in the background, and show them if the
+    /// repository's workflows are still listed. If another repository's are
+    /// listed by now, theirs are loaded next.
+    fn show_latest_runs(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        result: crate::error::Result<HashMap<u64, WorkflowRun>>,
+    ) {
+        let latest = match result {
+            Ok(latest) => latest,
+            Err(e) => {
+                self.log_warn(format!("Failed to load latest runs: {}", e));
+                return;
             }
-            RunnersViewLevel::Jobs {
-                owner,
-                repo,
-                run_id,
-                ..
-            } => self
-                .runners
-                .jobs
-                .selected_item()
-                .map(|job| RunnersViewLevel::Logs {
-                    owner,
-                    repo,
-                    run_id,
-                    job_id: job.id,
-                    job_name: job.name.clone(),
-                    job_status: job.status,
-                    job_conclusion: job.conclusion,
-                }),
-            RunnersViewLevel::Logs { .. } => None,
         };
+        if let Some(path) = cache::latest_runs_path(owner, repo) {
+            let _ = cache::write_cached(&path, &latest, false);
+        }
+        if let ViewLevel::Workflows {
+            owner: listed_owner,
+            repo: listed_repo,
+        } = self.workflows.nav.current().clone()
+        {
+            if (listed_owner.as_str(), listed_repo.as_str()) == (owner, repo) {
+                self.workflows.latest_runs = latest;
+            } else {
+                self.load_latest_runs(&listed_owner, &listed_repo);
+            }
+        }
+    }
 
-        if let Some(level) = next_level {
-            self.runners.nav.push(level);
-            self.load_runners_view().await;
+    /// Show upcoming scheduled runs of favorite workflows.
+    async fn show_schedule_overview(&mut self) {
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        let now = chrono::Utc::now();
+        let mut upcoming = Vec::new();
+        let mut favorites: Vec<&String> = self.favorite_workflows.iter().collect();
+        favorites.sort();
+        for key in favorites {
+            let mut parts = key.splitn(3, '/');
+            let (Some(owner), Some(repo), Some(Ok(workflow_id))) = (
+                parts.next(),
+                parts.next(),
+                parts.next().map(str::parse::<u64>),
+            ) else {
+                continue;
+            };
+            // Any cached schedule will do; the Workflows list refreshes edited ones
+            let cached = cache::workflow_schedule_path(owner, repo, workflow_id)
+                .and_then(|path| cache::read_cached::<WorkflowSchedule>(&path).ok().flatten())
+                .map(|cached| cached.data);
+            let schedule = match cached {
+                Some(schedule) => schedule,
+                None => {
+                    let result = match client.get_workflow(owner, repo, workflow_id).await {
+                        Ok(workflow) => workflow_schedule(client, owner, repo, &workflow).await,
+                        Err(e) => Err(e),
+                    };
+                    match result {
+                        Ok(schedule) => schedule,
+                        Err(_) => continue,
+                    }
+                }
+            };
+            upcoming.extend(
+                schedule
+                    .upcoming(now, SCHEDULE_OVERVIEW_RUNS)
+                    .into_iter()
+                    .map(|at| UpcomingRun {
+                        at,
+                        owner: owner.to_string(),
+                        repo: repo.to_string(),
+                        workflow: schedule.name.clone(),
+                    }),
+            );
+        }
+        upcoming.sort_by_key(|run| run.at);
+        upcoming.truncate(SCHEDULE_OVERVIEW_RUNS * 5);
+        self.schedule_overview = Some(upcoming);
+    }
+
+    /// Show the Actions cache entries of the repository in view.
+    async fn show_caches(&mut self) {
+        let Some(context) = self.nav_context() else {
+            return;
+        };
+        let (Some(owner), Some(repo)) = (context.owner, context.repo) else {
+            return;
+        };
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        let mut view = CachesView::new(owner, repo);
+
+        let mut caches = Vec::new();
+        let mut page = 1;
+        let result = loop {
+            match client
+                .get_actions_caches(&view.owner, &view.repo, page, 100)
+                .await
+            {
+                Ok((mut items, total_count)) => {
+                    let done = items.is_empty();
+                    caches.append(&mut items);
+                    if done || caches.len() as u64 >= total_count {
+                        break Ok(total_count);
+                    }
+                    page += 1;
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        match result {
+            Ok(total_count) => view.caches.set_loaded(caches, total_count),
+            Err(e) => {
+                let message = e.to_string();
+                self.log_error(format!("Failed to load Actions caches: {}", message));
+                view.caches.set_error(message);
+            }
+        }
+        self.caches_view = Some(view);
+    }
+
+    /// Show the secrets and variables available to the repository in view.
+    /// Scopes the token can't read are skipped with a note.
+    async fn show_secrets(&mut self) {
+        let Some(context) = self.nav_context() else {
+            return;
+        };
+        let (Some(owner), Some(repo)) = (context.owner, context.repo) else {
+            return;
+        };
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+
+        let mut inventory = Inventory::default();
+        let mut notes = Vec::new();
+        if client.lacks_scope("repo") {
+            notes.push("Repository secrets need a token with the repo scope".to_string());
+        } else {
+            let base = format!("/repos/{}/{}/actions", owner, repo);
+            match client
+                .get_actions_secrets(&format!("{}/secrets", base))
+                .await
+            {
+                Ok(secrets) => inventory.repo_secrets = secrets,
+                Err(e) => notes.push(format!("Repository secrets: {}", e)),
+            }
+            match client
+                .get_actions_variables(&format!("{}/variables", base))
+                .await
+            {
+                Ok(variables) => inventory.repo_variables = variables,
+                Err(e) => notes.push(format!("Repository variables: {}", e)),
+            }
+            // Organization secrets shared with the repository are listed
+            // without admin:org; user accounts have none
+            let owner_type = self
+                .workflows
+                .owners
+                .data
+                .data()
+                .and_then(|owners| owners.items.iter().find(|o| o.login == owner))
+                .map(|o| o.owner_type);
+            if owner_type != Some(OwnerType::User) {
+                match client
+                    .get_actions_secrets(&format!("{}/organization-secrets", base))
+                    .await
+                {
+                    Ok(secrets) => inventory.org_secrets = secrets,
+                    Err(JoltError::NotFound(_)) => {}
+                    Err(e) => notes.push(format!("Organization secrets: {}", e)),
+                }
+                match client
+                    .get_actions_variables(&format!("{}/organization-variables", base))
+                    .await
+                {
+                    Ok(variables) => inventory.org_variables = variables,
+                    Err(JoltError::NotFound(_)) => {}
+                    Err(e) => notes.push(format!("Organization variables: {}", e)),
+                }
+            }
+        }
+
+        let entries = inventory.entries();
+        let count = entries.len() as u64;
+        let mut list = SelectableList::new();
+        list.set_loaded(entries, count);
+        self.secrets_view = Some(SecretsView {
+            owner,
+            repo,
+            entries: list,
+            notes,
+        });
+    }
+
+    /// Show the Actions permissions and policy of the repository in view and its
+    /// organization. Settings the token can't read are skipped with a note.
+    async fn show_policy(&mut self) {
+        let Some(context) = self.nav_context() else {
+            return;
+        };
+        let (Some(owner), Some(repo)) = (context.owner, context.repo) else {
+            return;
+        };
+        let owner_type = self
+            .workflows
+            .owners
+            .data
+            .data()
+            .and_then(|owners| owners.items.iter().find(|o| o.login == owner))
+            .map(|o| o.owner_type);
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+
+        let mut sections = Vec::new();
+        let mut notes = Vec::new();
+        let base = format!("/repos/{}/{}", owner, repo);
+        // GitHub answers 403, or 404 for private repositories, to tokens without admin access
+        let settings = match actions_policy(client, &base, &mut notes).await {
+            Ok(parts) => parts.settings(),
+            Err(_) => {
+                notes.push("Reading it needs admin access to the repository".to_string());
+                Vec::new()
+            }
+        };
+        sections.push(PolicySection {
+            title: "Repository".to_string(),
+            settings,
+            notes,
+        });
+        // User accounts have no organization policy; owners not yet loaded are tried
+        if owner_type != Some(OwnerType::User) {
+            let mut settings = Vec::new();
+            let mut notes = Vec::new();
+            if client.lacks_scope("admin:org") {
+                if owner_type == Some(OwnerType::Organization) {
+                    notes.push("Reading it needs a token with the admin:org scope".to_string());
+                }
+            } else {
+                let base = format!("/orgs/{}", owner);
+                match actions_policy(client, &base, &mut notes).await {
+                    Ok(parts) => settings = parts.settings(),
+                    // Likely a user account, which has no organization
+                    Err(JoltError::NotFound(_)) if owner_type.is_none() => {}
+                    Err(_) => {
+                        notes.push("Reading it needs admin access to the organization".to_string())
+                    }
+                }
+            }
+            if !settings.is_empty() || !notes.is_empty() {
+                sections.push(PolicySection {
+                    title: format!("Organization {}", owner),
+                    settings,
+                    notes,
+                });
+            }
+        }
+        self.policy_view = Some(PolicyView {
+            owner,
+            repo,
+            sections,
+        });
+    }
+
+    /// Repository and run in view: the selected run of a Runs list, or the
+    /// run whose jobs or logs are shown.
+    fn viewed_run(&self) -> Option<(String, String, &WorkflowRun)> {
+        // None for the selected run, else the run drilled into
+        let (runs, run_id) = match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Runs { .. } => (&self.workflows.runs, None),
+                ViewLevel::Jobs { run_id, .. } | ViewLevel::Logs { run_id, .. } => {
+                    (&self.workflows.runs, Some(*run_id))
+                }
+                _ => return None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Runs { .. } => (&self.runners.runs, None),
+                RunnersViewLevel::Jobs { run_id, .. } | RunnersViewLevel::Logs { run_id, .. } => {
+                    (&self.runners.runs, Some(*run_id))
+                }
+                _ => return None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return None,
+        };
+        let run = match run_id {
+            None => runs.selected_item()?,
+            Some(run_id) => runs
+                .data
+                .data()?
+                .items
+                .iter()
+                .find(|run| run.id == run_id)?,
+        };
+        let context = self.nav_context()?;
+        Some((context.owner?, context.repo?, run))
+    }
+
+    /// Show the check runs of the selected run's commit, including those
+    /// reported by other CI providers and code scanning.
+    async fn show_checks(&mut self) {
+        let Some((owner, repo, run)) = self.viewed_run() else {
+            return;
+        };
+        let sha = run.head_sha.clone();
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        let mut view = ChecksView::new(owner, repo, sha);
+
+        let mut checks = Vec::new();
+        let mut page = 1;
+        let result = loop {
+            match client
+                .get_check_runs(&view.owner, &view.repo, &view.sha, page, 100)
+                .await
+            {
+                Ok((mut items, total_count)) => {
+                    let done = items.is_empty();
+                    checks.append(&mut items);
+                    if done || checks.len() as u64 >= total_count {
+                        break Ok(total_count);
+                    }
+                    page += 1;
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        match result {
+            Ok(total_count) => {
+                sort_checks(&mut checks);
+                view.checks.set_loaded(checks, total_count);
+            }
+            Err(e) => {
+                let message = e.to_string();
+                self.log_error(format!("Failed to load checks: {}", message));
+                view.checks.set_error(message);
+            }
+        }
+        self.checks_view = Some(view);
+    }
+
+    /// Show the files of the viewed run attempt's log archive, downloading
+    /// and extracting it into the cache unless that was done once the run
+    /// completed. A run still in progress gets more logs, so it's downloaded
+    /// each time.
+    async fn show_log_archive(&mut self) {
+        let Some((owner, repo, run)) = self.viewed_run() else {
+            return;
+        };
+        let (run_id, run_number, workflow_id) = (run.id, run.run_number, run.workflow_id);
+        let attempt = run.run_attempt.unwrap_or(1);
+        let completed = run.status == RunStatus::Completed;
+        let Some(dir) = cache::run_logs_dir(&owner, &repo, workflow_id, run_id, attempt) else {
+            return;
+        };
+        let mut view = LogArchiveView::new(owner, repo, run_number, dir);
+
+        let cached = list_archive(&view.dir)
+            .ok()
+            .filter(|files| completed && !files.is_empty());
+        let result = match cached {
+            Some(files) => Ok(files),
+            None => match self.github_client.as_mut() {
+                Some(client) => {
+                    match client
+                        .get_run_logs_archive(&view.owner, &view.repo, run_id, attempt)
+                        .await
+                    {
+                        Ok(bytes) => extract_archive(&bytes, &view.dir)
+                            .and_then(|()| list_archive(&view.dir))
+                            .map_err(JoltError::from),
+                        Err(e) => Err(e),
+                    }
+                }
+                None => return,
+            },
+        };
+        match result {
+            Ok(files) => {
+                let count = files.len() as u64;
+                view.files.set_loaded(files, count);
+            }
+            Err(e) => {
+                let message = e.to_string();
+                self.log_error(format!("Failed to load log archive: {}", message));
+                view.files.set_error(message);
+            }
+        }
+        self.log_archive = Some(view);
+    }
+
+    /// The job selected in a Jobs list, or whose log is open, with its repository.
+    fn viewed_job(&self) -> Option<(String, String, &Job)> {
+        let in_job = match self.active_tab {
+            Tab::Workflows => matches!(
+                self.workflows.nav.current(),
+                ViewLevel::Jobs { .. } | ViewLevel::Logs { .. }
+            ),
+            Tab::Runners => matches!(
+                self.runners.nav.current(),
+                RunnersViewLevel::Jobs { .. } | RunnersViewLevel::Logs { .. }
+            ),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => false,
+        };
+        if !in_job {
+            return None;
+        }
+        let context = self.nav_context()?;
+        // The jobs list keeps its selection while a log is open
+        let jobs = match self.active_tab {
+            Tab::Runners => &self.runners.jobs,
+            _ => &self.workflows.jobs,
+        };
+        Some((context.owner?, context.repo?, jobs.selected_item()?))
+    }
+
+    /// Show the summary the viewed job published, rendered from Markdown.
+    async fn show_job_summary(&mut self) {
+        let Some((owner, repo, job)) = self.viewed_job() else {
+            return;
+        };
+        let (job_id, job_name) = (job.id, job.name.clone());
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        match client.get_check_run(&owner, &repo, job_id).await {
+            Ok(check) => {
+                let markdown = check
+                    .output
+                    .as_ref()
+                    .map(summary_markdown)
+                    .unwrap_or_default();
+                self.job_summary = Some(JobSummaryView::new(job_name, markdown));
+            }
+            Err(e) => self.log_error(format!("Failed to load job summary: {}", e)),
+        }
+    }
+
+    /// Delete the Actions cache entry selected in the caches popup.
+    async fn delete_selected_cache(&mut self) {
+        let (Some(view), Some(client)) = (self.caches_view.as_mut(), self.github_client.as_mut())
+        else {
+            return;
+        };
+        let Some(cache) = view.caches.selected_item().cloned() else {
+            return;
+        };
+        match client
+            .delete_actions_cache(&view.owner, &view.repo, cache.id)
+            .await
+        {
+            Ok(()) => {
+                view.remove(cache.id);
+                self.log_info(format!("Deleted Actions cache {}", cache.key));
+            }
+            Err(e) => self.log_error(format!(
+                "Failed to delete Actions cache {}: {}",
+                cache.key, e
+            )),
+        }
+    }
+
+    /// Selected run in the current Runs list, to be re-run with debug logging.
+    fn selected_run_for_rerun(&self) -> Option<PendingRerun> {
+        let (owner, repo, run) = match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Runs { owner, repo, .. } => {
+                    (owner, repo, self.workflows.runs.selected_item()?)
+                }
+                _ => return None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Runs { owner, repo, .. } => {
+                    (owner, repo, self.runners.runs.selected_item()?)
+                }
+                _ => return None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => return None,
+        };
+        Some(PendingRerun {
+            owner: owner.clone(),
+            repo: repo.clone(),
+            run_id: run.id,
+            run_number: run.run_number,
+            attempt: run.run_attempt.unwrap_or(1) + 1,
+        })
+    }
+
+    /// Re-run a workflow run with debug logging and refresh the Runs list.
+    async fn rerun_with_debug(&mut self, rerun: PendingRerun) {
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        match client
+            .rerun_workflow_run(&rerun.owner, &rerun.repo, rerun.run_id, true)
+            .await
+        {
+            Ok(()) => {
+                self.debug_runs
+                    .insert(format!("{}/{}", rerun.run_id, rerun.attempt));
+                self.log_info(format!(
+                    "Re-running #{} with debug logging",
+                    rerun.run_number
+                ));
+                self.workflows.runs.mark_stale();
+                self.runners.runs.mark_stale();
+            }
+            Err(e) => self.log_error(format!("Failed to re-run #{}: {}", rerun.run_number, e)),
+        }
+    }
+
+    /// Show diagnostic logs for the selected self-hosted runner.
+    async fn open_diag_logs(&mut self) {
+        if self.active_tab != Tab::Runners {
+            return;
+        }
+        let RunnersViewLevel::Runners { owner, repo } = self.runners.nav.current().clone() else {
+            return;
+        };
+        let Some(RunnerRow::Runner(runner)) = self.runners.runners.selected_item() else {
+            return;
+        };
+        let level = RunnersViewLevel::DiagLogs {
+            owner,
+            repo,
+            runner_name: runner.name.clone(),
+        };
+        self.runners.nav.push(level);
+        self.load_runners_view().await;
+    }
+
+    /// Show the runner groups of the organization owning the selected or current repository.
+    async fn open_runner_groups(&mut self) {
+        if self.active_tab != Tab::Runners {
+            return;
+        }
+        let org = match self.runners.nav.current() {
+            RunnersViewLevel::Repositories => self
+                .runners
+                .repositories
+                .selected_item()
+                .map(|repo| repo.owner.login.clone()),
+            RunnersViewLevel::Runners { owner, .. } => Some(owner.clone()),
+            _ => None,
+        };
+        let Some(org) = org else {
+            return;
+        };
+        self.runners
+            .nav
+            .push(RunnersViewLevel::RunnerGroups { org });
+        self.load_runners_view().await;
+    }
+
+    /// Toggle showing only the user's own runs in Runs lists.
+    async fn toggle_my_runs(&mut self) {
+        let in_runs = match self.active_tab {
+            Tab::Workflows => matches!(self.workflows.nav.current(), ViewLevel::Runs { .. }),
+            Tab::Runners => matches!(self.runners.nav.current(), RunnersViewLevel::Runs { .. }),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => false,
+        };
+        if !in_runs {
+            return;
+        }
+        self.my_runs_only = !self.my_runs_only;
+        // Reload the visible list now and the other tab's when it's next shown
+        if self.active_tab == Tab::Workflows {
+            self.workflows.runs.reset();
+            self.runners.runs.mark_stale();
+        } else {
+            self.runners.runs.reset();
+            self.workflows.runs.mark_stale();
+        }
+        self.load_current_view().await;
+    }
+
+    /// Repository of the Runs list in view, as (owner, repo).
+    fn runs_repo(&self) -> Option<(String, String)> {
+        match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Runs { owner, repo, .. } => Some((owner.clone(), repo.clone())),
+                _ => None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Runs { owner, repo, .. } => Some((owner.clone(), repo.clone())),
+                _ => None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Show the current repository's branches to narrow the Runs list to one.
+    fn open_branch_picker(&mut self) {
+        let Some((owner, repo)) = self.runs_repo() else {
+            return;
+        };
+        if self.github_client.is_none() {
+            return;
+        }
+        let mut picker = BranchPicker::new(owner, repo);
+        picker.branches.set_loading();
+        self.branch_picker = Some(picker);
+        self.fetch_branch_page(1);
+    }
+
+    /// Fetch a page of the branch picker's branches in the background.
+    fn fetch_branch_page(&mut self, page: u32) {
+        let (Some(picker), Some(client)) = (&self.branch_picker, &self.github_client) else {
+            return;
+        };
+        let (owner, repo) = (picker.owner.clone(), picker.repo.clone());
+        self.tasks
+            .spawn(TaskKind::Branches, client, move |mut fork| async move {
+                let result = fork.get_branches(&owner, &repo, page, 100).await;
+                (
+                    fork,
+                    Loaded::Branches {
+                        owner,
+                        repo,
+                        page,
+                        result,
+                    },
+                )
+            });
+    }
+
+    /// Add a fetched page of branches to the picker and fetch the next one,
+    /// unless the picker was closed or opened on another repository since.
+    fn show_branches(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+        result: crate::error::Result<Vec<Branch>>,
+    ) {
+        let current = self.current_branch().map(str::to_string);
+        let Some(picker) = self.branch_picker.as_mut() else {
+            return;
+        };
+        if (picker.owner.as_str(), picker.repo.as_str()) != (owner, repo) {
+            // This picker's first page waited for the other one's request
+            if !picker.branches.data.is_loaded() {
+                self.fetch_branch_page(1);
+            }
+            return;
+        }
+        match result {
+            Ok(branches) => {
+                let more = branches.len() == 100 && page < MAX_BRANCH_PAGES;
+                picker.add_branches(branches, current.as_deref());
+                picker.loading = more;
+                if more {
+                    self.fetch_branch_page(page + 1);
+                }
+            }
+            Err(e) => {
+                picker.loading = false;
+                if !picker.branches.data.is_loaded() {
+                    picker.branches.set_error(e.to_string());
+                }
+                self.log_error(format!("Failed to load branches: {}", e));
+            }
+        }
+    }
+
+    /// Narrow the Runs list in view to `branch`, or show all branches, and reload it.
+    async fn set_branch_filter(&mut self, branch: Option<String>) {
+        if branch.as_deref() == self.current_branch() {
+            return;
+        }
+        if self.active_tab == Tab::Runners {
+            self.runners.current_branch = branch;
+            self.runners.branch_chosen = true;
+            self.runners.runs.reset();
+            self.load_runners_view().await;
+        } else {
+            self.workflows.current_branch = branch;
+            self.workflows.branch_chosen = true;
+            self.workflows.runs.reset();
+            self.load_current_view().await;
+        }
+    }
+
+    /// Show runs on all branches, or narrow back to the default branch.
+    async fn toggle_all_branches(&mut self) {
+        let Some((owner, repo)) = self.runs_repo() else {
+            return;
+        };
+        let branch = match self.current_branch() {
+            Some(_) => None,
+            None => match self.default_branch(&owner, &repo).await {
+                Some(branch) => Some(branch),
+                None => return,
+            },
+        };
+        self.set_branch_filter(branch).await;
+    }
+
+    /// Default branch of a repository, from a loaded repositories list or
+    /// else fetched, and remembered.
+    async fn default_branch(&mut self, owner: &str, repo: &str) -> Option<String> {
+        if let Some(branch) = self.known_default_branch(owner, repo) {
+            return Some(branch);
+        }
+        let result = self.github_client.as_mut()?.get_repo(owner, repo).await;
+        self.remember_default_branch(owner, repo, result.map(|r| r.default_branch))
+    }
+
+    /// Default branch of a repository, if remembered or in a loaded
+    /// repositories list.
+    fn known_default_branch(&mut self, owner: &str, repo: &str) -> Option<String> {
+        let key = format!("{}/{}", owner, repo);
+        if let Some(branch) = self.default_branches.get(&key) {
+            return Some(branch.clone());
+        }
+        let listed = [&self.workflows.repositories, &self.runners.repositories]
+            .into_iter()
+            .filter_map(|list| list.data.data())
+            .flat_map(|data| data.items.iter())
+            .find(|r| r.owner.login == owner && r.name == repo)
+            .and_then(|r| r.default_branch.clone())?;
+        self.default_branches.insert(key, listed.clone());
+        Some(listed)
+    }
+
+    /// Remember a fetched default branch, or report why it couldn't be.
+    fn remember_default_branch(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        result: crate::error::Result<Option<String>>,
+    ) -> Option<String> {
+        let key = format!("{}/{}", owner, repo);
+        match result {
+            Ok(branch) => {
+                let branch = branch?;
+                self.default_branches.insert(key, branch.clone());
+                Some(branch)
+            }
+            Err(e) => {
+                self.log_warn(format!("Failed to load default branch of {}: {}", key, e));
+                None
+            }
+        }
+    }
+
+    /// Branch a Runs list opens narrowed to: the default branch, unless the
+    /// config turns that off. None while the default branch isn't known yet,
+    /// which is fetched in the background meanwhile. Without a token, it isn't
+    /// fetched and runs of every branch are shown.
+    fn initial_branch(&mut self, owner: &str, repo: &str) -> Option<Option<String>> {
+        if !self.config.default_branch_runs {
+            return Some(None);
+        }
+        if let Some(branch) = self.known_default_branch(owner, repo) {
+            return Some(Some(branch));
+        }
+        if self.anonymous() {
+            return Some(None);
+        }
+        let Some(client) = self.github_client.as_ref() else {
+            return Some(None);
+        };
+        let (owner, repo) = (owner.to_string(), repo.to_string());
+        self.tasks
+            .spawn(TaskKind::DefaultBranch, client, |mut fork| async move {
+                let result = fork.get_repo(&owner, &repo).await.map(|r| r.default_branch);
+                (
+                    fork,
+                    Loaded::DefaultBranch {
+                        owner,
+                        repo,
+                        result,
+                    },
+                )
+            });
+        None
+    }
+
+    /// Narrow the Runs lists of a repository that opened before its default
+    /// branch was known.
+    async fn show_default_branch(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        result: crate::error::Result<Option<String>>,
+    ) {
+        let branch = self.remember_default_branch(owner, repo, result);
+        let workflows_waiting = !self.workflows.branch_chosen
+            && matches!(self.workflows.nav.current(),
+                ViewLevel::Runs { owner: o, repo: r, .. } if o == owner && r == repo);
+        if workflows_waiting {
+            self.workflows.branch_chosen = true;
+            if branch.is_some() {
+                self.workflows.current_branch.clone_from(&branch);
+                self.workflows.runs.reset();
+                if self.active_tab == Tab::Workflows {
+                    self.load_current_view().await;
+                }
+            }
+        }
+        let runners_waiting = !self.runners.branch_chosen
+            && matches!(self.runners.nav.current(),
+                RunnersViewLevel::Runs { owner: o, repo: r, .. } if o == owner && r == repo);
+        if runners_waiting {
+            self.runners.branch_chosen = true;
+            if branch.is_some() {
+                self.runners.current_branch = branch;
+                self.runners.runs.reset();
+                if self.active_tab == Tab::Runners {
+                    self.load_runners_view().await;
+                }
+            }
+        }
+    }
+
+    /// Branch the active tab's Runs list is narrowed to, if any.
+    pub fn current_branch(&self) -> Option<&str> {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.current_branch.as_deref(),
+            Tab::Runners => self.runners.current_branch.as_deref(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Group the Runs lists by trigger event, or restore their load order.
+    fn toggle_runs_grouping(&mut self) {
+        if self.current_runs_mut().is_none() {
+            return;
+        }
+        self.group_runs_by_event = !self.group_runs_by_event;
+        if !self.group_runs_by_event {
+            ungroup_runs(&mut self.workflows.runs);
+            ungroup_runs(&mut self.runners.runs);
+        }
+        self.apply_runs_grouping();
+    }
+
+    /// Fold the selected run's event group to its latest run, or unfold it.
+    fn toggle_event_fold(&mut self) {
+        if !self.group_runs_by_event {
+            return;
+        }
+        let Some(runs) = self.current_runs_mut() else {
+            return;
+        };
+        let Some(event) = runs.selected_item().map(|run| run.event.clone()) else {
+            return;
+        };
+        if !self.collapsed_events.remove(&event) {
+            self.collapsed_events.insert(event);
+        }
+        self.apply_runs_grouping();
+    }
+
+    /// Runs list of the active tab, if it's showing one.
+    fn current_runs_mut(&mut self) -> Option<&mut SelectableList<WorkflowRun>> {
+        match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Runs { .. } => Some(&mut self.workflows.runs),
+                _ => None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Runs { .. } => Some(&mut self.runners.runs),
+                _ => None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Group both tabs' Runs lists by event when grouping is on, and hide the
+    /// runs ignore rules match. Must run after any Runs list load, like the
+    /// favorites order.
+    fn apply_runs_grouping(&mut self) {
+        let rules = &self.config.ignore_runs;
+        for runs in [&mut self.workflows.runs, &mut self.runners.runs] {
+            if self.group_runs_by_event {
+                group_runs(runs, &self.collapsed_events, |run| is_ignored(rules, run));
+            } else if !rules.is_empty() {
+                runs.set_hidden(|run| is_ignored(rules, run));
+            }
+        }
+    }
+
+    /// Nest matrix variants under their base job in the Jobs lists, or restore
+    /// their load order.
+    fn toggle_jobs_grouping(&mut self) {
+        self.group_jobs_by_matrix = !self.group_jobs_by_matrix;
+        if !self.group_jobs_by_matrix {
+            ungroup_jobs(&mut self.workflows.jobs);
+            ungroup_jobs(&mut self.runners.jobs);
+        }
+        self.apply_jobs_grouping();
+    }
+
+    /// Fold the selected job's matrix variants to one, or unfold them.
+    fn toggle_matrix_fold(&mut self) {
+        if !self.group_jobs_by_matrix {
+            return;
+        }
+        let Some(jobs) = self.current_jobs_mut() else {
+            return;
+        };
+        let Some(base) = jobs
+            .selected_item()
+            .and_then(|job| matrix_parts(&job.name).map(|(base, _)| base.to_string()))
+        else {
+            return;
+        };
+        if !self.collapsed_matrix.remove(&base) {
+            self.collapsed_matrix.insert(base);
+        }
+        self.apply_jobs_grouping();
+    }
+
+    /// Show only the matrix variants that failed, or all of them again.
+    fn toggle_failed_variants(&mut self) {
+        if self.current_jobs_mut().is_none() {
+            return;
+        }
+        self.failed_variants_only = !self.failed_variants_only;
+        if !self.failed_variants_only && !self.group_jobs_by_matrix {
+            ungroup_jobs(&mut self.workflows.jobs);
+            ungroup_jobs(&mut self.runners.jobs);
+        }
+        self.apply_jobs_grouping();
+    }
+
+    /// Jobs list of the active tab, if it's showing one.
+    fn current_jobs_mut(&mut self) -> Option<&mut SelectableList<Job>> {
+        match self.active_tab {
+            Tab::Workflows => match self.workflows.nav.current() {
+                ViewLevel::Jobs { .. } => Some(&mut self.workflows.jobs),
+                _ => None,
+            },
+            Tab::Runners => match self.runners.nav.current() {
+                RunnersViewLevel::Jobs { .. } => Some(&mut self.runners.jobs),
+                _ => None,
+            },
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => None,
+        }
+    }
+
+    /// Group both tabs' Jobs lists by matrix job when grouping or the
+    /// failed-variants filter is on. Must run after any Jobs list load.
+    fn apply_jobs_grouping(&mut self) {
+        if !self.group_jobs_by_matrix && !self.failed_variants_only {
+            return;
+        }
+        let collapsed = if self.group_jobs_by_matrix {
+            &self.collapsed_matrix
+        } else {
+            &HashSet::new()
+        };
+        for jobs in [&mut self.workflows.jobs, &mut self.runners.jobs] {
+            group_jobs(jobs, collapsed, self.failed_variants_only);
+        }
+    }
+
+    /// Actor to filter Runs lists by, if only the user's own runs are shown.
+    async fn runs_actor(&mut self) -> Option<String> {
+        if !self.my_runs_only {
+            return None;
+        }
+        if self.current_user.is_none() {
+            match self.github_client.as_mut()?.get_current_user().await {
+                Ok(user) => self.current_user = Some(user.login),
+                Err(e) => self.log_error(format!("Failed to load current user: {}", e)),
+            }
+        }
+        self.current_user.clone()
+    }
+
+    /// Toggle favorite status for the currently selected item.
+    fn toggle_favorite(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.toggle_workflows_favorite(),
+            Tab::Runners => self.toggle_runners_favorite(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Toggle favorite in Workflows tab.
+    fn toggle_workflows_favorite(&mut self) {
+        match self.workflows.nav.current().clone() {
+            ViewLevel::Owners => {
+                if let Some(owner) = self.workflows.owners.selected_item() {
+                    toggle_key(&mut self.favorite_owners, owner.login.clone());
+                }
+            }
+            ViewLevel::Repositories { owner } => {
+                if let Some(repo) = self.workflows.repositories.selected_item() {
+                    let key = format!("{}/{}", owner, repo.name);
+                    toggle_key(&mut self.favorite_repos, key);
+                }
+            }
+            ViewLevel::Workflows { owner, repo } => {
+                if let Some(workflow) = self.workflows.workflows.selected_item() {
+                    let key = format!("{}/{}/{}", owner, repo, workflow.id);
+                    toggle_key(&mut self.favorite_workflows, key);
+                }
+            }
+            _ => return, // Can't favorite runs, jobs, or logs
+        }
+        self.apply_favorites_order();
+        self.update_metrics_repos();
+    }
+
+    /// Toggle favorite in Runners tab.
+    fn toggle_runners_favorite(&mut self) {
+        match self.runners.nav.current().clone() {
+            RunnersViewLevel::Repositories => {
+                if let Some(repo) = self.runners.repositories.selected_item() {
+                    let key = format!("{}/{}", repo.owner.login, repo.name);
+                    toggle_key(&mut self.favorite_repos, key);
+                }
+            }
+            RunnersViewLevel::Runners { owner, repo } => {
+                if let Some(runner) = self.runners.runners.selected_item() {
+                    let key = format!("{}/{}/{}", owner, repo, runner.name());
+                    toggle_key(&mut self.favorite_runners, key);
+                }
+            }
+            _ => return, // Can't favorite runs, jobs, or logs
+        }
+        self.apply_favorites_order();
+        self.update_metrics_repos();
+    }
+
+    /// Hide a repository from the Runners tab: the selected one, or the one whose
+    /// runners the token can't list, going back to the repositories.
+    async fn hide_runner_repo(&mut self) {
+        if self.active_tab != Tab::Runners {
+            return;
+        }
+        match self.runners.nav.current().clone() {
+            RunnersViewLevel::Repositories => {
+                if let Some(repo) = self.runners.repositories.selected_item() {
+                    let key = format!("{}/{}", repo.owner.login, repo.name);
+                    self.hidden_runner_repos.insert(key);
+                }
+            }
+            RunnersViewLevel::Runners { owner, repo } if self.runners.lacks_runner_access() => {
+                self.hidden_runner_repos
+                    .insert(format!("{}/{}", owner, repo));
+                self.handle_escape().await;
+            }
+            _ => return,
+        }
+        self.apply_hidden_runner_repos();
+    }
+
+    /// Show the repositories hidden from the Runners tab again.
+    fn unhide_runner_repos(&mut self) {
+        if self.active_tab == Tab::Runners
+            && *self.runners.nav.current() == RunnersViewLevel::Repositories
+        {
+            self.hidden_runner_repos.clear();
+            self.apply_hidden_runner_repos();
+        }
+    }
+
+    /// Hide the repositories the user hid from the Runners tab's list.
+    fn apply_hidden_runner_repos(&mut self) {
+        let hidden = &self.hidden_runner_repos;
+        self.runners
+            .repositories
+            .set_hidden(|r| hidden.contains(&format!("{}/{}", r.owner.login, r.name)));
+    }
+
+    /// Order the current lists of both tabs with favorites first.
+    /// Must run after any list load or favorites change so that rendering,
+    /// selection, and handlers all see the same order.
+    fn apply_favorites_order(&mut self) {
+        match self.workflows.nav.current().clone() {
+            ViewLevel::Owners => {
+                let favorites = &self.favorite_owners;
+                self.workflows
+                    .owners
+                    .sort_favorites_first(|o| favorites.contains(&o.login), |o| o.login.clone());
+            }
+            ViewLevel::Repositories { owner } => {
+                let favorites = &self.favorite_repos;
+                self.workflows.repositories.sort_favorites_first(
+                    |r| favorites.contains(&format!("{}/{}", owner, r.name)),
+                    |r| r.name.clone(),
+                );
+            }
+            ViewLevel::Workflows { owner, repo } => {
+                let favorites = &self.favorite_workflows;
+                self.workflows.workflows.sort_favorites_first(
+                    |w| favorites.contains(&format!("{}/{}/{}", owner, repo, w.id)),
+                    |w| w.name.clone(),
+                );
+            }
+            _ => {}
+        }
+
+        match self.runners.nav.current().clone() {
+            RunnersViewLevel::Repositories => {
+                let favorites = &self.favorite_repos;
+                self.runners.repositories.sort_favorites_first(
+                    |r| favorites.contains(&format!("{}/{}", r.owner.login, r.name)),
+                    |r| format!("{}/{}", r.owner.login, r.name),
+                );
+            }
+            RunnersViewLevel::Runners { owner, repo } => {
+                let favorites = &self.favorite_runners;
+                self.runners.runners.sort_favorites_first(
+                    |r| favorites.contains(&format!("{}/{}/{}", owner, repo, r.name())),
+                    |r| r.name().to_string(),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Get GitHub URL for current Workflows tab view.
+    fn get_workflows_github_url(&self) -> Option<String> {
+        match self.workflows.nav.current().clone() {
+            ViewLevel::Owners => self
+                .workflows
+                .owners
+                .selected_item()
+                .map(|owner| format!("https://github.com/{}", owner.login)),
+            ViewLevel::Repositories { owner } => self
+                .workflows
+                .repositories
+                .selected_item()
+                .map(|repo| format!("https://github.com/{}/{}", owner, repo.name)),
+            ViewLevel::Workflows { owner, repo } => {
+                self.workflows.workflows.selected_item().map(|workflow| {
+                    format!(
+                        "https://github.com/{}/{}/actions/workflows/{}",
+                        owner,
+                        repo,
+                        workflow.path.rsplit('/').next().unwrap_or(&workflow.path)
+                    )
+                })
+            }
+            ViewLevel::Runs { owner, repo, .. } => self.workflows.runs.selected_item().map(|run| {
+                format!(
+                    "https://github.com/{}/{}/actions/runs/{}",
+                    owner, repo, run.id
+                )
+            }),
+            ViewLevel::Jobs {
+                owner,
+                repo,
+                run_id,
+                ..
+            } => self.workflows.jobs.selected_item().map(|job| {
+                format!(
+                    "https://github.com/{}/{}/actions/runs/{}/job/{}",
+                    owner, repo, run_id, job.id
+                )
+            }),
+            ViewLevel::Logs {
+                owner,
+                repo,
+                run_id,
+                job_id,
+                ..
+            } => Some(format!(
+                "https://github.com/{}/{}/actions/runs/{}/job/{}",
+                owner, repo, run_id, job_id
+            )),
+            ViewLevel::Environments { .. } => self
+                .workflows
+                .environments
+                .selected_item()
+                .map(|env| env.html_url.clone()),
+            ViewLevel::Deployments {
+                owner,
+                repo,
+                environment,
+            } => Some(format!(
+                "https://github.com/{}/{}/deployments/{}",
+                owner, repo, environment
+            )),
+        }
+    }
+
+    /// Get GitHub URL for current Runners tab view.
+    fn get_runners_github_url(&self) -> Option<String> {
+        match self.runners.nav.current().clone() {
+            RunnersViewLevel::Repositories => self
+                .runners
+                .repositories
+                .selected_item()
+                .map(|repo| format!("https://github.com/{}/{}", repo.owner.login, repo.name)),
+            RunnersViewLevel::Runners { owner, repo } => Some(format!(
+                "https://github.com/{}/{}/settings/actions/runners",
+                owner, repo
+            )),
+            RunnersViewLevel::Runs { owner, repo, .. } => {
+                self.runners.runs.selected_item().map(|run| {
+                    format!(
+                        "https://github.com/{}/{}/actions/runs/{}",
+                        owner, repo, run.id
+                    )
+                })
+            }
+            RunnersViewLevel::Jobs {
+                owner,
+                repo,
+                run_id,
+                ..
+            } => self.runners.jobs.selected_item().map(|job| {
+                format!(
+                    "https://github.com/{}/{}/actions/runs/{}/job/{}",
+                    owner, repo, run_id, job.id
+                )
+            }),
+            RunnersViewLevel::Logs {
+                owner,
+                repo,
+                run_id,
+                job_id,
+                ..
+            } => Some(format!(
+                "https://github.com/{}/{}/actions/runs/{}/job/{}",
+                owner, repo, run_id, job_id
+            )),
+            RunnersViewLevel::DiagLogs { owner, repo, .. } => Some(format!(
+                "https://github.com/{}/{}/settings/actions/runners",
+                owner, repo
+            )),
+            RunnersViewLevel::RunnerGroups { org } => {
+                self.runners.runner_groups.selected_item().map(|group| {
+                    format!(
+                        "https://github.com/organizations/{}/settings/actions/runner-groups/{}",
+                        org, group.id
+                    )
+                })
+            }
+            RunnersViewLevel::GroupRunners { org, group_id, .. } => Some(format!(
+                "https://github.com/organizations/{}/settings/actions/runner-groups/{}",
+                org, group_id
+            )),
+        }
+    }
+
+    /// Handle Enter key (drill down).
+    async fn handle_enter(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.handle_workflows_enter().await,
+            Tab::Runners => self.handle_runners_enter().await,
+            // PRs have no drill-down, so Enter opens them like `o`
+            Tab::Inbox => self.handle_open_in_browser(),
+            Tab::Live => self.open_live_run().await,
+            Tab::Home => self.open_home_item().await,
+            Tab::Console => self.open_console_target().await,
+        }
+    }
+
+    /// Handle Enter in Workflows tab.
+    async fn handle_workflows_enter(&mut self) {
+        if self.workflows.annotations_focused {
+            self.workflows.jump_to_annotation();
+            return;
+        }
+        if self.workflows.tests_focused {
+            self.workflows.jump_to_test_failure();
+            return;
+        }
+        // Get the next navigation level based on current selection
+        let next_level =
+            match self.workflows.nav.current().clone() {
+                ViewLevel::Owners => {
+                    self.workflows
+                        .owners
+                        .selected_item()
+                        .map(|owner| ViewLevel::Repositories {
+                            owner: owner.login.clone(),
+                        })
+                }
+                ViewLevel::Repositories { owner } => self
+                    .workflows
+                    .repositories
+                    .selected_item()
+                    .map(|repo| ViewLevel::Workflows {
+                        owner,
+                        repo: repo.name.clone(),
+                    }),
+                ViewLevel::Workflows { owner, repo } => self
+                    .workflows
+                    .workflows
+                    .selected_item()
+                    .map(|workflow| ViewLevel::Runs {
+                        owner,
+                        repo,
+                        workflow_id: workflow.id,
+                        workflow_name: workflow.name.clone(),
+                    }),
+                ViewLevel::Runs {
+                    owner,
+                    repo,
+                    workflow_id,
+                    ..
+                } => self
+                    .workflows
+                    .runs
+                    .selected_item()
+                    .map(|run| ViewLevel::Jobs {
+                        owner,
+                        repo,
+                        workflow_id,
+                        run_id: run.id,
+                        run_number: run.run_number,
+                    }),
+                ViewLevel::Jobs {
+                    owner,
+                    repo,
+                    workflow_id,
+                    run_id,
+                    ..
+                } => self
+                    .workflows
+                    .jobs
+                    .selected_item()
+                    .map(|job| ViewLevel::Logs {
+                        owner,
+                        repo,
+                        workflow_id,
+                        run_id,
+                        job_id: job.id,
+                        job_name: job.name.clone(),
+                        job_status: job.status,
+                        job_conclusion: job.conclusion,
+                    }),
+                ViewLevel::Logs { .. } => None, // Can't drill down further
+                ViewLevel::Environments { owner, repo } => self
+                    .workflows
+                    .environments
+                    .selected_item()
+                    .map(|env| ViewLevel::Deployments {
+                        owner,
+                        repo,
+                        environment: env.name.clone(),
+                    }),
+                ViewLevel::Deployments { .. } => None,
+            };
+
+        if let Some(level) = next_level {
+            self.workflows.nav.push(level);
+            self.load_current_view().await;
+        }
+    }
+
+    /// Handle Enter in Runners tab.
+    async fn handle_runners_enter(&mut self) {
+        let next_level =
+            match self.runners.nav.current().clone() {
+                RunnersViewLevel::Repositories => {
+                    self.runners.repositories.selected_item().map(|repo| {
+                        RunnersViewLevel::Runners {
+                            owner: repo.owner.login.clone(),
+                            repo: repo.name.clone(),
+                        }
+                    })
+                }
+                RunnersViewLevel::Runners { owner, repo } => self
+                    .runners
+                    .runners
+                    .selected_item()
+                    .map(|runner| RunnersViewLevel::Runs {
+                        owner,
+                        repo,
+                        runner_name: Some(runner.name().to_string()),
+                    }),
+                RunnersViewLevel::Runs { owner, repo, .. } => self
+                    .runners
+                    .runs
+                    .selected_item()
+                    .map(|run| RunnersViewLevel::Jobs {
+                        owner,
+                        repo,
+                        run_id: run.id,
+                        run_number: run.run_number,
+                        workflow_id: Some(run.workflow_id),
+                    }),
+                RunnersViewLevel::Jobs {
+                    owner,
+                    repo,
+                    run_id,
+                    workflow_id,
+                    ..
+                } => self
+                    .runners
+                    .jobs
+                    .selected_item()
+                    .map(|job| RunnersViewLevel::Logs {
+                        owner,
+                        repo,
+                        run_id,
+                        job_id: job.id,
+                        job_name: job.name.clone(),
+                        job_status: job.status,
+                        job_conclusion: job.conclusion,
+                        workflow_id,
+                    }),
+                RunnersViewLevel::RunnerGroups { org } => {
+                    self.runners.runner_groups.selected_item().map(|group| {
+                        RunnersViewLevel::GroupRunners {
+                            org,
+                            group_id: group.id,
+                            group_name: group.name.clone(),
+                        }
+                    })
+                }
+                RunnersViewLevel::Logs { .. }
+                | RunnersViewLevel::DiagLogs { .. }
+                | RunnersViewLevel::GroupRunners { .. } => None,
+            };
+
+        if let Some(level) = next_level {
+            self.runners.nav.push(level);
+            self.load_runners_view().await;
+        }
+    }
+
+    /// Handle Escape key (go back).
+    async fn handle_escape(&mut self) {
+        // Clear a list filter before leaving the list
+        if self.list_filter().is_some() {
+            self.set_list_filter(None);
+            return;
+        }
+        match self.active_tab {
+            Tab::Workflows => {
+                if self.workflows.go_back() {
+                    self.load_current_view().await;
+                }
+            }
+            Tab::Runners => {
+                if self.runners.go_back() {
+                    self.load_runners_view().await;
+                }
+            }
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Filter the current list. Returns false if the current view isn't a list.
+    fn set_list_filter(&mut self, query: Option<String>) -> bool {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.set_list_filter(query),
+            Tab::Runners => self.runners.set_list_filter(query),
+            Tab::Inbox => {
+                self.inbox.set_filter(query);
+                true
+            }
+            Tab::Live => {
+                self.live.set_filter(query);
+                true
+            }
+            Tab::Home => {
+                self.home.set_filter(query);
+                true
+            }
+            Tab::Console => false,
+        }
+    }
+
+    /// Filter applied to the current list, if any.
+    pub fn list_filter(&self) -> Option<&str> {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.list_filter(),
+            Tab::Runners => self.runners.list_filter(),
+            Tab::Inbox => self.inbox.filter.as_deref(),
+            Tab::Live => self.live.filter.as_deref(),
+            Tab::Home => self.home.filter.as_deref(),
+            Tab::Console => None,
+        }
+    }
+
+    /// Depth of the active tab's navigation stack (0 for tabs without one).
+    fn nav_depth(&self) -> usize {
+        match self.active_tab {
+            Tab::Workflows => self.workflows.nav.depth(),
+            Tab::Runners => self.runners.nav.depth(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => 0,
+        }
+    }
+
+    /// Run or job being viewed in the Runners tab, as a recent item.
+    fn runners_recent_item(&self, now: chrono::DateTime<chrono::Utc>) -> Option<RecentItem> {
+        // The runs list keeps its selection while drilled into a run
+        let run = self.runners.runs.selected_item()?;
+        let (owner, repo, job) = match self.runners.nav.current() {
+            RunnersViewLevel::Jobs { owner, repo, .. } => (owner, repo, None),
+            RunnersViewLevel::Logs {
+                owner,
+                repo,
+                job_id,
+                job_name,
+                job_status,
+                job_conclusion,
+                ..
+            } => (
+                owner,
+                repo,
+                Some(RecentJob {
+                    id: *job_id,
+                    name: job_name.clone(),
+                    status: *job_status,
+                    conclusion: *job_conclusion,
+                }),
+            ),
+            _ => return None,
+        };
+        Some(RecentItem {
+            owner: owner.clone(),
+            repo: repo.clone(),
+            workflow_id: run.workflow_id,
+            workflow_name: run.name.clone().unwrap_or_else(|| "Workflow".to_string()),
+            run_id: run.id,
+            run_number: run.run_number,
+            job,
+            viewed_at: now,
+        })
+    }
+
+    /// Reopen a recently viewed run or job in the Workflows tab.
+    async fn open_recent(&mut self, item: RecentItem) {
+        self.active_tab = Tab::Workflows;
+        self.workflows = WorkflowsTabState::new();
+        for level in item.levels().into_iter().skip(1) {
+            self.workflows.nav.push(level);
+        }
+        self.load_current_view().await;
+    }
+
+    /// Handle a key while the permalink input is shown.
+    fn update_permalink_input(&mut self, key: KeyEvent) -> Option<Command> {
+        let input = self.permalink_input.as_mut()?;
+        if is_clear_input(key) {
+            input.clear();
+            return None;
+        }
+        match overlay_key(key) {
+            KeyCode::Esc => self.permalink_input = None,
+            KeyCode::Enter => {
+                let url = input.clone();
+                self.permalink_input = None;
+                match Permalink::parse(&url) {
+                    Some(link) => return Some(Command::OpenPermalink(link)),
+                    None => self.log_warn(format!("Not a GitHub Actions run or job URL: {}", url)),
+                }
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        None
+    }
+
+    /// Open the run or job a permalink refers to, scrolled to its log line.
+    async fn open_permalink(&mut self, link: Permalink) {
+        let Some(client) = self.github_client.as_mut() else {
+            self.log_error("Cannot open a permalink without a GitHub token");
+            return;
+        };
+        let run = match client
+            .get_workflow_run(&link.owner, &link.repo, link.run_id)
+            .await
+        {
+            Ok(run) => run,
+            Err(e) => {
+                self.log_error(format!("Failed to load run {}: {}", link.run_id, e));
+                return;
+            }
+        };
+        let job = match link.job_id {
+            Some(job_id) => match client.get_job(&link.owner, &link.repo, job_id).await {
+                Ok(job) => Some(job),
+                Err(e) => {
+                    self.log_error(format!("Failed to load job {}: {}", job_id, e));
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let item = RecentItem {
+            owner: link.owner,
+            repo: link.repo,
+            workflow_id: run.workflow_id,
+            workflow_name: run.name.clone().unwrap_or_default(),
+            run_id: run.id,
+            run_number: run.run_number,
+            job: job.as_ref().map(|job| RecentJob {
+                id: job.id,
+                name: job.name.clone(),
+                status: job.status,
+                conclusion: job.conclusion,
+            }),
+            viewed_at: chrono::Utc::now(),
+        };
+        self.open_recent(item).await;
+
+        let (Some(job), Some((step, line))) = (job, link.step_line) else {
+            return;
+        };
+        let target = self
+            .workflows
+            .log
+            .content
+            .data()
+            .and_then(|logs| clipboard::anchor_line(logs, step, line, &job.steps));
+        match target {
+            Some(target) => self.workflows.log.scroll_y = target as u16,
+            None => self.log_warn(format!("Line step:{}:{} not found in the log", step, line)),
+        }
+    }
+
+    /// Handle a key while the Workspaces picker is shown: arrows move, Enter
+    /// restores, `s` names a new snapshot, `x` deletes, and any other key closes.
+    fn update_workspaces_picker(&mut self, code: KeyCode) -> Option<Command> {
+        if let Some(name) = self.workspace_name.as_mut() {
+            match code {
+                KeyCode::Esc => self.workspace_name = None,
+                KeyCode::Enter => {
+                    let name = name.trim().to_string();
+                    self.workspace_name = None;
+                    if !name.is_empty() {
+                        self.save_workspace(name);
+                    }
+                }
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Char(c) => name.push(c),
+                _ => {}
+            }
+            return None;
+        }
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.workspace_selected = self.workspace_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.workspace_selected =
+                    (self.workspace_selected + 1).min(self.workspaces.len().saturating_sub(1));
+            }
+            KeyCode::Char('s') => {
+                // Re-saving starts from the highlighted workspace's name
+                let name = self.workspaces.get(self.workspace_selected);
+                self.workspace_name = Some(name.map(|w| w.name.clone()).unwrap_or_default());
+            }
+            KeyCode::Char('x') | KeyCode::Delete => {
+                if self.workspace_selected < self.workspaces.len() {
+                    let removed = self.workspaces.remove(self.workspace_selected);
+                    self.workspace_selected = self
+                        .workspace_selected
+                        .min(self.workspaces.len().saturating_sub(1));
+                    match save_workspaces(&self.workspaces) {
+                        Ok(()) => self.log_info(format!("Deleted workspace \"{}\"", removed.name)),
+                        Err(e) => self.log_error(format!("Failed to save workspaces: {}", e)),
+                    }
+                }
+            }
+            KeyCode::Enter => {
+                self.show_workspaces = false;
+                let workspace = self.workspaces.get(self.workspace_selected).cloned();
+                return workspace.map(Command::RestoreWorkspace);
+            }
+            _ => self.show_workspaces = false,
+        }
+        None
+    }
+
+    /// Snapshot the current state under `name`, replacing a workspace of the same name.
+    fn save_workspace(&mut self, name: String) {
+        let workspace = Workspace {
+            name: name.clone(),
+            saved_at: chrono::Utc::now(),
+            active_tab: self.active_tab,
+            workflows_nav: self.workflows.nav.clone(),
+            runners_nav: self.runners.nav.clone(),
+            workflows_filter: self.workflows.list_filter().map(String::from),
+            runners_filter: self.runners.list_filter().map(String::from),
+            workflows_log_scroll: (self.workflows.log.scroll_x, self.workflows.log.scroll_y),
+            runners_log_scroll: (self.runners.log.scroll_x, self.runners.log.scroll_y),
+            favorite_owners: self.favorite_owners.clone(),
+            favorite_repos: self.favorite_repos.clone(),
+            favorite_workflows: self.favorite_workflows.clone(),
+            favorite_runners: self.favorite_runners.clone(),
+        };
+        upsert_workspace(&mut self.workspaces, workspace);
+        self.workspace_selected = 0;
+        match save_workspaces(&self.workspaces) {
+            Ok(()) => self.log_info(format!("Saved workspace \"{}\"", name)),
+            Err(e) => self.log_error(format!("Failed to save workspaces: {}", e)),
+        }
+    }
+
+    /// Return to a saved workspace's tabs, open log, and filters. Its
+    /// favorites are added to the current ones, which aren't lost.
+    async fn restore_workspace(&mut self, workspace: Workspace) {
+        self.favorite_owners.extend(workspace.favorite_owners);
+        self.favorite_repos.extend(workspace.favorite_repos);
+        self.favorite_workflows.extend(workspace.favorite_workflows);
+        self.favorite_runners.extend(workspace.favorite_runners);
+        self.update_metrics_repos();
+        self.live.reset();
+        self.home.reset();
+
+        self.workflows = WorkflowsTabState::new();
+        self.workflows.nav = workspace.workflows_nav;
+        self.workflows.set_list_filter(workspace.workflows_filter);
+        (self.workflows.log.scroll_x, self.workflows.log.scroll_y) = workspace.workflows_log_scroll;
+
+        self.runners = RunnersTabState::new();
+        self.runners.nav = workspace.runners_nav;
+        self.runners.set_list_filter(workspace.runners_filter);
+        (self.runners.log.scroll_x, self.runners.log.scroll_y) = workspace.runners_log_scroll;
+
+        self.active_tab = workspace.active_tab;
+        self.clear_console_badge_if_viewing();
+        self.on_tab_change().await;
+        self.log_info(format!("Restored workspace \"{}\"", workspace.name));
+    }
+
+    /// Go back to an ancestor level (0 = root) in one step.
+    async fn jump_to_level(&mut self, level: usize) {
+        if level + 1 >= self.nav_depth() {
+            return;
+        }
+        match self.active_tab {
+            Tab::Workflows => {
+                while self.workflows.nav.depth() > level + 1 && self.workflows.go_back() {}
+                self.load_current_view().await;
+            }
+            Tab::Runners => {
+                while self.runners.nav.depth() > level + 1 && self.runners.go_back() {}
+                self.load_runners_view().await;
+            }
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => {}
+        }
+    }
+
+    /// Handle refresh key.
+    async fn handle_refresh(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => {
+                self.workflows.clear_current();
+                self.load_current_view().await;
+            }
+            Tab::Runners => {
+                self.runners.clear_current();
+                self.load_runners_view().await;
+            }
+            Tab::Inbox => {
+                self.inbox.clear_for_reload();
+                self.load_inbox().await;
+            }
+            Tab::Live => {
+                self.live.clear_for_reload();
+                self.load_live();
+            }
+            Tab::Home => {
+                self.home.clear_for_reload();
+                self.load_home();
+            }
+            Tab::Console => {}
+        }
+    }
+
+    /// Re-fetch the current view from the API, bypassing the cache. The lists
+    /// above it are revalidated too when going back to them, if configured.
+    async fn handle_hard_refresh(&mut self) {
+        let whole_path = self.config.hard_refresh_path;
+        match self.active_tab {
+            Tab::Workflows => {
+                if whole_path {
+                    self.workflows.mark_path_stale();
+                } else {
+                    self.workflows.mark_current_stale();
+                }
+                match self.workflows.nav.current().clone() {
+                    ViewLevel::Workflows { owner, repo } => {
+                        remove_cached(cache::latest_runs_path(&owner, &repo));
+                        self.workflows.latest_runs.clear();
+                    }
+                    ViewLevel::Logs {
+                        owner,
+                        repo,
+                        workflow_id,
+                        run_id,
+                        job_id,
+                        ..
+                    } => {
+                        remove_cached(cache::job_log_path(
+                            &owner,
+                            &repo,
+                            workflow_id,
+                            run_id,
+                            job_id,
+                        ));
+                        self.workflows.clear_current();
+                    }
+                    _ => {}
+                }
+                self.load_current_view().await;
+            }
+            Tab::Runners => {
+                if whole_path {
+                    self.runners.mark_path_stale();
+                } else {
+                    self.runners.mark_current_stale();
+                }
+                match self.runners.nav.current().clone() {
+                    RunnersViewLevel::Logs {
+                        owner,
+                        repo,
+                        run_id,
+                        job_id,
+                        workflow_id: Some(workflow_id),
+                        ..
+                    } => {
+                        remove_cached(cache::job_log_path(
+                            &owner,
+                            &repo,
+                            workflow_id,
+                            run_id,
+                            job_id,
+                        ));
+                        self.runners.clear_current();
+                    }
+                    RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => {
+                        self.runners.clear_current();
+                    }
+                    _ => {}
+                }
+                self.load_runners_view().await;
+            }
+            Tab::Inbox => {
+                self.inbox.mark_stale();
+                self.load_inbox().await;
+            }
+            Tab::Live => {
+                self.live.clear_for_reload();
+                self.load_live();
+            }
+            Tab::Home => {
+                self.home.clear_for_reload();
+                self.load_home();
+            }
+            Tab::Console => {}
+        }
+    }
+
+    /// Called when switching tabs.
+    async fn on_tab_change(&mut self) {
+        match self.active_tab {
+            Tab::Workflows => self.load_current_view().await,
+            Tab::Runners => self.load_runners_view().await,
+            Tab::Inbox => self.load_inbox().await,
+            Tab::Live => self.load_live(),
+            Tab::Home => self.load_home(),
+            Tab::Console => {}
+        }
+    }
+
+    /// Load pull requests awaiting the user, from cache if fresh.
+    async fn load_inbox(&mut self) {
+        match &self.github_client {
+            None => {
+                self.log_error("No GitHub token configured");
+                return;
+            }
+            // Pull requests awaiting review are searched for as the user
+            Some(client) if !client.is_authenticated() => {
+                self.inbox.set_error(&JoltError::MissingToken);
+                return;
+            }
+            Some(_) => {}
+        }
+        if self.inbox.data.is_loaded() {
+            return;
+        }
+        let ttl = self.config.cache_ttl(CacheKind::Runs);
+        if load_cached_list(&mut self.inbox, cache::inbox_path(), ttl) {
+            return;
+        }
+        self.inbox.set_loading();
+        let query = RowsQuery::default();
+        let client = self.github_client.as_mut().unwrap();
+        if let Some(rows) = fetch_rows(client, &ListView::Inbox, &query).await {
+            self.show_rows(&ListView::Inbox, &query, rows, false);
+        }
+    }
+
+    /// Load in-progress runs of favorite repositories in the background.
+    fn load_live(&mut self) {
+        let Some(client) = self.github_client.as_ref() else {
+            self.log_error("No GitHub token configured");
+            return;
+        };
+        if self.live.data.is_loaded() && !self.live.stale {
+            return;
+        }
+        if !self.live.stale {
+            self.live.set_loading();
+        }
+        let mut repos: Vec<String> = self.favorite_repos.iter().cloned().collect();
+        repos.sort();
+        let shown: Vec<LiveRun> = self
+            .live
+            .data
+            .data()
+            .map(|data| data.items.clone())
+            .unwrap_or_default();
+        self.tasks
+            .spawn(TaskKind::Live, client, move |mut fork| async move {
+                let (runs, finished, failures) = fetch_live(&mut fork, repos, shown).await;
+                (
+                    fork,
+                    Loaded::Live {
+                        runs,
+                        finished,
+                        failures,
+                    },
+                )
+            });
+    }
+
+    /// Show the in-progress runs fetched for the Live tab, and report the
+    /// runs that finished since as failed when they did.
+    fn show_live(&mut self, mut runs: Vec<LiveRun>, finished: Vec<LiveRun>, failures: Vec<String>) {
+        for failure in failures {
+            self.log_warn(failure);
+        }
+        for live in finished {
+            if is_failure(live.run.conclusion) {
+                self.report_failed_run(&live.owner, &live.repo, &live.run);
+            }
+        }
+        let rules = &self.config.ignore_runs;
+        runs.retain(|live| !is_ignored(rules, &live.run));
+        sort_live(&mut runs);
+        let count = runs.len() as u64;
+        self.live.set_refreshed(runs, count);
+    }
+
+    /// Log a failed run in the Console as an event that opens it, once per run.
+    fn report_failed_run(&mut self, owner: &str, repo: &str, run: &WorkflowRun) {
+        let reported = self.console_messages.iter().any(|msg| {
+            msg.kind == ConsoleKind::RunFailed
+                && msg
+                    .target
+                    .as_ref()
+                    .is_some_and(|target| target.run_id == run.id)
+        });
+        if reported {
+            return;
+        }
+        let message = ConsoleMessage::run_failed(owner, repo, run);
+        tracing::info!("{}", message.message);
+        self.console_messages.push(message);
+        self.console_unread += 1;
+    }
+
+    /// Load the Home dashboard in the background: failing and running runs,
+    /// and offline runners of favorites.
+    fn load_home(&mut self) {
+        let Some(client) = self.github_client.as_ref() else {
+            self.log_error("No GitHub token configured");
+            return;
+        };
+        if self.home.data.is_loaded() && !self.home.stale {
+            return;
+        }
+        if !self.home.stale {
+            self.home.set_loading();
+        }
+        let mut repos: Vec<String> = self.favorite_repos.iter().cloned().collect();
+        repos.sort();
+        let mut runners: Vec<(String, String, String)> = self
+            .favorite_runners
+            .iter()
+            .filter_map(|key| {
+                let mut parts = key.splitn(3, '/');
+                Some((
+                    parts.next()?.to_string(),
+                    parts.next()?.to_string(),
+                    parts.next()?.to_string(),
+                ))
+            })
+            .collect();
+        runners.sort();
+        let rules = self.config.ignore_runs.clone();
+        let per_page = self.config.per_page;
+        self.tasks
+            .spawn(TaskKind::Home, client, move |mut fork| async move {
+                let (items, failures) =
+                    fetch_home(&mut fork, repos, runners, &rules, per_page).await;
+                (fork, Loaded::Home { items, failures })
+            });
+    }
+
+    /// Show the fetched Home items followed by recently viewed runs and jobs,
+    /// reporting the runs that newly failed since the last refresh.
+    fn show_home(&mut self, mut items: Vec<HomeItem>, failures: Vec<String>) {
+        for failure in failures {
+            self.log_warn(failure);
+        }
+        // Failing runs already shown, so only newly failed ones are reported
+        let reported: Option<HashSet<u64>> = self.home.data.data().map(|data| {
+            data.items
+                .iter()
+                .filter_map(|item| match item {
+                    HomeItem::FailingRun { run, .. } => Some(run.id),
+                    _ => None,
+                })
+                .collect()
+        });
+        if let Some(reported) = reported {
+            for item in &items {
+                match item {
+                    HomeItem::FailingRun { owner, repo, run } if !reported.contains(&run.id) => {
+                        self.report_failed_run(owner, repo, run)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        items.extend(
+            self.recent
+                .iter()
+                .take(HOME_RECENT)
+                .cloned()
+                .map(HomeItem::Recent),
+        );
+        let count = items.len() as u64;
+        self.home.set_refreshed(items, count);
+    }
+
+    /// Open the run the selected Console message is about, if any.
+    async fn open_console_target(&mut self) {
+        // Newest messages are listed first
+        let target = self
+            .console_list_state
+            .selected()
+            .and_then(|i| self.console_messages.iter().rev().nth(i))
+            .and_then(|msg| msg.target.clone());
+        if let Some(target) = target {
+            self.open_recent(target).await;
+        }
+    }
+
+    /// Open the selected Home item: runs and jobs in the Workflows tab, and
+    /// runners in the Runners tab.
+    async fn open_home_item(&mut self) {
+        let Some(item) = self.home.selected_item().cloned() else {
+            return;
+        };
+        match (item.target(), item) {
+            (Some(target), _) => self.open_recent(target).await,
+            (None, HomeItem::OfflineRunner { owner, repo, .. }) => {
+                self.active_tab = Tab::Runners;
+                self.runners = RunnersTabState::new();
+                self.runners
+                    .nav
+                    .push(RunnersViewLevel::Runners { owner, repo });
+                self.load_runners_view().await;
+            }
+            (None, _) => {}
+        }
+    }
+
+    /// Start on the workflows of the repository the current directory belongs
+    /// to when `jump` is set, or offer to open them unless they're already shown.
+    pub fn open_cwd_repo(&mut self, owner: String, repo: String, jump: bool) {
+        let level = ViewLevel::Workflows {
+            owner: owner.clone(),
+            repo: repo.clone(),
+        };
+        if jump {
+            self.show_repo_workflows(&owner, &repo);
+        } else if !(self.active_tab == Tab::Workflows
+            && self.workflows.nav.levels().contains(&level))
+        {
+            self.repo_offer = Some((owner, repo));
+        }
+    }
+
+    /// Go back to where a saved session was. The caller loads the views.
+    fn restore_session(&mut self, session: PersistedState) {
+        self.active_tab = session.active_tab;
+        if let Some(nav) = session.workflows_nav {
+            self.workflows = WorkflowsTabState::new();
+            self.workflows.nav = nav;
+        }
+        if let Some(nav) = session.runners_nav {
+            self.runners = RunnersTabState::new();
+            self.runners.nav = nav;
+        }
+    }
+
+    /// Show a repository's workflows in the Workflows tab. The caller loads them.
+    fn show_repo_workflows(&mut self, owner: &str, repo: &str) {
+        self.active_tab = Tab::Workflows;
+        self.workflows = WorkflowsTabState::new();
+        self.workflows.nav.push(ViewLevel::Repositories {
+            owner: owner.to_string(),
+        });
+        self.workflows.nav.push(ViewLevel::Workflows {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        });
+    }
+
+    /// Open the selected in-progress run's jobs in the Workflows tab.
+    async fn open_live_run(&mut self) {
+        let Some(live) = self.live.selected_item().cloned() else {
+            return;
+        };
+        let LiveRun { owner, repo, run } = live;
+        self.active_tab = Tab::Workflows;
+        self.workflows = WorkflowsTabState::new();
+        let levels = [
+            ViewLevel::Repositories {
+                owner: owner.clone(),
+            },
+            ViewLevel::Workflows {
+                owner: owner.clone(),
+                repo: repo.clone(),
+            },
+            ViewLevel::Runs {
+                owner: owner.clone(),
+                repo: repo.clone(),
+                workflow_id: run.workflow_id,
+                workflow_name: run.name.clone().unwrap_or_default(),
+            },
+            ViewLevel::Jobs {
+                owner,
+                repo,
+                workflow_id: run.workflow_id,
+                run_id: run.id,
+                run_number: run.run_number,
+            },
+        ];
+        for level in levels {
+            self.workflows.nav.push(level);
+        }
+        self.load_current_view().await;
+    }
+
+    /// Load data for the current view level.
+    async fn load_current_view(&mut self) {
+        tracing::debug!(view = ?self.workflows.nav.current(), "load workflows view");
+        self.load_workflows_data().await;
+        self.apply_favorites_order();
+        self.apply_runs_grouping();
+        self.apply_jobs_grouping();
+        if let ViewLevel::Workflows { owner, repo } = self.workflows.nav.current().clone() {
+            self.load_schedules(&owner, &repo);
+            self.load_latest_runs(&owner, &repo);
+        }
+        if let Some(item) = RecentItem::from_levels(self.workflows.nav.levels(), chrono::Utc::now())
+        {
+            record_recent(&mut self.recent, item);
         }
+        if let ViewLevel::Jobs {
+            owner,
+            repo,
+            workflow_id,
+            run_id,
+            ..
+        } = self.workflows.nav.current().clone()
+        {
+            self.workflows.job_history = JobHistory::load(&owner, &repo, workflow_id, run_id);
+            // Queue failed jobs' logs so opening one is instant
+            queue_log_prefetch(
+                &mut self.workflows.log_prefetch,
+                &self.workflows.jobs,
+                &owner,
+                &repo,
+                workflow_id,
+                run_id,
+            );
+            let retried = self.workflows.jobs.data.data().is_some_and(|jobs| {
+                jobs.items
+                    .iter()
+                    .any(|job| job.run_attempt.unwrap_or(1) > 1)
+            });
+            self.workflows.flaky_jobs = if retried {
+                self.job_attempts(&owner, &repo, workflow_id, run_id)
+                    .await
+                    .map(|jobs| flaky_job_names(&jobs))
+                    .unwrap_or_default()
+            } else {
+                HashSet::new()
+            };
+        }
+        self.load_annotations().await;
     }
 
-    /// Handle Escape key (go back).
-    async fn handle_escape(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => {
-                if self.workflows.go_back() {
-                    self.load_current_view().await;
+    /// Fetch check-run annotations for the job in the log viewer.
+    async fn load_annotations(&mut self) {
+        let ViewLevel::Logs {
+            owner,
+            repo,
+            workflow_id,
+            run_id,
+            job_id,
+            job_status,
+            ..
+        } = self.workflows.nav.current().clone()
+        else {
+            return;
+        };
+        if !matches!(self.workflows.annotations.data, LoadingState::Idle) {
+            return;
+        }
+
+        // Annotations are final once the job completes
+        let path = cache::job_annotations_path(&owner, &repo, workflow_id, run_id, job_id);
+        if load_cached_list(&mut self.workflows.annotations, path.clone(), None) {
+            return;
+        }
+        let Some(client) = self.github_client.as_mut() else {
+            return;
+        };
+        self.workflows.annotations.set_loading();
+        match client.get_job_annotations(&owner, &repo, job_id).await {
+            Ok(annotations) => {
+                if let (Some(path), RunStatus::Completed) = (path, job_status) {
+                    let _ = cache::write_cached(&path, &annotations, true);
                 }
+                let count = annotations.len() as u64;
+                self.workflows.annotations.set_loaded(annotations, count);
             }
-            Tab::Runners => {
-                if self.runners.go_back() {
-                    self.load_runners_view().await;
-                }
+            Err(e) => {
+                self.workflows.annotations.set_error(&e);
+                self.log_warn(format!("Failed to load annotations: {}", e));
             }
-            Tab::Console => {}
         }
     }
 
-    /// Handle refresh key.
-    async fn handle_refresh(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => {
-                self.workflows.clear_current();
-                self.load_current_view().await;
-            }
-            Tab::Runners => {
-                self.runners.clear_current();
-                self.load_runners_view().await;
-            }
-            Tab::Console => {}
+    /// Move arrow keys between the annotations panel and the log.
+    fn toggle_annotations_focus(&mut self) {
+        let has_annotations = self
+            .workflows
+            .annotations
+            .data
+            .data()
+            .is_some_and(|list| !list.is_empty());
+        let in_logs = self.active_tab == Tab::Workflows
+            && matches!(self.workflows.nav.current(), ViewLevel::Logs { .. });
+        if in_logs && has_annotations {
+            self.workflows.annotations_focused = !self.workflows.annotations_focused;
+            self.workflows.tests_focused = false;
         }
     }
 
-    /// Called when switching tabs.
-    async fn on_tab_change(&mut self) {
-        match self.active_tab {
-            Tab::Workflows => self.load_current_view().await,
-            Tab::Runners => self.load_runners_view().await,
-            Tab::Console => {}
+    /// Show log timestamps, hide them, or show time elapsed in each step.
+    fn cycle_log_timestamps(&mut self) {
+        let in_logs = match self.active_tab {
+            Tab::Workflows => matches!(self.workflows.nav.current(), ViewLevel::Logs { .. }),
+            Tab::Runners => self.runners.nav.current().is_log_view(),
+            Tab::Home | Tab::Inbox | Tab::Console | Tab::Live => false,
+        };
+        if in_logs {
+            self.log_timestamps = self.log_timestamps.next();
         }
     }
 
-    /// Load data for the current view level.
-    async fn load_current_view(&mut self) {
-        if self.github_client.is_none() {
+    /// Toggle whether arrow keys select failed tests in the log viewer.
+    fn toggle_tests_focus(&mut self) {
+        let has_failures = self
+            .workflows
+            .test_failures
+            .data
+            .data()
+            .is_some_and(|list| !list.is_empty());
+        let in_logs = self.active_tab == Tab::Workflows
+            && matches!(self.workflows.nav.current(), ViewLevel::Logs { .. });
+        if in_logs && has_failures {
+            self.workflows.tests_focused = !self.workflows.tests_focused;
+            self.workflows.annotations_focused = false;
+        }
+    }
+
+    /// Fetch data for the Workflows tab current view level. Lists that are
+    /// already loaded are left alone; stale ones are refreshed by `revalidate`.
+    async fn load_workflows_data(&mut self) {
+        let Some(client) = self.github_client.as_ref() else {
             self.log_error("No GitHub token configured");
             return;
-        }
+        };
+        let authenticated = client.is_authenticated();
 
         let current_view = self.workflows.nav.current().clone();
+        let view = ListView::Workflows(current_view.clone());
+        let query = self.rows_query(&view).await;
+        let cache_path = rows_cache_path(&view, &query);
 
         match current_view {
             ViewLevel::Owners => {
                 if self.workflows.owners.data.is_loaded() {
                     return;
                 }
-                // Try to load from cache first
-                if let Some(path) = cache::owners_list_path() {
-                    if let Ok(Some(cached)) = cache::read_cached::<Vec<crate::github::Owner>>(&path)
-                    {
-                        if cached.is_valid(cache::DEFAULT_TTL) {
-                            let count = cached.data.len() as u64;
-                            self.workflows.owners.set_loaded(cached.data, count);
-                            return;
-                        }
-                    }
-                }
-                // No valid cache, fetch from API
-                self.workflows.owners.set_loading();
-                let result = Self::fetch_owners(self.github_client.as_mut().unwrap()).await;
-                match result {
-                    Ok((owners, count)) => {
-                        if let Some(path) = cache::owners_list_path() {
-                            let _ = cache::write_cached(&path, &owners, false);
-                        }
-                        self.workflows.owners.set_loaded(owners, count);
+                // Without a token, favorite repositories' owners are listed and not cached,
+                // so the user's own owners show once a token is set
+                if !authenticated {
+                    if query.public_owners.is_empty() {
+                        let error = ViewError::from(&JoltError::MissingToken).with_hint(
+                            "Without a token, favorite repositories' owners are listed here; \
+                             press p to open a public run's link",
+                        );
+                        self.workflows.owners.set_error(error);
+                        return;
                     }
-                    Err(e) => {
-                        self.workflows.owners.set_error(e.to_string());
-                        self.log_error(format!("Failed to load owners: {}", e));
+                } else {
+                    // Show cached data first; expired entries are revalidated after the next draw
+                    let ttl = self.config.cache_ttl(CacheKind::Owners);
+                    if load_cached_list(&mut self.workflows.owners, cache_path, ttl) {
+                        return;
                     }
                 }
+                self.workflows.owners.set_loading();
             }
-            ViewLevel::Repositories { ref owner } => {
+            ViewLevel::Repositories { .. } => {
                 if self.workflows.repositories.data.is_loaded() {
                     return;
                 }
-                let owner = owner.clone();
-                // Try to load from cache first
-                if let Some(path) = cache::repos_list_path(&owner) {
-                    if let Ok(Some(cached)) =
-                        cache::read_cached::<Vec<crate::github::Repository>>(&path)
-                    {
-                        if cached.is_valid(cache::DEFAULT_TTL) {
-                            let count = cached.data.len() as u64;
-                            self.workflows.repositories.set_loaded(cached.data, count);
-                            return;
-                        }
-                    }
+                let ttl = self.config.cache_ttl(CacheKind::Repositories);
+                if load_cached_list(&mut self.workflows.repositories, cache_path, ttl) {
+                    return;
                 }
-                // No valid cache, fetch from API
                 self.workflows.repositories.set_loading();
-                let result =
-                    Self::fetch_repositories(self.github_client.as_mut().unwrap(), &owner).await;
-                match result {
-                    Ok((repos, count)) => {
-                        if let Some(path) = cache::repos_list_path(&owner) {
-                            let _ = cache::write_cached(&path, &repos, false);
-                        }
-                        self.workflows.repositories.set_loaded(repos, count);
-                    }
-                    Err(e) => {
-                        self.workflows.repositories.set_error(e.to_string());
-                        self.log_error(format!("Failed to load repositories: {}", e));
-                    }
-                }
             }
-            ViewLevel::Workflows {
-                ref owner,
-                ref repo,
-            } => {
+            ViewLevel::Workflows { .. } => {
                 if self.workflows.workflows.data.is_loaded() {
                     return;
                 }
-                let owner = owner.clone();
-                let repo = repo.clone();
-                // Try to load from cache first
-                if let Some(path) = cache::workflows_list_path(&owner, &repo) {
-                    if let Ok(Some(cached)) =
-                        cache::read_cached::<Vec<crate::github::Workflow>>(&path)
-                    {
-                        if cached.is_valid(cache::DEFAULT_TTL) {
-                            let count = cached.data.len() as u64;
-                            self.workflows.workflows.set_loaded(cached.data, count);
-                            return;
-                        }
-                    }
+                let ttl = self.config.cache_ttl(CacheKind::Workflows);
+                if load_cached_list(&mut self.workflows.workflows, cache_path, ttl) {
+                    return;
                 }
-                // No valid cache, fetch from API
                 self.workflows.workflows.set_loading();
-                let result = self
-                    .github_client
-                    .as_mut()
-                    .unwrap()
-                    .get_workflows(&owner, &repo, 1, 30)
-                    .await;
-                match result {
-                    Ok((workflows, count)) => {
-                        if let Some(path) = cache::workflows_list_path(&owner, &repo) {
-                            let _ = cache::write_cached(&path, &workflows, false);
-                        }
-                        self.workflows.workflows.set_loaded(workflows, count);
-                    }
-                    Err(e) => {
-                        self.workflows.workflows.set_error(e.to_string());
-                        self.log_error(format!("Failed to load workflows: {}", e));
-                    }
-                }
             }
-            ViewLevel::Runs {
-                ref owner,
-                ref repo,
-                workflow_id,
-                ..
-            } => {
+            ViewLevel::Runs { .. } => {
                 if self.workflows.runs.data.is_loaded() {
                     return;
                 }
-                let owner = owner.clone();
-                let repo = repo.clone();
-                // Try to load from cache first
-                if let Some(path) = cache::runs_list_path(&owner, &repo, workflow_id) {
-                    if let Ok(Some(cached)) =
-                        cache::read_cached::<Vec<crate::github::WorkflowRun>>(&path)
-                    {
-                        if cached.is_valid(cache::DEFAULT_TTL) {
-                            let count = cached.data.len() as u64;
-                            self.workflows.runs.set_loaded(cached.data, count);
-                            return;
-                        }
-                    }
+                let ttl = self.config.cache_ttl(CacheKind::Runs);
+                if load_cached_list(&mut self.workflows.runs, cache_path, ttl) {
+                    return;
                 }
-                // No valid cache, fetch from API
                 self.workflows.runs.set_loading();
-                let result = self
-                    .github_client
-                    .as_mut()
-                    .unwrap()
-                    .get_workflow_runs_for_workflow(&owner, &repo, workflow_id, 1, 30)
-                    .await;
-                match result {
-                    Ok((runs, count)) => {
-                        if let Some(path) = cache::runs_list_path(&owner, &repo, workflow_id) {
-                            let _ = cache::write_cached(&path, &runs, false);
-                        }
-                        self.workflows.runs.set_loaded(runs, count);
-                    }
-                    Err(e) => {
-                        self.workflows.runs.set_error(e.to_string());
-                        self.log_error(format!("Failed to load runs: {}", e));
-                    }
-                }
             }
-            ViewLevel::Jobs {
-                ref owner,
-                ref repo,
-                workflow_id,
-                run_id,
-                ..
-            } => {
+            ViewLevel::Jobs { .. } => {
                 if self.workflows.jobs.data.is_loaded() {
                     return;
                 }
-                let owner = owner.clone();
-                let repo = repo.clone();
-                // Try to load from cache first
-                if let Some(path) = cache::jobs_list_path(&owner, &repo, workflow_id, run_id) {
-                    if let Ok(Some(cached)) = cache::read_cached::<Vec<crate::github::Job>>(&path) {
-                        if cached.is_valid(cache::DEFAULT_TTL) {
-                            let count = cached.data.len() as u64;
-                            self.workflows.jobs.set_loaded(cached.data, count);
-                            return;
-                        }
-                    }
+                let ttl = self.config.cache_ttl(CacheKind::Jobs);
+                if load_cached_list(&mut self.workflows.jobs, cache_path, ttl) {
+                    return;
                 }
-                // No valid cache, fetch from API
                 self.workflows.jobs.set_loading();
-                let result = self
-                    .github_client
-                    .as_mut()
-                    .unwrap()
-                    .get_jobs(&owner, &repo, run_id, 1, 30)
-                    .await;
-                match result {
-                    Ok((jobs, count)) => {
-                        if let Some(path) =
-                            cache::jobs_list_path(&owner, &repo, workflow_id, run_id)
-                        {
-                            let _ = cache::write_cached(&path, &jobs, false);
-                        }
-                        self.workflows.jobs.set_loaded(jobs, count);
-                    }
-                    Err(e) => {
-                        self.workflows.jobs.set_error(e.to_string());
-                        self.log_error(format!("Failed to load jobs: {}", e));
-                    }
-                }
             }
             ViewLevel::Logs {
                 ref owner,
@@ -1393,45 +5447,65 @@ impl App {
                 workflow_id,
                 run_id,
                 job_id,
+                job_conclusion,
                 ..
             } => {
-                if self.workflows.log_content.is_loaded() {
+                if self.workflows.log.content.is_loaded() {
                     return;
                 }
-                let owner = owner.clone();
-                let repo = repo.clone();
-                // Try to load from cache first (logs are immutable once job completes)
-                if let Some(path) = cache::job_log_path(&owner, &repo, workflow_id, run_id, job_id)
-                {
-                    if let Ok(Some(logs)) = cache::read_text(&path) {
-                        self.workflows.log_content = LoadingState::Loaded(logs);
-                        return;
-                    }
+                let jump_to_error = self.config.jump_to_first_error
+                    && job_conclusion == Some(RunConclusion::Failure);
+                // Try to load from cache first (logs never expire by default)
+                let cache_path = log_cache_path(
+                    owner,
+                    repo,
+                    Some(workflow_id),
+                    run_id,
+                    job_id,
+                    job_conclusion,
+                );
+                if let Some(logs) = self.read_cached_log(cache_path.as_deref()) {
+                    self.set_streamed_logs(Tab::Workflows, logs, jump_to_error);
+                    return;
                 }
                 // No cache, fetch from API
-                self.workflows.log_content = LoadingState::Loading;
+                self.workflows.log.content = LoadingState::Loading;
                 let result = self
                     .github_client
                     .as_mut()
                     .unwrap()
-                    .get_job_logs(&owner, &repo, job_id)
+                    .open_job_logs(owner, repo, job_id)
                     .await;
                 match result {
-                    Ok(logs) => {
-                        if let Some(path) =
-                            cache::job_log_path(&owner, &repo, workflow_id, run_id, job_id)
-                        {
-                            let _ = cache::write_text(&path, &logs);
-                        }
-                        self.workflows.log_content = LoadingState::Loaded(logs);
+                    Ok(response) => {
+                        let stream = LogStream::new(job_id, response, cache_path, jump_to_error);
+                        self.log_stream = Some((Tab::Workflows, stream));
                     }
                     Err(e) => {
-                        self.workflows.log_content = LoadingState::Error(e.to_string());
+                        self.workflows.log.content = LoadingState::Error(ViewError::from(&e));
                         self.log_error(format!("Failed to load logs: {}", e));
                     }
                 }
+                return;
+            }
+            ViewLevel::Environments { .. } => {
+                if self.workflows.environments.data.is_loaded() {
+                    return;
+                }
+                self.workflows.environments.set_loading();
+            }
+            ViewLevel::Deployments { .. } => {
+                if self.workflows.deployments.data.is_loaded() {
+                    return;
+                }
+                self.workflows.deployments.set_loading();
             }
         }
+
+        let client = self.github_client.as_mut().unwrap();
+        if let Some(rows) = fetch_rows(client, &view, &query).await {
+            self.show_rows(&view, &query, rows, false);
+        }
     }
 
     /// Fetch owners (current user + their orgs).
@@ -1456,9 +5530,17 @@ impl App {
     async fn fetch_repositories(
         client: &mut GitHubClient,
         owner: &str,
+        per_page: u32,
     ) -> crate::error::Result<(Vec<crate::github::Repository>, u64)> {
+        // Without a token only the owner's public repositories can be listed
+        if !client.is_authenticated() {
+            let repos = client.get_owner_repos(owner, 1, per_page).await?;
+            let count = repos.len() as u64;
+            return Ok((repos, count));
+        }
+
         // Try as user repos first, then org repos
-        let repos = client.get_user_repos(1, 30).await?;
+        let repos = client.get_user_repos(1, per_page).await?;
 
         // Filter to repos owned by this owner
         let filtered: Vec<_> = repos
@@ -1470,178 +5552,544 @@ impl App {
         Ok((filtered, count))
     }
 
+    /// Load the runner churn and queue time history shown in the runners list,
+    /// first recording `fetched` runners into it.
+    fn load_runner_history(&mut self, owner: &str, repo: &str, fetched: Option<&[Runner]>) {
+        let now = chrono::Utc::now();
+        let mut history = ChurnHistory::load(owner, repo);
+        if let Some(runners) = fetched {
+            history.record(runners, now);
+            history.save(owner, repo);
+        }
+        self.runners.churn = history.churn_by_label(
+            now,
+            chrono::Duration::hours(CHURN_WINDOW_HOURS),
+            CHURN_BUCKETS,
+        );
+        if self.runners.queue_times.is_none() {
+            self.runners.queue_times = Some(load_queue_times(owner, repo, now));
+        }
+    }
+
     /// Load data for the runners tab current view level.
     async fn load_runners_view(&mut self) {
-        if self.github_client.is_none() {
-            self.log_error("No GitHub token configured");
-            return;
+        tracing::debug!(view = ?self.runners.nav.current(), "load runners view");
+        self.load_runners_data().await;
+        self.apply_favorites_order();
+        self.apply_hidden_runner_repos();
+        self.apply_runs_grouping();
+        self.apply_jobs_grouping();
+        if let Some(item) = self.runners_recent_item(chrono::Utc::now()) {
+            record_recent(&mut self.recent, item);
+        }
+        if let RunnersViewLevel::Jobs {
+            owner,
+            repo,
+            run_id,
+            ..
+        } = self.runners.nav.current().clone()
+        {
+            // The runs list keeps its selection while drilled into a run
+            let Some(run) = self.runners.runs.selected_item() else {
+                return;
+            };
+            let (workflow_id, retried) = (run.workflow_id, run.run_attempt.unwrap_or(1) > 1);
+            self.runners.job_history = JobHistory::load(&owner, &repo, workflow_id, run_id);
+            queue_log_prefetch(
+                &mut self.runners.log_prefetch,
+                &self.runners.jobs,
+                &owner,
+                &repo,
+                workflow_id,
+                run_id,
+            );
+            self.runners.flaky_jobs = if retried {
+                self.job_attempts(&owner, &repo, workflow_id, run_id)
+                    .await
+                    .map(|jobs| flaky_job_names(&jobs))
+                    .unwrap_or_default()
+            } else {
+                HashSet::new()
+            };
+        }
+    }
+
+    /// Fetch data for the Runners tab current view level. Lists that are
+    /// already loaded are left alone; stale ones are refreshed by `revalidate`.
+    async fn load_runners_data(&mut self) {
+        match &self.github_client {
+            None => {
+                self.log_error("No GitHub token configured");
+                return;
+            }
+            // Self-hosted runners are only listed to repository admins
+            Some(client) if !client.is_authenticated() => {
+                self.runners
+                    .repositories
+                    .set_error(&JoltError::MissingToken);
+                return;
+            }
+            Some(_) => {}
         }
 
         let current_view = self.runners.nav.current().clone();
+        let view = ListView::Runners(current_view.clone());
+        let query = self.rows_query(&view).await;
+        let cache_path = rows_cache_path(&view, &query);
 
         match current_view {
             RunnersViewLevel::Repositories => {
                 if self.runners.repositories.data.is_loaded() {
                     return;
                 }
-                // Try to load from cache first
-                if let Some(path) = cache::runners_repos_path() {
-                    if let Ok(Some(cached)) =
-                        cache::read_cached::<Vec<crate::github::Repository>>(&path)
-                    {
-                        if cached.is_valid(cache::DEFAULT_TTL) {
-                            let count = cached.data.len() as u64;
-                            self.runners.repositories.set_loaded(cached.data, count);
-                            return;
-                        }
+                let ttl = self.config.cache_ttl(CacheKind::Repositories);
+                if load_cached_list(&mut self.runners.repositories, cache_path, ttl) {
+                    return;
+                }
+                self.runners.repositories.set_loading();
+            }
+            RunnersViewLevel::Runners {
+                ref owner,
+                ref repo,
+            } => {
+                if self.runners.runners.data.is_loaded() {
+                    return;
+                }
+                let ttl = self.config.cache_ttl(CacheKind::Runners);
+                if load_cached_rows(&mut self.runners.runners, cache_path, ttl, group_runners) {
+                    let runners: Vec<Runner> = self
+                        .runners
+                        .runners
+                        .data
+                        .data()
+                        .into_iter()
+                        .flat_map(|rows| &rows.items)
+                        .flat_map(|row| row.runners().iter().cloned())
+                        .collect();
+                    self.runners_listed(owner, repo, &runners, false);
+                    return;
+                }
+                self.runners.runners.set_loading();
+            }
+            RunnersViewLevel::Runs { .. } => {
+                if self.runners.runs.data.is_loaded() {
+                    return;
+                }
+                let ttl = self.config.cache_ttl(CacheKind::Runs);
+                if load_cached_list(&mut self.runners.runs, cache_path, ttl) {
+                    return;
+                }
+                self.runners.runs.set_loading();
+            }
+            RunnersViewLevel::Jobs { .. } => {
+                if self.runners.jobs.data.is_loaded() {
+                    return;
+                }
+                // Shares the Workflows tab's cache of the run's jobs
+                let ttl = self.config.cache_ttl(CacheKind::Jobs);
+                if load_cached_list(&mut self.runners.jobs, cache_path, ttl) {
+                    return;
+                }
+                self.runners.jobs.set_loading();
+            }
+            RunnersViewLevel::Logs {
+                ref owner,
+                ref repo,
+                run_id,
+                job_id,
+                job_conclusion,
+                workflow_id,
+                ..
+            } => {
+                if self.runners.log.content.is_loaded() {
+                    return;
+                }
+                let jump_to_error = self.config.jump_to_first_error
+                    && job_conclusion == Some(RunConclusion::Failure);
+                let cache_path =
+                    log_cache_path(owner, repo, workflow_id, run_id, job_id, job_conclusion);
+                if let Some(logs) = self.read_cached_log(cache_path.as_deref()) {
+                    self.set_streamed_logs(Tab::Runners, logs, jump_to_error);
+                    return;
+                }
+                self.runners.log.content = LoadingState::Loading;
+                let result = self
+                    .github_client
+                    .as_mut()
+                    .unwrap()
+                    .open_job_logs(owner, repo, job_id)
+                    .await;
+                match result {
+                    Ok(response) => {
+                        let stream = LogStream::new(job_id, response, cache_path, jump_to_error);
+                        self.log_stream = Some((Tab::Runners, stream));
+                    }
+                    Err(e) => {
+                        self.runners.log.content = LoadingState::Error(ViewError::from(&e));
+                        self.log_error(format!("Failed to load logs: {}", e));
+                    }
+                }
+                return;
+            }
+            RunnersViewLevel::DiagLogs {
+                ref runner_name, ..
+            } => {
+                if self.runners.log.content.is_loaded() {
+                    return;
+                }
+                // GitHub has no API for runner diagnostics, so they're only
+                // readable for runners installed on this machine
+                let Some(dir) = self.config.runner_dirs.get(runner_name).cloned() else {
+                    let message = format!(
+                        "No install directory for runner {} (add it under [runner_dirs] in config.toml)",
+                        runner_name
+                    );
+                    self.runners.log.content = LoadingState::Error(message.clone().into());
+                    self.log_warn(message);
+                    return;
+                };
+                match diag::read_diag_logs(&dir) {
+                    Ok(logs) => {
+                        self.runners.log.content = LoadingState::Loaded(logs);
+                    }
+                    Err(e) => {
+                        self.runners.log.content = LoadingState::Error(e.to_string().into());
+                        self.log_error(format!("Failed to read diagnostic logs: {}", e));
+                    }
+                }
+                return;
+            }
+            RunnersViewLevel::RunnerGroups { .. } => {
+                if self.runners.runner_groups.data.is_loaded() {
+                    return;
+                }
+                self.runners.runner_groups.set_loading();
+            }
+            RunnersViewLevel::GroupRunners { .. } => {
+                if self.runners.group_runners.data.is_loaded() {
+                    return;
+                }
+                self.runners.group_runners.set_loading();
+            }
+        }
+
+        let client = self.github_client.as_mut().unwrap();
+        if let Some(rows) = fetch_rows(client, &view, &query).await {
+            self.show_rows(&view, &query, rows, false);
+        }
+    }
+
+    /// What the rows of a list view are fetched with, choosing the Runs
+    /// list's initial branch the first time it's shown.
+    async fn rows_query(&mut self, view: &ListView) -> RowsQuery {
+        let mut query = RowsQuery {
+            per_page: self.config.per_page,
+            ..RowsQuery::default()
+        };
+        match view {
+            ListView::Workflows(ViewLevel::Owners) if self.anonymous() => {
+                query.public_owners = self
+                    .favorite_repos
+                    .iter()
+                    .filter_map(|full_name| full_name.split_once('/'))
+                    .map(|(owner, _)| owner.to_string())
+                    .collect();
+                query.public_owners.sort();
+                query.public_owners.dedup();
+            }
+            ListView::Workflows(ViewLevel::Runs { owner, repo, .. }) => {
+                if !self.workflows.branch_chosen {
+                    let initial = self.initial_branch(owner, repo);
+                    self.workflows.branch_chosen = initial.is_some();
+                    self.workflows.current_branch = initial.flatten();
+                }
+                query.actor = self.runs_actor().await;
+                query.branch = self.workflows.current_branch.clone();
+            }
+            ListView::Runners(RunnersViewLevel::Runs { owner, repo, .. }) => {
+                if !self.runners.branch_chosen {
+                    let initial = self.initial_branch(owner, repo);
+                    self.runners.branch_chosen = initial.is_some();
+                    self.runners.current_branch = initial.flatten();
+                }
+                query.actor = self.runs_actor().await;
+                query.branch = self.runners.current_branch.clone();
+            }
+            ListView::Runners(RunnersViewLevel::GroupRunners { .. }) => {
+                // Only groups open to selected repositories have a list to show
+                query.group_repos = self
+                    .runners
+                    .runner_groups
+                    .selected_item()
+                    .is_some_and(|group| group.visibility == RunnerGroupVisibility::Selected);
+            }
+            _ => {}
+        }
+        query
+    }
+
+    /// Show rows fetched for a list view and cache them, or the error that
+    /// stopped them. A failed revalidation keeps the stale rows on screen.
+    fn show_rows(&mut self, view: &ListView, query: &RowsQuery, rows: Rows, revalidating: bool) {
+        let path = rows_cache_path(view, query);
+        match rows {
+            Rows::Owners(result) => self.show_list(
+                |app| &mut app.workflows.owners,
+                result,
+                path,
+                revalidating,
+                "owners",
+            ),
+            Rows::PublicOwners { owners, failures } => {
+                for failure in failures {
+                    self.log_warn(failure);
+                }
+                let count = owners.len() as u64;
+                self.workflows.owners.set_refreshed(owners, count);
+            }
+            Rows::Repositories(result) => {
+                let list: fn(&mut Self) -> &mut SelectableList<crate::github::Repository> =
+                    match view {
+                        ListView::Runners(_) => |app| &mut app.runners.repositories,
+                        _ => |app| &mut app.workflows.repositories,
+                    };
+                self.show_list(list, result, path, revalidating, "repositories")
+            }
+            Rows::Workflows(result) => self.show_list(
+                |app| &mut app.workflows.workflows,
+                result,
+                path,
+                revalidating,
+                "workflows",
+            ),
+            Rows::Runs(result) => {
+                let list: fn(&mut Self) -> &mut SelectableList<WorkflowRun> = match view {
+                    ListView::Runners(_) => |app| &mut app.runners.runs,
+                    _ => |app| &mut app.workflows.runs,
+                };
+                self.show_list(list, result, path, revalidating, "runs")
+            }
+            Rows::Jobs(result) => {
+                let list: fn(&mut Self) -> &mut SelectableList<Job> = match view {
+                    ListView::Runners(_) => |app| &mut app.runners.jobs,
+                    _ => |app| &mut app.workflows.jobs,
+                };
+                self.show_list(list, result, path, revalidating, "jobs")
+            }
+            Rows::Environments(result) => self.show_list(
+                |app| &mut app.workflows.environments,
+                result,
+                path,
+                revalidating,
+                "environments",
+            ),
+            Rows::Deployments(result) => self.show_list(
+                |app| &mut app.workflows.deployments,
+                result,
+                path,
+                revalidating,
+                "deployments",
+            ),
+            Rows::Runners(result) => match result {
+                Ok((runners, _)) => {
+                    let ListView::Runners(RunnersViewLevel::Runners { owner, repo }) = view else {
+                        return;
+                    };
+                    if let Some(path) = path {
+                        let _ = cache::write_cached(&path, &runners, false);
+                    }
+                    self.runners_listed(owner, repo, &runners, true);
+                    // Grouping shrinks the list, so count rows rather than runners
+                    let rows = group_runners(runners);
+                    let count = rows.len() as u64;
+                    self.runners.runners.set_refreshed(rows, count);
+                }
+                Err(e) if revalidating => {
+                    self.runners.runners.stale = false;
+                    self.log_warn(format!("Failed to refresh runners: {}", e));
+                }
+                Err(e) => {
+                    // Shown as a dedicated empty state when the token lacks admin access
+                    self.runners.runners.set_error(&e);
+                    self.log_error(format!("Failed to load runners: {}", e));
+                }
+            },
+            Rows::RunnerGroups(result) => match result {
+                Ok((groups, count)) => self.runners.runner_groups.set_refreshed(groups, count),
+                Err(e) if revalidating => {
+                    self.runners.runner_groups.stale = false;
+                    self.log_warn(format!("Failed to refresh runner groups: {}", e));
+                }
+                Err(e) => {
+                    let mut error = ViewError::from(&e);
+                    // Users and repositories without an organization have no groups
+                    if matches!(e, JoltError::NotFound(_) | JoltError::Forbidden(_)) {
+                        error = error.with_hint(
+                            "Runner groups exist only for organizations, and listing them needs the admin:org scope",
+                        );
                     }
+                    self.runners.runner_groups.set_error(error);
+                    self.log_error(format!("Failed to load runner groups: {}", e));
                 }
-                // No valid cache, fetch from API
-                self.runners.repositories.set_loading();
-                let result = self
-                    .github_client
-                    .as_mut()
-                    .unwrap()
-                    .get_user_repos(1, 30)
-                    .await;
-                match result {
-                    Ok(repos) => {
-                        if let Some(path) = cache::runners_repos_path() {
-                            let _ = cache::write_cached(&path, &repos, false);
-                        }
-                        let count = repos.len() as u64;
-                        self.runners.repositories.set_loaded(repos, count);
+            },
+            Rows::GroupRunners { runners, repos } => {
+                match repos {
+                    Some(Ok(repos)) => {
+                        self.runners.group_repos =
+                            repos.into_iter().map(|repo| repo.name).collect();
                     }
-                    Err(e) => {
-                        self.runners.repositories.set_error(e.to_string());
-                        self.log_error(format!("Failed to load repositories: {}", e));
+                    Some(Err(e)) => {
+                        self.log_warn(format!("Failed to load runner group access: {}", e))
                     }
+                    None => {}
                 }
-            }
-            RunnersViewLevel::Runners {
-                ref owner,
-                ref repo,
-            } => {
-                if !self.runners.runners.data.is_loaded() {
-                    self.runners.runners.set_loading();
-                    let owner = owner.clone();
-                    let repo = repo.clone();
-                    let result = self
-                        .github_client
-                        .as_mut()
-                        .unwrap()
-                        .get_runners(&owner, &repo, 1, 30)
-                        .await;
-                    match result {
-                        Ok((runners, count)) => {
-                            self.runners.runners.set_loaded(runners, count);
-                        }
-                        Err(e) => {
-                            self.runners.runners.set_error(e.to_string());
-                            self.log_error(format!("Failed to load runners: {}", e));
-                        }
+                match runners {
+                    Ok((runners, _)) => {
+                        self.runner_utilization.record(&runners, chrono::Utc::now());
+                        let rows = group_runners(runners);
+                        let count = rows.len() as u64;
+                        self.runners.group_runners.set_refreshed(rows, count);
                     }
-                }
-            }
-            RunnersViewLevel::Runs {
-                ref owner,
-                ref repo,
-                ..
-            } => {
-                if !self.runners.runs.data.is_loaded() {
-                    self.runners.runs.set_loading();
-                    let owner = owner.clone();
-                    let repo = repo.clone();
-                    // Get all workflow runs for the repo
-                    let result = self
-                        .github_client
-                        .as_mut()
-                        .unwrap()
-                        .get_workflow_runs(&owner, &repo, 1, 30)
-                        .await;
-                    match result {
-                        Ok((runs, count)) => {
-                            self.runners.runs.set_loaded(runs, count);
-                        }
-                        Err(e) => {
-                            self.runners.runs.set_error(e.to_string());
-                            self.log_error(format!("Failed to load runs: {}", e));
-                        }
+                    Err(e) if revalidating => {
+                        self.runners.group_runners.stale = false;
+                        self.log_warn(format!("Failed to refresh group runners: {}", e));
                     }
-                }
-            }
-            RunnersViewLevel::Jobs {
-                ref owner,
-                ref repo,
-                run_id,
-                ..
-            } => {
-                if !self.runners.jobs.data.is_loaded() {
-                    self.runners.jobs.set_loading();
-                    let owner = owner.clone();
-                    let repo = repo.clone();
-                    let result = self
-                        .github_client
-                        .as_mut()
-                        .unwrap()
-                        .get_jobs(&owner, &repo, run_id, 1, 30)
-                        .await;
-                    match result {
-                        Ok((jobs, count)) => {
-                            self.runners.jobs.set_loaded(jobs, count);
-                        }
-                        Err(e) => {
-                            self.runners.jobs.set_error(e.to_string());
-                            self.log_error(format!("Failed to load jobs: {}", e));
-                        }
+                    Err(e) => {
+                        self.runners.group_runners.set_error(&e);
+                        self.log_error(format!("Failed to load group runners: {}", e));
                     }
                 }
             }
-            RunnersViewLevel::Logs {
-                ref owner,
-                ref repo,
-                job_id,
-                ..
-            } => {
-                if !self.runners.log_content.is_loaded() {
-                    self.runners.log_content = LoadingState::Loading;
-                    let owner = owner.clone();
-                    let repo = repo.clone();
-                    let result = self
-                        .github_client
-                        .as_mut()
-                        .unwrap()
-                        .get_job_logs(&owner, &repo, job_id)
-                        .await;
-                    match result {
-                        Ok(logs) => {
-                            self.runners.log_content = LoadingState::Loaded(logs);
-                        }
-                        Err(e) => {
-                            self.runners.log_content = LoadingState::Error(e.to_string());
-                            self.log_error(format!("Failed to load logs: {}", e));
+            Rows::Inbox(result) => self.show_list(
+                |app| &mut app.inbox,
+                result,
+                path,
+                revalidating,
+                "pull requests",
+            ),
+        }
+    }
+
+    /// Fetch the runners of the shown Runners list in the background, only
+    /// to record which are busy.
+    fn sample_runners(&mut self) {
+        let Some(client) = self.github_client.as_ref() else {
+            return;
+        };
+        let per_page = self.config.per_page;
+        match self.runners.nav.current().clone() {
+            RunnersViewLevel::Runners { owner, repo } => {
+                self.tasks
+                    .spawn(TaskKind::RunnerSample, client, move |mut fork| async move {
+                        let result = fork.get_runners(&owner, &repo, 1, per_page).await;
+                        let runners = result.map(|(runners, _)| runners);
+                        (fork, Loaded::RunnerSample(runners))
+                    });
+            }
+            RunnersViewLevel::GroupRunners { org, group_id, .. } => {
+                self.tasks
+                    .spawn(TaskKind::RunnerSample, client, move |mut fork| async move {
+                        let result = fork
+                            .get_runner_group_runners(&org, group_id, 1, per_page)
+                            .await;
+                        let runners = result.map(|(runners, _)| runners);
+                        (fork, Loaded::RunnerSample(runners))
+                    });
+            }
+            _ => {}
+        }
+    }
+
+    /// Follow up on a repository's runners being listed, whether `fetched` or
+    /// read from the cache: update their history and utilization, probe
+    /// their health, and look up the jobs of busy ones.
+    fn runners_listed(&mut self, owner: &str, repo: &str, runners: &[Runner], fetched: bool) {
+        self.load_runner_history(owner, repo, fetched.then_some(runners));
+        self.runner_utilization.record(runners, chrono::Utc::now());
+        self.probe_runner_health(runners);
+        self.runners.enrichment_loading = true;
+    }
+
+    /// Probe the health of listed runners installed on this machine, a few at
+    /// a time in the background, showing each result as it comes in.
+    fn probe_runner_health(&mut self, runners: &[Runner]) {
+        let probes: Vec<(String, PathBuf)> = runners
+            .iter()
+            .filter_map(|runner| {
+                let dir = self.config.runner_dirs.get(&runner.name)?;
+                Some((runner.name.clone(), dir.clone()))
+            })
+            .collect();
+        if probes.is_empty() {
+            return;
+        }
+        let command = self.config.runner_probe.clone();
+        let started = self
+            .tasks
+            .spawn_local(TaskKind::HealthProbe, move |progress| async move {
+                stream::iter(probes)
+                    .for_each_concurrent(ENRICHMENT_CONCURRENCY, |(name, dir)| {
+                        let (progress, command) = (&progress, &command);
+                        async move {
+                            let health = diag::probe_health(&name, &dir, command).await;
+                            progress.send(Loaded::RunnerHealth(name, health));
                         }
-                    }
+                    })
+                    .await;
+            });
+        if started {
+            self.runners.health.clear();
+        }
+    }
+
+    /// Show fetched rows in a list and cache them at `path`, or the error
+    /// that stopped them.
+    fn show_list<T: Serialize>(
+        &mut self,
+        list: fn(&mut Self) -> &mut SelectableList<T>,
+        result: crate::error::Result<(Vec<T>, u64)>,
+        path: Option<PathBuf>,
+        revalidating: bool,
+        what: &str,
+    ) {
+        match result {
+            Ok((items, count)) => {
+                if let Some(path) = path {
+                    let _ = cache::write_cached(&path, &items, false);
                 }
+                list(self).set_refreshed(items, count);
+            }
+            Err(e) if revalidating => {
+                list(self).stale = false;
+                self.log_warn(format!("Failed to refresh {}: {}", what, e));
+            }
+            Err(e) => {
+                list(self).set_error(&e);
+                self.log_error(format!("Failed to load {}: {}", what, e));
             }
         }
     }
 
-    /// Log an error to the console tab.
+    /// Log an error to the console tab and the application log.
     fn log_error(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::error!("{}", message);
         self.console_messages.push(ConsoleMessage::error(message));
         self.console_unread += 1;
     }
 
-    /// Log a warning to the console tab.
-    #[allow(dead_code)]
+    /// Log a warning to the console tab and the application log.
     fn log_warn(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::warn!("{}", message);
         self.console_messages.push(ConsoleMessage::warn(message));
     }
 
-    /// Log info to the console tab.
+    /// Log info to the console tab and the application log.
     #[allow(dead_code)]
     fn log_info(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::info!("{}", message);
         self.console_messages.push(ConsoleMessage::info(message));
     }
 
@@ -1688,3 +6136,810 @@ impl App {
         }
     }
 }
+
+/// Show a cached list, marking it stale if the entry is older than `ttl`.
+/// Returns false if there was nothing cached.
+fn load_cached_list<T: DeserializeOwned>(
+    list: &mut SelectableList<T>,
+    path: Option<PathBuf>,
+    ttl: Option<Duration>,
+) -> bool {
+    load_cached_rows(list, path, ttl, |items| items)
+}
+
+/// Like `load_cached_list`, for lists whose rows are built from the cached
+/// items (e.g., runners grouped into scale sets).
+fn load_cached_rows<T: DeserializeOwned, R>(
+    list: &mut SelectableList<R>,
+    path: Option<PathBuf>,
+    ttl: Option<Duration>,
+    rows: impl FnOnce(Vec<T>) -> Vec<R>,
+) -> bool {
+    let Some(path) = path else {
+        return false;
+    };
+    let Ok(Some(cached)) = cache::read_cached::<Vec<T>>(&path) else {
+        return false;
+    };
+    let expired = ttl.is_some_and(|ttl| cached.is_expired(ttl));
+    if expired {
+        cache::stats::record_stale(&path);
+    }
+    let rows = rows(cached.data);
+    let count = rows.len() as u64;
+    list.set_loaded(rows, count);
+    list.stale = expired;
+    true
+}
+
+/// Delete a cache file so the next load fetches from the API.
+/// Fetch the jobs of every attempt of a run. Complete runs don't change, so
+/// theirs are cached for good.
+async fn fetch_job_attempts(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    run_id: u64,
+) -> crate::error::Result<Vec<Job>> {
+    let path = cache::job_attempts_path(owner, repo, workflow_id, run_id);
+    if let Some(cached) = path
+        .as_ref()
+        .and_then(|path| cache::read_cached::<Vec<Job>>(path).ok().flatten())
+    {
+        return Ok(cached.data);
+    }
+    let jobs = client.get_job_attempts(owner, repo, run_id).await?;
+    let completed = jobs.iter().all(|job| job.status == RunStatus::Completed);
+    if let (Some(path), true) = (path, completed) {
+        let _ = cache::write_cached(&path, &jobs, true);
+    }
+    Ok(jobs)
+}
+
+/// Build the flaky jobs report from a workflow's recent runs, fetching the
+/// attempts of retried runs a few at a time.
+async fn fetch_flaky_report(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+) -> crate::error::Result<FlakyReport> {
+    let (runs, _) = client
+        .get_workflow_runs_for_workflow(
+            owner,
+            repo,
+            workflow_id,
+            None,
+            None,
+            1,
+            FLAKY_REPORT_RUNS as u32,
+        )
+        .await?;
+    // Only retried runs can have jobs that passed on retry
+    let retried: Vec<u64> = runs
+        .iter()
+        .filter(|run| run.run_attempt.unwrap_or(1) > 1)
+        .map(|run| run.id)
+        .collect();
+    let results: Vec<_> = stream::iter(retried)
+        .map(|run_id| {
+            let mut fork = client.fork();
+            async move {
+                let result = fetch_job_attempts(&mut fork, owner, repo, workflow_id, run_id).await;
+                (result, fork)
+            }
+        })
+        .buffer_unordered(ENRICHMENT_CONCURRENCY)
+        .collect()
+        .await;
+    let mut attempts = Vec::new();
+    for (result, fork) in results {
+        client.absorb(fork);
+        match result {
+            Ok(jobs) => attempts.push(jobs),
+            Err(e) => tracing::warn!("Failed to load job attempts: {}", e),
+        }
+    }
+    Ok(FlakyReport::from_attempts(runs.len(), &attempts))
+}
+
+/// The latest run of each of a repository's `workflows`: picked out of its
+/// recent runs, with workflows missing from them fetched a few at a time.
+async fn fetch_latest_runs(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    workflows: &[Workflow],
+) -> crate::error::Result<HashMap<u64, WorkflowRun>> {
+    let (runs, _) = client
+        .get_workflow_runs(owner, repo, None, None, 1, 100)
+        .await?;
+    let mut latest = latest_by_workflow(runs);
+    let results: Vec<_> = stream::iter(missing_workflows(workflows, &latest))
+        .map(|workflow_id| {
+            let mut fork = client.fork();
+            async move {
+                let result = fork
+                    .get_workflow_runs_for_workflow(owner, repo, workflow_id, None, None, 1, 1)
+                    .await;
+                (result, fork)
+            }
+        })
+        .buffer_unordered(ENRICHMENT_CONCURRENCY)
+        .collect()
+        .await;
+    for (result, fork) in results {
+        client.absorb(fork);
+        if let Ok((runs, _)) = result {
+            latest.extend(latest_by_workflow(runs));
+        }
+    }
+    Ok(latest)
+}
+
+/// The jobs busy runners of a repository are working on, by runner name,
+/// from the jobs of its in-progress runs fetched a few at a time. Also
+/// returns how many runs' jobs failed to load.
+async fn fetch_runner_jobs(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+) -> crate::error::Result<(HashMap<String, RunnerJob>, usize)> {
+    let runs = client.get_in_progress_runs(owner, repo).await?;
+    let results: Vec<_> = stream::iter(runs)
+        .map(|run| {
+            let mut fork = client.fork();
+            async move {
+                let result = fork.get_jobs(owner, repo, run.id, 1, 100).await;
+                (run, result, fork)
+            }
+        })
+        .buffer_unordered(ENRICHMENT_CONCURRENCY)
+        .collect()
+        .await;
+    let mut fetched = Vec::new();
+    let mut failures = 0;
+    for (run, result, fork) in results {
+        client.absorb(fork);
+        match result {
+            Ok((jobs, _)) => fetched.push((run, jobs)),
+            Err(_) => failures += 1,
+        }
+    }
+    Ok((jobs_by_runner(&fetched), failures))
+}
+
+/// Key code an overlay reads for a key press. Ctrl+D and Ctrl+U page as they
+/// do in lists, and other Ctrl and Alt chords aren't taken for typed characters.
+fn overlay_key(key: KeyEvent) -> KeyCode {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        _ if !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            key.code
+        }
+        KeyCode::Char('d') if ctrl => KeyCode::PageDown,
+        KeyCode::Char('u') if ctrl => KeyCode::PageUp,
+        KeyCode::Char(_) => KeyCode::Null,
+        code => code,
+    }
+}
+
+/// Whether a key press clears a text input: Ctrl+U, as in a shell.
+fn is_clear_input(key: KeyEvent) -> bool {
+    key.code == KeyCode::Char('u') && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Fetch the Home items of favorites, a few repositories at a time: the
+/// failing and running runs of `repos`, then the offline ones of `runners`
+/// (owner, repository, and name). Runs hidden by `rules` are left out, and
+/// failures are described for the Console.
+async fn fetch_home(
+    client: &mut GitHubClient,
+    repos: Vec<String>,
+    runners: Vec<(String, String, String)>,
+    rules: &[IgnoreRule],
+    per_page: u32,
+) -> (Vec<HomeItem>, Vec<String>) {
+    let results: Vec<_> = stream::iter(repos)
+        .map(|key| {
+            let mut fork = client.fork();
+            async move {
+                let mut failing = Vec::new();
+                let mut running = Vec::new();
+                let mut failures = Vec::new();
+                let Some((owner, repo)) = key.split_once('/') else {
+                    return (failing, running, failures, fork);
+                };
+                let item = |run| (owner.to_string(), repo.to_string(), Box::new(run));
+                match fork
+                    .get_workflow_runs(owner, repo, None, None, 1, per_page)
+                    .await
+                {
+                    Ok((runs, _)) => {
+                        let runs = runs.into_iter().filter(|run| !is_ignored(rules, run));
+                        failing.extend(failing_runs(runs.collect()).into_iter().map(|run| {
+                            let (owner, repo, run) = item(run);
+                            HomeItem::FailingRun { owner, repo, run }
+                        }));
+                    }
+                    Err(e) => failures.push(format!("Failed to load runs of {}: {}", key, e)),
+                }
+                match fork.get_in_progress_runs(owner, repo).await {
+                    Ok(runs) => {
+                        running.extend(runs.into_iter().filter(|run| !is_ignored(rules, run)).map(
+                            |run| {
+                                let (owner, repo, run) = item(run);
+                                HomeItem::RunningRun { owner, repo, run }
+                            },
+                        ))
+                    }
+                    Err(e) => {
+                        failures.push(format!("Failed to load in-progress runs of {}: {}", key, e))
+                    }
+                }
+                (failing, running, failures, fork)
+            }
+        })
+        .buffered(ENRICHMENT_CONCURRENCY)
+        .collect()
+        .await;
+    let mut items = Vec::new();
+    let mut running = Vec::new();
+    let mut failures = Vec::new();
+    for (repo_failing, repo_running, repo_failures, fork) in results {
+        client.absorb(fork);
+        items.extend(repo_failing);
+        running.extend(repo_running);
+        failures.extend(repo_failures);
+    }
+    items.extend(running);
+    if !client.can(Capability::ReadRunners) {
+        return (items, failures);
+    }
+
+    // Runners are listed once per repository with favorite runners
+    let mut by_repo: Vec<((String, String), Vec<String>)> = Vec::new();
+    for (owner, repo, name) in runners {
+        match by_repo.last_mut() {
+            Some(((o, r), names)) if (&*o, &*r) == (&owner, &repo) => names.push(name),
+            _ => by_repo.push(((owner, repo), vec![name])),
+        }
+    }
+    let results: Vec<_> = stream::iter(by_repo)
+        .map(|((owner, repo), names)| {
+            let mut fork = client.fork();
+            async move {
+                let result = fork.get_runners(&owner, &repo, 1, 100).await;
+                (owner, repo, names, result, fork)
+            }
+        })
+        .buffered(ENRICHMENT_CONCURRENCY)
+        .collect()
+        .await;
+    for (owner, repo, names, result, fork) in results {
+        client.absorb(fork);
+        match result {
+            Ok((fetched, _)) => items.extend(
+                fetched
+                    .into_iter()
+                    .filter(|r| names.contains(&r.name) && r.status == RunnerStatus::Offline)
+                    .map(|runner| HomeItem::OfflineRunner {
+                        owner: owner.clone(),
+                        repo: repo.clone(),
+                        runner,
+                    }),
+            ),
+            Err(e) => failures.push(format!(
+                "Failed to load runners of {}/{}: {}",
+                owner, repo, e
+            )),
+        }
+    }
+    (items, failures)
+}
+
+/// Fetch the in-progress runs of favorite repositories for the Live tab,
+/// along with the latest state of `shown` runs that have since finished.
+/// Failures are described for the Console.
+async fn fetch_live(
+    client: &mut GitHubClient,
+    repos: Vec<String>,
+    shown: Vec<LiveRun>,
+) -> (Vec<LiveRun>, Vec<LiveRun>, Vec<String>) {
+    let mut runs = Vec::new();
+    let mut failures = Vec::new();
+    for key in repos {
+        let Some((owner, repo)) = key.split_once('/') else {
+            continue;
+        };
+        match client.get_in_progress_runs(owner, repo).await {
+            Ok(repo_runs) => runs.extend(repo_runs.into_iter().map(|run| LiveRun {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                run,
+            })),
+            Err(e) => failures.push(format!("Failed to load in-progress runs of {}: {}", key, e)),
+        }
+    }
+    // Runs that left the list finished, whose conclusions are looked up a
+    // few at a time
+    let left: Vec<LiveRun> = shown
+        .into_iter()
+        .filter(|live| !runs.iter().any(|run| run.run.id == live.run.id))
+        .collect();
+    let results: Vec<_> = stream::iter(left)
+        .map(|live| {
+            let mut fork = client.fork();
+            async move {
+                let result = fork
+                    .get_workflow_run(&live.owner, &live.repo, live.run.id)
+                    .await;
+                (live, result, fork)
+            }
+        })
+        .buffer_unordered(ENRICHMENT_CONCURRENCY)
+        .collect()
+        .await;
+    let mut finished = Vec::new();
+    for (live, result, fork) in results {
+        client.absorb(fork);
+        match result {
+            Ok(run) => finished.push(LiveRun { run, ..live }),
+            Err(e) => failures.push(format!("Failed to load run {}: {}", live.run.id, e)),
+        }
+    }
+    (runs, finished, failures)
+}
+
+/// Cache entry for a list view's rows. Runs fetched for a branch or actor
+/// are cached apart from everyone's runs on every branch.
+fn rows_cache_path(view: &ListView, query: &RowsQuery) -> Option<PathBuf> {
+    match view {
+        ListView::Workflows(level) => match level {
+            ViewLevel::Owners if query.public_owners.is_empty() => cache::owners_list_path(),
+            ViewLevel::Repositories { owner } => cache::repos_list_path(owner),
+            ViewLevel::Workflows { owner, repo } => cache::workflows_list_path(owner, repo),
+            ViewLevel::Runs {
+                owner,
+                repo,
+                workflow_id,
+                ..
+            } => cache::runs_list_path(
+                owner,
+                repo,
+                *workflow_id,
+                query.branch.as_deref(),
+                query.actor.as_deref(),
+            ),
+            ViewLevel::Jobs {
+                owner,
+                repo,
+                workflow_id,
+                run_id,
+                ..
+            } => cache::jobs_list_path(owner, repo, *workflow_id, *run_id),
+            _ => None,
+        },
+        ListView::Runners(level) => match level {
+            RunnersViewLevel::Repositories => cache::runners_repos_path(),
+            RunnersViewLevel::Runners { owner, repo } => cache::runners_list_path(owner, repo),
+            RunnersViewLevel::Runs { owner, repo, .. } => cache::repo_runs_list_path(
+                owner,
+                repo,
+                query.branch.as_deref(),
+                query.actor.as_deref(),
+            ),
+            // Shares the Workflows tab's cache of the run's jobs
+            RunnersViewLevel::Jobs {
+                owner,
+                repo,
+                run_id,
+                workflow_id,
+                ..
+            } => workflow_id
+                .and_then(|workflow_id| cache::jobs_list_path(owner, repo, workflow_id, *run_id)),
+            _ => None,
+        },
+        ListView::Inbox => cache::inbox_path(),
+    }
+}
+
+/// Fetch the rows of a list view, or None for views that aren't lists.
+/// Runs on a forked client when revalidating in the background.
+async fn fetch_rows(client: &mut GitHubClient, view: &ListView, query: &RowsQuery) -> Option<Rows> {
+    let per_page = query.per_page;
+    let rows = match view {
+        ListView::Workflows(level) => match level {
+            ViewLevel::Owners if !client.is_authenticated() => {
+                let mut owners = Vec::new();
+                let mut failures = Vec::new();
+                for login in &query.public_owners {
+                    match client.get_owner(login).await {
+                        Ok(owner) => owners.push(owner),
+                        Err(e) => failures.push(format!("Failed to load owner {}: {}", login, e)),
+                    }
+                }
+                Rows::PublicOwners { owners, failures }
+            }
+            ViewLevel::Owners => Rows::Owners(App::fetch_owners(client).await),
+            ViewLevel::Repositories { owner } => {
+                Rows::Repositories(App::fetch_repositories(client, owner, per_page).await)
+            }
+            ViewLevel::Workflows { owner, repo } => {
+                Rows::Workflows(client.get_workflows(owner, repo, 1, per_page).await)
+            }
+            ViewLevel::Runs {
+                owner,
+                repo,
+                workflow_id,
+                ..
+            } => Rows::Runs(
+                client
+                    .get_workflow_runs_for_workflow(
+                        owner,
+                        repo,
+                        *workflow_id,
+                        query.actor.as_deref(),
+                        query.branch.as_deref(),
+                        1,
+                        per_page,
+                    )
+                    .await,
+            ),
+            ViewLevel::Jobs {
+                owner,
+                repo,
+                run_id,
+                ..
+            } => Rows::Jobs(client.get_jobs(owner, repo, *run_id, 1, per_page).await),
+            ViewLevel::Environments { owner, repo } => {
+                Rows::Environments(client.get_environments(owner, repo, 1, per_page).await)
+            }
+            ViewLevel::Deployments {
+                owner,
+                repo,
+                environment,
+            } => Rows::Deployments(
+                client
+                    .get_deployments(owner, repo, environment, RECENT_DEPLOYMENTS)
+                    .await
+                    .map(|deployments| {
+                        let count = deployments.len() as u64;
+                        (deployments, count)
+                    }),
+            ),
+            ViewLevel::Logs { .. } => return None,
+        },
+        ListView::Runners(level) => match level {
+            RunnersViewLevel::Repositories => {
+                Rows::Repositories(client.get_user_repos(1, per_page).await.map(|repos| {
+                    let count = repos.len() as u64;
+                    (repos, count)
+                }))
+            }
+            RunnersViewLevel::Runners { owner, repo } => {
+                Rows::Runners(client.get_runners(owner, repo, 1, per_page).await)
+            }
+            RunnersViewLevel::Runs { owner, repo, .. } => Rows::Runs(
+                client
+                    .get_workflow_runs(
+                        owner,
+                        repo,
+                        query.actor.as_deref(),
+                        query.branch.as_deref(),
+                        1,
+                        per_page,
+                    )
+                    .await,
+            ),
+            RunnersViewLevel::Jobs {
+                owner,
+                repo,
+                run_id,
+                ..
+            } => Rows::Jobs(client.get_jobs(owner, repo, *run_id, 1, per_page).await),
+            RunnersViewLevel::RunnerGroups { org } => {
+                Rows::RunnerGroups(client.get_runner_groups(org, 1, per_page).await)
+            }
+            RunnersViewLevel::GroupRunners { org, group_id, .. } => {
+                let runners = client
+                    .get_runner_group_runners(org, *group_id, 1, per_page)
+                    .await;
+                let repos = if query.group_repos {
+                    Some(client.get_runner_group_repos(org, *group_id).await)
+                } else {
+                    None
+                };
+                Rows::GroupRunners { runners, repos }
+            }
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => return None,
+        },
+        ListView::Inbox => Rows::Inbox(client.get_pull_request_inbox().await.map(|mut prs| {
+            sort_inbox(&mut prs);
+            let count = prs.len() as u64;
+            (prs, count)
+        })),
+    };
+    Some(rows)
+}
+
+fn remove_cached(path: Option<PathBuf>) {
+    if let Some(path) = path {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Add the key to the set if absent, or remove it if present.
+fn toggle_key(set: &mut HashSet<String>, key: String) {
+    if !set.remove(&key) {
+        set.insert(key);
+    }
+}
+
+/// Cron triggers of a workflow, from the cache unless the workflow changed since.
+async fn workflow_schedule(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    workflow: &Workflow,
+) -> crate::error::Result<WorkflowSchedule> {
+    let path = cache::workflow_schedule_path(owner, repo, workflow.id);
+    let cached = path
+        .as_ref()
+        .and_then(|path| cache::read_cached::<WorkflowSchedule>(path).ok().flatten())
+        .filter(|cached| cached.cached_at >= workflow.updated_at);
+    if let Some(cached) = cached {
+        return Ok(cached.data);
+    }
+    // Dynamic workflows (e.g., Dependabot) have no file to read
+    let schedule = if workflow.path.starts_with(".github/") {
+        let yaml = client
+            .get_workflow_file(owner, repo, &workflow.path)
+            .await?;
+        WorkflowSchedule::from_file(workflow.name.clone(), &yaml)
+    } else {
+        WorkflowSchedule {
+            name: workflow.name.clone(),
+            crons: Vec::new(),
+        }
+    };
+    if let Some(path) = path {
+        let _ = cache::write_cached(&path, &schedule, false);
+    }
+    Ok(schedule)
+}
+
+/// Fetch the Actions policy of a repository ("/repos/{owner}/{repo}") or
+/// organization ("/orgs/{org}"), noting settings that couldn't be read.
+/// Fails with the 404 or 403 of the permissions when the token can't read the
+/// account's policy at all; other settings that don't exist are skipped.
+async fn actions_policy(
+    client: &mut GitHubClient,
+    base: &str,
+    notes: &mut Vec<String>,
+) -> crate::error::Result<PolicyParts> {
+    let mut parts = PolicyParts::default();
+    match client.get_actions_permissions(base).await {
+        Ok(permissions) => parts.permissions = Some(permissions),
+        Err(e @ (JoltError::NotFound(_) | JoltError::Forbidden(_))) => return Err(e),
+        Err(e) => notes.push(format!("Permissions: {}", e)),
+    }
+    let selected = parts
+        .permissions
+        .as_ref()
+        .is_some_and(|p| p.allowed_actions.as_deref() == Some("selected"));
+    if selected {
+        match client.get_selected_actions(base).await {
+            Ok(actions) => parts.selected = Some(actions),
+            Err(JoltError::NotFound(_)) => {}
+            Err(e) => notes.push(format!("Allowed actions: {}", e)),
+        }
+    }
+    match client.get_workflow_permissions(base).await {
+        Ok(permissions) => parts.workflow = Some(permissions),
+        Err(JoltError::NotFound(_)) => {}
+        Err(e) => notes.push(format!("Workflow permissions: {}", e)),
+    }
+    match client.get_fork_pr_approval(base).await {
+        Ok(approval) => parts.fork_approval = Some(approval),
+        Err(JoltError::NotFound(_)) => {}
+        Err(e) => notes.push(format!("Fork PR approval: {}", e)),
+    }
+    Ok(parts)
+}
+
+/// Where a job's log is cached. Only logs of jobs that concluded are cached,
+/// since a running job's log is still growing, and a job's log never changes
+/// once it has concluded (re-runs are new jobs).
+fn log_cache_path(
+    owner: &str,
+    repo: &str,
+    workflow_id: Option<u64>,
+    run_id: u64,
+    job_id: u64,
+    conclusion: Option<RunConclusion>,
+) -> Option<PathBuf> {
+    conclusion?;
+    cache::job_log_path(owner, repo, workflow_id?, run_id, job_id)
+}
+
+/// Download a completed job's log into the cache, unless already cached.
+pub(crate) async fn download_job_log(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    job: &Job,
+) -> crate::error::Result<()> {
+    let Some(path) = cache::job_log_path(owner, repo, workflow_id, job.run_id, job.id) else {
+        return Ok(());
+    };
+    if job.status != RunStatus::Completed || path.exists() {
+        return Ok(());
+    }
+    let logs = client.get_job_logs(owner, repo, job.id).await?;
+    cache::write_text(&path, &logs)
+}
+
+/// Queue the run's failed jobs whose logs aren't in the cache yet for prefetching.
+fn queue_log_prefetch(
+    prefetch: &mut LogPrefetch,
+    jobs: &SelectableList<Job>,
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    run_id: u64,
+) {
+    let jobs = jobs.data.data().map(|jobs| jobs.items.as_slice());
+    prefetch.queue(owner, repo, workflow_id, jobs.unwrap_or_default(), |job| {
+        cache::job_log_path(owner, repo, workflow_id, run_id, job.id)
+            .is_none_or(|path| path.exists())
+    });
+}
+
+/// Download the logs of every completed job in a run into the cache.
+async fn download_run_logs(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    run: &WorkflowRun,
+    per_page: u32,
+) -> crate::error::Result<()> {
+    let (jobs, _) = client.get_jobs(owner, repo, run.id, 1, per_page).await?;
+    for job in &jobs {
+        download_job_log(client, owner, repo, run.workflow_id, job).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    #[test]
+    fn test_state_save_keeps_backup_and_reads_old_versions() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("state.json");
+        let backup = temp.path().join("state.json.bak");
+
+        // State saved before versioning has no version field
+        std::fs::write(&path, r#"{"active_tab": "Inbox"}"#).unwrap();
+        let old = PersistedState::read(&path).unwrap();
+        assert_eq!((old.version, old.active_tab), (0, Tab::Inbox));
+
+        let state = PersistedState {
+            version: STATE_VERSION,
+            active_tab: Tab::Live,
+            ..PersistedState::default()
+        };
+        state.save_to(&path, &backup).unwrap();
+        assert_eq!(
+            PersistedState::read(&backup).unwrap().active_tab,
+            Tab::Inbox
+        );
+
+        // A corrupt state file isn't backed up over the last good save
+        std::fs::write(&path, "{").unwrap();
+        assert!(PersistedState::read(&path).is_none());
+        state.save_to(&path, &backup).unwrap();
+        assert_eq!(
+            PersistedState::read(&backup).unwrap().active_tab,
+            Tab::Inbox
+        );
+        assert_eq!(PersistedState::read(&path).unwrap().version, STATE_VERSION);
+    }
+
+    #[test]
+    fn test_only_concluded_job_logs_are_cached() {
+        let success = Some(RunConclusion::Success);
+        assert!(log_cache_path("octo", "repo", Some(1), 2, 3, None).is_none());
+        assert!(log_cache_path("octo", "repo", None, 2, 3, success).is_none());
+        assert_eq!(
+            log_cache_path("octo", "repo", Some(1), 2, 3, success),
+            cache::job_log_path("octo", "repo", 1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn test_overlays_read_keys_before_actions() {
+        let mut app = App::with_state(
+            PersistedState::default(),
+            Config::default(),
+            None,
+            None,
+            TermCaps::from_env(|_| None),
+        );
+        let key = |c| Message::Key(KeyEvent::from(KeyCode::Char(c)));
+
+        // `q` closes help rather than quitting
+        assert!(app.update(key('?')).is_none());
+        assert!(app.show_help);
+        app.update(key('q'));
+        assert!(!app.show_help && !app.should_quit);
+
+        // Text inputs take bound keys as text, and pastes
+        app.update(key('p'));
+        app.update(key('2'));
+        app.update(Message::Paste(" /runs/1 ".to_string()));
+        assert_eq!(app.permalink_input.as_deref(), Some("2/runs/1"));
+        // Chords aren't typed, and Ctrl+U clears the input
+        let ctrl = |c| Message::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+        app.update(ctrl('r'));
+        assert_eq!(app.permalink_input.as_deref(), Some("2/runs/1"));
+        app.update(ctrl('u'));
+        assert_eq!(app.permalink_input.as_deref(), Some(""));
+        app.update(Message::Key(KeyEvent::from(KeyCode::Esc)));
+        assert!(app.permalink_input.is_none());
+
+        let command = app.update(key('2'));
+        assert!(matches!(command, Some(Command::LoadTab)));
+        assert_eq!(app.active_tab, Tab::Workflows);
+        app.update(key('q'));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_failed_run_is_reported_once_with_its_target() {
+        let mut app = App::with_state(
+            PersistedState::default(),
+            Config::default(),
+            None,
+            None,
+            TermCaps::from_env(|_| None),
+        );
+        let at = chrono::DateTime::from_timestamp(0, 0).unwrap();
+        let run = WorkflowRun {
+            id: 42,
+            name: Some("CI".to_string()),
+            run_number: 123,
+            run_attempt: None,
+            status: RunStatus::Completed,
+            conclusion: Some(RunConclusion::Failure),
+            workflow_id: 7,
+            head_branch: None,
+            head_sha: String::new(),
+            event: String::new(),
+            created_at: at,
+            updated_at: at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        };
+        app.report_failed_run("octo", "repo", &run);
+        app.report_failed_run("octo", "repo", &run);
+
+        assert_eq!(app.console_messages.len(), 1);
+        let message = &app.console_messages[0];
+        assert_eq!(message.message, "Run #123 of CI failed in octo/repo");
+        let target = message.target.as_ref().unwrap();
+        assert_eq!((target.run_id, target.workflow_id), (42, 7));
+        assert_eq!(target.levels().len(), 5);
+    }
+}
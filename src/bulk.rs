@@ -0,0 +1,194 @@
+// Bulk actions on marked runs and jobs.
+// Lists the actions that apply to each kind of marked item.
+
+use crate::github::{Job, RunConclusion, WorkflowRun};
+
+/// Action applied to every marked item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    Cancel,
+    RerunFailed,
+    Delete,
+    DownloadLogs,
+}
+
+impl BulkAction {
+    /// All actions, in the order shown in the picker.
+    pub const ALL: [BulkAction; 4] = [
+        BulkAction::Cancel,
+        BulkAction::RerunFailed,
+        BulkAction::Delete,
+        BulkAction::DownloadLogs,
+    ];
+
+    /// Key that selects this action in the picker.
+    pub fn key(&self) -> char {
+        match self {
+            BulkAction::Cancel => 'c',
+            BulkAction::RerunFailed => 'r',
+            BulkAction::Delete => 'x',
+            BulkAction::DownloadLogs => 'l',
+        }
+    }
+
+    /// Display label for the picker.
+    pub fn label(&self) -> &'static str {
+        match self {
+            BulkAction::Cancel => "Cancel",
+            BulkAction::RerunFailed => "Re-run failed jobs",
+            BulkAction::Delete => "Delete",
+            BulkAction::DownloadLogs => "Download logs to cache",
+        }
+    }
+
+    /// Whether the action changes runs on GitHub, so it waits for `y`.
+    pub fn needs_confirmation(&self) -> bool {
+        !matches!(self, BulkAction::DownloadLogs)
+    }
+
+    /// Question asked before applying the action (e.g., "Delete 3 runs?").
+    pub fn prompt(&self, items: &BulkItems) -> String {
+        let described = items.describe();
+        match (self, items) {
+            (BulkAction::Cancel, _) => format!("Cancel {}?", described),
+            (BulkAction::RerunFailed, BulkItems::Runs(_)) => {
+                format!("Re-run failed jobs of {}?", described)
+            }
+            (BulkAction::RerunFailed, BulkItems::Jobs { .. }) => {
+                format!("Re-run {}?", described)
+            }
+            (BulkAction::Delete, _) => format!("Delete {}?", described),
+            (BulkAction::DownloadLogs, _) => format!("Download logs of {}?", described),
+        }
+    }
+
+    /// Whether the action applies to the marked items.
+    /// Jobs can only be re-run individually or have their logs downloaded.
+    pub fn applies_to(&self, items: &BulkItems) -> bool {
+        match items {
+            BulkItems::Runs(_) => true,
+            BulkItems::Jobs { .. } => {
+                matches!(self, BulkAction::RerunFailed | BulkAction::DownloadLogs)
+            }
+        }
+    }
+
+    /// Actions that apply to the marked items.
+    pub fn available(items: &BulkItems) -> Vec<BulkAction> {
+        Self::ALL
+            .into_iter()
+            .filter(|action| action.applies_to(items))
+            .collect()
+    }
+}
+
+/// Marked items of the current list.
+#[derive(Debug, Clone)]
+pub enum BulkItems {
+    Runs(Vec<WorkflowRun>),
+    Jobs { workflow_id: u64, jobs: Vec<Job> },
+}
+
+impl BulkItems {
+    /// Number of marked items.
+    pub fn len(&self) -> usize {
+        match self {
+            BulkItems::Runs(runs) => runs.len(),
+            BulkItems::Jobs { jobs, .. } => jobs.len(),
+        }
+    }
+
+    /// The items the action is applied to: re-running failed jobs skips
+    /// marked jobs that didn't fail.
+    pub fn for_action(self, action: BulkAction) -> BulkItems {
+        match self {
+            BulkItems::Jobs { workflow_id, jobs } if action == BulkAction::RerunFailed => {
+                BulkItems::Jobs {
+                    workflow_id,
+                    jobs: jobs
+                        .into_iter()
+                        .filter(|job| job.conclusion == Some(RunConclusion::Failure))
+                        .collect(),
+                }
+            }
+            items => items,
+        }
+    }
+
+    /// Description of the marked items (e.g., "3 runs").
+    pub fn describe(&self) -> String {
+        let noun = match self {
+            BulkItems::Runs(_) => "run",
+            BulkItems::Jobs { .. } => "job",
+        };
+        let count = self.len();
+        format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    #[test]
+    fn test_available_actions() {
+        let runs = BulkItems::Runs(Vec::new());
+        assert_eq!(BulkAction::available(&runs), BulkAction::ALL);
+
+        let jobs = BulkItems::Jobs {
+            workflow_id: 1,
+            jobs: Vec::new(),
+        };
+        assert_eq!(
+            BulkAction::available(&jobs),
+            [BulkAction::RerunFailed, BulkAction::DownloadLogs]
+        );
+        assert_eq!(jobs.describe(), "0 jobs");
+    }
+
+    fn job(id: u64, conclusion: Option<RunConclusion>) -> Job {
+        Job {
+            id,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: format!("job {}", id),
+            status: RunStatus::Completed,
+            conclusion,
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_rerun_failed_skips_jobs_that_did_not_fail() {
+        let jobs = BulkItems::Jobs {
+            workflow_id: 1,
+            jobs: vec![
+                job(1, Some(RunConclusion::Failure)),
+                job(2, Some(RunConclusion::Success)),
+                job(3, None),
+                job(4, Some(RunConclusion::Failure)),
+            ],
+        };
+        let items = jobs.clone().for_action(BulkAction::RerunFailed);
+        let BulkItems::Jobs { jobs: failed, .. } = &items else {
+            panic!("expected jobs");
+        };
+        let ids: Vec<u64> = failed.iter().map(|job| job.id).collect();
+        assert_eq!(ids, [1, 4]);
+        assert_eq!(BulkAction::RerunFailed.prompt(&items), "Re-run 2 jobs?");
+        assert_eq!(jobs.for_action(BulkAction::DownloadLogs).len(), 4);
+
+        let runs = BulkItems::Runs(Vec::new());
+        assert_eq!(BulkAction::Delete.prompt(&runs), "Delete 0 runs?");
+        assert!(!BulkAction::DownloadLogs.needs_confirmation());
+    }
+}
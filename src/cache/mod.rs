@@ -4,9 +4,11 @@
 #![allow(dead_code, unused_imports)]
 
 pub mod paths;
+pub mod stats;
 pub mod store;
 
 pub use paths::*;
 pub use store::{
-    CachedData, DEFAULT_TTL, read_cached, read_if_valid, read_text, write_cached, write_text,
+    CachedData, DEFAULT_TTL, modified_at, read_cached, read_if_valid, read_text, write_cached,
+    write_text,
 };
@@ -15,11 +15,46 @@ pub fn state_path() -> Option<PathBuf> {
     cache_dir().map(|dir| dir.join("state.json"))
 }
 
+/// Path to the backup of the previously saved application state.
+pub fn state_backup_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("state.json.bak"))
+}
+
+/// Path to the session saved when jolt crashed, offered on the next launch.
+pub fn crash_state_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("crash_state.json"))
+}
+
+/// Directory of the application's daily log files.
+pub fn logs_dir() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("logs"))
+}
+
+/// Directory of crash reports.
+pub fn crash_reports_dir() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("crashes"))
+}
+
+/// Path to the requests and log downloads counted per day.
+pub fn usage_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("usage.json"))
+}
+
+/// Path to the saved workspaces file.
+pub fn workspaces_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("workspaces.json"))
+}
+
 /// Path to the cached runners repositories list.
 pub fn runners_repos_path() -> Option<PathBuf> {
     cache_dir().map(|dir| dir.join("runners_repos.json"))
 }
 
+/// Path to the cached pull request inbox.
+pub fn inbox_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("inbox.json"))
+}
+
 /// Path to the cached owners list.
 pub fn owners_list_path() -> Option<PathBuf> {
     cache_dir().map(|dir| dir.join("owners.json"))
@@ -35,9 +70,54 @@ pub fn workflows_list_path(owner: &str, repo: &str) -> Option<PathBuf> {
     repo_dir(owner, repo).map(|dir| dir.join("workflows.json"))
 }
 
-/// Path to the cached runs list for a workflow.
-pub fn runs_list_path(owner: &str, repo: &str, workflow_id: u64) -> Option<PathBuf> {
-    workflow_dir(owner, repo, workflow_id).map(|dir| dir.join("runs.json"))
+/// Path to the cached latest run of each workflow in a repository.
+pub fn latest_runs_path(owner: &str, repo: &str) -> Option<PathBuf> {
+    repo_dir(owner, repo).map(|dir| dir.join("latest_runs.json"))
+}
+
+/// Path to the cached runs list of every workflow in a repository, or of
+/// its runs on `branch` and by `actor` when given.
+pub fn repo_runs_list_path(
+    owner: &str,
+    repo: &str,
+    branch: Option<&str>,
+    actor: Option<&str>,
+) -> Option<PathBuf> {
+    repo_dir(owner, repo).map(|dir| dir.join(runs_file_name(branch, actor)))
+}
+
+/// Path to the cached runs list for a workflow, or of its runs on `branch`
+/// and by `actor` when given.
+pub fn runs_list_path(
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    branch: Option<&str>,
+    actor: Option<&str>,
+) -> Option<PathBuf> {
+    workflow_dir(owner, repo, workflow_id).map(|dir| dir.join(runs_file_name(branch, actor)))
+}
+
+/// Name of a runs list file. Branches and actors are percent-encoded, down
+/// to their dashes, so names can't collide.
+fn runs_file_name(branch: Option<&str>, actor: Option<&str>) -> String {
+    let mut name = "runs".to_string();
+    for (key, value) in [("branch", branch), ("actor", actor)] {
+        if let Some(value) = value {
+            name.push_str(&format!("-{}-{}", key, encode_name(value)));
+        }
+    }
+    name + ".json"
+}
+
+/// Percent-encode every byte of `name` but letters, digits, `.` and `_`.
+fn encode_name(name: &str) -> String {
+    name.bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'_' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
 }
 
 /// Path to the cached jobs list for a run.
@@ -45,6 +125,16 @@ pub fn jobs_list_path(owner: &str, repo: &str, workflow_id: u64, run_id: u64) ->
     run_dir(owner, repo, workflow_id, run_id).map(|dir| dir.join("jobs.json"))
 }
 
+/// Path to the cached jobs of every attempt of a run.
+pub fn job_attempts_path(
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    run_id: u64,
+) -> Option<PathBuf> {
+    run_dir(owner, repo, workflow_id, run_id).map(|dir| dir.join("attempts.json"))
+}
+
 /// Path to an owner's directory.
 pub fn owner_dir(owner: &str) -> Option<PathBuf> {
     cache_dir().map(|dir| dir.join("owners").join(sanitize_name(owner)))
@@ -70,6 +160,16 @@ pub fn runners_dir(owner: &str, repo: &str) -> Option<PathBuf> {
     repo_dir(owner, repo).map(|dir| dir.join("runners"))
 }
 
+/// Path to the cached runners list for a repository.
+pub fn runners_list_path(owner: &str, repo: &str) -> Option<PathBuf> {
+    runners_dir(owner, repo).map(|dir| dir.join("runners.json"))
+}
+
+/// Path to a repository's recorded runner snapshots.
+pub fn runner_snapshots_path(owner: &str, repo: &str) -> Option<PathBuf> {
+    runners_dir(owner, repo).map(|dir| dir.join("snapshots.json"))
+}
+
 /// Path to a runner's metadata file.
 pub fn runner_path(owner: &str, repo: &str, runner_id: u64) -> Option<PathBuf> {
     runners_dir(owner, repo).map(|dir| dir.join(format!("{}.json", runner_id)))
@@ -90,6 +190,11 @@ pub fn workflow_path(owner: &str, repo: &str, workflow_id: u64) -> Option<PathBu
     workflow_dir(owner, repo, workflow_id).map(|dir| dir.join("workflow.json"))
 }
 
+/// Path to a workflow's cron triggers.
+pub fn workflow_schedule_path(owner: &str, repo: &str, workflow_id: u64) -> Option<PathBuf> {
+    workflow_dir(owner, repo, workflow_id).map(|dir| dir.join("schedule.json"))
+}
+
 /// Path to a workflow's runs directory.
 pub fn runs_dir(owner: &str, repo: &str, workflow_id: u64) -> Option<PathBuf> {
     workflow_dir(owner, repo, workflow_id).map(|dir| dir.join("runs"))
@@ -105,6 +210,17 @@ pub fn run_path(owner: &str, repo: &str, workflow_id: u64, run_id: u64) -> Optio
     run_dir(owner, repo, workflow_id, run_id).map(|dir| dir.join("run.json"))
 }
 
+/// Path to the extracted log archive of an attempt of a workflow run.
+pub fn run_logs_dir(
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    run_id: u64,
+    attempt: u64,
+) -> Option<PathBuf> {
+    run_dir(owner, repo, workflow_id, run_id).map(|dir| dir.join("logs").join(attempt.to_string()))
+}
+
 /// Path to a workflow run's jobs directory.
 pub fn jobs_dir(owner: &str, repo: &str, workflow_id: u64, run_id: u64) -> Option<PathBuf> {
     run_dir(owner, repo, workflow_id, run_id).map(|dir| dir.join("jobs"))
@@ -143,6 +259,17 @@ pub fn job_log_path(
     job_dir(owner, repo, workflow_id, run_id, job_id).map(|dir| dir.join("log.txt"))
 }
 
+/// Path to a job's annotations file.
+pub fn job_annotations_path(
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+    run_id: u64,
+    job_id: u64,
+) -> Option<PathBuf> {
+    job_dir(owner, repo, workflow_id, run_id, job_id).map(|dir| dir.join("annotations.json"))
+}
+
 /// Sanitize a name for use in filesystem paths.
 /// Replaces problematic characters with underscores.
 fn sanitize_name(name: &str) -> String {
@@ -191,5 +318,15 @@ mod tests {
 
         let log_p = job_log_path(owner, repo, workflow_id, run_id, job_id).unwrap();
         assert!(log_p.ends_with("jobs/11111/log.txt"));
+
+        let runs_p = runs_list_path(owner, repo, workflow_id, None, None).unwrap();
+        assert!(runs_p.ends_with("workflows/12345/runs.json"));
+        let branch_p = runs_list_path(owner, repo, workflow_id, Some("fix/a-b"), None).unwrap();
+        assert!(branch_p.ends_with("runs-branch-fix%2Fa%2Db.json"));
+        let both_p = repo_runs_list_path(owner, repo, Some("main"), Some("octocat")).unwrap();
+        assert!(both_p.ends_with("repos/jolt/runs-branch-main-actor-octocat.json"));
+
+        let archive_p = run_logs_dir(owner, repo, workflow_id, run_id, 2).unwrap();
+        assert!(archive_p.ends_with("runs/67890/logs/2"));
     }
 }
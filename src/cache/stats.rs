@@ -0,0 +1,92 @@
+// Cache hit and miss counters.
+// Counts reads per kind of data to show whether TTLs are saving API requests.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Reads of one kind of cached data since startup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheCounts {
+    /// Reads served from the cache, including stale ones.
+    pub hits: u64,
+    /// Reads that found nothing cached.
+    pub misses: u64,
+    /// Hits served past their TTL while a fresh copy was fetched.
+    pub stale: u64,
+}
+
+impl CacheCounts {
+    /// Share of reads served from the cache, as a percentage.
+    pub fn hit_rate(&self) -> Option<u64> {
+        let total = self.hits + self.misses;
+        (total > 0).then(|| self.hits * 100 / total)
+    }
+}
+
+static COUNTS: Mutex<BTreeMap<String, CacheCounts>> = Mutex::new(BTreeMap::new());
+
+/// Kind of data in a cache file, named after the file (e.g., "runs", "log").
+fn kind(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Update the counts for the kind of data at `path`.
+fn update(path: &Path, f: impl FnOnce(&mut CacheCounts)) {
+    if let Ok(mut counts) = COUNTS.lock() {
+        f(counts.entry(kind(path)).or_default());
+    }
+}
+
+/// Record a cache read that found data.
+pub fn record_hit(path: &Path) {
+    update(path, |c| c.hits += 1);
+}
+
+/// Record a cache read that found nothing.
+pub fn record_miss(path: &Path) {
+    update(path, |c| c.misses += 1);
+}
+
+/// Record that a hit was served past its TTL.
+pub fn record_stale(path: &Path) {
+    update(path, |c| c.stale += 1);
+}
+
+/// Counts per kind of data, by kind name.
+pub fn snapshot() -> Vec<(String, CacheCounts)> {
+    COUNTS
+        .lock()
+        .map(|counts| counts.iter().map(|(k, c)| (k.clone(), *c)).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_by_file_kind() {
+        let dir = Path::new("/tmp/jolt-stats-test/owners/o/repos/r");
+        record_miss(&dir.join("stats_runs.json"));
+        record_hit(&dir.join("stats_runs.json"));
+        record_hit(&dir.join("stats_runs.json"));
+        record_stale(&dir.join("stats_runs.json"));
+
+        let counts = snapshot()
+            .into_iter()
+            .find(|(kind, _)| kind == "stats_runs")
+            .map(|(_, counts)| counts);
+        assert_eq!(
+            counts,
+            Some(CacheCounts {
+                hits: 2,
+                misses: 1,
+                stale: 1,
+            })
+        );
+        assert_eq!(counts.and_then(|c| c.hit_rate()), Some(66));
+    }
+}
@@ -11,6 +11,8 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use crate::error::Result;
 
+use super::stats;
+
 /// Default TTL for mutable data (runners, active runs): 5 minutes.
 pub const DEFAULT_TTL: Duration = Duration::from_secs(5 * 60);
 
@@ -58,11 +60,17 @@ impl<T> CachedData<T> {
 /// Read cached JSON data from a file.
 pub fn read_cached<T: DeserializeOwned>(path: &Path) -> Result<Option<CachedData<T>>> {
     if !path.exists() {
+        tracing::trace!(path = %path.display(), "cache miss");
+        stats::record_miss(path);
         return Ok(None);
     }
+    tracing::trace!(path = %path.display(), "cache hit");
+    stats::record_hit(path);
 
     let contents = fs::read_to_string(path)?;
-    let cached: CachedData<T> = serde_json::from_str(&contents)?;
+    let cached: CachedData<T> = serde_json::from_str(&contents).inspect_err(|e| {
+        tracing::warn!(path = %path.display(), error = %e, "unreadable cache entry");
+    })?;
     Ok(Some(cached))
 }
 
@@ -76,6 +84,7 @@ pub fn read_if_valid<T: DeserializeOwned>(path: &Path, ttl: Duration) -> Result<
 
 /// Write data to cache as JSON.
 pub fn write_cached<T: Serialize>(path: &Path, data: &T, immutable: bool) -> Result<()> {
+    tracing::trace!(path = %path.display(), immutable, "cache write");
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
@@ -114,8 +123,12 @@ pub fn write_text(path: &Path, text: &str) -> Result<()> {
 /// Read raw text data from cache (for logs).
 pub fn read_text(path: &Path) -> Result<Option<String>> {
     if !path.exists() {
+        tracing::trace!(path = %path.display(), "cache miss");
+        stats::record_miss(path);
         return Ok(None);
     }
+    tracing::trace!(path = %path.display(), "cache hit");
+    stats::record_hit(path);
 
     let contents = fs::read_to_string(path)?;
     Ok(Some(contents))
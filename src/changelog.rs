@@ -0,0 +1,107 @@
+// Changelog of new capabilities, embedded in the binary.
+// Lists features added since the version last run, for the What's New panel.
+
+use serde::Deserialize;
+
+/// Structured changelog, newest release first.
+const CHANGELOG: &str = include_str!("../CHANGELOG.toml");
+
+/// Version of this build.
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A capability added in a release.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Feature {
+    pub title: String,
+    /// Keys that reach the feature (e.g., "Ctrl+R").
+    pub keys: String,
+    pub description: String,
+}
+
+/// Capabilities added in one version.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Release {
+    pub version: String,
+    pub features: Vec<Feature>,
+}
+
+#[derive(Deserialize)]
+struct Changelog {
+    release: Vec<Release>,
+}
+
+/// All releases in the embedded changelog, newest first.
+pub fn releases() -> Vec<Release> {
+    toml::from_str::<Changelog>(CHANGELOG)
+        .map(|changelog| changelog.release)
+        .unwrap_or_default()
+}
+
+/// Releases after `last_seen` up to and including `current`, newest first.
+/// Installs that predate version tracking pass "0.0.0" to see everything.
+pub fn new_since(releases: Vec<Release>, last_seen: &str, current: &str) -> Vec<Release> {
+    let (Some(last_seen), Some(current)) = (parse_version(last_seen), parse_version(current))
+    else {
+        return Vec::new();
+    };
+    releases
+        .into_iter()
+        .filter(|release| {
+            parse_version(&release.version)
+                .is_some_and(|version| version > last_seen && version <= current)
+        })
+        .collect()
+}
+
+/// Major, minor, and patch numbers of a version like "1.2.3".
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.trim_start_matches('v').splitn(3, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    // Ignore pre-release and build suffixes (e.g., "3-beta.1")
+    let patch = parts
+        .next()?
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(version: &str) -> Release {
+        Release {
+            version: version.to_string(),
+            features: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_embedded_changelog_parses() {
+        let releases = releases();
+        assert!(!releases.is_empty());
+        assert!(releases.iter().all(|r| parse_version(&r.version).is_some()));
+        assert!(releases.iter().all(|r| !r.features.is_empty()));
+    }
+
+    #[test]
+    fn test_new_since() {
+        let releases = vec![release("0.3.0"), release("0.2.1"), release("0.2.0")];
+        let versions = |releases: Vec<Release>| -> Vec<String> {
+            releases.into_iter().map(|r| r.version).collect()
+        };
+        assert_eq!(
+            versions(new_since(releases.clone(), "0.2.0", "0.3.0")),
+            ["0.3.0", "0.2.1"]
+        );
+        // A newer changelog entry than the running build isn't shown yet
+        assert_eq!(
+            versions(new_since(releases.clone(), "0.2.0", "0.2.1-beta.1")),
+            ["0.2.1"]
+        );
+        assert!(new_since(releases, "0.3.0", "0.3.0").is_empty());
+    }
+}
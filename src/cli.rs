@@ -0,0 +1,705 @@
+// Command-line subcommands run without the TUI.
+// `jolt logs download` primes the log cache; `jolt export` dumps runs and jobs;
+// `jolt metrics serve` runs the Prometheus exporter headless; `jolt watch`
+// follows a run for scripts and git hooks.
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::Duration as StdDuration;
+
+use chrono::{Duration, Utc};
+
+use crate::app::{PersistedState, download_job_log};
+use crate::cache;
+use crate::config::Config;
+use crate::error::{JoltError, Result};
+use crate::export::{self, ExportFormat, ExportRow};
+use crate::github::{GitHubClient, Job, RunConclusion, RunStatus, WorkflowRun};
+use crate::metrics::{self, DEFAULT_METRICS_ADDR};
+use crate::state::git_remote;
+use crate::state::permalink::Permalink;
+use crate::watch::{self, Progress};
+
+/// Requests kept in reserve; downloads pause for the rate limit reset below this.
+const RATE_LIMIT_RESERVE: u64 = 50;
+
+/// How often `jolt watch` polls the run.
+const WATCH_INTERVAL: StdDuration = StdDuration::from_secs(10);
+
+/// Polls in a row `jolt watch` retries after network or server errors.
+const WATCH_RETRIES: u32 = 6;
+
+/// Exit status of `jolt watch` when the run didn't pass.
+const EXIT_RUN_FAILED: u8 = 1;
+
+/// Exit status of `jolt watch` when GitHub stayed unreachable, so scripts can
+/// tell a failed run from a failed watch.
+const EXIT_UNREACHABLE: u8 = 3;
+
+/// Usage shown for malformed commands.
+pub const USAGE: &str = "usage: jolt [--here] [--ascii]
+       jolt logs download <owner/repo> [--since <N>(m|h|d|w)] [--failed-only]
+       jolt export runs <owner/repo> [--workflow <id>] [--limit <N>] [--format csv|json]
+       jolt export jobs <owner/repo> <run_id> [--format csv|json]
+       jolt metrics serve [--listen <addr>] [<owner/repo>...]
+       jolt watch <run-url> | --latest [<owner/repo>]";
+
+/// Flags of the TUI itself, given without a subcommand.
+const TUI_FLAGS: [&str; 2] = ["--here", "--ascii"];
+
+/// Runs exported when no --limit is given.
+const DEFAULT_EXPORT_LIMIT: usize = 100;
+
+/// Subcommand given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Download job logs of recent runs into the cache.
+    DownloadLogs {
+        owner: String,
+        repo: String,
+        since: Duration,
+        failed_only: bool,
+    },
+    /// Print recent runs of a repository or workflow.
+    ExportRuns {
+        owner: String,
+        repo: String,
+        workflow_id: Option<u64>,
+        limit: usize,
+        format: ExportFormat,
+    },
+    /// Print the jobs of a run.
+    ExportJobs {
+        owner: String,
+        repo: String,
+        run_id: u64,
+        format: ExportFormat,
+    },
+    /// Serve Prometheus gauges of repositories until interrupted.
+    ServeMetrics {
+        addr: String,
+        /// "owner/repo" keys; empty means the favorite repositories.
+        repos: Vec<String>,
+    },
+    /// Follow a run until it completes, exiting with its conclusion.
+    Watch {
+        /// Owner and name; None means the current directory's repository.
+        repo: Option<(String, String)>,
+        /// None means the repository's latest run.
+        run_id: Option<u64>,
+    },
+}
+
+/// Parse command-line arguments (without the program name).
+/// Returns None when no subcommand was given, to start the TUI.
+pub fn parse(args: &[String]) -> std::result::Result<Option<Command>, String> {
+    if args.iter().all(|arg| TUI_FLAGS.contains(&arg.as_str())) {
+        return Ok(None);
+    }
+    let command: Vec<&str> = args.iter().take(2).map(String::as_str).collect();
+    match command[..] {
+        ["logs", "download"] => parse_download(&args[2..]).map(Some),
+        ["export", "runs"] => parse_export_runs(&args[2..]).map(Some),
+        ["export", "jobs"] => parse_export_jobs(&args[2..]).map(Some),
+        ["metrics", "serve"] => parse_serve_metrics(&args[2..]).map(Some),
+        ["watch", ..] => parse_watch(&args[1..]).map(Some),
+        _ => Err(format!("unknown command: {}\n{}", args.join(" "), USAGE)),
+    }
+}
+
+/// Parse the arguments of `jolt logs download`.
+fn parse_download(args: &[String]) -> std::result::Result<Command, String> {
+    let mut repo = None;
+    let mut since = Duration::days(7);
+    let mut failed_only = false;
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--failed-only" => failed_only = true,
+            "--since" => {
+                let value = rest
+                    .next()
+                    .ok_or(format!("--since needs a value\n{}", USAGE))?;
+                since = parse_since(value).ok_or(format!("invalid --since: {}", value))?;
+            }
+            _ if repo.is_none() && !arg.starts_with('-') => repo = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument: {}\n{}", arg, USAGE)),
+        }
+    }
+
+    let (owner, repo) = split_repo(repo)?;
+    Ok(Command::DownloadLogs {
+        owner,
+        repo,
+        since,
+        failed_only,
+    })
+}
+
+/// Parse the arguments of `jolt export runs`.
+fn parse_export_runs(args: &[String]) -> std::result::Result<Command, String> {
+    let mut repo = None;
+    let mut workflow_id = None;
+    let mut limit = DEFAULT_EXPORT_LIMIT;
+    let mut format = ExportFormat::Csv;
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--workflow" => {
+                let value = option_value(&mut rest, "--workflow")?;
+                workflow_id = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid --workflow: {}", value))?,
+                );
+            }
+            "--limit" => {
+                let value = option_value(&mut rest, "--limit")?;
+                limit = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("invalid --limit: {}", value))?;
+            }
+            "--format" => format = parse_format(option_value(&mut rest, "--format")?)?,
+            _ if repo.is_none() && !arg.starts_with('-') => repo = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    let (owner, repo) = split_repo(repo)?;
+    Ok(Command::ExportRuns {
+        owner,
+        repo,
+        workflow_id,
+        limit,
+        format,
+    })
+}
+
+/// Parse the arguments of `jolt export jobs`.
+fn parse_export_jobs(args: &[String]) -> std::result::Result<Command, String> {
+    let mut positional = Vec::new();
+    let mut format = ExportFormat::Csv;
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--format" => format = parse_format(option_value(&mut rest, "--format")?)?,
+            _ if positional.len() < 2 && !arg.starts_with('-') => positional.push(arg.clone()),
+            _ => return Err(format!("unexpected argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    let mut positional = positional.into_iter();
+    let (owner, repo) = split_repo(positional.next())?;
+    let run_id = positional.next().ok_or(USAGE.to_string())?;
+    let run_id = run_id
+        .parse()
+        .map_err(|_| format!("invalid run id: {}", run_id))?;
+    Ok(Command::ExportJobs {
+        owner,
+        repo,
+        run_id,
+        format,
+    })
+}
+
+/// Parse the arguments of `jolt metrics serve`.
+fn parse_serve_metrics(args: &[String]) -> std::result::Result<Command, String> {
+    let mut addr = DEFAULT_METRICS_ADDR.to_string();
+    let mut repos = Vec::new();
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--listen" => addr = option_value(&mut rest, "--listen")?.to_string(),
+            _ if !arg.starts_with('-') => {
+                let (owner, repo) = split_repo(Some(arg.clone()))?;
+                repos.push(format!("{}/{}", owner, repo));
+            }
+            _ => return Err(format!("unexpected argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    Ok(Command::ServeMetrics { addr, repos })
+}
+
+/// Parse the arguments of `jolt watch`.
+fn parse_watch(args: &[String]) -> std::result::Result<Command, String> {
+    let mut latest = false;
+    let mut target = None;
+    for arg in args {
+        match arg.as_str() {
+            "--latest" => latest = true,
+            _ if target.is_none() && !arg.starts_with('-') => target = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    match (latest, target) {
+        (true, None) => Ok(Command::Watch {
+            repo: None,
+            run_id: None,
+        }),
+        (true, Some(repo)) => Ok(Command::Watch {
+            repo: Some(split_repo(Some(repo))?),
+            run_id: None,
+        }),
+        (false, Some(url)) => {
+            let link = Permalink::parse(&url).ok_or(format!("not a run URL: {}", url))?;
+            Ok(Command::Watch {
+                repo: Some((link.owner, link.repo)),
+                run_id: Some(link.run_id),
+            })
+        }
+        (false, None) => Err(USAGE.to_string()),
+    }
+}
+
+/// Value following an option, or an error naming the option.
+fn option_value<'a>(
+    rest: &mut impl Iterator<Item = &'a String>,
+    option: &str,
+) -> std::result::Result<&'a str, String> {
+    rest.next()
+        .map(String::as_str)
+        .ok_or(format!("{} needs a value\n{}", option, USAGE))
+}
+
+fn parse_format(value: &str) -> std::result::Result<ExportFormat, String> {
+    ExportFormat::parse(value).ok_or(format!("invalid --format: {} (csv or json)", value))
+}
+
+/// Split an "owner/repo" argument.
+fn split_repo(repo: Option<String>) -> std::result::Result<(String, String), String> {
+    let repo = repo.ok_or(USAGE.to_string())?;
+    match repo.split_once('/') {
+        Some((owner, name)) if !owner.is_empty() && !name.is_empty() => {
+            Ok((owner.to_string(), name.to_string()))
+        }
+        _ => Err(format!("expected owner/repo, got {}", repo)),
+    }
+}
+
+/// Parse a lookback like "30m", "12h", "7d", or "2w".
+fn parse_since(value: &str) -> Option<Duration> {
+    let split = value.len().checked_sub(1)?;
+    let (count, unit) = value.split_at(split);
+    let count: i64 = count.parse().ok().filter(|&n| n > 0)?;
+    match unit {
+        "m" => Some(Duration::minutes(count)),
+        "h" => Some(Duration::hours(count)),
+        "d" => Some(Duration::days(count)),
+        "w" => Some(Duration::weeks(count)),
+        _ => None,
+    }
+}
+
+/// Run a subcommand.
+/// Run a subcommand, returning the status jolt exits with.
+pub async fn run(command: Command) -> Result<ExitCode> {
+    let result = match command {
+        Command::DownloadLogs {
+            owner,
+            repo,
+            since,
+            failed_only,
+        } => download_logs(&owner, &repo, since, failed_only).await,
+        Command::ExportRuns {
+            owner,
+            repo,
+            workflow_id,
+            limit,
+            format,
+        } => export_runs(&owner, &repo, workflow_id, limit, format).await,
+        Command::ExportJobs {
+            owner,
+            repo,
+            run_id,
+            format,
+        } => export_jobs(&owner, &repo, run_id, format).await,
+        Command::ServeMetrics { addr, repos } => serve_metrics(&addr, repos).await,
+        Command::Watch { repo, run_id } => return watch_run(repo, run_id).await,
+    };
+    result.map(|()| ExitCode::SUCCESS)
+}
+
+/// Print a run's progress until it completes, with the errors of failed jobs.
+/// Exits with status 1 unless the run passed, or 3 if GitHub stayed
+/// unreachable through `WATCH_RETRIES` polls in a row.
+async fn watch_run(repo: Option<(String, String)>, run_id: Option<u64>) -> Result<ExitCode> {
+    let (owner, repo) = repo.or_else(git_remote::current_repo).ok_or_else(|| {
+        JoltError::Other("not in a clone of a GitHub repository; pass owner/repo".to_string())
+    })?;
+    let mut client = github_client()?;
+    let run_id = match run_id {
+        Some(run_id) => run_id,
+        None => {
+            let (runs, _) = client
+                .get_workflow_runs(&owner, &repo, None, None, 1, 1)
+                .await?;
+            let run = runs.first().ok_or_else(|| {
+                JoltError::Other(format!("{}/{} has no workflow runs", owner, repo))
+            })?;
+            run.id
+        }
+    };
+
+    let mut progress = Progress::default();
+    let mut printed_failures = HashSet::new();
+    let mut announced = false;
+    let mut failed_polls = 0;
+    loop {
+        pace(&client).await;
+        let (run, jobs) = match poll_run(&mut client, &owner, &repo, run_id).await {
+            Ok(polled) => {
+                failed_polls = 0;
+                polled
+            }
+            Err(e) if e.is_transient() && failed_polls < WATCH_RETRIES => {
+                failed_polls += 1;
+                eprintln!("Failed to poll the run, retrying: {}", e);
+                tokio::time::sleep(retry_delay(&e)).await;
+                continue;
+            }
+            Err(e) if e.is_transient() => {
+                eprintln!("Gave up watching the run: {}", e);
+                return Ok(ExitCode::from(EXIT_UNREACHABLE));
+            }
+            Err(e) => return Err(e),
+        };
+        if !announced {
+            announced = true;
+            println!(
+                "Watching {} #{} on {} ({})",
+                run.name.as_deref().unwrap_or("Run"),
+                run.run_number,
+                run.head_branch.as_deref().unwrap_or("?"),
+                run.html_url
+            );
+        }
+        for line in progress.update(&jobs) {
+            println!("{}", line);
+        }
+        for job in jobs.iter().filter(|job| watch::is_failed(job)) {
+            if !printed_failures.insert(job.id) {
+                continue;
+            }
+            if let Err(e) = download_job_log(&mut client, &owner, &repo, run.workflow_id, job).await
+            {
+                eprintln!("    Failed to download the log of {}: {}", job.name, e);
+                continue;
+            }
+            let log = cache::job_log_path(&owner, &repo, run.workflow_id, run_id, job.id)
+                .and_then(|path| std::fs::read_to_string(path).ok());
+            for line in log
+                .as_deref()
+                .map(watch::failure_excerpt)
+                .unwrap_or_default()
+            {
+                println!("    │ {}", line);
+            }
+        }
+        let _ = io::stdout().flush();
+
+        if run.status == RunStatus::Completed {
+            println!("{}", watch::summary(&run));
+            if !watch::run_passed(&run) {
+                return Ok(ExitCode::from(EXIT_RUN_FAILED));
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+        tokio::time::sleep(WATCH_INTERVAL).await;
+    }
+}
+
+/// Fetch a watched run and all its jobs.
+async fn poll_run(
+    client: &mut GitHubClient,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<(WorkflowRun, Vec<Job>)> {
+    let run = client.get_workflow_run(owner, repo, run_id).await?;
+    let jobs = client.get_all_jobs(owner, repo, run_id).await?;
+    Ok((run, jobs))
+}
+
+/// How long `jolt watch` waits before polling again after `error`: until the
+/// rate limit resets, or the usual interval.
+fn retry_delay(error: &JoltError) -> StdDuration {
+    match error {
+        JoltError::RateLimited { reset_at } => (*reset_at - Utc::now())
+            .to_std()
+            .unwrap_or_default()
+            .max(WATCH_INTERVAL),
+        _ => WATCH_INTERVAL,
+    }
+}
+
+/// Serve Prometheus gauges of `repos`, or of the favorite repositories.
+async fn serve_metrics(addr: &str, mut repos: Vec<String>) -> Result<()> {
+    let client = github_client()?;
+    if repos.is_empty() {
+        repos = PersistedState::load().favorite_repos.into_iter().collect();
+        repos.sort();
+    }
+    if repos.is_empty() {
+        return Err(JoltError::Other(
+            "no repositories given and no favorite repositories to watch".to_string(),
+        ));
+    }
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    eprintln!(
+        "Serving metrics of {} on http://{}/metrics",
+        repos.join(", "),
+        addr
+    );
+    let (_repos, watched) = tokio::sync::watch::channel(repos);
+    metrics::serve(listener, client, watched).await
+}
+
+/// Print up to `limit` recent runs of a repository, or of one of its workflows.
+async fn export_runs(
+    owner: &str,
+    repo: &str,
+    workflow_id: Option<u64>,
+    limit: usize,
+    format: ExportFormat,
+) -> Result<()> {
+    let mut client = github_client()?;
+    let mut runs = Vec::new();
+    let mut page = 1;
+    while runs.len() < limit {
+        pace(&client).await;
+        let (mut items, total_count) = match workflow_id {
+            Some(id) => {
+                client
+                    .get_workflow_runs_for_workflow(owner, repo, id, None, None, page, 100)
+                    .await?
+            }
+            None => {
+                client
+                    .get_workflow_runs(owner, repo, None, None, page, 100)
+                    .await?
+            }
+        };
+        let done = items.is_empty();
+        runs.append(&mut items);
+        if done || runs.len() as u64 >= total_count {
+            break;
+        }
+        page += 1;
+    }
+    runs.truncate(limit);
+    let rows: Vec<ExportRow> = runs.iter().map(ExportRow::from_run).collect();
+    print!("{}", export::render(&rows, format));
+    Ok(())
+}
+
+/// Print the jobs of a run.
+async fn export_jobs(owner: &str, repo: &str, run_id: u64, format: ExportFormat) -> Result<()> {
+    let mut client = github_client()?;
+    let run = client.get_workflow_run(owner, repo, run_id).await?;
+    let jobs = client.get_all_jobs(owner, repo, run_id).await?;
+    let rows: Vec<ExportRow> = jobs
+        .iter()
+        .map(|job| ExportRow::from_job(job, Some(&run)))
+        .collect();
+    print!("{}", export::render(&rows, format));
+    Ok(())
+}
+
+/// Download the logs of runs created within `since` into the cache.
+async fn download_logs(owner: &str, repo: &str, since: Duration, failed_only: bool) -> Result<()> {
+    let mut client = github_client()?;
+    let cutoff = Utc::now() - since;
+
+    let mut runs = Vec::new();
+    let mut page = 1;
+    loop {
+        pace(&client).await;
+        let (mut items, total_count) = client
+            .get_workflow_runs_since(owner, repo, cutoff, failed_only, page, 100)
+            .await?;
+        let done = items.is_empty();
+        runs.append(&mut items);
+        if done || runs.len() as u64 >= total_count {
+            break;
+        }
+        page += 1;
+    }
+    println!(
+        "{} runs in {}/{} since {}",
+        runs.len(),
+        owner,
+        repo,
+        cutoff.format("%Y-%m-%d %H:%M")
+    );
+
+    let (mut downloaded, mut cached, mut failed) = (0, 0, 0);
+    for (i, run) in runs.iter().enumerate() {
+        pace(&client).await;
+        let jobs = match client.get_jobs(owner, repo, run.id, 1, 100).await {
+            Ok((jobs, _)) => jobs,
+            Err(e) => {
+                eprintln!("[{}/{}] #{}: {}", i + 1, runs.len(), run.run_number, e);
+                failed += 1;
+                continue;
+            }
+        };
+        for job in &jobs {
+            if failed_only
+                && !matches!(
+                    job.conclusion,
+                    Some(RunConclusion::Failure | RunConclusion::TimedOut)
+                )
+            {
+                continue;
+            }
+            let path = cache::job_log_path(owner, repo, run.workflow_id, run.id, job.id);
+            if path.is_some_and(|path| path.exists()) {
+                cached += 1;
+                continue;
+            }
+            pace(&client).await;
+            print!(
+                "[{}/{}] #{} {} ... ",
+                i + 1,
+                runs.len(),
+                run.run_number,
+                job.name
+            );
+            let _ = io::stdout().flush();
+            match download_job_log(&mut client, owner, repo, run.workflow_id, job).await {
+                Ok(()) => {
+                    downloaded += 1;
+                    println!("ok");
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("failed: {}", e);
+                }
+            }
+        }
+    }
+    println!(
+        "Downloaded {} logs ({} already cached, {} failed)",
+        downloaded, cached, failed
+    );
+    Ok(())
+}
+
+/// Client from GITHUB_TOKEN with the config file's timeouts and retries.
+fn github_client() -> Result<GitHubClient> {
+    let http = Config::load().map(|config| config.http).unwrap_or_default();
+    GitHubClient::from_env_with_config(&http)
+}
+
+/// Wait for the rate limit to reset when few requests remain.
+async fn pace(client: &GitHubClient) {
+    let limit = client.rate_limit();
+    if limit.limit == 0 || limit.remaining > RATE_LIMIT_RESERVE {
+        return;
+    }
+    let wait = (limit.reset as i64 - Utc::now().timestamp()).max(0) as u64 + 1;
+    println!(
+        "{} requests left, waiting {}s for the rate limit to reset",
+        limit.remaining, wait
+    );
+    tokio::time::sleep(StdDuration::from_secs(wait)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_download() {
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&args("--here")), Ok(None));
+        assert_eq!(parse(&args("--ascii --here")), Ok(None));
+        assert_eq!(
+            parse(&args(
+                "logs download phatblat/jolt --since 12h --failed-only"
+            )),
+            Ok(Some(Command::DownloadLogs {
+                owner: "phatblat".to_string(),
+                repo: "jolt".to_string(),
+                since: Duration::hours(12),
+                failed_only: true,
+            }))
+        );
+        assert!(parse(&args("logs download jolt")).is_err());
+        assert!(parse(&args("logs download phatblat/jolt --since 7x")).is_err());
+    }
+
+    #[test]
+    fn test_parse_export() {
+        assert_eq!(
+            parse(&args(
+                "export runs phatblat/jolt --workflow 9 --format json"
+            )),
+            Ok(Some(Command::ExportRuns {
+                owner: "phatblat".to_string(),
+                repo: "jolt".to_string(),
+                workflow_id: Some(9),
+                limit: DEFAULT_EXPORT_LIMIT,
+                format: ExportFormat::Json,
+            }))
+        );
+        assert_eq!(
+            parse(&args("export jobs phatblat/jolt 123")),
+            Ok(Some(Command::ExportJobs {
+                owner: "phatblat".to_string(),
+                repo: "jolt".to_string(),
+                run_id: 123,
+                format: ExportFormat::Csv,
+            }))
+        );
+        assert!(parse(&args("export jobs phatblat/jolt")).is_err());
+        assert!(parse(&args("export runs phatblat/jolt --format xml")).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve_metrics() {
+        assert_eq!(
+            parse(&args("metrics serve --listen 0.0.0.0:9000 phatblat/jolt")),
+            Ok(Some(Command::ServeMetrics {
+                addr: "0.0.0.0:9000".to_string(),
+                repos: vec!["phatblat/jolt".to_string()],
+            }))
+        );
+        assert_eq!(
+            parse(&args("metrics serve")),
+            Ok(Some(Command::ServeMetrics {
+                addr: DEFAULT_METRICS_ADDR.to_string(),
+                repos: Vec::new(),
+            }))
+        );
+        assert!(parse(&args("metrics serve jolt")).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        assert_eq!(
+            parse(&args(
+                "watch https://github.com/phatblat/jolt/actions/runs/123"
+            )),
+            Ok(Some(Command::Watch {
+                repo: Some(("phatblat".to_string(), "jolt".to_string())),
+                run_id: Some(123),
+            }))
+        );
+        assert_eq!(
+            parse(&args("watch --latest")),
+            Ok(Some(Command::Watch {
+                repo: None,
+                run_id: None,
+            }))
+        );
+        assert!(parse(&args("watch --latest phatblat/jolt")).is_ok());
+        assert!(parse(&args("watch")).is_err());
+        assert!(parse(&args("watch phatblat/jolt")).is_err());
+    }
+}
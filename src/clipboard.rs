@@ -0,0 +1,368 @@
+// Copying log excerpts to the clipboard.
+// Formats excerpts as raw text, GitHub permalinks, or Markdown snippets, or copies
+// the job's URLs, commit, branch, runner, or whole context as JSON, and sends them
+// to the system clipboard or, over SSH, through the terminal (OSC 52).
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::ClipboardBackend;
+use crate::github::Step;
+
+/// How a log excerpt is formatted when copied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyMode {
+    /// The log text as shown.
+    Raw,
+    /// Link to the line on the GitHub job page.
+    Permalink,
+    /// Fenced code block headed by a link to the source.
+    Markdown,
+    /// GitHub job page URL.
+    JobUrl,
+    /// GitHub run page URL.
+    RunUrl,
+    /// Commit the run was triggered for.
+    Sha,
+    /// Branch the run was triggered for.
+    Branch,
+    /// Runner that ran the job.
+    Runner,
+    /// Repository, workflow, run, and job identifiers as JSON.
+    Context,
+}
+
+impl CopyMode {
+    /// All modes, in the order shown in the picker.
+    pub const ALL: [CopyMode; 9] = [
+        CopyMode::Raw,
+        CopyMode::Permalink,
+        CopyMode::Markdown,
+        CopyMode::JobUrl,
+        CopyMode::RunUrl,
+        CopyMode::Sha,
+        CopyMode::Branch,
+        CopyMode::Runner,
+        CopyMode::Context,
+    ];
+
+    /// Key that selects this mode in the picker.
+    pub fn key(&self) -> char {
+        match self {
+            CopyMode::Raw => 'r',
+            CopyMode::Permalink => 'p',
+            CopyMode::Markdown => 'm',
+            CopyMode::JobUrl => 'j',
+            CopyMode::RunUrl => 'u',
+            CopyMode::Sha => 's',
+            CopyMode::Branch => 'b',
+            CopyMode::Runner => 'n',
+            CopyMode::Context => 'c',
+        }
+    }
+
+    /// Display label for the picker.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CopyMode::Raw => "Raw text",
+            CopyMode::Permalink => "GitHub permalink",
+            CopyMode::Markdown => "Markdown snippet",
+            CopyMode::JobUrl => "Job URL",
+            CopyMode::RunUrl => "Run URL",
+            CopyMode::Sha => "Commit SHA",
+            CopyMode::Branch => "Branch name",
+            CopyMode::Runner => "Runner name",
+            CopyMode::Context => "Context as JSON",
+        }
+    }
+}
+
+/// Log text to copy and where it came from.
+#[derive(Debug, Clone)]
+pub struct LogExcerpt {
+    pub text: String,
+    /// Line anchor on the job page (e.g., "step:3:12"), if it could be located.
+    pub anchor: Option<String>,
+    pub context: LogContext,
+}
+
+/// Repository, run, and job a log belongs to, as copied in JSON.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LogContext {
+    pub owner: String,
+    pub repo: String,
+    pub workflow: Option<String>,
+    pub run_id: u64,
+    pub run_number: Option<u64>,
+    /// GitHub run page URL.
+    pub run_url: String,
+    pub job_id: u64,
+    pub job_name: String,
+    /// GitHub job page URL.
+    pub job_url: String,
+    pub head_sha: Option<String>,
+    pub head_branch: Option<String>,
+    pub runner_name: Option<String>,
+}
+
+impl LogExcerpt {
+    /// Job page URL, pointing at the excerpt's line when known.
+    pub fn permalink(&self) -> String {
+        match &self.anchor {
+            Some(anchor) => format!("{}#{}", self.context.job_url, anchor),
+            None => self.context.job_url.clone(),
+        }
+    }
+
+    /// Format the excerpt for the clipboard, or None if the value copied is unknown.
+    pub fn format(&self, mode: CopyMode) -> Option<String> {
+        let context = &self.context;
+        let text = match mode {
+            CopyMode::Raw => self.text.clone(),
+            CopyMode::Permalink => self.permalink(),
+            CopyMode::Markdown => {
+                // Use a fence longer than any backtick run in the text
+                let longest = self
+                    .text
+                    .split(|c| c != '`')
+                    .map(str::len)
+                    .max()
+                    .unwrap_or(0);
+                let fence = "`".repeat(longest.max(2) + 1);
+                format!(
+                    "[{}]({})\n{}\n{}\n{}",
+                    context.job_name,
+                    self.permalink(),
+                    fence,
+                    self.text,
+                    fence
+                )
+            }
+            CopyMode::JobUrl => context.job_url.clone(),
+            CopyMode::RunUrl => context.run_url.clone(),
+            CopyMode::Sha => context.head_sha.clone()?,
+            CopyMode::Branch => context.head_branch.clone()?,
+            CopyMode::Runner => context.runner_name.clone()?,
+            CopyMode::Context => serde_json::to_string_pretty(context).ok()?,
+        };
+        Some(text)
+    }
+}
+
+/// Locate a log line on the GitHub job page, which numbers lines per step
+/// ("step:<number>:<line>").
+///
+/// Job logs prefix each line with a timestamp, so the step is the last one
+/// that started at or before the line's timestamp, and the line number counts
+/// from the first line logged after that step started.
+pub fn step_anchor(logs: &str, line_index: usize, steps: &[Step]) -> Option<String> {
+    let lines: Vec<&str> = logs.lines().collect();
+    let time = line_timestamp(lines.get(line_index)?)?;
+    let step = steps
+        .iter()
+        .filter(|step| step.started_at.is_some_and(|start| start <= time))
+        .max_by_key(|step| step.started_at)?;
+    let start = step.started_at?;
+    let first = lines[..=line_index]
+        .iter()
+        .position(|line| line_timestamp(line).is_some_and(|t| t >= start))?;
+    Some(format!("step:{}:{}", step.number, line_index - first + 1))
+}
+
+/// Index of the log line an anchor like "step:3:12" points at; the inverse of
+/// `step_anchor`. Lines past the end of the log land on its last line.
+pub fn anchor_line(logs: &str, step_number: u64, line: usize, steps: &[Step]) -> Option<usize> {
+    let start = steps
+        .iter()
+        .find(|step| step.number == step_number)?
+        .started_at?;
+    let lines: Vec<&str> = logs.lines().collect();
+    let first = lines
+        .iter()
+        .position(|line| line_timestamp(line).is_some_and(|t| t >= start))?;
+    Some((first + line.saturating_sub(1)).min(lines.len() - 1))
+}
+
+/// Timestamp at the start of a job log line, truncated to whole seconds to
+/// match step times.
+fn line_timestamp(line: &str) -> Option<DateTime<Utc>> {
+    let stamp = line.split_whitespace().next()?;
+    let time = stamp.parse::<DateTime<Utc>>().ok()?;
+    DateTime::from_timestamp(time.timestamp(), 0)
+}
+
+/// Copy text to the clipboard with the given backend.
+pub fn copy(text: &str, backend: ClipboardBackend) -> io::Result<()> {
+    let remote =
+        std::env::var_os("SSH_CONNECTION").is_some() || std::env::var_os("SSH_TTY").is_some();
+    match backend {
+        ClipboardBackend::Osc52 => copy_osc52(text),
+        ClipboardBackend::Auto if remote => copy_osc52(text),
+        ClipboardBackend::Auto | ClipboardBackend::System => copy_system(text),
+    }
+}
+
+/// Copy text through the terminal, which works from remote sessions.
+fn copy_osc52(text: &str) -> io::Result<()> {
+    let tmux = std::env::var_os("TMUX").is_some();
+    let mut stdout = io::stdout();
+    stdout.write_all(osc52_sequence(text, tmux).as_bytes())?;
+    stdout.flush()
+}
+
+/// OSC 52 escape sequence setting the clipboard to `text`. Inside tmux the
+/// sequence is wrapped in a passthrough so it reaches the outer terminal.
+fn osc52_sequence(text: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()));
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+/// Standard base64 with padding.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Copy text to the system clipboard.
+fn copy_system(text: &str) -> io::Result<()> {
+    let mut child = Command::new("pbcopy").stdin(Stdio::piped()).spawn()?;
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    fn step(number: u64, started: &str) -> Step {
+        Step {
+            name: format!("step {}", number),
+            status: RunStatus::Completed,
+            conclusion: None,
+            number,
+            started_at: started.parse().ok(),
+            completed_at: None,
+        }
+    }
+
+    const LOGS: &str = "\
+2024-05-01T10:00:00.1234567Z Current runner version: '2.316.0'
+2024-05-01T10:00:01.0000000Z Syncing repository
+2024-05-01T10:00:05.5000000Z ##[group]Run cargo build
+2024-05-01T10:00:06.0000000Z    Compiling jolt v0.1.0
+2024-05-01T10:00:09.0000000Z error[E0308]: mismatched types";
+
+    #[test]
+    fn test_step_anchor() {
+        let steps = vec![
+            step(1, "2024-05-01T10:00:00Z"),
+            step(2, "2024-05-01T10:00:01Z"),
+            step(3, "2024-05-01T10:00:05Z"),
+        ];
+        assert_eq!(step_anchor(LOGS, 0, &steps).as_deref(), Some("step:1:1"));
+        assert_eq!(step_anchor(LOGS, 4, &steps).as_deref(), Some("step:3:3"));
+        assert_eq!(step_anchor("no timestamp", 0, &steps), None);
+    }
+
+    #[test]
+    fn test_anchor_line() {
+        let steps = vec![
+            step(1, "2024-05-01T10:00:00Z"),
+            step(2, "2024-05-01T10:00:01Z"),
+            step(3, "2024-05-01T10:00:05Z"),
+        ];
+        assert_eq!(anchor_line(LOGS, 3, 3, &steps), Some(4));
+        assert_eq!(anchor_line(LOGS, 1, 1, &steps), Some(0));
+        assert_eq!(anchor_line(LOGS, 9, 1, &steps), None);
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("hi", false), "\x1b]52;c;aGk=\x07");
+        assert_eq!(
+            osc52_sequence("hi", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+        );
+    }
+
+    fn excerpt() -> LogExcerpt {
+        LogExcerpt {
+            text: "error: use `cargo fix`".to_string(),
+            anchor: Some("step:3:3".to_string()),
+            context: LogContext {
+                owner: "o".to_string(),
+                repo: "r".to_string(),
+                run_id: 1,
+                run_url: "https://github.com/o/r/actions/runs/1".to_string(),
+                job_id: 2,
+                job_name: "build".to_string(),
+                job_url: "https://github.com/o/r/actions/runs/1/job/2".to_string(),
+                head_sha: Some("abc123".to_string()),
+                head_branch: Some("main".to_string()),
+                ..LogContext::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_format_markdown() {
+        let excerpt = excerpt();
+        assert_eq!(
+            excerpt.format(CopyMode::Raw).unwrap(),
+            "error: use `cargo fix`"
+        );
+        assert_eq!(
+            excerpt.format(CopyMode::Permalink).unwrap(),
+            "https://github.com/o/r/actions/runs/1/job/2#step:3:3"
+        );
+        assert_eq!(
+            excerpt.format(CopyMode::Markdown).unwrap(),
+            "[build](https://github.com/o/r/actions/runs/1/job/2#step:3:3)\n```\nerror: use `cargo fix`\n```"
+        );
+    }
+
+    #[test]
+    fn test_format_context() {
+        let excerpt = excerpt();
+        assert_eq!(excerpt.format(CopyMode::Sha).unwrap(), "abc123");
+        assert_eq!(excerpt.format(CopyMode::Runner), None);
+        let json: serde_json::Value =
+            serde_json::from_str(&excerpt.format(CopyMode::Context).unwrap()).unwrap();
+        assert_eq!(json["run_url"], "https://github.com/o/r/actions/runs/1");
+        assert_eq!(json["head_branch"], "main");
+        assert_eq!(json["runner_name"], serde_json::Value::Null);
+    }
+}
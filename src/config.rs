@@ -0,0 +1,584 @@
+// User configuration loaded from the config file.
+// Holds runtime settings editable from the Settings overlay.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+use crate::app::Tab;
+use crate::error::{JoltError, Result};
+use crate::github::retry::RetryPolicy;
+
+/// Color theme for the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// Tuned for dark terminal backgrounds.
+    #[default]
+    Dark,
+    /// Tuned for light terminal backgrounds.
+    Light,
+}
+
+impl Theme {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+        }
+    }
+}
+
+/// Where copied text is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardBackend {
+    /// OSC 52 in SSH sessions, the system clipboard otherwise.
+    #[default]
+    Auto,
+    /// The system clipboard command (pbcopy).
+    System,
+    /// An OSC 52 escape sequence, which the terminal copies to its local clipboard.
+    Osc52,
+}
+
+impl ClipboardBackend {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClipboardBackend::Auto => "Auto",
+            ClipboardBackend::System => "System",
+            ClipboardBackend::Osc52 => "OSC 52 (terminal)",
+        }
+    }
+}
+
+/// Where the state of the run being viewed is shown outside the TUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowTitle {
+    /// The terminal title is left alone.
+    Off,
+    /// The terminal window title.
+    #[default]
+    Title,
+    /// The window title and the `@jolt_status` tmux option, for tmux status lines.
+    TitleAndTmux,
+}
+
+impl WindowTitle {
+    pub fn label(&self) -> &'static str {
+        match self {
+            WindowTitle::Off => "Off",
+            WindowTitle::Title => "Window title",
+            WindowTitle::TitleAndTmux => "Window title and tmux",
+        }
+    }
+}
+
+/// Log viewer highlight for lines matching a regular expression.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HighlightRule {
+    /// Regular expression matched against each log line.
+    pub pattern: String,
+    /// Text color, by name ("yellow", "light-red") or hex ("#ff8800").
+    pub fg: Option<String>,
+    /// Background color, in the same forms as `fg`.
+    pub bg: Option<String>,
+    pub bold: bool,
+    /// Whether the whole line is styled rather than just the matched text.
+    pub line: bool,
+}
+
+/// Runs to hide from Runs lists and the Live tab. Each field set must match,
+/// and may use `*` to match any text (e.g., `workflow = "Nightly *"`).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoreRule {
+    /// Login of the user or bot that triggered the run (e.g., "dependabot[bot]").
+    pub actor: Option<String>,
+    /// Event that triggered the run (e.g., "schedule").
+    pub event: Option<String>,
+    /// Name of the run's workflow.
+    pub workflow: Option<String>,
+}
+
+/// Kinds of cached data with independently configurable TTLs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheKind {
+    Owners,
+    Repositories,
+    Workflows,
+    Runs,
+    Jobs,
+    Logs,
+    Runners,
+}
+
+/// Per-entity cache TTLs in seconds (0 never expires).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheTtls {
+    pub owners: u64,
+    pub repositories: u64,
+    pub workflows: u64,
+    pub runs: u64,
+    pub jobs: u64,
+    pub logs: u64,
+    pub runners: u64,
+}
+
+impl Default for CacheTtls {
+    fn default() -> Self {
+        let default_secs = crate::cache::DEFAULT_TTL.as_secs();
+        Self {
+            owners: 24 * 60 * 60,
+            repositories: default_secs,
+            workflows: default_secs,
+            runs: 60,
+            jobs: 60,
+            logs: 0,
+            runners: 60,
+        }
+    }
+}
+
+impl CacheTtls {
+    /// TTL for a kind of cached data, or None if it never expires.
+    pub fn get(&self, kind: CacheKind) -> Option<Duration> {
+        let secs = match kind {
+            CacheKind::Owners => self.owners,
+            CacheKind::Repositories => self.repositories,
+            CacheKind::Workflows => self.workflows,
+            CacheKind::Runs => self.runs,
+            CacheKind::Jobs => self.jobs,
+            CacheKind::Logs => self.logs,
+            CacheKind::Runners => self.runners,
+        };
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+}
+
+/// Timeouts and retries of GitHub API requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+    /// Seconds to wait for a connection (0 waits indefinitely).
+    pub connect_timeout_secs: u64,
+    /// Seconds to wait for each read of a response (0 waits indefinitely).
+    pub read_timeout_secs: u64,
+    /// Seconds the TUI waits for a response to a request it's blocked on,
+    /// which isn't retried (0 waits for the read timeout).
+    pub interactive_timeout_secs: u64,
+    /// Retries of requests failing with a server error or network error.
+    pub retries: u32,
+    /// Milliseconds before the first retry, doubling with each one after it.
+    pub retry_backoff_ms: u64,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        let policy = RetryPolicy::default();
+        Self {
+            connect_timeout_secs: 10,
+            read_timeout_secs: 30,
+            interactive_timeout_secs: 10,
+            retries: policy.retries,
+            retry_backoff_ms: policy.backoff.as_millis() as u64,
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Connection timeout, or None to wait indefinitely.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        (self.connect_timeout_secs > 0).then(|| Duration::from_secs(self.connect_timeout_secs))
+    }
+
+    /// Read timeout, or None to wait indefinitely.
+    pub fn read_timeout(&self) -> Option<Duration> {
+        (self.read_timeout_secs > 0).then(|| Duration::from_secs(self.read_timeout_secs))
+    }
+
+    /// Time the TUI waits for a response it's blocked on, or None to wait
+    /// for the read timeout.
+    pub fn interactive_timeout(&self) -> Option<Duration> {
+        (self.interactive_timeout_secs > 0)
+            .then(|| Duration::from_secs(self.interactive_timeout_secs))
+    }
+
+    /// How failed requests are retried.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            retries: self.retries,
+            backoff: Duration::from_millis(self.retry_backoff_ms),
+        }
+    }
+}
+
+/// Runtime settings persisted to the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Auto-refresh interval for list views in seconds (0 disables).
+    pub refresh_interval_secs: u64,
+    /// TTLs for cached data, per kind of entity.
+    pub cache_ttl: CacheTtls,
+    /// Page size for list requests.
+    pub per_page: u32,
+    /// Color theme.
+    pub theme: Theme,
+    /// Tab to open on startup (None restores the last active tab).
+    pub default_tab: Option<Tab>,
+    /// Install directories of self-hosted runners on this machine, by runner name.
+    pub runner_dirs: BTreeMap<String, PathBuf>,
+    /// Shell command whose first output line is shown as a local runner's health.
+    pub runner_probe: String,
+    /// Whether logs of failed jobs open scrolled to their first error.
+    pub jump_to_first_error: bool,
+    /// Address to serve Prometheus gauges of favorite repositories on while the
+    /// TUI runs (None disables).
+    pub metrics_addr: Option<String>,
+    /// Where copied log lines are sent.
+    pub clipboard: ClipboardBackend,
+    /// Where the state of the run being viewed is shown.
+    pub window_title: WindowTitle,
+    /// Whether a hard refresh also revalidates the lists above the current one.
+    pub hard_refresh_path: bool,
+    /// Highlights applied in the log viewer, earlier rules taking precedence.
+    pub log_highlights: Vec<HighlightRule>,
+    /// Whether emoji and status glyphs are drawn as plain characters, for
+    /// terminals and fonts that get their width wrong.
+    pub ascii: bool,
+    /// Runs hidden from Runs lists and the Live tab, such as bot updates.
+    pub ignore_runs: Vec<IgnoreRule>,
+    /// Whether Runs lists open narrowed to the repository's default branch.
+    pub default_branch_runs: bool,
+    /// Timeouts and retries of GitHub API requests.
+    pub http: HttpConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            refresh_interval_secs: 0,
+            cache_ttl: CacheTtls::default(),
+            per_page: 30,
+            theme: Theme::default(),
+            default_tab: None,
+            runner_dirs: BTreeMap::new(),
+            runner_probe: "uptime".to_string(),
+            jump_to_first_error: true,
+            metrics_addr: None,
+            clipboard: ClipboardBackend::default(),
+            window_title: WindowTitle::default(),
+            hard_refresh_path: true,
+            log_highlights: Vec::new(),
+            ascii: false,
+            ignore_runs: Vec::new(),
+            default_branch_runs: true,
+            http: HttpConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Path to the config file (~/.config/jolt/config.toml on Linux).
+    pub fn path() -> Option<PathBuf> {
+        ProjectDirs::from("", "", "jolt").map(|dirs| dirs.config_dir().join("config.toml"))
+    }
+
+    /// Load the config file, falling back to defaults if it doesn't exist.
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents)
+            .map_err(|e| JoltError::Other(format!("Invalid config {}: {}", path.display(), e)))
+    }
+
+    /// Save the config file.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = toml::to_string_pretty(self).map_err(|e| JoltError::Other(e.to_string()))?;
+        fs::write(&path, contents)?;
+        Ok(())
+    }
+
+    /// TTL for a kind of cached data, or None if it never expires.
+    pub fn cache_ttl(&self, kind: CacheKind) -> Option<Duration> {
+        self.cache_ttl.get(kind)
+    }
+
+    /// Auto-refresh interval, if enabled.
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        (self.refresh_interval_secs > 0).then(|| Duration::from_secs(self.refresh_interval_secs))
+    }
+}
+
+/// A setting row in the Settings overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsField {
+    RefreshInterval,
+    RunsCacheTtl,
+    PerPage,
+    Theme,
+    DefaultTab,
+    FirstError,
+    Clipboard,
+    WindowTitle,
+    HardRefreshPath,
+    Ascii,
+    DefaultBranchRuns,
+}
+
+impl SettingsField {
+    /// All fields in display order.
+    pub const ALL: [SettingsField; 11] = [
+        SettingsField::RefreshInterval,
+        SettingsField::RunsCacheTtl,
+        SettingsField::PerPage,
+        SettingsField::Theme,
+        SettingsField::DefaultTab,
+        SettingsField::FirstError,
+        SettingsField::Clipboard,
+        SettingsField::WindowTitle,
+        SettingsField::HardRefreshPath,
+        SettingsField::Ascii,
+        SettingsField::DefaultBranchRuns,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingsField::RefreshInterval => "Auto-refresh interval",
+            SettingsField::RunsCacheTtl => "Runs cache TTL",
+            SettingsField::PerPage => "Items per page",
+            SettingsField::Theme => "Theme",
+            SettingsField::DefaultTab => "Startup tab",
+            SettingsField::FirstError => "Open failed logs at first error",
+            SettingsField::Clipboard => "Clipboard",
+            SettingsField::WindowTitle => "Run status in title",
+            SettingsField::HardRefreshPath => "Hard refresh whole path",
+            SettingsField::Ascii => "Plain-character icons",
+            SettingsField::DefaultBranchRuns => "Runs on default branch",
+        }
+    }
+
+    /// Format the current value of this field.
+    pub fn value(&self, config: &Config) -> String {
+        match self {
+            SettingsField::RefreshInterval => match config.refresh_interval_secs {
+                0 => "Off".to_string(),
+                secs => format_secs(secs),
+            },
+            SettingsField::RunsCacheTtl => match config.cache_ttl.runs {
+                0 => "Never expires".to_string(),
+                secs => format_secs(secs),
+            },
+            SettingsField::PerPage => config.per_page.to_string(),
+            SettingsField::Theme => config.theme.label().to_string(),
+            SettingsField::DefaultTab => config
+                .default_tab
+                .map(|tab| tab.title().to_string())
+                .unwrap_or_else(|| "Last used".to_string()),
+            SettingsField::FirstError => match config.jump_to_first_error {
+                true => "On".to_string(),
+                false => "Off".to_string(),
+            },
+            SettingsField::Clipboard => config.clipboard.label().to_string(),
+            SettingsField::WindowTitle => config.window_title.label().to_string(),
+            SettingsField::HardRefreshPath => match config.hard_refresh_path {
+                true => "On".to_string(),
+                false => "Off".to_string(),
+            },
+            SettingsField::Ascii => match config.ascii {
+                true => "On".to_string(),
+                false => "Off".to_string(),
+            },
+            SettingsField::DefaultBranchRuns => match config.default_branch_runs {
+                true => "On".to_string(),
+                false => "Off".to_string(),
+            },
+        }
+    }
+
+    /// Step this field to the next (or previous) preset value.
+    pub fn cycle(&self, config: &mut Config, forward: bool) {
+        match self {
+            SettingsField::RefreshInterval => {
+                config.refresh_interval_secs = cycle_value(
+                    &[0, 30, 60, 120, 300],
+                    config.refresh_interval_secs,
+                    forward,
+                );
+            }
+            SettingsField::RunsCacheTtl => {
+                config.cache_ttl.runs =
+                    cycle_value(&[30, 60, 300, 900, 3600], config.cache_ttl.runs, forward);
+            }
+            SettingsField::PerPage => {
+                config.per_page = cycle_value(&[10, 30, 50, 100], config.per_page, forward);
+            }
+            SettingsField::Theme => {
+                config.theme = cycle_value(&[Theme::Dark, Theme::Light], config.theme, forward);
+            }
+            SettingsField::DefaultTab => {
+                config.default_tab = cycle_value(
+                    &[
+                        None,
+                        Some(Tab::Home),
+                        Some(Tab::Runners),
+                        Some(Tab::Workflows),
+                        Some(Tab::Inbox),
+                        Some(Tab::Console),
+                        Some(Tab::Live),
+                    ],
+                    config.default_tab,
+                    forward,
+                );
+            }
+            SettingsField::FirstError => {
+                config.jump_to_first_error = !config.jump_to_first_error;
+            }
+            SettingsField::Clipboard => {
+                config.clipboard = cycle_value(
+                    &[
+                        ClipboardBackend::Auto,
+                        ClipboardBackend::System,
+                        ClipboardBackend::Osc52,
+                    ],
+                    config.clipboard,
+                    forward,
+                );
+            }
+            SettingsField::HardRefreshPath => {
+                config.hard_refresh_path = !config.hard_refresh_path;
+            }
+            SettingsField::Ascii => {
+                config.ascii = !config.ascii;
+            }
+            SettingsField::DefaultBranchRuns => {
+                config.default_branch_runs = !config.default_branch_runs;
+            }
+            SettingsField::WindowTitle => {
+                config.window_title = cycle_value(
+                    &[
+                        WindowTitle::Off,
+                        WindowTitle::Title,
+                        WindowTitle::TitleAndTmux,
+                    ],
+                    config.window_title,
+                    forward,
+                );
+            }
+        }
+    }
+}
+
+/// Pick the preset after (or before) `current`, wrapping around.
+/// Values not in the preset list snap to the first preset.
+fn cycle_value<T: Copy + PartialEq>(presets: &[T], current: T, forward: bool) -> T {
+    let len = presets.len();
+    match presets.iter().position(|v| *v == current) {
+        Some(i) if forward => presets[(i + 1) % len],
+        Some(i) => presets[(i + len - 1) % len],
+        None => presets[0],
+    }
+}
+
+/// Format a number of seconds compactly (e.g., "30s", "5m", "1h", "1d").
+fn format_secs(secs: u64) -> String {
+    if secs >= 86400 && secs.is_multiple_of(86400) {
+        format!("{}d", secs / 86400)
+    } else if secs >= 3600 && secs.is_multiple_of(3600) {
+        format!("{}h", secs / 3600)
+    } else if secs >= 60 && secs.is_multiple_of(60) {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_wraps() {
+        let mut config = Config::default();
+        SettingsField::PerPage.cycle(&mut config, true);
+        assert_eq!(config.per_page, 50);
+        SettingsField::PerPage.cycle(&mut config, true);
+        SettingsField::PerPage.cycle(&mut config, true);
+        assert_eq!(config.per_page, 10);
+        SettingsField::PerPage.cycle(&mut config, false);
+        assert_eq!(config.per_page, 100);
+    }
+
+    #[test]
+    fn test_round_trip_toml() {
+        let config = Config {
+            refresh_interval_secs: 60,
+            theme: Theme::Light,
+            default_tab: Some(Tab::Runners),
+            clipboard: ClipboardBackend::Osc52,
+            window_title: WindowTitle::TitleAndTmux,
+            log_highlights: vec![HighlightRule {
+                pattern: "^WARN".to_string(),
+                fg: Some("yellow".to_string()),
+                line: true,
+                ..HighlightRule::default()
+            }],
+            ..Config::default()
+        };
+        let text = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let parsed: Config = toml::from_str("per_page = 50").unwrap();
+        assert_eq!(parsed.per_page, 50);
+        assert_eq!(parsed.cache_ttl, CacheTtls::default());
+    }
+
+    #[test]
+    fn test_cache_ttl_overrides() {
+        let parsed: Config = toml::from_str("[cache_ttl]\nruns = 120\nlogs = 3600").unwrap();
+        assert_eq!(
+            parsed.cache_ttl(CacheKind::Runs),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parsed.cache_ttl(CacheKind::Logs),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(
+            parsed.cache_ttl(CacheKind::Owners),
+            Some(Duration::from_secs(86400))
+        );
+        assert_eq!(Config::default().cache_ttl(CacheKind::Logs), None);
+    }
+
+    #[test]
+    fn test_format_secs() {
+        assert_eq!(format_secs(30), "30s");
+        assert_eq!(format_secs(300), "5m");
+        assert_eq!(format_secs(3600), "1h");
+        assert_eq!(format_secs(86400), "1d");
+    }
+}
@@ -0,0 +1,106 @@
+// Panic recovery.
+// Writes a crash report with a backtrace to the cache directory when jolt
+// panics, and restores the terminal when the app itself crashes so the shell
+// stays usable.
+
+use std::backtrace::Backtrace;
+use std::io;
+use std::panic::{self, PanicHookInfo};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crossterm::{
+    cursor::Show,
+    event::{DisableBracketedPaste, DisableMouseCapture},
+    execute,
+    terminal::{LeaveAlternateScreen, disable_raw_mode},
+};
+
+use crate::cache;
+use crate::changelog::CURRENT_VERSION;
+
+/// The last panic and where its report was written, printed once the
+/// terminal is restored.
+static LAST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Write a crash report of each panic instead of printing it. Panics in
+/// background tasks don't stop the TUI, so the terminal is left as it is;
+/// `main` restores it when the app itself panics.
+pub fn install_hook() {
+    panic::set_hook(Box::new(|info| {
+        let backtrace = Backtrace::force_capture();
+        let message = panic_message(info);
+        let location = info.location().map(|l| l.to_string()).unwrap_or_default();
+        tracing::error!(location, "panic: {}", message);
+        let text = report(&message, &location, &backtrace.to_string());
+        let mut summary = format!("jolt panicked at {}:\n{}", location, message);
+        if let Some(path) = write_report(&text) {
+            summary.push_str(&format!("\nCrash report written to {}", path.display()));
+        }
+        if let Ok(mut last) = LAST_PANIC.lock() {
+            *last = Some(summary);
+        }
+    }));
+}
+
+/// The last panic's message and crash report path, to print after the
+/// terminal is restored.
+pub fn take_last_panic() -> Option<String> {
+    LAST_PANIC.lock().ok()?.take()
+}
+
+/// Leave raw mode and the alternate screen. Errors are ignored, as the
+/// terminal may already be restored.
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        Show
+    );
+}
+
+/// Message a panic was raised with.
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Text of a crash report.
+fn report(message: &str, location: &str, backtrace: &str) -> String {
+    format!(
+        "jolt {} crashed at {}\n\nPanic: {}\nLocation: {}\n\nBacktrace:\n{}\n",
+        CURRENT_VERSION,
+        chrono::Utc::now().to_rfc3339(),
+        message,
+        location,
+        backtrace
+    )
+}
+
+/// Write a crash report into the cache directory. Returns its path.
+fn write_report(text: &str) -> Option<PathBuf> {
+    let name = format!("crash-{}.txt", chrono::Utc::now().format("%Y%m%d-%H%M%S"));
+    let path = cache::crash_reports_dir()?.join(name);
+    cache::write_text(&path, text).ok()?;
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_includes_panic_and_backtrace() {
+        let text = report("index out of bounds", "src/app.rs:42:7", "0: jolt::main");
+        assert!(text.starts_with(&format!("jolt {} crashed at ", CURRENT_VERSION)));
+        assert!(text.contains("Panic: index out of bounds\nLocation: src/app.rs:42:7\n"));
+        assert!(text.ends_with("Backtrace:\n0: jolt::main\n"));
+    }
+}
@@ -0,0 +1,166 @@
+// Diagnostics for self-hosted runners installed on this machine.
+// Reads the logs a runner writes to its _diag directory and probes its health.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::time::Duration;
+
+use tokio::process::Command;
+
+/// Longest a health check may take before it's reported as failed.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Health of a runner's host, as seen from this machine.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunnerHealth {
+    /// Whether the runner's listener process is running.
+    pub process_up: Option<bool>,
+    /// Free disk space in the runner's install directory, in KiB.
+    pub disk_free_kb: Option<u64>,
+    /// First line of the configured probe command's output, or its error.
+    pub probe: Option<String>,
+}
+
+/// Check a runner's listener process, free disk space, and run the probe command.
+///
+/// The probe runs through `sh -c` with `RUNNER_NAME` and `RUNNER_DIR` set, so
+/// it can reach runners on other hosts (e.g., `ssh build-mac uptime`).
+pub async fn probe_health(name: &str, runner_dir: &Path, probe_command: &str) -> RunnerHealth {
+    let listener = runner_dir.join("bin").join("Runner.Listener");
+    let process_up = run(Command::new("pgrep").arg("-f").arg(&listener))
+        .await
+        .map(|output| output.status.success())
+        .ok();
+    let disk_free_kb = run(Command::new("df").arg("-Pk").arg(runner_dir))
+        .await
+        .ok()
+        .and_then(|output| parse_df_available(&String::from_utf8_lossy(&output.stdout)));
+
+    let probe = if probe_command.trim().is_empty() {
+        None
+    } else {
+        let output = run(Command::new("sh")
+            .arg("-c")
+            .arg(probe_command)
+            .env("RUNNER_NAME", name)
+            .env("RUNNER_DIR", runner_dir))
+        .await;
+        Some(match output {
+            Ok(output) if output.status.success() => first_line(&output.stdout),
+            Ok(output) => format!("probe failed: {}", first_line(&output.stderr)),
+            Err(e) => format!("probe failed: {}", e),
+        })
+    };
+
+    RunnerHealth {
+        process_up,
+        disk_free_kb,
+        probe,
+    }
+}
+
+/// Run a command, giving up after the probe timeout.
+async fn run(command: &mut Command) -> io::Result<Output> {
+    command.kill_on_drop(true);
+    tokio::time::timeout(PROBE_TIMEOUT, command.output())
+        .await
+        .unwrap_or_else(|_| Err(io::Error::new(io::ErrorKind::TimedOut, "timed out")))
+}
+
+/// First non-empty line of command output, trimmed.
+fn first_line(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Available KiB from `df -Pk` output (the fourth column of the second line).
+fn parse_df_available(output: &str) -> Option<u64> {
+    output
+        .lines()
+        .nth(1)?
+        .split_whitespace()
+        .nth(3)?
+        .parse()
+        .ok()
+}
+
+/// Read the latest runner and worker logs from a runner's install directory.
+///
+/// Runners write `Runner_<timestamp>.log` for the listener and one
+/// `Worker_<timestamp>.log` per job under `_diag`, so the newest of each is
+/// where agent-level failures show up.
+pub fn read_diag_logs(runner_dir: &Path) -> io::Result<String> {
+    let diag_dir = runner_dir.join("_diag");
+    let mut names: Vec<String> = fs::read_dir(&diag_dir)?
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".log"))
+        .collect();
+    // Timestamps in the names sort chronologically
+    names.sort();
+
+    let latest: Vec<PathBuf> = ["Runner_", "Worker_"]
+        .iter()
+        .filter_map(|prefix| names.iter().rev().find(|name| name.starts_with(prefix)))
+        .map(|name| diag_dir.join(name))
+        .collect();
+    if latest.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no runner logs in {}", diag_dir.display()),
+        ));
+    }
+
+    let mut output = String::new();
+    for path in latest {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        output.push_str(&format!("==> {} <==\n", name));
+        output.push_str(&fs::read_to_string(&path)?);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_latest_runner_and_worker_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let diag = dir.path().join("_diag");
+        fs::create_dir(&diag).unwrap();
+        fs::write(diag.join("Runner_20240501-100000-utc.log"), "old listener").unwrap();
+        fs::write(diag.join("Runner_20240502-100000-utc.log"), "listener").unwrap();
+        fs::write(diag.join("Worker_20240502-110000-utc.log"), "worker").unwrap();
+
+        let logs = read_diag_logs(dir.path()).unwrap();
+        assert_eq!(
+            logs,
+            "==> Runner_20240502-100000-utc.log <==\nlistener\n\
+             ==> Worker_20240502-110000-utc.log <==\nworker\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_df_available() {
+        let output = "\
+Filesystem     1024-blocks      Used Available Capacity Mounted on
+/dev/disk3s5     482797652 301234567 160000000      66% /System/Volumes/Data
+";
+        assert_eq!(parse_df_available(output), Some(160000000));
+        assert_eq!(parse_df_available(""), None);
+    }
+
+    #[test]
+    fn test_missing_diag_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(read_diag_logs(dir.path()).is_err());
+    }
+}
@@ -3,6 +3,7 @@
 
 #![allow(dead_code)]
 
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -16,8 +17,20 @@ pub enum JoltError {
     #[error("Resource not found: {0}")]
     NotFound(String),
 
-    #[error("Rate limit exceeded, resets at {reset_at}")]
-    RateLimited { reset_at: String },
+    #[error("Rate limit exceeded, resets at {}", .reset_at.format("%H:%M:%S"))]
+    RateLimited { reset_at: DateTime<Utc> },
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("HTTP {status}: {body}")]
+    Http {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+
+    #[error("No response from {endpoint} within {secs}s")]
+    Timeout { endpoint: String, secs: u64 },
 
     #[error("Missing GITHUB_TOKEN environment variable")]
     MissingToken,
@@ -32,4 +45,169 @@ pub enum JoltError {
     Other(String),
 }
 
+impl JoltError {
+    /// Whether the request may succeed if made again later: network errors,
+    /// server errors and the rate limit.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            JoltError::Api(e) => !e.is_decode() && !e.is_builder(),
+            JoltError::Http { status, .. } => status.is_server_error(),
+            JoltError::RateLimited { .. } | JoltError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, JoltError>;
+
+/// Kind of error shown in a view, kept from the `JoltError` it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Unauthorized,
+    NotFound,
+    RateLimited { reset_at: DateTime<Utc> },
+    Forbidden,
+    MissingToken,
+    Network,
+    Other,
+}
+
+/// Error shown in place of a view's data, with guidance on how to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewError {
+    pub kind: ErrorKind,
+    pub message: String,
+    /// Guidance for this view that replaces the kind's generic hint.
+    hint: Option<String>,
+}
+
+impl ViewError {
+    /// Replace the generic hint with guidance specific to the view.
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// What the user can do about the error, as of `now`.
+    pub fn hint(&self, now: DateTime<Utc>) -> Option<String> {
+        if let Some(hint) = &self.hint {
+            return Some(hint.clone());
+        }
+        match self.kind {
+            ErrorKind::Unauthorized => Some(
+                "Token invalid or expired: export a new GITHUB_TOKEN and restart jolt".to_string(),
+            ),
+            ErrorKind::RateLimited { reset_at } => {
+                let secs = (reset_at - now).num_seconds().max(0);
+                Some(format!(
+                    "Rate limit resets in {}m {:02}s, then press r to retry",
+                    secs / 60,
+                    secs % 60
+                ))
+            }
+            ErrorKind::Forbidden => {
+                Some("The token lacks a scope or permission this view needs".to_string())
+            }
+            ErrorKind::MissingToken => Some(
+                "Export GITHUB_TOKEN with a personal access token and restart jolt".to_string(),
+            ),
+            ErrorKind::Network => Some("Check your connection, then press r to retry".to_string()),
+            ErrorKind::NotFound | ErrorKind::Other => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ViewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<&JoltError> for ViewError {
+    fn from(error: &JoltError) -> Self {
+        let kind = match error {
+            JoltError::Unauthorized => ErrorKind::Unauthorized,
+            JoltError::NotFound(_) => ErrorKind::NotFound,
+            JoltError::RateLimited { reset_at } => ErrorKind::RateLimited {
+                reset_at: *reset_at,
+            },
+            JoltError::Forbidden(_) => ErrorKind::Forbidden,
+            JoltError::MissingToken => ErrorKind::MissingToken,
+            JoltError::Api(_) | JoltError::Timeout { .. } => ErrorKind::Network,
+            JoltError::Http { .. }
+            | JoltError::Json(_)
+            | JoltError::Io(_)
+            | JoltError::Other(_) => ErrorKind::Other,
+        };
+        Self {
+            kind,
+            message: error.to_string(),
+            hint: None,
+        }
+    }
+}
+
+impl From<String> for ViewError {
+    fn from(message: String) -> Self {
+        Self {
+            kind: ErrorKind::Other,
+            message,
+            hint: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_error_hints() {
+        let now = DateTime::from_timestamp(1_000_000, 0).unwrap();
+        let limited = ViewError::from(&JoltError::RateLimited {
+            reset_at: now + chrono::Duration::seconds(754),
+        });
+        assert_eq!(
+            limited.hint(now).as_deref(),
+            Some("Rate limit resets in 12m 34s, then press r to retry")
+        );
+
+        let missing = ViewError::from(&JoltError::NotFound("/repos/o/r".to_string()));
+        assert_eq!(missing.hint(now), None);
+        let runners = missing.with_hint("Listing runners needs admin access");
+        assert_eq!(
+            runners.hint(now).as_deref(),
+            Some("Listing runners needs admin access")
+        );
+        assert_eq!(runners.to_string(), "Resource not found: /repos/o/r");
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        let server = JoltError::Http {
+            status: reqwest::StatusCode::BAD_GATEWAY,
+            body: String::new(),
+        };
+        assert!(server.is_transient());
+        assert_eq!(server.to_string(), "HTTP 502 Bad Gateway: ");
+        let client = JoltError::Http {
+            status: reqwest::StatusCode::UNPROCESSABLE_ENTITY,
+            body: String::new(),
+        };
+        assert!(!client.is_transient());
+        assert!(
+            JoltError::RateLimited {
+                reset_at: Utc::now()
+            }
+            .is_transient()
+        );
+        let timeout = JoltError::Timeout {
+            endpoint: "/user".to_string(),
+            secs: 10,
+        };
+        assert!(timeout.is_transient());
+        assert_eq!(ViewError::from(&timeout).kind, ErrorKind::Network);
+        assert!(!JoltError::NotFound("/repos/o/r".to_string()).is_transient());
+        assert!(!JoltError::Unauthorized.is_transient());
+    }
+}
@@ -0,0 +1,217 @@
+// Export of runs and jobs lists.
+// Writes durations, conclusions, SHAs, and URLs as JSON or CSV for spreadsheet analysis.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::github::{Job, RunConclusion, RunStatus, WorkflowRun};
+
+/// File format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    /// All formats, in the order shown in the picker.
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Json];
+
+    /// Key that selects this format in the picker.
+    pub fn key(&self) -> char {
+        match self {
+            ExportFormat::Json => 'j',
+            ExportFormat::Csv => 'c',
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    /// Parse a format name given on the command line.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(ExportFormat::Json),
+            "csv" => Some(ExportFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// One exported run or job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportRow {
+    pub id: u64,
+    pub name: String,
+    /// Run number (runs) or run ID (jobs).
+    pub run: u64,
+    pub attempt: Option<u64>,
+    pub status: RunStatus,
+    pub conclusion: Option<RunConclusion>,
+    pub branch: Option<String>,
+    pub sha: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration_secs: Option<i64>,
+    pub url: String,
+}
+
+impl ExportRow {
+    pub fn from_run(run: &WorkflowRun) -> Self {
+        let started_at = run.run_started_at.unwrap_or(run.created_at);
+        // Runs have no completion time; their last update is when they finished
+        let completed_at = (run.status == RunStatus::Completed).then_some(run.updated_at);
+        Self {
+            id: run.id,
+            name: run.name.clone().unwrap_or_default(),
+            run: run.run_number,
+            attempt: run.run_attempt,
+            status: run.status,
+            conclusion: run.conclusion,
+            branch: run.head_branch.clone(),
+            sha: run.head_sha.clone(),
+            started_at: Some(started_at),
+            completed_at,
+            duration_secs: completed_at.map(|end| (end - started_at).num_seconds()),
+            url: run.html_url.clone(),
+        }
+    }
+
+    /// Row for a job of a run, which carries the run's branch and SHA.
+    pub fn from_job(job: &Job, run: Option<&WorkflowRun>) -> Self {
+        Self {
+            id: job.id,
+            name: job.name.clone(),
+            run: job.run_id,
+            attempt: job.run_attempt,
+            status: job.status,
+            conclusion: job.conclusion,
+            branch: run.and_then(|run| run.head_branch.clone()),
+            sha: run.map(|run| run.head_sha.clone()).unwrap_or_default(),
+            started_at: job.started_at,
+            completed_at: job.completed_at,
+            duration_secs: job
+                .started_at
+                .zip(job.completed_at)
+                .map(|(start, end)| (end - start).num_seconds()),
+            url: job.html_url.clone(),
+        }
+    }
+}
+
+/// Render rows in a format.
+pub fn render(rows: &[ExportRow], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(rows).unwrap_or_default(),
+        ExportFormat::Csv => to_csv(rows),
+    }
+}
+
+const CSV_HEADER: &str =
+    "id,name,run,attempt,status,conclusion,branch,sha,started_at,completed_at,duration_secs,url";
+
+/// Rows as CSV with a header line.
+fn to_csv(rows: &[ExportRow]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for row in rows {
+        let time = |t: Option<DateTime<Utc>>| t.map(|t| t.to_rfc3339()).unwrap_or_default();
+        let fields = [
+            row.id.to_string(),
+            csv_field(&row.name),
+            row.run.to_string(),
+            row.attempt.map(|a| a.to_string()).unwrap_or_default(),
+            enum_name(&row.status),
+            row.conclusion.as_ref().map(enum_name).unwrap_or_default(),
+            csv_field(row.branch.as_deref().unwrap_or_default()),
+            row.sha.clone(),
+            time(row.started_at),
+            time(row.completed_at),
+            row.duration_secs.map(|d| d.to_string()).unwrap_or_default(),
+            csv_field(&row.url),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Serialized name of an enum value (e.g., "in_progress").
+fn enum_name(value: &impl Serialize) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// File name for an export of a repository's runs or jobs (e.g., "jolt-runs-20261016-101500.csv").
+pub fn file_name(repo: &str, kind: &str, format: ExportFormat, now: DateTime<Utc>) -> String {
+    format!(
+        "{}-{}-{}.{}",
+        repo,
+        kind,
+        now.format("%Y%m%d-%H%M%S"),
+        format.extension()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run() -> WorkflowRun {
+        let created = DateTime::from_timestamp(1_000_000, 0).unwrap();
+        WorkflowRun {
+            id: 7,
+            name: Some("CI, nightly".to_string()),
+            run_number: 42,
+            run_attempt: Some(2),
+            status: RunStatus::Completed,
+            conclusion: Some(RunConclusion::TimedOut),
+            workflow_id: 1,
+            head_branch: Some("main".to_string()),
+            head_sha: "abc123".to_string(),
+            event: "push".to_string(),
+            created_at: created,
+            updated_at: created + chrono::Duration::seconds(95),
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: "https://github.com/o/r/actions/runs/7".to_string(),
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = render(&[ExportRow::from_run(&run())], ExportFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "7,\"CI, nightly\",42,2,completed,timed_out,main,abc123,\
+             1970-01-12T13:46:40+00:00,1970-01-12T13:48:15+00:00,95,\
+             https://github.com/o/r/actions/runs/7"
+        );
+    }
+
+    #[test]
+    fn test_json_keeps_enum_names() {
+        let json = render(&[ExportRow::from_run(&run())], ExportFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["conclusion"], "timed_out");
+        assert_eq!(value[0]["duration_secs"], 95);
+    }
+}
@@ -0,0 +1,48 @@
+// Rate limit budgeting.
+// Projects when the remaining requests run out at this session's request
+// rate, so heavy use can be eased off before hitting the limit.
+
+use std::time::Duration;
+
+/// Session length before a request rate is projected, as the first
+/// requests of a session come in a burst.
+const MIN_SAMPLE: Duration = Duration::from_secs(60);
+
+/// Time until `remaining` requests run out at the rate of `used` requests
+/// in `elapsed`. None if they last until the limit resets in `resets_in`.
+pub fn exhausted_in(
+    remaining: u64,
+    used: u64,
+    elapsed: Duration,
+    resets_in: Duration,
+) -> Option<Duration> {
+    if used == 0 || elapsed < MIN_SAMPLE {
+        return None;
+    }
+    let per_sec = used as f64 / elapsed.as_secs_f64();
+    let left = Duration::from_secs_f64(remaining as f64 / per_sec);
+    (left < resets_in).then_some(left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exhausted_in_projects_session_rate() {
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        // 100 requests in 10 minutes leaves 400 for 40 minutes
+        assert_eq!(
+            exhausted_in(400, 100, minutes(10), minutes(50)),
+            Some(minutes(40))
+        );
+        // The limit resets first
+        assert_eq!(exhausted_in(400, 100, minutes(10), minutes(30)), None);
+        // Too early in the session to tell
+        assert_eq!(
+            exhausted_in(400, 100, Duration::from_secs(5), minutes(50)),
+            None
+        );
+        assert_eq!(exhausted_in(400, 0, minutes(10), minutes(50)), None);
+    }
+}
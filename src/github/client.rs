@@ -1,13 +1,19 @@
 // GitHub API HTTP client.
 // Handles authentication, rate limiting, and request/response processing.
 
+use std::time::{Duration, Instant};
+
 use reqwest::{
-    Client, Response, StatusCode,
+    Client, RequestBuilder, Response, StatusCode,
     header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT},
 };
 
+use crate::config::HttpConfig;
 use crate::error::{JoltError, Result};
 
+use super::retry::{RetryPolicy, is_retryable_error, is_retryable_status, jitter};
+use super::scopes::Capability;
+use super::timing::{EndpointTiming, EndpointTimings};
 use super::types::RateLimit;
 
 const GITHUB_API_BASE: &str = "https://api.github.com";
@@ -17,18 +23,57 @@ const GITHUB_API_VERSION: &str = "2022-11-28";
 pub struct GitHubClient {
     client: Client,
     rate_limit: RateLimit,
+    /// OAuth scopes of a classic token, from the last response (None if not reported).
+    scopes: Option<Vec<String>>,
+    /// Latency of recent requests, by endpoint.
+    timings: EndpointTimings,
+    /// How failed requests are retried.
+    retry_policy: RetryPolicy,
+    /// Whether the UI waits on this client's requests, which then fail fast
+    /// instead of being retried.
+    interactive: bool,
+    /// How long interactive requests wait for a response (None waits for the
+    /// read timeout).
+    interactive_timeout: Option<Duration>,
+    /// Retried failures not yet reported.
+    retries: Vec<String>,
+    /// Whether requests carry a token. Unauthenticated clients can only read
+    /// public repositories, at a much lower rate limit.
+    authenticated: bool,
+    /// Requests sent this session, counting retries.
+    requests: u64,
+    /// Job logs and log archives downloaded this session.
+    log_downloads: u64,
+    /// When the session started, for its request rate.
+    session_start: Instant,
 }
 
 impl GitHubClient {
     /// Create a new GitHub client with the given token.
     pub fn new(token: &str) -> Result<Self> {
+        Self::with_config(token, &HttpConfig::default())
+    }
+
+    /// Create a client with the given token, timeouts, and retry policy.
+    pub fn with_config(token: &str, http: &HttpConfig) -> Result<Self> {
+        Self::build(Some(token), http)
+    }
+
+    /// Create a client without a token, for browsing public repositories.
+    pub fn anonymous(http: &HttpConfig) -> Result<Self> {
+        Self::build(None, http)
+    }
+
+    fn build(token: Option<&str>, http: &HttpConfig) -> Result<Self> {
         let mut headers = HeaderMap::new();
 
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", token))
-                .map_err(|e| JoltError::Other(e.to_string()))?,
-        );
+        if let Some(token) = token {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", token))
+                    .map_err(|e| JoltError::Other(e.to_string()))?,
+            );
+        }
         headers.insert(
             ACCEPT,
             HeaderValue::from_static("application/vnd.github+json"),
@@ -39,21 +84,90 @@ impl GitHubClient {
         );
         headers.insert(USER_AGENT, HeaderValue::from_static("jolt-tui"));
 
-        let client = Client::builder()
-            .default_headers(headers)
-            .build()
-            .map_err(JoltError::Api)?;
+        let mut builder = Client::builder().default_headers(headers);
+        if let Some(timeout) = http.connect_timeout() {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = http.read_timeout() {
+            builder = builder.read_timeout(timeout);
+        }
+        let client = builder.build().map_err(JoltError::Api)?;
 
         Ok(Self {
             client,
             rate_limit: RateLimit::default(),
+            scopes: None,
+            timings: EndpointTimings::default(),
+            retry_policy: http.retry_policy(),
+            interactive: false,
+            interactive_timeout: http.interactive_timeout(),
+            retries: Vec::new(),
+            authenticated: token.is_some(),
+            requests: 0,
+            log_downloads: 0,
+            session_start: Instant::now(),
         })
     }
 
     /// Create a client from the GITHUB_TOKEN environment variable.
     pub fn from_env() -> Result<Self> {
+        Self::from_env_with_config(&HttpConfig::default())
+    }
+
+    /// Create a client from the GITHUB_TOKEN environment variable with the
+    /// given timeouts and retry policy.
+    pub fn from_env_with_config(http: &HttpConfig) -> Result<Self> {
         let token = std::env::var("GITHUB_TOKEN").map_err(|_| JoltError::MissingToken)?;
-        Self::new(&token)
+        Self::with_config(&token, http)
+    }
+
+    /// Client sharing this one's token and connection pool, for requests made
+    /// concurrently. Hand it back with `absorb` to keep its rate limit and timings.
+    pub fn fork(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            rate_limit: self.rate_limit.clone(),
+            scopes: self.scopes.clone(),
+            timings: EndpointTimings::default(),
+            retry_policy: self.retry_policy,
+            interactive: self.interactive,
+            interactive_timeout: self.interactive_timeout,
+            retries: Vec::new(),
+            authenticated: self.authenticated,
+            requests: 0,
+            log_downloads: 0,
+            session_start: self.session_start,
+        }
+    }
+
+    /// This client for requests the UI waits on: they give up after the
+    /// interactive timeout and aren't retried, so a slow endpoint doesn't
+    /// freeze the UI.
+    pub fn interactive(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
+    /// This client for requests made in a spawned task, which wait out the
+    /// read timeout and are retried.
+    pub fn in_background(mut self) -> Self {
+        self.interactive = false;
+        self
+    }
+
+    /// Take back what a forked client learned: the lowest remaining rate limit,
+    /// its request timings and counts, and its retried failures.
+    pub fn absorb(&mut self, fork: GitHubClient) {
+        let known = fork.rate_limit.limit > 0;
+        if known
+            && (self.rate_limit.limit == 0 || fork.rate_limit.remaining < self.rate_limit.remaining)
+        {
+            self.rate_limit = fork.rate_limit;
+        }
+        self.timings.merge(fork.timings);
+        self.requests += fork.requests;
+        self.log_downloads += fork.log_downloads;
+        self.retries.extend(fork.retries);
     }
 
     /// Get the current rate limit information.
@@ -61,13 +175,78 @@ impl GitHubClient {
         &self.rate_limit
     }
 
+    /// Requests sent this session and how long it has run.
+    pub fn session_usage(&self) -> (u64, Duration) {
+        (self.requests, self.session_start.elapsed())
+    }
+
+    /// Job logs and log archives downloaded this session.
+    pub fn log_downloads(&self) -> u64 {
+        self.log_downloads
+    }
+
+    /// Count a downloaded job log or log archive.
+    pub(super) fn record_log_download(&mut self) {
+        self.log_downloads += 1;
+    }
+
+    /// Whether requests carry a token.
+    pub fn is_authenticated(&self) -> bool {
+        self.authenticated
+    }
+
+    /// Whether the token is known to lack an OAuth scope.
+    /// Fine-grained tokens don't report scopes, so they are never known to lack one.
+    pub fn lacks_scope(&self, scope: &str) -> bool {
+        self.scopes
+            .as_ref()
+            .is_some_and(|scopes| !scopes.iter().any(|s| s == scope))
+    }
+
+    /// Whether the token's scopes allow a capability (assumed until scopes are
+    /// reported). Without a token, no capability is granted.
+    pub fn can(&self, capability: Capability) -> bool {
+        self.authenticated && capability.granted_by(self.scopes.as_deref())
+    }
+
+    /// Capabilities the token's scopes are known not to grant.
+    pub fn missing_capabilities(&self) -> Vec<Capability> {
+        Capability::ALL
+            .into_iter()
+            .filter(|capability| !self.can(*capability))
+            .collect()
+    }
+
+    /// Latency summaries of the endpoints requested so far, slowest first.
+    pub fn endpoint_timings(&self) -> Vec<EndpointTiming> {
+        self.timings.summary()
+    }
+
+    /// Endpoints that became consistently slow since the last call.
+    pub fn take_slow_endpoints(&mut self) -> Vec<EndpointTiming> {
+        self.timings.take_newly_slow()
+    }
+
+    /// Failures retried since the last call, for reporting as warnings.
+    pub fn take_retries(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.retries)
+    }
+
     /// Make a GET request to the GitHub API.
     pub async fn get(&mut self, endpoint: &str) -> Result<Response> {
         let url = format!("{}{}", GITHUB_API_BASE, endpoint);
-        let response = self.client.get(&url).send().await.map_err(JoltError::Api)?;
+        let request = self.client.get(&url);
+        self.send(endpoint, request, true).await
+    }
 
-        self.update_rate_limit(&response);
-        self.check_response(response).await
+    /// Make a GET request for raw file contents instead of JSON.
+    pub async fn get_raw(&mut self, endpoint: &str) -> Result<Response> {
+        let url = format!("{}{}", GITHUB_API_BASE, endpoint);
+        let request = self
+            .client
+            .get(&url)
+            .header(ACCEPT, "application/vnd.github.raw+json");
+        self.send(endpoint, request, true).await
     }
 
     /// Make a GET request with query parameters.
@@ -77,18 +256,120 @@ impl GitHubClient {
         params: &T,
     ) -> Result<Response> {
         let url = format!("{}{}", GITHUB_API_BASE, endpoint);
-        let response = self
-            .client
-            .get(&url)
-            .query(params)
-            .send()
-            .await
-            .map_err(JoltError::Api)?;
+        let request = self.client.get(&url).query(params);
+        self.send(endpoint, request, true).await
+    }
+
+    /// Make a POST request with a JSON body.
+    pub async fn post_json<T: serde::Serialize + ?Sized>(
+        &mut self,
+        endpoint: &str,
+        body: &T,
+    ) -> Result<Response> {
+        let url = format!("{}{}", GITHUB_API_BASE, endpoint);
+        let request = self.client.post(&url).json(body);
+        self.send(endpoint, request, false).await
+    }
+
+    /// Make a DELETE request.
+    pub async fn delete(&mut self, endpoint: &str) -> Result<Response> {
+        let url = format!("{}{}", GITHUB_API_BASE, endpoint);
+        let request = self.client.delete(&url);
+        self.send(endpoint, request, true).await
+    }
+
+    /// Send a request, retrying server errors and network errors per the
+    /// retry policy. Requests that aren't `idempotent` are only retried if
+    /// they never reached the server. Interactive requests aren't retried.
+    async fn send(
+        &mut self,
+        endpoint: &str,
+        request: RequestBuilder,
+        idempotent: bool,
+    ) -> Result<Response> {
+        let retries = if self.interactive {
+            0
+        } else {
+            self.retry_policy.retries
+        };
+        let mut retry = 0;
+        let result = loop {
+            // Requests with streamed bodies can't be sent twice
+            let Some(attempt) = request.try_clone() else {
+                break self.send_once(endpoint, request).await?;
+            };
+            let started = Instant::now();
+            let result = self.send_once(endpoint, attempt).await;
+            let elapsed = started.elapsed();
+            self.timings.record(endpoint, elapsed);
+            self.requests += 1;
+            let result = result?;
+            match &result {
+                Ok(response) => tracing::debug!(
+                    endpoint,
+                    status = response.status().as_u16(),
+                    elapsed_ms = elapsed.as_millis() as u64,
+                    "request"
+                ),
+                Err(e) => tracing::warn!(endpoint, error = %e, "request failed"),
+            }
+
+            let failure = match &result {
+                Ok(response) if idempotent && is_retryable_status(response.status()) => {
+                    format!("HTTP {}", response.status())
+                }
+                Err(e) if is_retryable_error(e, idempotent) => e.to_string(),
+                _ => break result,
+            };
+            if retry >= retries {
+                break result;
+            }
+            let delay = self.retry_policy.delay(retry, jitter());
+            retry += 1;
+            tracing::info!(
+                endpoint,
+                retry,
+                delay_ms = delay.as_millis() as u64,
+                "retrying"
+            );
+            self.retries.push(format!(
+                "{} failed ({}), retry {}/{} in {:.1}s",
+                endpoint,
+                failure,
+                retry,
+                retries,
+                delay.as_secs_f64()
+            ));
+            tokio::time::sleep(delay).await;
+        };
 
+        let response = result.map_err(JoltError::Api)?;
         self.update_rate_limit(&response);
+        self.update_scopes(&response);
         self.check_response(response).await
     }
 
+    /// Send a request once, giving up on interactive requests without a
+    /// response within the interactive timeout.
+    async fn send_once(
+        &self,
+        endpoint: &str,
+        request: RequestBuilder,
+    ) -> Result<reqwest::Result<Response>> {
+        match self.interactive_timeout.filter(|_| self.interactive) {
+            Some(timeout) => tokio::time::timeout(timeout, request.send())
+                .await
+                .map_err(|_| {
+                    tracing::warn!(endpoint, "request timed out");
+                    JoltError::Timeout {
+                        endpoint: endpoint.to_string(),
+                        secs: timeout.as_secs(),
+                    }
+                }),
+            None => Ok(request.send().await),
+        }
+    }
+
     /// Update rate limit from response headers.
     fn update_rate_limit(&mut self, response: &Response) {
         if let Some(limit) = response
@@ -119,10 +400,30 @@ impl GitHubClient {
         }
     }
 
+    /// Update token scopes from response headers.
+    fn update_scopes(&mut self, response: &Response) {
+        if let Some(scopes) = response
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|v| v.to_str().ok())
+        {
+            self.scopes = Some(
+                scopes
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect(),
+            );
+        }
+    }
+
     /// Check response status and convert errors.
     async fn check_response(&self, response: Response) -> Result<Response> {
         match response.status() {
-            StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => Ok(response),
+            StatusCode::OK
+            | StatusCode::CREATED
+            | StatusCode::ACCEPTED
+            | StatusCode::NO_CONTENT => Ok(response),
             StatusCode::UNAUTHORIZED => Err(JoltError::Unauthorized),
             StatusCode::NOT_FOUND => {
                 let url = response.url().to_string();
@@ -133,21 +434,18 @@ impl GitHubClient {
                 if self.rate_limit.remaining == 0 {
                     let reset_at =
                         chrono::DateTime::from_timestamp(self.rate_limit.reset as i64, 0)
-                            .map(|dt| dt.format("%H:%M:%S").to_string())
-                            .unwrap_or_else(|| "unknown".to_string());
+                            .unwrap_or_else(chrono::Utc::now);
                     Err(JoltError::RateLimited { reset_at })
                 } else {
-                    Err(JoltError::Other(format!(
-                        "Forbidden: {}",
-                        response.text().await.unwrap_or_default()
-                    )))
+                    Err(JoltError::Forbidden(
+                        response.text().await.unwrap_or_default(),
+                    ))
                 }
             }
-            status => Err(JoltError::Other(format!(
-                "HTTP {}: {}",
+            status => Err(JoltError::Http {
                 status,
-                response.text().await.unwrap_or_default()
-            ))),
+                body: response.text().await.unwrap_or_default(),
+            }),
         }
     }
 }
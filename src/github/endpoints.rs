@@ -1,13 +1,20 @@
 // GitHub API endpoint functions.
 // Provides typed methods for fetching data from the GitHub REST API.
 
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
 use reqwest::Response;
 use serde::{Deserialize, de::DeserializeOwned};
 
 use crate::error::{JoltError, Result};
 
 use super::client::GitHubClient;
-use super::types::{Job, Owner, Repository, Runner, Workflow, WorkflowRun};
+use super::types::{
+    ActionsCache, ActionsPermissions, ActionsSecret, ActionsVariable, Annotation, Branch, CheckRun,
+    CheckState, Deployment, DeploymentState, DeploymentStatus, Environment, ForkPrApproval,
+    InboxPullRequest, InboxReason, Job, Owner, Repository, Runner, RunnerGroup, SelectedActions,
+    Workflow, WorkflowPermissions, WorkflowRun,
+};
 
 /// Parse JSON response with better error messages.
 async fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T> {
@@ -51,6 +58,194 @@ struct RunnersResponse {
     runners: Vec<Runner>,
 }
 
+/// Response wrapper for runner groups list.
+#[derive(Debug, Deserialize)]
+struct RunnerGroupsResponse {
+    total_count: u64,
+    runner_groups: Vec<RunnerGroup>,
+}
+
+/// Response wrapper for repositories allowed to use a runner group.
+#[derive(Debug, Deserialize)]
+struct GroupRepositoriesResponse {
+    total_count: u64,
+    repositories: Vec<Repository>,
+}
+
+/// Response wrapper for a commit's check runs.
+#[derive(Debug, Deserialize)]
+struct CheckRunsResponse {
+    total_count: u64,
+    check_runs: Vec<CheckRun>,
+}
+
+/// Response wrapper for Actions cache entries.
+#[derive(Debug, Deserialize)]
+struct ActionsCachesResponse {
+    total_count: u64,
+    actions_caches: Vec<ActionsCache>,
+}
+
+/// Response wrapper for environments list.
+#[derive(Debug, Deserialize)]
+struct EnvironmentsResponse {
+    total_count: u64,
+    environments: Vec<Environment>,
+}
+
+/// Response wrapper for Actions secrets.
+#[derive(Debug, Deserialize)]
+struct SecretsResponse {
+    total_count: u64,
+    secrets: Vec<ActionsSecret>,
+}
+
+/// Response wrapper for Actions variables.
+#[derive(Debug, Deserialize)]
+struct VariablesResponse {
+    total_count: u64,
+    variables: Vec<ActionsVariable>,
+}
+
+/// Query parameters for listing workflow runs.
+fn runs_params(
+    actor: Option<&str>,
+    branch: Option<&str>,
+    page: u32,
+    per_page: u32,
+) -> Vec<(&'static str, String)> {
+    let mut params = vec![
+        ("page", page.to_string()),
+        ("per_page", per_page.to_string()),
+    ];
+    if let Some(actor) = actor {
+        params.push(("actor", actor.to_string()));
+    }
+    if let Some(branch) = branch {
+        params.push(("branch", branch.to_string()));
+    }
+    params
+}
+
+/// Deployment statuses fetched at once for a list of deployments.
+const STATUS_CONCURRENCY: usize = 4;
+
+/// GraphQL query for open pull requests awaiting the user's review or authored
+/// by them, with the check status of each head commit.
+const INBOX_QUERY: &str = r#"
+fragment InboxFields on PullRequest {
+  number
+  title
+  url
+  isDraft
+  updatedAt
+  author { login }
+  repository { nameWithOwner }
+  commits(last: 1) { nodes { commit { statusCheckRollup { state } } } }
+}
+query {
+  reviewRequested: search(query: "is:pr is:open archived:false review-requested:@me", type: ISSUE, first: 50) {
+    nodes { ...InboxFields }
+  }
+  authored: search(query: "is:pr is:open archived:false author:@me", type: ISSUE, first: 50) {
+    nodes { ...InboxFields }
+  }
+}
+"#;
+
+/// Response wrapper for GraphQL queries.
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InboxData {
+    review_requested: SearchNodes,
+    authored: SearchNodes,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchNodes {
+    nodes: Vec<InboxNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InboxNode {
+    number: u64,
+    title: String,
+    url: String,
+    is_draft: bool,
+    updated_at: chrono::DateTime<chrono::Utc>,
+    author: Option<LoginNode>,
+    repository: RepositoryNode,
+    commits: Nodes<CommitNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginNode {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepositoryNode {
+    name_with_owner: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitNode {
+    commit: CommitStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitStatus {
+    status_check_rollup: Option<StateNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StateNode {
+    state: CheckState,
+}
+
+impl InboxNode {
+    fn into_pull_request(self, reason: InboxReason) -> InboxPullRequest {
+        let checks = self
+            .commits
+            .nodes
+            .into_iter()
+            .next()
+            .and_then(|node| node.commit.status_check_rollup)
+            .map(|rollup| rollup.state);
+        InboxPullRequest {
+            number: self.number,
+            title: self.title,
+            url: self.url,
+            repository: self.repository.name_with_owner,
+            author: self.author.map(|author| author.login),
+            is_draft: self.is_draft,
+            updated_at: self.updated_at,
+            checks,
+            reason,
+        }
+    }
+}
+
 impl GitHubClient {
     /// Get the authenticated user.
     pub async fn get_current_user(&mut self) -> Result<Owner> {
@@ -64,6 +259,12 @@ impl GitHubClient {
         parse_json(response).await
     }
 
+    /// Get a user or organization by login.
+    pub async fn get_owner(&mut self, login: &str) -> Result<Owner> {
+        let response = self.get(&format!("/users/{}", login)).await?;
+        parse_json(response).await
+    }
+
     /// Get repositories accessible to the authenticated user.
     pub async fn get_user_repos(&mut self, page: u32, per_page: u32) -> Result<Vec<Repository>> {
         let params = [
@@ -95,12 +296,49 @@ impl GitHubClient {
         parse_json(response).await
     }
 
+    /// Get the public repositories of a user or organization.
+    pub async fn get_owner_repos(
+        &mut self,
+        owner: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<Vec<Repository>> {
+        let params = [
+            ("sort", "updated"),
+            ("direction", "desc"),
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(&format!("/users/{}/repos", owner), &params)
+            .await?;
+        parse_json(response).await
+    }
+
     /// Get a specific repository.
     pub async fn get_repo(&mut self, owner: &str, repo: &str) -> Result<Repository> {
         let response = self.get(&format!("/repos/{}/{}", owner, repo)).await?;
         parse_json(response).await
     }
 
+    /// Get a page of a repository's branches.
+    pub async fn get_branches(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<Vec<Branch>> {
+        let params = [
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(&format!("/repos/{}/{}/branches", owner, repo), &params)
+            .await?;
+        parse_json(response).await
+    }
+
     /// Get workflows for a repository.
     pub async fn get_workflows(
         &mut self,
@@ -123,18 +361,98 @@ impl GitHubClient {
         Ok((wrapper.workflows, wrapper.total_count))
     }
 
-    /// Get workflow runs for a repository.
+    /// Get a specific workflow.
+    pub async fn get_workflow(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        workflow_id: u64,
+    ) -> Result<Workflow> {
+        let response = self
+            .get(&format!(
+                "/repos/{}/{}/actions/workflows/{}",
+                owner, repo, workflow_id
+            ))
+            .await?;
+        parse_json(response).await
+    }
+
+    /// Get the contents of a workflow file on the default branch.
+    pub async fn get_workflow_file(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> Result<String> {
+        let response = self
+            .get_raw(&format!("/repos/{}/{}/contents/{}", owner, repo, path))
+            .await?;
+        response.text().await.map_err(JoltError::Api)
+    }
+
+    /// Get workflow runs for a repository, optionally only those by `actor` or on `branch`.
     pub async fn get_workflow_runs(
         &mut self,
         owner: &str,
         repo: &str,
+        actor: Option<&str>,
+        branch: Option<&str>,
         page: u32,
         per_page: u32,
     ) -> Result<(Vec<WorkflowRun>, u64)> {
-        let params = [
-            ("page", &page.to_string()),
-            ("per_page", &per_page.to_string()),
-        ];
+        let params = runs_params(actor, branch, page, per_page);
+        let response = self
+            .get_with_params(&format!("/repos/{}/{}/actions/runs", owner, repo), &params)
+            .await?;
+        let wrapper: WorkflowRunsResponse = parse_json(response).await?;
+        Ok((wrapper.workflow_runs, wrapper.total_count))
+    }
+
+    /// Get the in-progress workflow runs of a repository.
+    pub async fn get_in_progress_runs(
+        &mut self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<WorkflowRun>> {
+        self.get_runs_with_status(owner, repo, "in_progress").await
+    }
+
+    /// Get the latest 100 workflow runs of a repository with a status or conclusion
+    /// such as "queued" or "completed".
+    pub async fn get_runs_with_status(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        status: &str,
+    ) -> Result<Vec<WorkflowRun>> {
+        let mut params = runs_params(None, None, 1, 100);
+        params.push(("status", status.to_string()));
+        let response = self
+            .get_with_params(&format!("/repos/{}/{}/actions/runs", owner, repo), &params)
+            .await?;
+        let wrapper: WorkflowRunsResponse = parse_json(response).await?;
+        Ok(wrapper.workflow_runs)
+    }
+
+    /// Get workflow runs for a repository created since `since`, optionally only
+    /// those that failed.
+    pub async fn get_workflow_runs_since(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        since: DateTime<Utc>,
+        failed_only: bool,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<WorkflowRun>, u64)> {
+        let mut params = runs_params(None, None, page, per_page);
+        params.push((
+            "created",
+            format!(">={}", since.format("%Y-%m-%dT%H:%M:%SZ")),
+        ));
+        if failed_only {
+            params.push(("status", "failure".to_string()));
+        }
         let response = self
             .get_with_params(&format!("/repos/{}/{}/actions/runs", owner, repo), &params)
             .await?;
@@ -142,19 +460,20 @@ impl GitHubClient {
         Ok((wrapper.workflow_runs, wrapper.total_count))
     }
 
-    /// Get workflow runs for a specific workflow.
+    /// Get workflow runs for a specific workflow, optionally only those by `actor`
+    /// or on `branch`.
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_workflow_runs_for_workflow(
         &mut self,
         owner: &str,
         repo: &str,
         workflow_id: u64,
+        actor: Option<&str>,
+        branch: Option<&str>,
         page: u32,
         per_page: u32,
     ) -> Result<(Vec<WorkflowRun>, u64)> {
-        let params = [
-            ("page", &page.to_string()),
-            ("per_page", &per_page.to_string()),
-        ];
+        let params = runs_params(actor, branch, page, per_page);
         let response = self
             .get_with_params(
                 &format!(
@@ -168,6 +487,101 @@ impl GitHubClient {
         Ok((wrapper.workflow_runs, wrapper.total_count))
     }
 
+    /// Get up to 100 runs of a workflow with the given status (e.g., "pending").
+    pub async fn get_workflow_runs_with_status(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        workflow_id: u64,
+        status: &str,
+    ) -> Result<Vec<WorkflowRun>> {
+        let mut params = runs_params(None, None, 1, 100);
+        params.push(("status", status.to_string()));
+        let response = self
+            .get_with_params(
+                &format!(
+                    "/repos/{}/{}/actions/workflows/{}/runs",
+                    owner, repo, workflow_id
+                ),
+                &params,
+            )
+            .await?;
+        let wrapper: WorkflowRunsResponse = parse_json(response).await?;
+        Ok(wrapper.workflow_runs)
+    }
+
+    /// Get deployment environments of a repository.
+    pub async fn get_environments(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<Environment>, u64)> {
+        let params = [
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(&format!("/repos/{}/{}/environments", owner, repo), &params)
+            .await?;
+        let wrapper: EnvironmentsResponse = parse_json(response).await?;
+        Ok((wrapper.environments, wrapper.total_count))
+    }
+
+    /// Get recent deployments to an environment, newest first, with their latest status.
+    pub async fn get_deployments(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        environment: &str,
+        per_page: u32,
+    ) -> Result<Vec<Deployment>> {
+        let params = [
+            ("environment", environment),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(&format!("/repos/{}/{}/deployments", owner, repo), &params)
+            .await?;
+        let mut deployments: Vec<Deployment> = parse_json(response).await?;
+        let ids: Vec<u64> = deployments.iter().map(|deployment| deployment.id).collect();
+        let states: Vec<_> = stream::iter(ids)
+            .map(|id| {
+                let mut fork = self.fork();
+                let (owner, repo) = (owner.to_string(), repo.to_string());
+                async move {
+                    let result = fork.get_deployment_state(&owner, &repo, id).await;
+                    (result, fork)
+                }
+            })
+            .buffered(STATUS_CONCURRENCY)
+            .collect()
+            .await;
+        for (deployment, (result, fork)) in deployments.iter_mut().zip(states) {
+            self.absorb(fork);
+            deployment.state = result?;
+        }
+        Ok(deployments)
+    }
+
+    /// Get the latest status of a deployment.
+    async fn get_deployment_state(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        id: u64,
+    ) -> Result<Option<DeploymentState>> {
+        let response = self
+            .get_with_params(
+                &format!("/repos/{}/{}/deployments/{}/statuses", owner, repo, id),
+                &[("per_page", "1")],
+            )
+            .await?;
+        let statuses: Vec<DeploymentStatus> = parse_json(response).await?;
+        Ok(statuses.first().map(|status| status.state))
+    }
+
     /// Get a specific workflow run.
     pub async fn get_workflow_run(
         &mut self,
@@ -184,6 +598,17 @@ impl GitHubClient {
         parse_json(response).await
     }
 
+    /// Get a single job by ID.
+    pub async fn get_job(&mut self, owner: &str, repo: &str, job_id: u64) -> Result<Job> {
+        let response = self
+            .get(&format!(
+                "/repos/{}/{}/actions/jobs/{}",
+                owner, repo, job_id
+            ))
+            .await?;
+        parse_json(response).await
+    }
+
     /// Get jobs for a workflow run.
     pub async fn get_jobs(
         &mut self,
@@ -207,9 +632,94 @@ impl GitHubClient {
         Ok((wrapper.jobs, wrapper.total_count))
     }
 
+    /// Get all jobs of a run's latest attempt, a page of 100 at a time.
+    pub async fn get_all_jobs(&mut self, owner: &str, repo: &str, run_id: u64) -> Result<Vec<Job>> {
+        let mut jobs = Vec::new();
+        for page in 1.. {
+            let (items, total_count) = self.get_jobs(owner, repo, run_id, page, 100).await?;
+            let done = items.is_empty();
+            jobs.extend(items);
+            if done || jobs.len() as u64 >= total_count {
+                break;
+            }
+        }
+        Ok(jobs)
+    }
+
+    /// Get the jobs of every attempt of a run, oldest attempt first.
+    pub async fn get_job_attempts(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<Vec<Job>> {
+        let mut jobs = Vec::new();
+        for page in 1.. {
+            let params = [
+                ("filter", "all".to_string()),
+                ("page", page.to_string()),
+                ("per_page", "100".to_string()),
+            ];
+            let response = self
+                .get_with_params(
+                    &format!("/repos/{}/{}/actions/runs/{}/jobs", owner, repo, run_id),
+                    &params,
+                )
+                .await?;
+            let wrapper: JobsResponse = parse_json(response).await?;
+            let done = wrapper.jobs.is_empty();
+            jobs.extend(wrapper.jobs);
+            if done || jobs.len() as u64 >= wrapper.total_count {
+                break;
+            }
+        }
+        jobs.sort_by_key(|job| job.run_attempt);
+        Ok(jobs)
+    }
+
     /// Get logs for a job (returns raw text).
     /// Returns a user-friendly error if logs are not available.
     pub async fn get_job_logs(&mut self, owner: &str, repo: &str, job_id: u64) -> Result<String> {
+        let response = self.open_job_logs(owner, repo, job_id).await?;
+        response.text().await.map_err(JoltError::Api)
+    }
+
+    /// Download the zip archive of every job's logs in an attempt of a run.
+    /// Returns a user-friendly error if the logs are gone.
+    pub async fn get_run_logs_archive(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+        attempt: u64,
+    ) -> Result<Vec<u8>> {
+        let result = self
+            .get(&format!(
+                "/repos/{}/{}/actions/runs/{}/attempts/{}/logs",
+                owner, repo, run_id, attempt
+            ))
+            .await;
+
+        match result {
+            Ok(response) => {
+                self.record_log_download();
+                Ok(response.bytes().await.map_err(JoltError::Api)?.to_vec())
+            }
+            Err(JoltError::NotFound(_)) => Err(JoltError::Other(
+                "Log archive not available (logs may have expired)".to_string(),
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Start downloading a job's logs, leaving the body to be read as it arrives.
+    /// Returns a user-friendly error if logs are not available.
+    pub async fn open_job_logs(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> Result<Response> {
         let result = self
             .get(&format!(
                 "/repos/{}/{}/actions/jobs/{}/logs",
@@ -219,8 +729,8 @@ impl GitHubClient {
 
         match result {
             Ok(response) => {
-                let logs = response.text().await.map_err(JoltError::Api)?;
-                Ok(logs)
+                self.record_log_download();
+                Ok(response)
             }
             Err(JoltError::NotFound(_)) => Err(JoltError::Other(
                 "Logs not available (may have expired or job is still running)".to_string(),
@@ -229,6 +739,62 @@ impl GitHubClient {
         }
     }
 
+    /// Get annotations for a job (a job's ID is also its check run ID).
+    pub async fn get_job_annotations(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> Result<Vec<Annotation>> {
+        let response = self
+            .get(&format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                owner, repo, job_id
+            ))
+            .await?;
+        parse_json(response).await
+    }
+
+    /// Get the latest check runs of a commit from every app that reports checks.
+    pub async fn get_check_runs(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<CheckRun>, u64)> {
+        let params = [
+            ("filter", "latest"),
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(
+                &format!("/repos/{}/{}/commits/{}/check-runs", owner, repo, sha),
+                &params,
+            )
+            .await?;
+        let wrapper: CheckRunsResponse = parse_json(response).await?;
+        Ok((wrapper.check_runs, wrapper.total_count))
+    }
+
+    /// Get a check run. An Actions job's check run shares the job's ID.
+    pub async fn get_check_run(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        check_run_id: u64,
+    ) -> Result<CheckRun> {
+        let response = self
+            .get(&format!(
+                "/repos/{}/{}/check-runs/{}",
+                owner, repo, check_run_id
+            ))
+            .await?;
+        parse_json(response).await
+    }
+
     /// Get runners for a repository (requires admin access).
     pub async fn get_runners(
         &mut self,
@@ -250,4 +816,281 @@ impl GitHubClient {
         let wrapper: RunnersResponse = parse_json(response).await?;
         Ok((wrapper.runners, wrapper.total_count))
     }
+
+    /// Get the runner groups of an organization (requires admin:org).
+    pub async fn get_runner_groups(
+        &mut self,
+        org: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<RunnerGroup>, u64)> {
+        let params = [
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(&format!("/orgs/{}/actions/runner-groups", org), &params)
+            .await?;
+        let wrapper: RunnerGroupsResponse = parse_json(response).await?;
+        Ok((wrapper.runner_groups, wrapper.total_count))
+    }
+
+    /// Get the runners in an organization runner group.
+    pub async fn get_runner_group_runners(
+        &mut self,
+        org: &str,
+        group_id: u64,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<Runner>, u64)> {
+        let params = [
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(
+                &format!("/orgs/{}/actions/runner-groups/{}/runners", org, group_id),
+                &params,
+            )
+            .await?;
+        let wrapper: RunnersResponse = parse_json(response).await?;
+        Ok((wrapper.runners, wrapper.total_count))
+    }
+
+    /// Get the repositories allowed to use a runner group with selected access.
+    pub async fn get_runner_group_repos(
+        &mut self,
+        org: &str,
+        group_id: u64,
+    ) -> Result<Vec<Repository>> {
+        let mut repos = Vec::new();
+        for page in 1.. {
+            let params = [("page", page.to_string()), ("per_page", "100".to_string())];
+            let response = self
+                .get_with_params(
+                    &format!(
+                        "/orgs/{}/actions/runner-groups/{}/repositories",
+                        org, group_id
+                    ),
+                    &params,
+                )
+                .await?;
+            let wrapper: GroupRepositoriesResponse = parse_json(response).await?;
+            let done = wrapper.repositories.is_empty();
+            repos.extend(wrapper.repositories);
+            if done || repos.len() as u64 >= wrapper.total_count {
+                break;
+            }
+        }
+        Ok(repos)
+    }
+
+    /// Get open pull requests awaiting the user's review or authored by them.
+    pub async fn get_pull_request_inbox(&mut self) -> Result<Vec<InboxPullRequest>> {
+        let body = serde_json::json!({ "query": INBOX_QUERY });
+        let response = self.post_json("/graphql", &body).await?;
+        let wrapper: GraphQlResponse<InboxData> = parse_json(response).await?;
+        if let Some(error) = wrapper.errors.first() {
+            return Err(JoltError::Other(format!(
+                "GraphQL error: {}",
+                error.message
+            )));
+        }
+        let Some(data) = wrapper.data else {
+            return Ok(Vec::new());
+        };
+
+        let mut prs: Vec<InboxPullRequest> = data
+            .review_requested
+            .nodes
+            .into_iter()
+            .map(|node| node.into_pull_request(InboxReason::ReviewRequested))
+            .collect();
+        for node in data.authored.nodes {
+            if !prs.iter().any(|pr| pr.url == node.url) {
+                prs.push(node.into_pull_request(InboxReason::Authored));
+            }
+        }
+        Ok(prs)
+    }
+
+    /// Re-run a workflow run, optionally with debug logging enabled.
+    pub async fn rerun_workflow_run(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+        enable_debug_logging: bool,
+    ) -> Result<()> {
+        let body = serde_json::json!({ "enable_debug_logging": enable_debug_logging });
+        self.post_json(
+            &format!("/repos/{}/{}/actions/runs/{}/rerun", owner, repo, run_id),
+            &body,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Cancel a workflow run.
+    pub async fn cancel_workflow_run(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<()> {
+        self.post_json(
+            &format!("/repos/{}/{}/actions/runs/{}/cancel", owner, repo, run_id),
+            &serde_json::json!({}),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Re-run the failed jobs of a workflow run.
+    pub async fn rerun_failed_jobs(&mut self, owner: &str, repo: &str, run_id: u64) -> Result<()> {
+        self.post_json(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
+                owner, repo, run_id
+            ),
+            &serde_json::json!({}),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Re-run a single job.
+    pub async fn rerun_job(&mut self, owner: &str, repo: &str, job_id: u64) -> Result<()> {
+        self.post_json(
+            &format!("/repos/{}/{}/actions/jobs/{}/rerun", owner, repo, job_id),
+            &serde_json::json!({}),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Get a repository's Actions cache entries, largest first.
+    pub async fn get_actions_caches(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<ActionsCache>, u64)> {
+        let params = [
+            ("sort", "size_in_bytes"),
+            ("direction", "desc"),
+            ("page", &page.to_string()),
+            ("per_page", &per_page.to_string()),
+        ];
+        let response = self
+            .get_with_params(
+                &format!("/repos/{}/{}/actions/caches", owner, repo),
+                &params,
+            )
+            .await?;
+        let wrapper: ActionsCachesResponse = parse_json(response).await?;
+        Ok((wrapper.actions_caches, wrapper.total_count))
+    }
+
+    /// Delete an Actions cache entry.
+    pub async fn delete_actions_cache(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        cache_id: u64,
+    ) -> Result<()> {
+        self.delete(&format!(
+            "/repos/{}/{}/actions/caches/{}",
+            owner, repo, cache_id
+        ))
+        .await?;
+        Ok(())
+    }
+
+    /// Get all Actions secret names listed at `path`: a repository's own
+    /// ("/repos/{owner}/{repo}/actions/secrets") or the organization secrets
+    /// shared with it ("/repos/{owner}/{repo}/actions/organization-secrets").
+    pub async fn get_actions_secrets(&mut self, path: &str) -> Result<Vec<ActionsSecret>> {
+        let mut secrets = Vec::new();
+        for page in 1.. {
+            let params = [("page", page.to_string()), ("per_page", "100".to_string())];
+            let response = self.get_with_params(path, &params).await?;
+            let wrapper: SecretsResponse = parse_json(response).await?;
+            let done = wrapper.secrets.is_empty();
+            secrets.extend(wrapper.secrets);
+            if done || secrets.len() as u64 >= wrapper.total_count {
+                break;
+            }
+        }
+        Ok(secrets)
+    }
+
+    /// Get all Actions variables listed at `path`: a repository's own
+    /// ("/repos/{owner}/{repo}/actions/variables") or the organization variables
+    /// shared with it ("/repos/{owner}/{repo}/actions/organization-variables").
+    pub async fn get_actions_variables(&mut self, path: &str) -> Result<Vec<ActionsVariable>> {
+        let mut variables = Vec::new();
+        for page in 1.. {
+            // Variables are listed at most 30 per page
+            let params = [("page", page.to_string()), ("per_page", "30".to_string())];
+            let response = self.get_with_params(path, &params).await?;
+            let wrapper: VariablesResponse = parse_json(response).await?;
+            let done = wrapper.variables.is_empty();
+            variables.extend(wrapper.variables);
+            if done || variables.len() as u64 >= wrapper.total_count {
+                break;
+            }
+        }
+        Ok(variables)
+    }
+
+    /// Get the Actions permissions of a repository ("/repos/{owner}/{repo}") or
+    /// organization ("/orgs/{org}").
+    pub async fn get_actions_permissions(&mut self, base: &str) -> Result<ActionsPermissions> {
+        let response = self.get(&format!("{}/actions/permissions", base)).await?;
+        parse_json(response).await
+    }
+
+    /// Get the actions allowed when a repository or organization allows selected actions.
+    pub async fn get_selected_actions(&mut self, base: &str) -> Result<SelectedActions> {
+        let response = self
+            .get(&format!("{}/actions/permissions/selected-actions", base))
+            .await?;
+        parse_json(response).await
+    }
+
+    /// Get the default GITHUB_TOKEN permissions of a repository or organization.
+    pub async fn get_workflow_permissions(&mut self, base: &str) -> Result<WorkflowPermissions> {
+        let response = self
+            .get(&format!("{}/actions/permissions/workflow", base))
+            .await?;
+        parse_json(response).await
+    }
+
+    /// Get the fork pull request approval policy of a repository or organization.
+    pub async fn get_fork_pr_approval(&mut self, base: &str) -> Result<ForkPrApproval> {
+        let response = self
+            .get(&format!(
+                "{}/actions/permissions/fork-pr-contributor-approval",
+                base
+            ))
+            .await?;
+        parse_json(response).await
+    }
+
+    /// Delete a workflow run and its logs.
+    pub async fn delete_workflow_run(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<()> {
+        self.delete(&format!(
+            "/repos/{}/{}/actions/runs/{}",
+            owner, repo, run_id
+        ))
+        .await?;
+        Ok(())
+    }
 }
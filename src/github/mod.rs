@@ -3,9 +3,14 @@
 
 #![allow(dead_code, unused_imports)]
 
+pub mod budget;
 pub mod client;
 pub mod endpoints;
+pub mod retry;
+pub mod scopes;
+pub mod timing;
 pub mod types;
 
 pub use client::GitHubClient;
+pub use scopes::Capability;
 pub use types::*;
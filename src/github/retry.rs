@@ -0,0 +1,87 @@
+// Retries of failed GitHub API requests.
+// Server errors and dropped connections are often momentary (e.g., behind a
+// flaky proxy), so requests are retried a few times with jittered backoff
+// before the failure is reported.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// Longest wait before a retry, however large the configured backoff.
+const MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// How many times and how far apart failed requests are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying).
+    pub retries: u32,
+    /// Delay before the first retry, doubling with each one after it.
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: 2,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (from 0), scaled by `jitter` in
+    /// [0, 1) to between half and all of the backoff so clients that failed
+    /// together don't retry together. The backoff is capped at `MAX_DELAY`,
+    /// which also keeps the scaling from overflowing.
+    pub fn delay(&self, retry: u32, jitter: f64) -> Duration {
+        let full = self
+            .backoff
+            .min(MAX_DELAY)
+            .saturating_mul(1 << retry.min(16))
+            .min(MAX_DELAY);
+        full.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// Whether a response status is worth retrying.
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error()
+}
+
+/// Whether a request error is worth retrying. Requests that aren't
+/// `idempotent` are only retried if they never reached the server, so their
+/// side effects don't happen twice.
+pub fn is_retryable_error(error: &reqwest::Error, idempotent: bool) -> bool {
+    error.is_connect() || (idempotent && (error.is_timeout() || error.is_request()))
+}
+
+/// Random value in [0, 1) for backoff jitter.
+pub fn jitter() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_with_jitter() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(0, 0.0), Duration::from_millis(250));
+        assert_eq!(policy.delay(0, 1.0), Duration::from_millis(500));
+        assert_eq!(policy.delay(2, 1.0), Duration::from_secs(2));
+        assert!((0.0..1.0).contains(&jitter()));
+
+        let huge = RetryPolicy {
+            retries: 2,
+            backoff: Duration::from_millis(u64::MAX),
+        };
+        assert_eq!(huge.delay(16, 1.0), MAX_DELAY);
+
+        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    }
+}
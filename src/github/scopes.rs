@@ -0,0 +1,63 @@
+// Token capabilities derived from OAuth scopes.
+// Lets the UI grey out actions a classic token isn't allowed to perform.
+
+/// Something the token needs a scope for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// List self-hosted runners and read private repositories.
+    ReadRunners,
+    /// Re-run, cancel, and delete runs.
+    WriteActions,
+    /// Read organization runners and secrets.
+    AdminOrg,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 3] = [
+        Capability::ReadRunners,
+        Capability::WriteActions,
+        Capability::AdminOrg,
+    ];
+
+    /// Classic token scopes that grant the capability (any one of them).
+    pub fn scopes(&self) -> &'static [&'static str] {
+        match self {
+            Capability::ReadRunners => &["repo"],
+            Capability::WriteActions => &["repo", "public_repo"],
+            Capability::AdminOrg => &["admin:org"],
+        }
+    }
+
+    /// What the capability allows, as shown to the user.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Capability::ReadRunners => "read runners",
+            Capability::WriteActions => "re-run and cancel runs",
+            Capability::AdminOrg => "read organization settings",
+        }
+    }
+
+    /// Whether a token with these scopes has the capability.
+    /// Fine-grained tokens report no scopes (None), so they're assumed capable.
+    pub fn granted_by(&self, scopes: Option<&[String]>) -> bool {
+        scopes.is_none_or(|scopes| {
+            self.scopes()
+                .iter()
+                .any(|scope| scopes.iter().any(|s| s == scope))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_granted_by() {
+        let public = vec!["public_repo".to_string(), "read:org".to_string()];
+        assert!(Capability::WriteActions.granted_by(Some(&public)));
+        assert!(!Capability::ReadRunners.granted_by(Some(&public)));
+        assert!(!Capability::AdminOrg.granted_by(Some(&public)));
+        assert!(Capability::AdminOrg.granted_by(None));
+    }
+}
@@ -0,0 +1,181 @@
+// Request latency per API endpoint.
+// Shows which endpoints are slow, to guide pagination and caching work.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::time::Duration;
+
+/// Latencies kept per endpoint; older ones are dropped.
+const SAMPLES_PER_ENDPOINT: usize = 100;
+
+/// Requests to an endpoint needed before it can be reported as slow.
+const SLOW_MIN_SAMPLES: usize = 5;
+
+/// Median latency above which an endpoint is reported as slow.
+pub const SLOW_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// Latency summary of one endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointTiming {
+    /// Path with owner, repo, and ids replaced (e.g., "/repos/:owner/:repo/actions/runs/:id/jobs").
+    pub endpoint: String,
+    pub count: usize,
+    pub p50: Duration,
+    pub p95: Duration,
+}
+
+impl EndpointTiming {
+    /// Whether requests to the endpoint are consistently slow.
+    pub fn is_slow(&self) -> bool {
+        self.count >= SLOW_MIN_SAMPLES && self.p50 >= SLOW_THRESHOLD
+    }
+}
+
+/// Recent request latencies, by endpoint.
+#[derive(Debug, Default)]
+pub struct EndpointTimings {
+    samples: BTreeMap<String, VecDeque<Duration>>,
+    /// Endpoints already reported as slow.
+    reported: HashSet<String>,
+}
+
+impl EndpointTimings {
+    /// Record the latency of a request to `path`.
+    pub fn record(&mut self, path: &str, elapsed: Duration) {
+        let samples = self.samples.entry(endpoint_pattern(path)).or_default();
+        if samples.len() == SAMPLES_PER_ENDPOINT {
+            samples.pop_front();
+        }
+        samples.push_back(elapsed);
+    }
+
+    /// Add the latencies recorded by another client, e.g. one used for concurrent requests.
+    pub fn merge(&mut self, other: EndpointTimings) {
+        for (endpoint, latencies) in other.samples {
+            let samples = self.samples.entry(endpoint).or_default();
+            for elapsed in latencies {
+                if samples.len() == SAMPLES_PER_ENDPOINT {
+                    samples.pop_front();
+                }
+                samples.push_back(elapsed);
+            }
+        }
+    }
+
+    /// Latency summaries, slowest p95 first.
+    pub fn summary(&self) -> Vec<EndpointTiming> {
+        let mut summary: Vec<EndpointTiming> = self
+            .samples
+            .iter()
+            .map(|(endpoint, samples)| {
+                let mut sorted: Vec<Duration> = samples.iter().copied().collect();
+                sorted.sort();
+                EndpointTiming {
+                    endpoint: endpoint.clone(),
+                    count: sorted.len(),
+                    p50: percentile(&sorted, 50),
+                    p95: percentile(&sorted, 95),
+                }
+            })
+            .collect();
+        summary.sort_by_key(|timing| Reverse(timing.p95));
+        summary
+    }
+
+    /// Endpoints that became consistently slow since the last call.
+    pub fn take_newly_slow(&mut self) -> Vec<EndpointTiming> {
+        let slow: Vec<EndpointTiming> = self
+            .summary()
+            .into_iter()
+            .filter(|timing| timing.is_slow() && !self.reported.contains(&timing.endpoint))
+            .collect();
+        self.reported
+            .extend(slow.iter().map(|timing| timing.endpoint.clone()));
+        slow
+    }
+}
+
+/// Nearest-rank percentile of sorted latencies.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Endpoint of a request path, with owner, repo, and numeric ids replaced.
+pub fn endpoint_pattern(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').collect();
+    let root = segments.get(1).copied();
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| match (root, i) {
+            (Some("repos" | "orgs" | "users"), 2) => ":owner",
+            (Some("repos"), 3) => ":repo",
+            _ if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) => ":id",
+            _ => segment,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_pattern() {
+        assert_eq!(
+            endpoint_pattern("/repos/phatblat/jolt/actions/runs/42/jobs"),
+            "/repos/:owner/:repo/actions/runs/:id/jobs"
+        );
+        assert_eq!(
+            endpoint_pattern("/orgs/acme/actions/secrets"),
+            "/orgs/:owner/actions/secrets"
+        );
+        assert_eq!(endpoint_pattern("/user/orgs"), "/user/orgs");
+    }
+
+    #[test]
+    fn test_reports_consistently_slow_endpoints_once() {
+        let mut timings = EndpointTimings::default();
+        for run_id in 1..=SLOW_MIN_SAMPLES as u64 {
+            timings.record(
+                &format!("/repos/o/r/actions/runs/{}/jobs", run_id),
+                Duration::from_secs(3),
+            );
+            timings.record("/repos/o/r/actions/workflows", Duration::from_millis(200));
+        }
+        timings.record("/repos/o/r/actions/workflows", Duration::from_secs(5));
+
+        let summary = timings.summary();
+        assert_eq!(summary[0].endpoint, "/repos/:owner/:repo/actions/workflows");
+        assert_eq!(summary[0].p50, Duration::from_millis(200));
+        assert_eq!(summary[0].p95, Duration::from_secs(5));
+
+        let slow = timings.take_newly_slow();
+        assert_eq!(slow.len(), 1);
+        assert_eq!(
+            slow[0].endpoint,
+            "/repos/:owner/:repo/actions/runs/:id/jobs"
+        );
+        assert!(timings.take_newly_slow().is_empty());
+    }
+
+    #[test]
+    fn test_merge_adds_samples() {
+        let mut timings = EndpointTimings::default();
+        timings.record("/repos/o/r/actions/runs/1/jobs", Duration::from_millis(100));
+        let mut other = EndpointTimings::default();
+        other.record("/repos/o/r/actions/runs/2/jobs", Duration::from_millis(300));
+        other.record("/user", Duration::from_millis(50));
+        timings.merge(other);
+
+        let summary = timings.summary();
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].count, 2);
+        assert_eq!(summary[0].p95, Duration::from_millis(300));
+    }
+}
@@ -36,6 +36,17 @@ pub struct Repository {
     pub description: Option<String>,
     pub updated_at: DateTime<Utc>,
     pub pushed_at: Option<DateTime<Utc>>,
+    /// Branch the repository's pull requests merge into (e.g., "main").
+    #[serde(default)]
+    pub default_branch: Option<String>,
+}
+
+/// Branch of a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Branch {
+    pub name: String,
+    #[serde(default)]
+    pub protected: bool,
 }
 
 /// GitHub Actions workflow definition.
@@ -74,8 +85,20 @@ pub struct WorkflowRun {
     pub workflow_id: u64,
     pub head_branch: Option<String>,
     pub head_sha: String,
+    /// Event that triggered the run (e.g., "push", "pull_request", "schedule").
+    #[serde(default)]
+    pub event: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the current attempt started.
+    #[serde(default)]
+    pub run_started_at: Option<DateTime<Utc>>,
+    /// User whose push or pull request started the run.
+    #[serde(default)]
+    pub actor: Option<Owner>,
+    /// User who started the current attempt, which differs from `actor` on re-runs.
+    #[serde(default)]
+    pub triggering_actor: Option<Owner>,
     pub html_url: String,
     #[serde(default)]
     pub pull_requests: Vec<PullRequestRef>,
@@ -133,15 +156,27 @@ pub struct GitRef {
 pub struct Job {
     pub id: u64,
     pub run_id: u64,
+    #[serde(default)]
+    pub run_attempt: Option<u64>,
+    #[serde(default)]
+    pub head_sha: String,
+    #[serde(default)]
+    pub head_branch: Option<String>,
     pub name: String,
     pub status: RunStatus,
     pub conclusion: Option<RunConclusion>,
+    /// When the job was queued.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub html_url: String,
     #[serde(default)]
     pub steps: Vec<Step>,
     pub runner_name: Option<String>,
+    /// Runner labels requested with `runs-on`.
+    #[serde(default)]
+    pub labels: Vec<String>,
 }
 
 /// Step within a job.
@@ -151,6 +186,200 @@ pub struct Step {
     pub status: RunStatus,
     pub conclusion: Option<RunConclusion>,
     pub number: u64,
+    #[serde(default)]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Check-run annotation (an error or warning reported by a job).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Annotation {
+    pub path: String,
+    pub start_line: u64,
+    pub annotation_level: AnnotationLevel,
+    pub message: String,
+    pub title: Option<String>,
+}
+
+/// Annotation severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationLevel {
+    Notice,
+    Warning,
+    Failure,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Entry in a repository's GitHub Actions cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsCache {
+    pub id: u64,
+    pub key: String,
+    /// Git ref the cache was created for (e.g., "refs/heads/main").
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub size_in_bytes: u64,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Check run reported for a commit, by GitHub Actions or another app
+/// (e.g., an external CI service or code scanning).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub name: String,
+    pub status: RunStatus,
+    pub conclusion: Option<RunConclusion>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub html_url: Option<String>,
+    /// Page on the reporting app's own site, if it links one.
+    pub details_url: Option<String>,
+    pub app: Option<CheckApp>,
+    /// What the check reported; for Actions jobs, the job summary.
+    #[serde(default)]
+    pub output: Option<CheckRunOutput>,
+}
+
+/// Output of a check run, written in Markdown.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckRunOutput {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub text: Option<String>,
+}
+
+/// App that reported a check run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckApp {
+    pub slug: String,
+    pub name: String,
+}
+
+/// Actions secret; GitHub never returns secret values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsSecret {
+    pub name: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Actions configuration variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsVariable {
+    pub name: String,
+    pub value: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Whether Actions is enabled for a repository or organization, and which actions may run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsPermissions {
+    /// Repository setting; organizations report `enabled_repositories` instead.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Which of an organization's repositories may use Actions ("all", "none", "selected").
+    #[serde(default)]
+    pub enabled_repositories: Option<String>,
+    /// "all", "local_only", or "selected"; absent when Actions is disabled.
+    #[serde(default)]
+    pub allowed_actions: Option<String>,
+}
+
+/// Actions allowed when `allowed_actions` is "selected".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedActions {
+    #[serde(default)]
+    pub github_owned_allowed: bool,
+    #[serde(default)]
+    pub verified_allowed: bool,
+    /// Allowed action and reusable workflow patterns (e.g., "octo-org/*").
+    #[serde(default)]
+    pub patterns_allowed: Vec<String>,
+}
+
+/// Permissions of the GITHUB_TOKEN given to workflows by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowPermissions {
+    /// "read" or "write".
+    pub default_workflow_permissions: String,
+    #[serde(default)]
+    pub can_approve_pull_request_reviews: bool,
+}
+
+/// Which outside contributors need approval before their fork pull requests run workflows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForkPrApproval {
+    pub approval_policy: String,
+}
+
+/// Deployment environment of a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Environment {
+    pub id: u64,
+    pub name: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub protection_rules: Vec<ProtectionRule>,
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+/// Rule gating deployments to an environment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtectionRule {
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    /// Minutes to wait before deploying (wait_timer rules).
+    pub wait_timer: Option<u64>,
+    /// Required reviewers (required_reviewers rules).
+    #[serde(default)]
+    pub reviewers: Vec<serde_json::Value>,
+}
+
+/// Branches allowed to deploy to an environment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentBranchPolicy {
+    pub protected_branches: bool,
+    pub custom_branch_policies: bool,
+}
+
+/// Deployment of a ref to an environment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deployment {
+    pub id: u64,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub environment: String,
+    pub creator: Option<Owner>,
+    pub created_at: DateTime<Utc>,
+    /// Latest status, fetched separately from the deployment.
+    #[serde(default)]
+    pub state: Option<DeploymentState>,
+}
+
+/// Deployment status state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentState {
+    Error,
+    Failure,
+    Inactive,
+    Pending,
+    Success,
+    Queued,
+    InProgress,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status update of a deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentStatus {
+    pub state: DeploymentState,
 }
 
 /// Self-hosted runner.
@@ -184,6 +413,72 @@ pub struct RunnerLabel {
     pub label_type: Option<String>,
 }
 
+/// Organization runner group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunnerGroup {
+    pub id: u64,
+    pub name: String,
+    pub visibility: RunnerGroupVisibility,
+    #[serde(default)]
+    pub default: bool,
+    /// Shared with the organization by its enterprise.
+    #[serde(default)]
+    pub inherited: bool,
+    #[serde(default)]
+    pub allows_public_repositories: bool,
+    #[serde(default)]
+    pub restricted_to_workflows: bool,
+    #[serde(default)]
+    pub selected_workflows: Vec<String>,
+}
+
+/// Repositories allowed to use a runner group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunnerGroupVisibility {
+    All,
+    Selected,
+    Private,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Open pull request awaiting the user's attention.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboxPullRequest {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    /// Repository as "owner/repo".
+    pub repository: String,
+    pub author: Option<String>,
+    pub is_draft: bool,
+    pub updated_at: DateTime<Utc>,
+    /// Combined check status of the head commit (None if it has no checks).
+    pub checks: Option<CheckState>,
+    pub reason: InboxReason,
+}
+
+/// Combined status of a commit's checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CheckState {
+    Success,
+    Failure,
+    Error,
+    Pending,
+    Expected,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Why a pull request is in the inbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InboxReason {
+    ReviewRequested,
+    Authored,
+}
+
 /// Paginated list response wrapper.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListResponse<T> {
@@ -0,0 +1,731 @@
+// Key bindings for user actions.
+// BINDINGS is the one table keys are decoded from in the list and log views,
+// and the status bar hints show the keys it binds. Each view level declares
+// the actions it supports for those hints.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::Tab;
+use crate::github::Capability;
+use crate::state::{RunnersViewLevel, ViewLevel};
+
+/// A user action bound to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Navigate,
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+    SwitchTab,
+    NextTab,
+    PrevTab,
+    SelectTab(Tab),
+    Refresh,
+    HardRefresh,
+    Scroll,
+    Page,
+    PageUp,
+    PageDown,
+    Jump,
+    Top,
+    Bottom,
+    Search,
+    NextMatch,
+    PrevMatch,
+    CancelSearch,
+    ConfirmSearch,
+    Filter,
+    ConfirmFilter,
+    ClearFilter,
+    OpenInBrowser,
+    Favorite,
+    HideRepo,
+    UnhideRepos,
+    ShowPath,
+    Recent,
+    Workspaces,
+    Permalink,
+    TogglePreview,
+    Mark,
+    Bulk,
+    Export,
+    MyRuns,
+    Branch,
+    AllBranches,
+    GroupRuns,
+    Fold,
+    FailedVariants,
+    DebugRerun,
+    FlakyReport,
+    RunQueue,
+    Schedule,
+    Environments,
+    Caches,
+    Secrets,
+    Policy,
+    Checks,
+    LogArchive,
+    JobSummary,
+    DiagLogs,
+    RunnerGroups,
+    FocusAnnotations,
+    FocusTests,
+    Timestamps,
+    Bookmark,
+    NextBookmark,
+    PrevBookmark,
+    Copy,
+    DebugLog,
+    Settings,
+    Help,
+    Quit,
+}
+
+impl Action {
+    /// Actions available everywhere, shown after the view's own actions.
+    pub const GLOBAL: [Action; 3] = [Action::Settings, Action::Help, Action::Quit];
+
+    /// Key (or keys) bound to this action, as shown to the user.
+    pub fn key(&self) -> String {
+        match self {
+            // Hints standing for several actions show each one's key
+            Action::Navigate => bound_keys(&[Action::Up, Action::Down], ""),
+            Action::Scroll => {
+                bound_keys(&[Action::Up, Action::Down, Action::Left, Action::Right], "")
+            }
+            Action::Page => bound_keys(&[Action::PageUp, Action::PageDown], "/"),
+            Action::Jump => bound_keys(&[Action::Top, Action::Bottom], "/"),
+            Action::SwitchTab => bound_keys(&[Action::NextTab], ""),
+            Action::NextMatch => bound_keys(&[Action::NextMatch, Action::PrevMatch], "/"),
+            Action::NextBookmark => bound_keys(&[Action::PrevBookmark, Action::NextBookmark], "/"),
+            // Keys that act differently in some views
+            Action::Filter => bound_keys(&[Action::Search], ""),
+            Action::Bookmark => bound_keys(&[Action::MyRuns], ""),
+            // Text inputs read keys directly
+            Action::CancelSearch | Action::ClearFilter => "Esc".to_string(),
+            Action::ConfirmSearch | Action::ConfirmFilter => "↵".to_string(),
+            action => bound_keys(&[*action], ""),
+        }
+    }
+
+    /// Action bound to a key press in the list and log views, if any.
+    pub fn from_key(key: KeyEvent) -> Option<Action> {
+        BINDINGS
+            .iter()
+            .find(|(binding, _)| binding.matches(key))
+            .map(|(_, action)| *action)
+    }
+
+    /// Short description for the status bar.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::Navigate => "Navigate",
+            Action::Up => "Up",
+            Action::Down => "Down",
+            Action::Left => "Left",
+            Action::Right => "Right",
+            Action::Select => "Select",
+            Action::Back => "Back",
+            Action::SwitchTab => "Switch",
+            Action::NextTab => "Next tab",
+            Action::PrevTab => "Previous tab",
+            Action::SelectTab(_) => "Tab",
+            Action::Refresh => "Refresh",
+            Action::HardRefresh => "Hard refresh",
+            Action::Scroll => "Scroll",
+            Action::Page => "Page",
+            Action::PageUp => "Page up",
+            Action::PageDown => "Page down",
+            Action::Jump => "Jump",
+            Action::Top => "Top",
+            Action::Bottom => "Bottom",
+            Action::Search => "Search",
+            Action::NextMatch => "Next/Prev",
+            Action::PrevMatch => "Previous match",
+            Action::CancelSearch => "Cancel",
+            Action::ConfirmSearch => "Search",
+            Action::Filter => "Filter",
+            Action::ConfirmFilter => "Done",
+            Action::ClearFilter => "Clear",
+            Action::OpenInBrowser => "Open",
+            Action::Favorite => "Favorite",
+            Action::HideRepo => "Hide",
+            Action::UnhideRepos => "Unhide",
+            Action::ShowPath => "Path",
+            Action::Recent => "Recent",
+            Action::Workspaces => "Workspaces",
+            Action::Permalink => "Open URL",
+            Action::TogglePreview => "Preview",
+            Action::Mark => "Mark",
+            Action::Bulk => "Bulk",
+            Action::Export => "Export",
+            Action::MyRuns => "Mine",
+            Action::Branch => "Branch",
+            Action::AllBranches => "All branches",
+            Action::GroupRuns => "Group",
+            Action::Fold => "Fold",
+            Action::FailedVariants => "Failed variants",
+            Action::DebugRerun => "Debug re-run",
+            Action::FlakyReport => "Flaky",
+            Action::RunQueue => "Queue",
+            Action::Schedule => "Schedule",
+            Action::Environments => "Environments",
+            Action::Caches => "Caches",
+            Action::Secrets => "Secrets",
+            Action::Policy => "Policy",
+            Action::Checks => "Checks",
+            Action::LogArchive => "Log archive",
+            Action::JobSummary => "Summary",
+            Action::DiagLogs => "Diag logs",
+            Action::RunnerGroups => "Groups",
+            Action::FocusAnnotations => "Annotations",
+            Action::FocusTests => "Tests",
+            Action::Timestamps => "Timestamps",
+            Action::Bookmark => "Bookmark",
+            Action::NextBookmark => "Bookmarks",
+            Action::PrevBookmark => "Previous bookmark",
+            Action::Copy => "Copy",
+            Action::DebugLog => "Debug log",
+            Action::Settings => "Settings",
+            Action::Help => "Help",
+            Action::Quit => "Quit",
+        }
+    }
+
+    /// Capability the token needs for this action, if any.
+    pub fn capability(&self) -> Option<Capability> {
+        match self {
+            Action::DebugRerun | Action::Bulk => Some(Capability::WriteActions),
+            Action::RunnerGroups => Some(Capability::AdminOrg),
+            _ => None,
+        }
+    }
+}
+
+/// Key press an action is bound to. Shifted characters are bound as typed
+/// (e.g., 'R'), so only Ctrl is matched separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyBinding {
+    const fn key(code: KeyCode) -> Self {
+        Self { code, ctrl: false }
+    }
+
+    const fn char(c: char) -> Self {
+        Self::key(KeyCode::Char(c))
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: true,
+        }
+    }
+
+    /// Whether a key press is this binding. Unbound Ctrl keys don't fall
+    /// through to the plain key.
+    pub fn matches(&self, key: KeyEvent) -> bool {
+        key.code == self.code && key.modifiers.contains(KeyModifiers::CONTROL) == self.ctrl
+    }
+
+    /// The key as shown to the user (e.g., "↵", "^r", "R").
+    pub fn display(&self) -> String {
+        let key = match self.code {
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Enter => "↵".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "S-Tab".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            code => format!("{:?}", code),
+        };
+        if self.ctrl { format!("^{}", key) } else { key }
+    }
+}
+
+/// Keys of the list and log views and the actions they're bound to. An
+/// action's first key is the one shown in hints; each key is bound once.
+pub const BINDINGS: &[(KeyBinding, Action)] = &[
+    (KeyBinding::key(KeyCode::Up), Action::Up),
+    (KeyBinding::char('k'), Action::Up),
+    (KeyBinding::key(KeyCode::Down), Action::Down),
+    (KeyBinding::char('j'), Action::Down),
+    (KeyBinding::key(KeyCode::Left), Action::Left),
+    (KeyBinding::char('h'), Action::Left),
+    (KeyBinding::key(KeyCode::Right), Action::Right),
+    (KeyBinding::char('l'), Action::Right),
+    (KeyBinding::key(KeyCode::PageUp), Action::PageUp),
+    (KeyBinding::ctrl('u'), Action::PageUp),
+    (KeyBinding::ctrl('b'), Action::PageUp),
+    (KeyBinding::key(KeyCode::PageDown), Action::PageDown),
+    (KeyBinding::ctrl('d'), Action::PageDown),
+    (KeyBinding::ctrl('f'), Action::PageDown),
+    (KeyBinding::key(KeyCode::Home), Action::Top),
+    (KeyBinding::char('g'), Action::Top),
+    (KeyBinding::key(KeyCode::End), Action::Bottom),
+    (KeyBinding::char('G'), Action::Bottom),
+    (KeyBinding::key(KeyCode::Enter), Action::Select),
+    (KeyBinding::key(KeyCode::Esc), Action::Back),
+    (KeyBinding::key(KeyCode::Tab), Action::NextTab),
+    (KeyBinding::key(KeyCode::BackTab), Action::PrevTab),
+    (KeyBinding::char('0'), Action::SelectTab(Tab::Home)),
+    (KeyBinding::char('1'), Action::SelectTab(Tab::Runners)),
+    (KeyBinding::char('2'), Action::SelectTab(Tab::Workflows)),
+    (KeyBinding::char('3'), Action::SelectTab(Tab::Inbox)),
+    (KeyBinding::char('4'), Action::SelectTab(Tab::Console)),
+    (KeyBinding::char('5'), Action::SelectTab(Tab::Live)),
+    (KeyBinding::char('r'), Action::Refresh),
+    (KeyBinding::char('H'), Action::HardRefresh),
+    (KeyBinding::char('/'), Action::Search),
+    (KeyBinding::char('n'), Action::NextMatch),
+    (KeyBinding::char('N'), Action::PrevMatch),
+    (KeyBinding::char('o'), Action::OpenInBrowser),
+    (KeyBinding::char('f'), Action::Favorite),
+    (KeyBinding::char('x'), Action::HideRepo),
+    (KeyBinding::char('X'), Action::UnhideRepos),
+    (KeyBinding::char('b'), Action::ShowPath),
+    (KeyBinding::ctrl('r'), Action::Recent),
+    (KeyBinding::char('W'), Action::Workspaces),
+    (KeyBinding::char('p'), Action::Permalink),
+    (KeyBinding::char('|'), Action::TogglePreview),
+    (KeyBinding::char(' '), Action::Mark),
+    (KeyBinding::char('B'), Action::Bulk),
+    (KeyBinding::char('E'), Action::Export),
+    (KeyBinding::char('m'), Action::MyRuns),
+    (KeyBinding::char('s'), Action::Branch),
+    (KeyBinding::char('A'), Action::AllBranches),
+    (KeyBinding::char('v'), Action::GroupRuns),
+    (KeyBinding::char('z'), Action::Fold),
+    (KeyBinding::char('V'), Action::FailedVariants),
+    (KeyBinding::char('D'), Action::DebugRerun),
+    (KeyBinding::char('F'), Action::FlakyReport),
+    (KeyBinding::char('c'), Action::RunQueue),
+    (KeyBinding::char('U'), Action::Schedule),
+    (KeyBinding::char('e'), Action::Environments),
+    (KeyBinding::char('C'), Action::Caches),
+    (KeyBinding::char('S'), Action::Secrets),
+    (KeyBinding::char('P'), Action::Policy),
+    (KeyBinding::char('K'), Action::Checks),
+    (KeyBinding::char('L'), Action::LogArchive),
+    (KeyBinding::char('u'), Action::JobSummary),
+    (KeyBinding::char('d'), Action::DiagLogs),
+    (KeyBinding::char('R'), Action::RunnerGroups),
+    (KeyBinding::char('a'), Action::FocusAnnotations),
+    (KeyBinding::char('t'), Action::FocusTests),
+    (KeyBinding::char('T'), Action::Timestamps),
+    (KeyBinding::char('['), Action::PrevBookmark),
+    (KeyBinding::char(']'), Action::NextBookmark),
+    (KeyBinding::char('y'), Action::Copy),
+    (KeyBinding::char('~'), Action::DebugLog),
+    (KeyBinding::char(','), Action::Settings),
+    (KeyBinding::char('?'), Action::Help),
+    (KeyBinding::char('q'), Action::Quit),
+];
+
+/// First keys bound to `actions`, as shown to the user, joined by `separator`.
+fn bound_keys(actions: &[Action], separator: &str) -> String {
+    actions
+        .iter()
+        .filter_map(|action| BINDINGS.iter().find(|(_, bound)| bound == action))
+        .map(|(binding, _)| binding.display())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Actions while typing a search query.
+pub const SEARCH_INPUT_ACTIONS: &[Action] = &[Action::ConfirmSearch, Action::CancelSearch];
+
+/// Actions while typing a list filter.
+pub const FILTER_INPUT_ACTIONS: &[Action] =
+    &[Action::Navigate, Action::ConfirmFilter, Action::ClearFilter];
+
+/// Actions in the log viewer.
+const LOG_ACTIONS: &[Action] = &[
+    Action::Scroll,
+    Action::Page,
+    Action::Jump,
+    Action::Search,
+    Action::Timestamps,
+    Action::Bookmark,
+    Action::NextBookmark,
+    Action::Copy,
+    Action::JobSummary,
+    Action::Back,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::Refresh,
+];
+
+/// Actions in the Workflows tab log viewer, which also lists annotations.
+const WORKFLOW_LOG_ACTIONS: &[Action] = &[
+    Action::Scroll,
+    Action::FocusAnnotations,
+    Action::FocusTests,
+    Action::Page,
+    Action::Jump,
+    Action::Search,
+    Action::Timestamps,
+    Action::Bookmark,
+    Action::NextBookmark,
+    Action::Copy,
+    Action::JobSummary,
+    Action::Back,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::Refresh,
+];
+
+/// Actions for top-level lists, which have nothing to go back to.
+const ROOT_LIST_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Favorite,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for the Runners tab repositories list.
+const RUNNER_REPOS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Favorite,
+    Action::HideRepo,
+    Action::UnhideRepos,
+    Action::RunnerGroups,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for lists whose items can be favorited.
+const FAVORITE_LIST_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::Favorite,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for a repository's workflows list.
+const WORKFLOWS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::Favorite,
+    Action::Schedule,
+    Action::RunQueue,
+    Action::Environments,
+    Action::Caches,
+    Action::Secrets,
+    Action::Policy,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for a repository's environments list.
+const ENVIRONMENTS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for an environment's deployments list.
+const DEPLOYMENTS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Filter,
+    Action::Back,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for the self-hosted runners list.
+const RUNNERS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::Favorite,
+    Action::HideRepo,
+    Action::DiagLogs,
+    Action::RunnerGroups,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for an organization's runner groups list.
+const RUNNER_GROUPS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for the runners in a runner group.
+const GROUP_RUNNERS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Filter,
+    Action::Back,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for Jobs lists.
+const JOBS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::Mark,
+    Action::Bulk,
+    Action::Export,
+    Action::GroupRuns,
+    Action::Fold,
+    Action::FailedVariants,
+    Action::Checks,
+    Action::LogArchive,
+    Action::JobSummary,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for the workflow Runs list, which can show a preview pane.
+const RUNS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::TogglePreview,
+    Action::Mark,
+    Action::Bulk,
+    Action::Export,
+    Action::MyRuns,
+    Action::Branch,
+    Action::AllBranches,
+    Action::GroupRuns,
+    Action::Fold,
+    Action::DebugRerun,
+    Action::FlakyReport,
+    Action::RunQueue,
+    Action::Checks,
+    Action::LogArchive,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions for the Runners tab Runs list.
+const RUNNER_RUNS_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::Back,
+    Action::Mark,
+    Action::Bulk,
+    Action::Export,
+    Action::MyRuns,
+    Action::Branch,
+    Action::AllBranches,
+    Action::GroupRuns,
+    Action::Fold,
+    Action::DebugRerun,
+    Action::Checks,
+    Action::LogArchive,
+    Action::OpenInBrowser,
+    Action::ShowPath,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions in the pull request inbox.
+pub const INBOX_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Filter,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions in the Live tab of in-progress runs.
+pub const LIVE_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions in the Home dashboard.
+pub const HOME_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::Filter,
+    Action::OpenInBrowser,
+    Action::SwitchTab,
+    Action::Refresh,
+];
+
+/// Actions in the console tab.
+pub const CONSOLE_ACTIONS: &[Action] = &[
+    Action::Navigate,
+    Action::Select,
+    Action::DebugLog,
+    Action::SwitchTab,
+];
+
+impl ViewLevel {
+    /// Actions available at this level.
+    pub fn actions(&self) -> &'static [Action] {
+        match self {
+            ViewLevel::Owners => ROOT_LIST_ACTIONS,
+            ViewLevel::Repositories { .. } => FAVORITE_LIST_ACTIONS,
+            ViewLevel::Workflows { .. } => WORKFLOWS_ACTIONS,
+            ViewLevel::Environments { .. } => ENVIRONMENTS_ACTIONS,
+            ViewLevel::Deployments { .. } => DEPLOYMENTS_ACTIONS,
+            ViewLevel::Runs { .. } => RUNS_ACTIONS,
+            ViewLevel::Jobs { .. } => JOBS_ACTIONS,
+            ViewLevel::Logs { .. } => WORKFLOW_LOG_ACTIONS,
+        }
+    }
+}
+
+impl RunnersViewLevel {
+    /// Actions available at this level.
+    pub fn actions(&self) -> &'static [Action] {
+        match self {
+            RunnersViewLevel::Repositories => RUNNER_REPOS_ACTIONS,
+            RunnersViewLevel::Runners { .. } => RUNNERS_ACTIONS,
+            RunnersViewLevel::Runs { .. } => RUNNER_RUNS_ACTIONS,
+            RunnersViewLevel::Jobs { .. } => JOBS_ACTIONS,
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => LOG_ACTIONS,
+            RunnersViewLevel::RunnerGroups { .. } => RUNNER_GROUPS_ACTIONS,
+            RunnersViewLevel::GroupRunners { .. } => GROUP_RUNNERS_ACTIONS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_vim_keys_match_arrows() {
+        for (vim, arrow) in [
+            ('k', KeyCode::Up),
+            ('j', KeyCode::Down),
+            ('h', KeyCode::Left),
+            ('l', KeyCode::Right),
+            ('g', KeyCode::Home),
+            ('G', KeyCode::End),
+        ] {
+            assert_eq!(
+                Action::from_key(key(KeyCode::Char(vim), KeyModifiers::NONE)),
+                Action::from_key(key(arrow, KeyModifiers::NONE))
+            );
+        }
+    }
+
+    #[test]
+    fn test_control_and_shifted_keys() {
+        assert_eq!(
+            Action::from_key(key(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            Some(Action::PageDown)
+        );
+        assert_eq!(
+            Action::from_key(key(KeyCode::Char('r'), KeyModifiers::CONTROL)),
+            Some(Action::Recent)
+        );
+        // Unbound Ctrl keys don't fall through to the plain key
+        assert_eq!(
+            Action::from_key(key(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+            None
+        );
+        assert_eq!(
+            Action::from_key(key(KeyCode::Char('H'), KeyModifiers::SHIFT)),
+            Some(Action::HardRefresh)
+        );
+        assert_eq!(
+            Action::from_key(key(KeyCode::Char('3'), KeyModifiers::NONE)),
+            Some(Action::SelectTab(Tab::Inbox))
+        );
+        assert_eq!(
+            Action::from_key(key(KeyCode::Char('i'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_each_key_bound_once_and_hinted_from_bindings() {
+        for (i, (binding, _)) in BINDINGS.iter().enumerate() {
+            assert!(
+                !BINDINGS[i + 1..].iter().any(|(other, _)| other == binding),
+                "{} is bound twice",
+                binding.display()
+            );
+        }
+        let hinted = [
+            ViewLevel::Owners.actions(),
+            RUNS_ACTIONS,
+            JOBS_ACTIONS,
+            WORKFLOW_LOG_ACTIONS,
+            RUNNER_REPOS_ACTIONS,
+            RUNNERS_ACTIONS,
+            CONSOLE_ACTIONS,
+            SEARCH_INPUT_ACTIONS,
+            FILTER_INPUT_ACTIONS,
+            &Action::GLOBAL,
+        ];
+        for action in hinted.into_iter().flatten() {
+            assert!(!action.key().is_empty(), "{:?} has no key", action);
+        }
+        assert_eq!(Action::Navigate.key(), "↑↓");
+        assert_eq!(Action::NextMatch.key(), "n/N");
+        assert_eq!(Action::NextBookmark.key(), "[/]");
+        assert_eq!(Action::Mark.key(), "Space");
+        assert_eq!(Action::Recent.key(), "^r");
+    }
+}
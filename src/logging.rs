@@ -0,0 +1,115 @@
+// Application log, written to a daily file in the cache directory.
+// Requests, cache reads, and view loads are traced there for bug reports, and
+// the latest lines are kept in memory for the debug overlay (`~`).
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::Mutex;
+
+use tracing::level_filters::LevelFilter;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::writer::MakeWriterExt;
+
+use crate::cache;
+
+/// Environment variable setting the most detailed level logged (e.g., "debug").
+const LEVEL_VAR: &str = "JOLT_LOG";
+
+/// Daily log files kept before the oldest is deleted.
+const MAX_LOG_FILES: usize = 7;
+
+/// Lines kept in memory for the debug overlay.
+const TAIL_LINES: usize = 500;
+
+/// Latest log lines, oldest first.
+static TAIL: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Start logging at the level in JOLT_LOG, info by default. Without a cache
+/// directory only the in-memory tail is kept. The log file is written on a
+/// background thread, whose remaining lines are flushed when the returned
+/// guard is dropped, so keep it until exiting.
+pub fn init() -> Option<WorkerGuard> {
+    let level = std::env::var(LEVEL_VAR)
+        .ok()
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(LevelFilter::INFO);
+    let file = cache::logs_dir().and_then(|dir| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix("jolt")
+            .filename_suffix("log")
+            .max_log_files(MAX_LOG_FILES)
+            .build(dir)
+            .ok()
+    });
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_max_level(level);
+    match file {
+        Some(file) => {
+            let (file, guard) = tracing_appender::non_blocking(file);
+            let _ = subscriber.with_writer(file.and(|| TailWriter)).try_init();
+            Some(guard)
+        }
+        None => {
+            let _ = subscriber.with_writer(|| TailWriter).try_init();
+            None
+        }
+    }
+}
+
+/// Latest log lines, oldest first.
+pub fn tail() -> Vec<String> {
+    TAIL.lock()
+        .map(|tail| tail.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Append the lines of `text` to `tail`, dropping the oldest past `TAIL_LINES`.
+fn push_lines(tail: &mut VecDeque<String>, text: &str) {
+    for line in text.lines() {
+        if tail.len() == TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(line.to_string());
+    }
+}
+
+/// Writer keeping formatted log lines in the in-memory tail.
+struct TailWriter;
+
+impl io::Write for TailWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Ok(mut tail) = TAIL.lock() {
+            push_lines(&mut tail, &String::from_utf8_lossy(buf));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_keeps_latest_lines() {
+        let mut tail = VecDeque::new();
+        push_lines(&mut tail, "first\nsecond\n");
+        assert_eq!(tail, ["first", "second"]);
+
+        for i in 0..TAIL_LINES {
+            push_lines(&mut tail, &format!("line {}\n", i));
+        }
+        assert_eq!(tail.len(), TAIL_LINES);
+        assert_eq!(tail.front().map(String::as_str), Some("line 0"));
+        assert_eq!(
+            tail.back().cloned(),
+            Some(format!("line {}", TAIL_LINES - 1))
+        );
+    }
+}
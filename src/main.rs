@@ -2,44 +2,108 @@
 // Initializes terminal, runs the app, and handles cleanup.
 
 mod app;
+mod bulk;
 mod cache;
+mod changelog;
+mod cli;
+mod clipboard;
+mod config;
+mod crash;
+mod diag;
 mod error;
+mod export;
 mod github;
+mod keymap;
+mod logging;
+mod message;
+mod metrics;
 mod state;
+mod tasks;
+mod title;
 mod ui;
+mod watch;
 
 use std::io;
+use std::panic::AssertUnwindSafe;
+use std::process::ExitCode;
 
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use futures::FutureExt;
 use ratatui::prelude::*;
 
 use app::App;
 
 #[tokio::main]
-async fn main() -> io::Result<()> {
-    // Setup terminal
+async fn main() -> io::Result<ExitCode> {
+    // Subcommands run without the TUI
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match cli::parse(&args) {
+        Ok(Some(command)) => {
+            return cli::run(command)
+                .await
+                .map_err(|e| io::Error::other(e.to_string()));
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            return Ok(ExitCode::from(2));
+        }
+    }
+
+    // Setup terminal, restored below even if jolt crashes
+    let _log_guard = logging::init();
+    tracing::info!(version = changelog::CURRENT_VERSION, "jolt started");
+    crash::install_hook();
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app and run it
+    // Create app and run it, restoring the window title it changes afterwards
     let mut app = App::new();
-    let result = app.run(&mut terminal).await;
+    if args.iter().any(|arg| arg == "--ascii") {
+        app.ascii_flag = true;
+        app.term_caps.ascii = true;
+    }
+    if let Some((owner, repo)) = state::git_remote::current_repo() {
+        app.open_cwd_repo(owner, repo, args.iter().any(|arg| arg == "--here"));
+    }
+    title::push()?;
+    let result = AssertUnwindSafe(app.run(&mut terminal))
+        .catch_unwind()
+        .await;
+    title::pop()?;
+    let Ok(result) = result else {
+        // The hook wrote the crash report, to print once the shell is back
+        crash::restore_terminal();
+        if let Some(panic) = crash::take_last_panic() {
+            eprintln!("{}", panic);
+        }
+        if let Err(e) = app.save_crash_state() {
+            eprintln!("Failed to save state: {}", e);
+        }
+        return Ok(ExitCode::from(101));
+    };
 
     // Restore terminal
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
-    result
+    result.map(|()| ExitCode::SUCCESS)
 }
@@ -0,0 +1,202 @@
+// Messages the app state is updated with.
+// A key press goes to the focused overlay or text input as it is, and in the
+// list and log views to the action the keymap binds it to. Updates that need
+// the network return a command to run afterwards.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crossterm::event::KeyEvent;
+
+use crate::app::{PendingBulk, PendingRerun, Tab};
+use crate::diag::RunnerHealth;
+use crate::error::Result;
+use crate::github::{
+    Branch, Deployment, Environment, InboxPullRequest, Job, Owner, Repository, Runner, RunnerGroup,
+    Workflow, WorkflowRun,
+};
+use crate::state::flaky::FlakyReport;
+use crate::state::home::HomeItem;
+use crate::state::live::LiveRun;
+use crate::state::permalink::Permalink;
+use crate::state::recent::RecentItem;
+use crate::state::runners::RunnerJob;
+use crate::state::schedule::WorkflowSchedule;
+use crate::state::workspace::Workspace;
+use crate::state::{RunnersViewLevel, ViewLevel};
+
+/// Input the app state is updated with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// Key press, read by the focused overlay or decoded to its action.
+    Key(KeyEvent),
+    /// Text pasted into the terminal.
+    Paste(String),
+}
+
+/// Work a message leaves to do after updating the state, usually API requests.
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Load the newly active tab.
+    LoadTab,
+    ToggleMyRuns,
+    OpenBranches,
+    ToggleAllBranches,
+    OpenDiagLogs,
+    OpenRunnerGroups,
+    ShowFlakyReport,
+    ShowRunQueue,
+    HideRunnerRepo,
+    ShowSchedule,
+    OpenEnvironments,
+    ShowCaches,
+    ShowSecrets,
+    ShowPolicy,
+    ShowChecks,
+    ShowLogArchive,
+    ShowJobSummary,
+    DrillDown,
+    GoBack,
+    Refresh,
+    HardRefresh,
+    /// Reopen a recently viewed run or job.
+    OpenRecent(RecentItem),
+    /// Open the run or job a GitHub URL points to.
+    OpenPermalink(Permalink),
+    /// Return to a saved workspace.
+    RestoreWorkspace(Workspace),
+    /// Go back to the navigation level at this depth.
+    JumpToLevel(usize),
+    /// Filter runs by a branch, or show all branches.
+    SetBranch(Option<String>),
+    /// Delete the Actions cache entry selected in the caches popup.
+    DeleteCache,
+    /// Apply a confirmed bulk action to marked items.
+    ApplyBulk(Box<PendingBulk>),
+    /// Re-run a run with debug logging.
+    DebugRerun(PendingRerun),
+}
+
+/// Results of background work, applied to the state between frames.
+pub enum Loaded {
+    /// Fresh rows for a list that was showing stale ones.
+    Revalidated {
+        view: ListView,
+        query: RowsQuery,
+        rows: Option<Rows>,
+    },
+    /// Jobs of the run previewed beside the Runs list, and where to cache them.
+    Preview {
+        run_id: u64,
+        path: Option<PathBuf>,
+        result: Result<(Vec<Job>, u64)>,
+    },
+    /// Report of the jobs passing on retry in a workflow's recent runs.
+    FlakyReport(Result<FlakyReport>),
+    /// Cron triggers of a repository's workflows by ID, and how many workflow files couldn't be read.
+    Schedules {
+        owner: String,
+        repo: String,
+        schedules: Vec<(u64, WorkflowSchedule)>,
+        failures: usize,
+    },
+    /// Latest run of each of a repository's workflows, by workflow ID.
+    LatestRuns {
+        owner: String,
+        repo: String,
+        result: Result<HashMap<u64, WorkflowRun>>,
+    },
+    /// Jobs a repository's busy runners are working on, by runner name, and
+    /// how many in-progress runs' jobs failed to load.
+    RunnerJobs {
+        owner: String,
+        repo: String,
+        result: Result<(HashMap<String, RunnerJob>, usize)>,
+    },
+    /// Jobs of a run whose Jobs list was open in `tab`, refetched to update
+    /// its in-progress jobs.
+    JobTick {
+        tab: Tab,
+        run_id: u64,
+        result: Result<Vec<Job>>,
+    },
+    /// Default branch of a repository whose Runs list waits to be narrowed to it.
+    DefaultBranch {
+        owner: String,
+        repo: String,
+        result: Result<Option<String>>,
+    },
+    /// A page of a repository's branches for the branch picker.
+    Branches {
+        owner: String,
+        repo: String,
+        page: u32,
+        result: Result<Vec<Branch>>,
+    },
+    /// Whether a failed job's log was downloaded into the cache, by job name.
+    LogPrefetch {
+        job_name: String,
+        result: Result<()>,
+    },
+    /// Home items of favorites, and what failed to load.
+    Home {
+        items: Vec<HomeItem>,
+        failures: Vec<String>,
+    },
+    /// Runners of the shown Runners list, fetched to sample which are busy.
+    RunnerSample(Result<Vec<Runner>>),
+    /// Health of a runner installed on this machine, by name.
+    RunnerHealth(String, RunnerHealth),
+    /// In-progress runs of favorite repositories, the runs that left the
+    /// Live tab since with their conclusion, and what failed to load.
+    Live {
+        runs: Vec<LiveRun>,
+        finished: Vec<LiveRun>,
+        failures: Vec<String>,
+    },
+}
+
+/// A list view whose rows come from the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListView {
+    Workflows(ViewLevel),
+    Runners(RunnersViewLevel),
+    Inbox,
+}
+
+/// What a list's rows are fetched with besides its view.
+#[derive(Debug, Clone, Default)]
+pub struct RowsQuery {
+    pub per_page: u32,
+    /// Only runs triggered by this user.
+    pub actor: Option<String>,
+    /// Only runs on this branch.
+    pub branch: Option<String>,
+    /// Owners listed without a token, from favorite repositories.
+    pub public_owners: Vec<String>,
+    /// Whether the runner group is open to selected repositories, which are listed too.
+    pub group_repos: bool,
+}
+
+/// Rows fetched for a list view, with the total count.
+pub enum Rows {
+    Owners(Result<(Vec<Owner>, u64)>),
+    /// Owners fetched one by one, with the failures to report.
+    PublicOwners {
+        owners: Vec<Owner>,
+        failures: Vec<String>,
+    },
+    Repositories(Result<(Vec<Repository>, u64)>),
+    Workflows(Result<(Vec<Workflow>, u64)>),
+    Runs(Result<(Vec<WorkflowRun>, u64)>),
+    Jobs(Result<(Vec<Job>, u64)>),
+    Environments(Result<(Vec<Environment>, u64)>),
+    Deployments(Result<(Vec<Deployment>, u64)>),
+    Runners(Result<(Vec<Runner>, u64)>),
+    RunnerGroups(Result<(Vec<RunnerGroup>, u64)>),
+    GroupRunners {
+        runners: Result<(Vec<Runner>, u64)>,
+        repos: Option<Result<Vec<Repository>>>,
+    },
+    Inbox(Result<(Vec<InboxPullRequest>, u64)>),
+}
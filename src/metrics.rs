@@ -0,0 +1,311 @@
+// Prometheus exporter for runner and workflow health of favorite repositories.
+// Collects gauges periodically and serves them as text on GET /metrics.
+// A repository that fails to collect keeps its last good values, with
+// `jolt_up` at 0 so scrapers can tell stale values from fresh ones.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{RwLock, watch};
+
+use crate::error::{JoltError, Result};
+use crate::github::{GitHubClient, RunConclusion, RunnerStatus};
+
+/// Address the exporter listens on when none is given.
+pub const DEFAULT_METRICS_ADDR: &str = "127.0.0.1:9464";
+
+/// How often gauges are collected from GitHub.
+const COLLECT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Health of one repository at the last collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoHealth {
+    /// "owner/repo".
+    pub repo: String,
+    /// Online self-hosted runners, or None without admin access to list them.
+    pub runners_online: Option<usize>,
+    /// Online runners currently running a job.
+    pub runners_busy: Option<usize>,
+    /// Runs waiting for a runner to pick up their first job.
+    pub queued_runs: usize,
+    /// Share of the latest completed runs that succeeded, ignoring cancelled and
+    /// skipped runs, or None when there are none.
+    pub success_rate: Option<f64>,
+}
+
+/// Collection state of one repository.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct RepoStatus {
+    /// Health at the last successful collection.
+    last_good: Option<RepoHealth>,
+    /// Whether the latest collection succeeded.
+    up: bool,
+    /// Failed collections since the exporter started.
+    errors: u64,
+}
+
+/// Health of the watched repositories across collections, by "owner/repo".
+#[derive(Debug, Clone, Default)]
+pub struct Collection {
+    repos: BTreeMap<String, RepoStatus>,
+}
+
+impl Collection {
+    /// Collect the health of each "owner/repo" in `repos`, forgetting
+    /// repositories no longer watched.
+    pub async fn collect(&mut self, client: &mut GitHubClient, repos: &[String]) {
+        self.repos.retain(|key, _| repos.contains(key));
+        for key in repos {
+            let Some((owner, repo)) = key.split_once('/') else {
+                continue;
+            };
+            let result = collect_repo(client, owner, repo).await;
+            if let Err(e) = &result {
+                tracing::warn!(repo = %key, "metrics collection failed: {}", e);
+            }
+            self.record(key, result);
+        }
+    }
+
+    /// Record a repository's collection, keeping its last good health on errors.
+    fn record(&mut self, key: &str, result: Result<RepoHealth>) {
+        let status = self.repos.entry(key.to_string()).or_default();
+        match result {
+            Ok(health) => {
+                status.last_good = Some(health);
+                status.up = true;
+            }
+            Err(_) => {
+                status.up = false;
+                status.errors += 1;
+            }
+        }
+    }
+}
+
+async fn collect_repo(client: &mut GitHubClient, owner: &str, repo: &str) -> Result<RepoHealth> {
+    // Listing runners needs admin access, which the token may not have
+    let runners = match client.get_runners(owner, repo, 1, 100).await {
+        Ok(runners) => Some(runners),
+        Err(JoltError::NotFound(_) | JoltError::Forbidden(_)) => None,
+        Err(e) => return Err(e),
+    };
+    let online = runners.as_ref().map(|(runners, _)| {
+        runners
+            .iter()
+            .filter(|r| r.status == RunnerStatus::Online)
+            .collect::<Vec<_>>()
+    });
+
+    let queued_runs = client
+        .get_runs_with_status(owner, repo, "queued")
+        .await?
+        .len();
+
+    let completed = client
+        .get_runs_with_status(owner, repo, "completed")
+        .await?;
+    let conclusions: Vec<RunConclusion> = completed.iter().filter_map(|r| r.conclusion).collect();
+
+    Ok(RepoHealth {
+        repo: format!("{}/{}", owner, repo),
+        runners_online: online.as_ref().map(Vec::len),
+        runners_busy: online.map(|online| online.iter().filter(|r| r.busy).count()),
+        queued_runs,
+        success_rate: success_rate(&conclusions),
+    })
+}
+
+/// Share of successful conclusions, ignoring cancelled, skipped, and neutral ones.
+fn success_rate(conclusions: &[RunConclusion]) -> Option<f64> {
+    let decided: Vec<_> = conclusions
+        .iter()
+        .filter(|c| {
+            !matches!(
+                c,
+                RunConclusion::Cancelled | RunConclusion::Skipped | RunConclusion::Neutral
+            )
+        })
+        .collect();
+    let successes = decided
+        .iter()
+        .filter(|c| ***c == RunConclusion::Success)
+        .count();
+    (!decided.is_empty()).then(|| successes as f64 / decided.len() as f64)
+}
+
+/// Reads a gauge's value for a repository, if known.
+type GaugeValue = fn(&RepoHealth) -> Option<f64>;
+
+/// Gauges in the Prometheus text exposition format.
+pub fn render(collection: &Collection) -> String {
+    let gauges: [(&str, &str, GaugeValue); 4] = [
+        (
+            "jolt_runners_online",
+            "Online self-hosted runners registered to the repository.",
+            |h| h.runners_online.map(|n| n as f64),
+        ),
+        (
+            "jolt_runners_busy",
+            "Online self-hosted runners currently running a job.",
+            |h| h.runners_busy.map(|n| n as f64),
+        ),
+        ("jolt_queued_runs", "Runs waiting for a runner.", |h| {
+            Some(h.queued_runs as f64)
+        }),
+        (
+            "jolt_workflow_success_rate",
+            "Share of the latest 100 completed runs that succeeded.",
+            |h| h.success_rate,
+        ),
+    ];
+    let mut out = String::new();
+    for (name, help, value) in gauges {
+        write_header(&mut out, name, help, "gauge");
+        for (key, status) in &collection.repos {
+            if let Some(value) = status.last_good.as_ref().and_then(value) {
+                write_sample(&mut out, name, key, value);
+            }
+        }
+    }
+
+    write_header(
+        &mut out,
+        "jolt_up",
+        "Whether the latest collection of the repository succeeded.",
+        "gauge",
+    );
+    for (key, status) in &collection.repos {
+        write_sample(&mut out, "jolt_up", key, if status.up { 1.0 } else { 0.0 });
+    }
+    write_header(
+        &mut out,
+        "jolt_collect_errors_total",
+        "Failed collections of the repository since the exporter started.",
+        "counter",
+    );
+    for (key, status) in &collection.repos {
+        write_sample(
+            &mut out,
+            "jolt_collect_errors_total",
+            key,
+            status.errors as f64,
+        );
+    }
+    out
+}
+
+fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn write_sample(out: &mut String, name: &str, repo: &str, value: f64) {
+    let repo = repo.replace('\\', "\\\\").replace('"', "\\\"");
+    let _ = writeln!(out, "{}{{repo=\"{}\"}} {}", name, repo, value);
+}
+
+/// Collect gauges for the repositories in `repos` every minute and serve them
+/// on `listener` until it fails. Changes sent to `repos` apply from the next
+/// collection.
+pub async fn serve(
+    listener: TcpListener,
+    mut client: GitHubClient,
+    repos: watch::Receiver<Vec<String>>,
+) -> Result<()> {
+    let mut collection = Collection::default();
+    let body = Arc::new(RwLock::new(render(&collection)));
+    let collected = Arc::clone(&body);
+    tokio::spawn(async move {
+        loop {
+            let watched = repos.borrow().clone();
+            collection.collect(&mut client, &watched).await;
+            *collected.write().await = render(&collection);
+            tokio::time::sleep(COLLECT_INTERVAL).await;
+        }
+    });
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let body = Arc::clone(&body);
+        tokio::spawn(async move {
+            let _ = respond(stream, &body).await;
+        });
+    }
+}
+
+/// Answer one HTTP request: the gauges on GET /metrics, 404 otherwise.
+async fn respond(mut stream: TcpStream, body: &RwLock<String>) -> std::io::Result<()> {
+    let mut request = [0u8; 1024];
+    let len = stream.read(&mut request).await?;
+    let request = String::from_utf8_lossy(&request[..len]);
+    let response = if request.starts_with("GET /metrics ") {
+        let body = body.read().await;
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    } else {
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+    };
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let health = RepoHealth {
+            repo: "phatblat/jolt".to_string(),
+            runners_online: None,
+            runners_busy: None,
+            queued_runs: 3,
+            success_rate: success_rate(&[
+                RunConclusion::Success,
+                RunConclusion::Failure,
+                RunConclusion::Cancelled,
+                RunConclusion::Success,
+                RunConclusion::Success,
+            ]),
+        };
+        let mut collection = Collection::default();
+        collection.record("phatblat/jolt", Ok(health));
+        let text = render(&collection);
+        assert!(text.contains("# TYPE jolt_queued_runs gauge\n"));
+        assert!(text.contains("jolt_queued_runs{repo=\"phatblat/jolt\"} 3\n"));
+        assert!(text.contains("jolt_workflow_success_rate{repo=\"phatblat/jolt\"} 0.75\n"));
+        assert!(!text.contains("jolt_runners_online{"));
+        assert!(text.contains("jolt_up{repo=\"phatblat/jolt\"} 1\n"));
+    }
+
+    #[test]
+    fn test_failed_collection_keeps_last_good_values() {
+        let health = RepoHealth {
+            repo: "phatblat/jolt".to_string(),
+            runners_online: Some(2),
+            runners_busy: Some(1),
+            queued_runs: 0,
+            success_rate: None,
+        };
+        let mut collection = Collection::default();
+        collection.record("phatblat/jolt", Ok(health));
+        collection.record("phatblat/jolt", Err(JoltError::Other("502".to_string())));
+        collection.record("phatblat/down", Err(JoltError::Other("502".to_string())));
+
+        let text = render(&collection);
+        assert!(text.contains("jolt_runners_online{repo=\"phatblat/jolt\"} 2\n"));
+        assert!(text.contains("jolt_up{repo=\"phatblat/jolt\"} 0\n"));
+        assert!(text.contains("# TYPE jolt_collect_errors_total counter\n"));
+        assert!(text.contains("jolt_collect_errors_total{repo=\"phatblat/jolt\"} 1\n"));
+        // Never collected: only reported as down
+        assert!(text.contains("jolt_up{repo=\"phatblat/down\"} 0\n"));
+        assert!(!text.contains("jolt_queued_runs{repo=\"phatblat/down\"}"));
+    }
+}
@@ -0,0 +1,181 @@
+// Branch picker for Runs lists.
+// Lists a repository's branches, narrowed as the user types, to filter runs by.
+// Pages of branches are added as they arrive, and a branch not listed yet can
+// be picked by typing its full name.
+
+use crossterm::event::KeyCode;
+
+use crate::github::Branch;
+
+use super::popup::PopupKey;
+use super::workflows::{SelectableList, text_id};
+
+/// Branches of a repository, shown in the branch picker.
+#[derive(Debug)]
+pub struct BranchPicker {
+    pub owner: String,
+    pub repo: String,
+    pub branches: SelectableList<Branch>,
+    /// Text typed to narrow the list (fuzzy matched).
+    pub query: String,
+    /// Whether more pages of branches are still being fetched.
+    pub loading: bool,
+}
+
+impl BranchPicker {
+    pub fn new(owner: String, repo: String) -> Self {
+        Self {
+            owner,
+            repo,
+            branches: SelectableList::with_id_of(|branch| text_id(&branch.name)),
+            query: String::new(),
+            loading: true,
+        }
+    }
+
+    /// Show the fetched branches, protected ones first, with `current` selected.
+    pub fn set_branches(&mut self, branches: Vec<Branch>, current: Option<&str>) {
+        let count = branches.len() as u64;
+        self.branches.set_loaded(branches, count);
+        self.branches
+            .sort_by(|a, b| b.protected.cmp(&a.protected).then(a.name.cmp(&b.name)));
+        let position = self
+            .branches
+            .sorted_items()
+            .iter()
+            .position(|b| Some(b.name.as_str()) == current);
+        if position.is_some() {
+            self.branches.list_state.select(position);
+        }
+    }
+
+    /// Add a page of branches to those already listed, keeping the highlighted one.
+    pub fn add_branches(&mut self, branches: Vec<Branch>, current: Option<&str>) {
+        let Some(listed) = self.branches.data.data() else {
+            self.set_branches(branches, current);
+            return;
+        };
+        let mut all = listed.items.clone();
+        all.extend(branches);
+        let count = all.len() as u64;
+        self.branches.set_refreshed(all, count);
+        self.branches
+            .sort_by(|a, b| b.protected.cmp(&a.protected).then(a.name.cmp(&b.name)));
+    }
+
+    /// Add a typed character to the query.
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.branches.set_filter(Some(self.query.clone()));
+    }
+
+    /// Remove the last character of the query.
+    pub fn pop(&mut self) {
+        self.query.pop();
+        let query = Some(self.query.clone()).filter(|q| !q.is_empty());
+        self.branches.set_filter(query);
+    }
+
+    /// Name of the highlighted branch.
+    pub fn selected(&self) -> Option<&str> {
+        self.branches.selected_item().map(|b| b.name.as_str())
+    }
+
+    /// Handle a key press: typing narrows the list, arrows move, Enter picks
+    /// the highlighted branch, or the typed name when none matches, and Delete
+    /// picks all branches (None).
+    pub fn update(&mut self, code: KeyCode) -> PopupKey<Option<String>> {
+        match code {
+            KeyCode::Up => self.branches.select_prev(),
+            KeyCode::Down => self.branches.select_next(),
+            KeyCode::Backspace => self.pop(),
+            KeyCode::Char(c) => self.push(c),
+            KeyCode::Enter => match self.selected() {
+                Some(name) => return PopupKey::Act(Some(name.to_string())),
+                None if !self.query.trim().is_empty() => {
+                    return PopupKey::Act(Some(self.query.trim().to_string()));
+                }
+                None => {}
+            },
+            KeyCode::Delete => return PopupKey::Act(None),
+            KeyCode::Esc => return PopupKey::Close,
+            _ => {}
+        }
+        PopupKey::Stay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branch(name: &str, protected: bool) -> Branch {
+        Branch {
+            name: name.to_string(),
+            protected,
+        }
+    }
+
+    fn picker() -> BranchPicker {
+        let mut picker = BranchPicker::new("o".to_string(), "r".to_string());
+        picker.set_branches(
+            vec![
+                branch("feature/login", false),
+                branch("main", true),
+                branch("release/1.2", true),
+            ],
+            Some("release/1.2"),
+        );
+        picker
+    }
+
+    #[test]
+    fn test_protected_first_with_current_selected() {
+        let picker = picker();
+        let names: Vec<&str> = picker
+            .branches
+            .sorted_items()
+            .iter()
+            .map(|b| b.name.as_str())
+            .collect();
+        assert_eq!(names, ["main", "release/1.2", "feature/login"]);
+        assert_eq!(picker.selected(), Some("release/1.2"));
+    }
+
+    #[test]
+    fn test_query_narrows_branches() {
+        let mut picker = picker();
+        for c in "fl".chars() {
+            picker.push(c);
+        }
+        assert_eq!(picker.branches.visible_len(), 1);
+        assert_eq!(picker.selected(), Some("feature/login"));
+        picker.pop();
+        picker.pop();
+        assert_eq!(picker.branches.visible_len(), 3);
+        assert_eq!(picker.branches.filter, None);
+    }
+
+    #[test]
+    fn test_pages_added_keep_highlighted_branch() {
+        let mut picker = picker();
+        picker.branches.select_next();
+        assert_eq!(picker.selected(), Some("feature/login"));
+        picker.add_branches(vec![branch("dev", true)], None);
+        assert_eq!(picker.branches.visible_len(), 4);
+        assert_eq!(picker.selected(), Some("feature/login"));
+    }
+
+    #[test]
+    fn test_enter_takes_typed_name_when_nothing_matches() {
+        let mut picker = picker();
+        for c in "hotfix/9".chars() {
+            picker.push(c);
+        }
+        assert_eq!(picker.branches.visible_len(), 0);
+        assert_eq!(
+            picker.update(KeyCode::Enter),
+            PopupKey::Act(Some("hotfix/9".to_string()))
+        );
+    }
+}
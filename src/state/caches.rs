@@ -0,0 +1,100 @@
+// Repository Actions cache usage.
+// Lists cache entries and totals them against GitHub's per-repository limit.
+
+use crossterm::event::KeyCode;
+
+use crate::github::ActionsCache;
+
+use super::popup::PopupKey;
+use super::workflows::SelectableList;
+
+/// Cache storage GitHub keeps per repository before evicting old entries.
+pub const CACHE_LIMIT_BYTES: u64 = 10 * 1024 * 1024 * 1024;
+
+/// Actions cache entries of a repository, shown in the caches popup.
+#[derive(Debug)]
+pub struct CachesView {
+    pub owner: String,
+    pub repo: String,
+    pub caches: SelectableList<ActionsCache>,
+    /// Whether the selected entry awaits confirmation to be deleted.
+    pub confirm_delete: bool,
+}
+
+impl CachesView {
+    pub fn new(owner: String, repo: String) -> Self {
+        Self {
+            owner,
+            repo,
+            caches: SelectableList::new(),
+            confirm_delete: false,
+        }
+    }
+
+    /// Handle a key press: arrows move, `x` then `y` deletes the selected
+    /// entry, and Esc closes.
+    pub fn update(&mut self, code: KeyCode) -> PopupKey {
+        let confirming = std::mem::take(&mut self.confirm_delete);
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.caches.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => self.caches.select_next(),
+            KeyCode::Char('x') => self.confirm_delete = self.caches.selected_item().is_some(),
+            KeyCode::Char('y') if confirming => return PopupKey::Act(()),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('C') => return PopupKey::Close,
+            _ => {}
+        }
+        PopupKey::Stay
+    }
+
+    /// Combined size of the loaded entries.
+    pub fn total_bytes(&self) -> u64 {
+        self.caches
+            .data
+            .data()
+            .map(|list| list.items.iter().map(|c| c.size_in_bytes).sum())
+            .unwrap_or(0)
+    }
+
+    /// Remove a deleted entry, keeping the selection in range.
+    pub fn remove(&mut self, cache_id: u64) {
+        let Some(list) = self.caches.data.data() else {
+            return;
+        };
+        let items: Vec<ActionsCache> = list
+            .items
+            .iter()
+            .filter(|c| c.id != cache_id)
+            .cloned()
+            .collect();
+        let total = list.total_count.saturating_sub(1);
+        self.caches.set_refreshed(items, total);
+    }
+}
+
+/// Human-readable size (e.g., "1.5 GB", "120.0 MB", "512 B").
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(120 * 1024 * 1024), "120.0 MB");
+        assert_eq!(format_size(CACHE_LIMIT_BYTES), "10.0 GB");
+    }
+}
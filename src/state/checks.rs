@@ -0,0 +1,127 @@
+// Check runs of a commit from every CI provider.
+// Shows the full status picture for a run's commit, including checks reported
+// by other apps (e.g., external CI services or code scanning).
+
+use crossterm::event::KeyCode;
+
+use crate::github::CheckRun;
+
+use super::popup::PopupKey;
+use super::workflows::SelectableList;
+
+/// Slug of the app that reports GitHub Actions jobs as check runs.
+const ACTIONS_APP_SLUG: &str = "github-actions";
+
+/// Check runs of a run's commit, shown in the checks popup.
+#[derive(Debug)]
+pub struct ChecksView {
+    pub owner: String,
+    pub repo: String,
+    pub sha: String,
+    pub checks: SelectableList<CheckRun>,
+}
+
+impl ChecksView {
+    pub fn new(owner: String, repo: String, sha: String) -> Self {
+        Self {
+            owner,
+            repo,
+            sha,
+            checks: SelectableList::new(),
+        }
+    }
+
+    /// Handle a key press: arrows move, Enter or `o` opens the selected
+    /// check's page (its URL), and any other key closes.
+    pub fn update(&mut self, code: KeyCode) -> PopupKey<String> {
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.checks.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => self.checks.select_next(),
+            KeyCode::Enter | KeyCode::Char('o') => {
+                if let Some(url) = self.checks.selected_item().and_then(check_url) {
+                    return PopupKey::Act(url.to_string());
+                }
+            }
+            _ => return PopupKey::Close,
+        }
+        PopupKey::Stay
+    }
+}
+
+/// Whether a check run is a GitHub Actions job.
+pub fn is_actions_check(check: &CheckRun) -> bool {
+    check
+        .app
+        .as_ref()
+        .is_some_and(|app| app.slug == ACTIONS_APP_SLUG)
+}
+
+/// Name of the app that reported a check run (e.g., "CodeQL").
+pub fn check_app_name(check: &CheckRun) -> &str {
+    check.app.as_ref().map_or("Unknown app", |app| &app.name)
+}
+
+/// Page to open for a check run: the reporting app's own page for checks
+/// from other providers, falling back to the check's page on GitHub.
+pub fn check_url(check: &CheckRun) -> Option<&str> {
+    let details = check.details_url.as_deref().filter(|url| !url.is_empty());
+    if is_actions_check(check) {
+        check.html_url.as_deref().or(details)
+    } else {
+        details.or(check.html_url.as_deref())
+    }
+}
+
+/// Sort checks from other providers first, grouped by app, then Actions jobs.
+pub fn sort_checks(checks: &mut [CheckRun]) {
+    checks.sort_by(|a, b| {
+        is_actions_check(a)
+            .cmp(&is_actions_check(b))
+            .then_with(|| check_app_name(a).cmp(check_app_name(b)))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::{CheckApp, RunStatus};
+
+    fn check(name: &str, slug: &str, app: &str) -> CheckRun {
+        CheckRun {
+            id: 1,
+            name: name.to_string(),
+            status: RunStatus::Completed,
+            conclusion: None,
+            started_at: None,
+            completed_at: None,
+            html_url: Some(format!("https://github.com/checks/{}", name)),
+            details_url: Some(format!("https://{}.example.com/{}", slug, name)),
+            app: Some(CheckApp {
+                slug: slug.to_string(),
+                name: app.to_string(),
+            }),
+            output: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_checks_and_urls() {
+        let mut checks = vec![
+            check("build", ACTIONS_APP_SLUG, "GitHub Actions"),
+            check("ci/circleci", "circleci-checks", "CircleCI Checks"),
+            check("Analyze", "github-code-scanning", "CodeQL"),
+        ];
+        sort_checks(&mut checks);
+        let names: Vec<&str> = checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["ci/circleci", "Analyze", "build"]);
+        assert_eq!(
+            check_url(&checks[0]),
+            Some("https://circleci-checks.example.com/ci/circleci")
+        );
+        assert_eq!(
+            check_url(&checks[2]),
+            Some("https://github.com/checks/build")
+        );
+    }
+}
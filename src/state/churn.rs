@@ -0,0 +1,194 @@
+// Runner churn tracking for self-hosted fleets.
+// Records a snapshot of registered runners on each load and diffs them over time.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::cache;
+use crate::github::Runner;
+
+/// Maximum number of snapshots kept per repository.
+const MAX_SNAPSHOTS: usize = 500;
+
+/// Runners registered at a point in time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunnerSnapshot {
+    pub taken_at: DateTime<Utc>,
+    pub runners: Vec<SnapshotRunner>,
+}
+
+/// Runner identity and labels as recorded in a snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotRunner {
+    pub id: u64,
+    pub labels: Vec<String>,
+}
+
+/// Registrations and removals of runners with a label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelChurn {
+    pub label: String,
+    pub added: usize,
+    pub removed: usize,
+    /// Registrations plus removals per time bucket, oldest first.
+    pub buckets: Vec<u64>,
+}
+
+/// Runner snapshots for a repository, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChurnHistory {
+    snapshots: Vec<RunnerSnapshot>,
+}
+
+impl ChurnHistory {
+    /// Load recorded snapshots for a repository.
+    pub fn load(owner: &str, repo: &str) -> Self {
+        cache::runner_snapshots_path(owner, repo)
+            .and_then(|path| cache::read_cached::<Self>(&path).ok().flatten())
+            .map(|cached| cached.data)
+            .unwrap_or_default()
+    }
+
+    /// Save snapshots for a repository.
+    pub fn save(&self, owner: &str, repo: &str) {
+        if let Some(path) = cache::runner_snapshots_path(owner, repo) {
+            let _ = cache::write_cached(&path, self, false);
+        }
+    }
+
+    /// Record the currently registered runners.
+    /// Unchanged fleets aren't recorded again, since only changes count as churn.
+    pub fn record(&mut self, runners: &[Runner], now: DateTime<Utc>) {
+        let ids: HashSet<u64> = runners.iter().map(|r| r.id).collect();
+        if let Some(last) = self.snapshots.last() {
+            let last_ids: HashSet<u64> = last.runners.iter().map(|r| r.id).collect();
+            if last_ids == ids {
+                return;
+            }
+        }
+        self.snapshots.push(RunnerSnapshot {
+            taken_at: now,
+            runners: runners
+                .iter()
+                .map(|r| SnapshotRunner {
+                    id: r.id,
+                    labels: r.labels.iter().map(|l| l.name.clone()).collect(),
+                })
+                .collect(),
+        });
+        if self.snapshots.len() > MAX_SNAPSHOTS {
+            self.snapshots.remove(0);
+        }
+    }
+
+    /// Churn per label over `window`, split into `bucket_count` buckets.
+    /// Labels with no churn are left out; the busiest labels come first,
+    /// ties in label order.
+    pub fn churn_by_label(
+        &self,
+        now: DateTime<Utc>,
+        window: Duration,
+        bucket_count: usize,
+    ) -> Vec<LabelChurn> {
+        let start = now - window;
+        let bucket_secs = (window.num_seconds() / bucket_count.max(1) as i64).max(1);
+        let mut by_label: BTreeMap<String, LabelChurn> = BTreeMap::new();
+
+        for pair in self.snapshots.windows(2) {
+            let (before, after) = (&pair[0], &pair[1]);
+            if after.taken_at < start || after.taken_at > now {
+                continue;
+            }
+            let bucket = (((after.taken_at - start).num_seconds() / bucket_secs) as usize)
+                .min(bucket_count.saturating_sub(1));
+            let before_ids: HashSet<u64> = before.runners.iter().map(|r| r.id).collect();
+            let after_ids: HashSet<u64> = after.runners.iter().map(|r| r.id).collect();
+
+            let added = after.runners.iter().filter(|r| !before_ids.contains(&r.id));
+            let removed = before.runners.iter().filter(|r| !after_ids.contains(&r.id));
+            for (runner, is_added) in added.map(|r| (r, true)).chain(removed.map(|r| (r, false))) {
+                for label in &runner.labels {
+                    let churn = by_label.entry(label.clone()).or_insert_with(|| LabelChurn {
+                        label: label.clone(),
+                        added: 0,
+                        removed: 0,
+                        buckets: vec![0; bucket_count],
+                    });
+                    if is_added {
+                        churn.added += 1;
+                    } else {
+                        churn.removed += 1;
+                    }
+                    if let Some(count) = churn.buckets.get_mut(bucket) {
+                        *count += 1;
+                    }
+                }
+            }
+        }
+
+        let mut churn: Vec<LabelChurn> = by_label.into_values().collect();
+        churn.sort_by_key(|c| Reverse(c.added + c.removed));
+        churn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::{RunnerLabel, RunnerStatus};
+
+    fn runner(id: u64, label: &str) -> Runner {
+        Runner {
+            id,
+            name: format!("runner-{}", id),
+            os: "Linux".to_string(),
+            status: RunnerStatus::Online,
+            busy: false,
+            labels: vec![RunnerLabel {
+                id: None,
+                name: label.to_string(),
+                label_type: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_churn_by_label() {
+        let base = DateTime::from_timestamp(0, 0).unwrap();
+        let mut history = ChurnHistory::default();
+        history.record(&[runner(1, "linux")], base);
+        // Unchanged fleets aren't recorded
+        history.record(&[runner(1, "linux")], base + Duration::minutes(10));
+        assert_eq!(history.snapshots.len(), 1);
+
+        // Runner 1 replaced by 2 and 3 in the first half hour
+        history.record(
+            &[runner(2, "linux"), runner(3, "gpu")],
+            base + Duration::minutes(20),
+        );
+        // Runner 3 removed in the second half hour
+        history.record(&[runner(2, "linux")], base + Duration::minutes(50));
+
+        let churn = history.churn_by_label(base + Duration::hours(1), Duration::hours(1), 2);
+        assert_eq!(
+            churn,
+            vec![
+                LabelChurn {
+                    label: "gpu".to_string(),
+                    added: 1,
+                    removed: 1,
+                    buckets: vec![1, 1],
+                },
+                LabelChurn {
+                    label: "linux".to_string(),
+                    added: 1,
+                    removed: 1,
+                    buckets: vec![2, 0],
+                },
+            ]
+        );
+    }
+}
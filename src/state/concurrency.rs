@@ -0,0 +1,143 @@
+// Run queue and concurrency insight.
+// Counts a repository's or workflow's unfinished runs by status, and pairs
+// runs held back by a concurrency group with the run they are likely waiting on.
+
+use crate::github::{RunStatus, WorkflowRun};
+
+/// Run statuses fetched for the queue view.
+pub const QUEUE_STATUSES: [&str; 4] = ["in_progress", "queued", "waiting", "pending"];
+
+/// A run that hasn't started, and for a pending run, the in-progress run it is
+/// likely held back by.
+#[derive(Debug, Clone)]
+pub struct BlockedRun {
+    pub run: WorkflowRun,
+    /// In-progress run of the same workflow and branch, matching the common
+    /// `${{ github.workflow }}-${{ github.ref }}` concurrency group. The API
+    /// doesn't expose groups, so runs with custom keys may have none.
+    pub blocker: Option<WorkflowRun>,
+}
+
+/// Unfinished runs of a repository or workflow by status.
+#[derive(Debug, Clone)]
+pub struct RunQueue {
+    /// What the runs belong to (e.g., "owner/repo" or a workflow name).
+    pub scope: String,
+    pub in_progress: usize,
+    pub queued: usize,
+    /// Waiting for an environment's protection rules (e.g., a required reviewer).
+    pub waiting: usize,
+    /// Held back by a concurrency group until another run finishes.
+    pub pending: usize,
+    /// Queued and pending runs, oldest first.
+    pub blocked: Vec<BlockedRun>,
+}
+
+impl RunQueue {
+    /// Build the queue from unfinished runs. Duplicate runs are counted once.
+    pub fn from_runs(scope: String, mut runs: Vec<WorkflowRun>) -> Self {
+        runs.sort_by_key(|run| run.id);
+        runs.dedup_by_key(|run| run.id);
+        let count = |status: RunStatus| runs.iter().filter(|run| run.status == status).count();
+        let in_progress = count(RunStatus::InProgress);
+        let queued = count(RunStatus::Queued);
+        let waiting = count(RunStatus::Waiting);
+        let pending = count(RunStatus::Pending);
+
+        let mut blocked: Vec<BlockedRun> = runs
+            .iter()
+            .filter(|run| matches!(run.status, RunStatus::Queued | RunStatus::Pending))
+            .map(|run| BlockedRun {
+                run: run.clone(),
+                blocker: blocker_of(run, &runs).cloned(),
+            })
+            .collect();
+        blocked.sort_by_key(|b| b.run.created_at);
+        Self {
+            scope,
+            in_progress,
+            queued,
+            waiting,
+            pending,
+            blocked,
+        }
+    }
+
+    /// Whether no run is unfinished.
+    pub fn is_empty(&self) -> bool {
+        self.in_progress + self.queued + self.waiting + self.pending == 0
+    }
+}
+
+/// The earliest in-progress run sharing `run`'s workflow and branch, if `run`
+/// is pending. Queued runs wait for a runner rather than a concurrency group.
+fn blocker_of<'a>(run: &WorkflowRun, runs: &'a [WorkflowRun]) -> Option<&'a WorkflowRun> {
+    if run.status != RunStatus::Pending {
+        return None;
+    }
+    runs.iter()
+        .filter(|other| {
+            other.status == RunStatus::InProgress
+                && other.id != run.id
+                && other.workflow_id == run.workflow_id
+                && other.head_branch == run.head_branch
+        })
+        .min_by_key(|other| other.created_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn run(id: u64, workflow_id: u64, branch: &str, status: RunStatus) -> WorkflowRun {
+        let at = DateTime::from_timestamp(id as i64, 0).unwrap();
+        WorkflowRun {
+            id,
+            name: None,
+            run_number: id,
+            run_attempt: None,
+            status,
+            conclusion: None,
+            workflow_id,
+            head_branch: Some(branch.to_string()),
+            head_sha: String::new(),
+            event: String::new(),
+            created_at: at,
+            updated_at: at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_run_queue_pairs_blocked_runs() {
+        let runs = vec![
+            run(1, 1, "main", RunStatus::InProgress),
+            run(2, 1, "main", RunStatus::Pending),
+            run(3, 1, "main", RunStatus::Queued),
+            run(4, 2, "main", RunStatus::Waiting),
+            run(2, 1, "main", RunStatus::Pending),
+        ];
+        let queue = RunQueue::from_runs("o/r".to_string(), runs);
+        assert_eq!(
+            (
+                queue.in_progress,
+                queue.queued,
+                queue.waiting,
+                queue.pending
+            ),
+            (1, 1, 1, 1)
+        );
+        let blocked: Vec<(u64, Option<u64>)> = queue
+            .blocked
+            .iter()
+            .map(|b| (b.run.id, b.blocker.as_ref().map(|r| r.id)))
+            .collect();
+        assert_eq!(blocked, [(2, Some(1)), (3, None)]);
+        assert!(!queue.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+// Fuzzy filtering for lists.
+// Matches typed text as an in-order subsequence of each item's filter text.
+
+use crate::github::{
+    Annotation, Branch, Deployment, Environment, InboxPullRequest, Job, Owner, Repository,
+    RunnerGroup, Workflow, WorkflowRun,
+};
+
+use super::runners::RunnerRow;
+use super::test_report::TestFailure;
+
+/// Item that can be narrowed by a list filter.
+pub trait Filterable {
+    /// Text the filter is matched against.
+    fn filter_text(&self) -> String;
+}
+
+/// Char positions in `text` matching `query` as a case-insensitive subsequence.
+/// Spaces in the query are ignored. Returns None if the text doesn't match.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut chars = text.chars().enumerate();
+    for q in query.chars().filter(|c| !c.is_whitespace()) {
+        let q = q.to_lowercase().next().unwrap_or(q);
+        let (i, _) = chars.find(|(_, c)| c.to_lowercase().next() == Some(q))?;
+        positions.push(i);
+    }
+    Some(positions)
+}
+
+impl Filterable for String {
+    fn filter_text(&self) -> String {
+        self.clone()
+    }
+}
+
+impl Filterable for Owner {
+    fn filter_text(&self) -> String {
+        self.login.clone()
+    }
+}
+
+impl Filterable for Repository {
+    fn filter_text(&self) -> String {
+        format!("{}/{}", self.owner.login, self.name)
+    }
+}
+
+impl Filterable for Branch {
+    fn filter_text(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Filterable for Workflow {
+    fn filter_text(&self) -> String {
+        format!("{} {}", self.name, self.path)
+    }
+}
+
+impl Filterable for WorkflowRun {
+    fn filter_text(&self) -> String {
+        format!(
+            "#{} {} {}",
+            self.run_number,
+            self.head_branch.as_deref().unwrap_or_default(),
+            self.actor
+                .as_ref()
+                .map(|actor| format!("@{}", actor.login))
+                .unwrap_or_default()
+        )
+    }
+}
+
+impl Filterable for Job {
+    fn filter_text(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Filterable for Environment {
+    fn filter_text(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Filterable for Deployment {
+    fn filter_text(&self) -> String {
+        format!("{} {}", self.git_ref, self.sha)
+    }
+}
+
+impl Filterable for RunnerRow {
+    fn filter_text(&self) -> String {
+        self.name().to_string()
+    }
+}
+
+impl Filterable for RunnerGroup {
+    fn filter_text(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Filterable for InboxPullRequest {
+    fn filter_text(&self) -> String {
+        format!("{} {}", self.repository, self.title)
+    }
+}
+
+impl Filterable for Annotation {
+    fn filter_text(&self) -> String {
+        format!("{} {}", self.path, self.message)
+    }
+}
+
+impl Filterable for TestFailure {
+    fn filter_text(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("bld", "Build (ubuntu)"), Some(vec![0, 3, 4]));
+        assert_eq!(fuzzy_match("B U", "build ubuntu"), Some(vec![0, 1]));
+        assert_eq!(fuzzy_match("", "anything"), Some(vec![]));
+        // Characters must appear in order
+        assert_eq!(fuzzy_match("db", "build"), None);
+    }
+}
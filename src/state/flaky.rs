@@ -0,0 +1,134 @@
+// Flaky job detection across run attempts.
+// A job is flaky in a run when it failed in one attempt and passed on retry.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::github::{Job, RunConclusion};
+
+/// Number of recent runs covered by the flaky jobs report.
+pub const FLAKY_REPORT_RUNS: usize = 50;
+
+/// A job that passed on retry, and how many runs it happened in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlakyJob {
+    pub name: String,
+    pub runs: usize,
+}
+
+/// Most frequently retried jobs of a workflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlakyReport {
+    /// Number of runs examined.
+    pub runs: usize,
+    /// Flaky jobs, most frequent first.
+    pub jobs: Vec<FlakyJob>,
+}
+
+impl FlakyReport {
+    /// Build a report from the job attempts of each retried run.
+    /// Runs that were never retried can't have flaky jobs, so only `runs`
+    /// counts them.
+    pub fn from_attempts(runs: usize, attempts: &[Vec<Job>]) -> Self {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for jobs in attempts {
+            for name in flaky_job_names(jobs) {
+                *counts.entry(name).or_default() += 1;
+            }
+        }
+        let mut jobs: Vec<FlakyJob> = counts
+            .into_iter()
+            .map(|(name, runs)| FlakyJob { name, runs })
+            .collect();
+        jobs.sort_by_key(|job| Reverse(job.runs));
+        Self { runs, jobs }
+    }
+}
+
+/// Names of jobs that failed in an attempt of a run and passed in a later one.
+pub fn flaky_job_names(jobs: &[Job]) -> HashSet<String> {
+    let mut by_name: HashMap<&str, Vec<&Job>> = HashMap::new();
+    for job in jobs {
+        by_name.entry(&job.name).or_default().push(job);
+    }
+    by_name
+        .into_iter()
+        .filter(|(_, attempts)| {
+            let failed_at = attempts
+                .iter()
+                .filter(|job| job.conclusion == Some(RunConclusion::Failure))
+                .map(|job| job.run_attempt.unwrap_or(1))
+                .min();
+            failed_at.is_some_and(|failed_at| {
+                attempts.iter().any(|job| {
+                    job.conclusion == Some(RunConclusion::Success)
+                        && job.run_attempt.unwrap_or(1) > failed_at
+                })
+            })
+        })
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    fn job(name: &str, attempt: u64, conclusion: RunConclusion) -> Job {
+        Job {
+            id: attempt,
+            run_id: 1,
+            run_attempt: Some(attempt),
+            head_sha: String::new(),
+            head_branch: None,
+            name: name.to_string(),
+            status: RunStatus::Completed,
+            conclusion: Some(conclusion),
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_flaky_report() {
+        let run1 = vec![
+            job("test", 1, RunConclusion::Failure),
+            job("test", 2, RunConclusion::Success),
+            job("lint", 1, RunConclusion::Failure),
+            job("lint", 2, RunConclusion::Success),
+        ];
+        let run2 = vec![
+            job("test", 1, RunConclusion::Failure),
+            job("test", 2, RunConclusion::Success),
+            // Passed, then failed on retry: broken, not flaky
+            job("build", 1, RunConclusion::Success),
+            job("build", 2, RunConclusion::Failure),
+        ];
+        let run3 = vec![
+            job("lint", 1, RunConclusion::Failure),
+            job("lint", 2, RunConclusion::Failure),
+        ];
+
+        let report = FlakyReport::from_attempts(10, &[run1, run2, run3]);
+        assert_eq!(report.runs, 10);
+        assert_eq!(
+            report.jobs,
+            vec![
+                FlakyJob {
+                    name: "test".to_string(),
+                    runs: 2,
+                },
+                FlakyJob {
+                    name: "lint".to_string(),
+                    runs: 1,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,58 @@
+// Repository of the current directory.
+// Reads the origin remote so jolt can open the project the user is working in.
+
+use std::process::{Command, Stdio};
+
+/// Owner and name of the GitHub repository the current directory's origin points to.
+pub fn current_repo() -> Option<(String, String)> {
+    let output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_remote(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+/// Owner and name from a github.com remote URL, in HTTPS
+/// ("https://github.com/owner/repo.git"), SCP-like SSH
+/// ("git@github.com:owner/repo.git"), or SSH URL form.
+pub fn parse_remote(url: &str) -> Option<(String, String)> {
+    let path = if let Some(rest) = url.strip_prefix("git@github.com:") {
+        rest
+    } else {
+        let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let (host, path) = rest.split_once('/')?;
+        // Drop credentials (e.g., "git@" or "token@") before the host
+        let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+        if !host.eq_ignore_ascii_case("github.com") {
+            return None;
+        }
+        path
+    };
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.split_once('/')?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_remote() {
+        let jolt = Some(("phatblat".to_string(), "jolt".to_string()));
+        assert_eq!(parse_remote("https://github.com/phatblat/jolt.git"), jolt);
+        assert_eq!(parse_remote("https://github.com/phatblat/jolt"), jolt);
+        assert_eq!(parse_remote("git@github.com:phatblat/jolt.git"), jolt);
+        assert_eq!(parse_remote("ssh://git@github.com/phatblat/jolt.git"), jolt);
+        assert_eq!(parse_remote("https://gitlab.com/phatblat/jolt.git"), None);
+        assert_eq!(parse_remote("https://github.com/phatblat"), None);
+    }
+}
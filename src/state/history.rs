@@ -0,0 +1,270 @@
+// Historical job durations for ETA estimation.
+// Built from cached jobs of earlier runs of the same workflow.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs;
+
+use chrono::{DateTime, Utc};
+
+use crate::cache;
+use crate::github::{Job, RunConclusion, RunStatus, WorkflowRun};
+
+/// Durations (in seconds) of successfully completed jobs and their steps.
+#[derive(Debug, Clone, Default)]
+pub struct JobHistory {
+    /// Job durations keyed by job name.
+    jobs: HashMap<String, Vec<i64>>,
+    /// Step durations keyed by job name and step name.
+    steps: HashMap<(String, String), Vec<i64>>,
+}
+
+impl JobHistory {
+    /// Build history from completed jobs. Failed and cancelled jobs are
+    /// skipped since they usually stop early.
+    pub fn from_jobs<'a>(jobs: impl IntoIterator<Item = &'a Job>) -> Self {
+        let mut history = Self::default();
+        for job in jobs {
+            if job.conclusion != Some(RunConclusion::Success) {
+                continue;
+            }
+            if let (Some(start), Some(end)) = (job.started_at, job.completed_at) {
+                history
+                    .jobs
+                    .entry(job.name.clone())
+                    .or_default()
+                    .push(end.signed_duration_since(start).num_seconds());
+            }
+            for step in &job.steps {
+                if let (Some(start), Some(end)) = (step.started_at, step.completed_at) {
+                    history
+                        .steps
+                        .entry((job.name.clone(), step.name.clone()))
+                        .or_default()
+                        .push(end.signed_duration_since(start).num_seconds());
+                }
+            }
+        }
+        history
+    }
+
+    /// Load history from cached jobs of other runs of a workflow.
+    pub fn load(owner: &str, repo: &str, workflow_id: u64, exclude_run_id: u64) -> Self {
+        let Some(runs_dir) = cache::runs_dir(owner, repo, workflow_id) else {
+            return Self::default();
+        };
+        let Ok(entries) = fs::read_dir(runs_dir) else {
+            return Self::default();
+        };
+
+        let mut jobs = Vec::new();
+        for entry in entries.flatten() {
+            if entry.file_name().to_str() == Some(&exclude_run_id.to_string()) {
+                continue;
+            }
+            let path = entry.path().join("jobs.json");
+            if let Ok(Some(cached)) = cache::read_cached::<Vec<Job>>(&path) {
+                jobs.extend(cached.data);
+            }
+        }
+        Self::from_jobs(&jobs)
+    }
+
+    /// Estimated seconds remaining for an in-progress job.
+    ///
+    /// Sums typical durations of the steps that haven't finished, so the
+    /// estimate tightens as steps complete. Falls back to the typical job
+    /// duration when steps have no history.
+    pub fn eta(&self, job: &Job, now: DateTime<Utc>) -> Option<i64> {
+        if job.status != RunStatus::InProgress {
+            return None;
+        }
+        self.eta_from_steps(job, now).or_else(|| {
+            let typical = median(self.jobs.get(&job.name)?)?;
+            let elapsed = now.signed_duration_since(job.started_at?).num_seconds();
+            Some((typical - elapsed).max(0))
+        })
+    }
+
+    fn eta_from_steps(&self, job: &Job, now: DateTime<Utc>) -> Option<i64> {
+        if job.steps.is_empty() {
+            return None;
+        }
+        let mut remaining = 0;
+        for step in job
+            .steps
+            .iter()
+            .filter(|s| s.status != RunStatus::Completed)
+        {
+            let key = (job.name.clone(), step.name.clone());
+            let typical = median(self.steps.get(&key)?)?;
+            let elapsed = match (step.status, step.started_at) {
+                (RunStatus::InProgress, Some(start)) => {
+                    now.signed_duration_since(start).num_seconds()
+                }
+                _ => 0,
+            };
+            remaining += (typical - elapsed).max(0);
+        }
+        Some(remaining)
+    }
+}
+
+/// Success rate of the most recent completed runs, as (percent, runs counted).
+/// Cancelled and skipped runs don't count either way.
+pub fn success_rate(runs: &[WorkflowRun], limit: usize) -> Option<(u32, usize)> {
+    let mut recent: Vec<&WorkflowRun> = runs
+        .iter()
+        .filter(|run| {
+            !matches!(
+                run.conclusion,
+                None | Some(RunConclusion::Cancelled) | Some(RunConclusion::Skipped)
+            )
+        })
+        .collect();
+    recent.sort_by_key(|run| Reverse(run.created_at));
+    recent.truncate(limit);
+    if recent.is_empty() {
+        return None;
+    }
+    let successes = recent
+        .iter()
+        .filter(|run| run.conclusion == Some(RunConclusion::Success))
+        .count();
+    let percent = (successes * 100 / recent.len()) as u32;
+    Some((percent, recent.len()))
+}
+
+/// Median of a list of durations.
+fn median(values: &[i64]) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    Some(sorted[sorted.len() / 2])
+}
+
+/// Format an ETA for display (e.g., "~4m remaining").
+pub fn format_eta(secs: i64) -> String {
+    if secs < 60 {
+        "<1m remaining".to_string()
+    } else {
+        format!("~{}m remaining", (secs + 30) / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::Step;
+    use chrono::Duration;
+
+    fn step(name: &str, status: RunStatus, start: i64, end: Option<i64>) -> Step {
+        let base = DateTime::from_timestamp(0, 0).unwrap();
+        Step {
+            name: name.to_string(),
+            status,
+            conclusion: None,
+            number: 1,
+            started_at: Some(base + Duration::seconds(start)),
+            completed_at: end.map(|end| base + Duration::seconds(end)),
+        }
+    }
+
+    fn job(status: RunStatus, steps: Vec<Step>) -> Job {
+        let base = DateTime::from_timestamp(0, 0).unwrap();
+        let completed = status == RunStatus::Completed;
+        Job {
+            id: 1,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: "build".to_string(),
+            status,
+            conclusion: completed.then_some(RunConclusion::Success),
+            created_at: None,
+            started_at: Some(base),
+            completed_at: completed.then(|| base + Duration::seconds(600)),
+            html_url: String::new(),
+            steps,
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_eta_from_steps() {
+        let past = job(
+            RunStatus::Completed,
+            vec![
+                step("checkout", RunStatus::Completed, 0, Some(60)),
+                step("compile", RunStatus::Completed, 60, Some(600)),
+            ],
+        );
+        let history = JobHistory::from_jobs([&past]);
+
+        let running = job(
+            RunStatus::InProgress,
+            vec![
+                step("checkout", RunStatus::Completed, 0, Some(60)),
+                step("compile", RunStatus::InProgress, 60, None),
+            ],
+        );
+        let now = DateTime::from_timestamp(300, 0).unwrap();
+        // Compile typically takes 540s and has been running for 240s
+        assert_eq!(history.eta(&running, now), Some(300));
+    }
+
+    #[test]
+    fn test_eta_falls_back_to_job_duration() {
+        let past = job(RunStatus::Completed, Vec::new());
+        let history = JobHistory::from_jobs([&past]);
+
+        let running = job(RunStatus::InProgress, Vec::new());
+        let now = DateTime::from_timestamp(240, 0).unwrap();
+        assert_eq!(history.eta(&running, now), Some(360));
+        assert_eq!(JobHistory::default().eta(&running, now), None);
+    }
+
+    #[test]
+    fn test_success_rate() {
+        let run = |number: u64, conclusion: Option<RunConclusion>| WorkflowRun {
+            id: number,
+            name: None,
+            run_number: number,
+            run_attempt: None,
+            status: RunStatus::Completed,
+            conclusion,
+            workflow_id: 1,
+            head_branch: None,
+            head_sha: String::new(),
+            event: String::new(),
+            created_at: DateTime::from_timestamp(number as i64, 0).unwrap(),
+            updated_at: DateTime::from_timestamp(number as i64, 0).unwrap(),
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        };
+        let runs = vec![
+            run(1, Some(RunConclusion::Failure)),
+            run(2, Some(RunConclusion::Success)),
+            run(3, Some(RunConclusion::Cancelled)),
+            run(4, Some(RunConclusion::Success)),
+            run(5, None),
+        ];
+        assert_eq!(success_rate(&runs, 50), Some((66, 3)));
+        // Only the two most recent completed runs
+        assert_eq!(success_rate(&runs, 2), Some((100, 2)));
+        assert_eq!(success_rate(&[], 50), None);
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(20), "<1m remaining");
+        assert_eq!(format_eta(240), "~4m remaining");
+    }
+}
@@ -0,0 +1,208 @@
+// Home dashboard.
+// Summarizes what needs attention across favorites: failing runs, running runs,
+// offline runners, and recently viewed runs, each opened with Enter.
+
+use crate::github::{RunConclusion, Runner, WorkflowRun};
+
+use super::filter::Filterable;
+use super::latest_runs::latest_by_workflow;
+use super::recent::RecentItem;
+
+/// Recently viewed runs and jobs shown on the dashboard.
+pub const HOME_RECENT: usize = 5;
+
+/// Section of the dashboard an item is listed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HomeSection {
+    FailingRuns,
+    RunningRuns,
+    OfflineRunners,
+    Recent,
+}
+
+impl HomeSection {
+    pub fn title(&self) -> &'static str {
+        match self {
+            HomeSection::FailingRuns => "Failing runs",
+            HomeSection::RunningRuns => "Running runs",
+            HomeSection::OfflineRunners => "Offline runners",
+            HomeSection::Recent => "Recently viewed",
+        }
+    }
+}
+
+/// Item listed on the dashboard.
+#[derive(Debug, Clone)]
+pub enum HomeItem {
+    /// Latest run of a favorite repository's workflow, which failed.
+    FailingRun {
+        owner: String,
+        repo: String,
+        run: Box<WorkflowRun>,
+    },
+    /// Run in progress in a favorite repository.
+    RunningRun {
+        owner: String,
+        repo: String,
+        run: Box<WorkflowRun>,
+    },
+    /// Favorite runner that's offline.
+    OfflineRunner {
+        owner: String,
+        repo: String,
+        runner: Runner,
+    },
+    /// Recently viewed run or job.
+    Recent(RecentItem),
+}
+
+impl HomeItem {
+    pub fn section(&self) -> HomeSection {
+        match self {
+            HomeItem::FailingRun { .. } => HomeSection::FailingRuns,
+            HomeItem::RunningRun { .. } => HomeSection::RunningRuns,
+            HomeItem::OfflineRunner { .. } => HomeSection::OfflineRunners,
+            HomeItem::Recent(_) => HomeSection::Recent,
+        }
+    }
+
+    /// Id kept selected across reloads.
+    pub fn id(&self) -> u64 {
+        match self {
+            HomeItem::FailingRun { run, .. } | HomeItem::RunningRun { run, .. } => run.id,
+            HomeItem::OfflineRunner { runner, .. } => runner.id,
+            HomeItem::Recent(item) => item.job.as_ref().map_or(item.run_id, |job| job.id),
+        }
+    }
+
+    /// Page of the item on GitHub.
+    pub fn url(&self) -> String {
+        match self {
+            HomeItem::FailingRun { run, .. } | HomeItem::RunningRun { run, .. } => {
+                run.html_url.clone()
+            }
+            HomeItem::OfflineRunner { owner, repo, .. } => format!(
+                "https://github.com/{}/{}/settings/actions/runners",
+                owner, repo
+            ),
+            HomeItem::Recent(item) => match &item.job {
+                Some(job) => format!(
+                    "https://github.com/{}/{}/actions/runs/{}/job/{}",
+                    item.owner, item.repo, item.run_id, job.id
+                ),
+                None => format!(
+                    "https://github.com/{}/{}/actions/runs/{}",
+                    item.owner, item.repo, item.run_id
+                ),
+            },
+        }
+    }
+
+    /// The run or job to open in the Workflows tab; None for runners.
+    pub fn target(&self) -> Option<RecentItem> {
+        let (owner, repo, run) = match self {
+            HomeItem::FailingRun { owner, repo, run }
+            | HomeItem::RunningRun { owner, repo, run } => (owner, repo, run.as_ref()),
+            HomeItem::OfflineRunner { .. } => return None,
+            HomeItem::Recent(item) => return Some(item.clone()),
+        };
+        Some(RecentItem {
+            owner: owner.clone(),
+            repo: repo.clone(),
+            workflow_id: run.workflow_id,
+            workflow_name: run.name.clone().unwrap_or_default(),
+            run_id: run.id,
+            run_number: run.run_number,
+            job: None,
+            viewed_at: chrono::Utc::now(),
+        })
+    }
+}
+
+impl Filterable for HomeItem {
+    fn filter_text(&self) -> String {
+        match self {
+            HomeItem::FailingRun { owner, repo, run }
+            | HomeItem::RunningRun { owner, repo, run } => format!(
+                "{}/{} {} {}",
+                owner,
+                repo,
+                run.name.as_deref().unwrap_or_default(),
+                run.head_branch.as_deref().unwrap_or_default()
+            ),
+            HomeItem::OfflineRunner {
+                owner,
+                repo,
+                runner,
+            } => format!("{}/{} {}", owner, repo, runner.name),
+            HomeItem::Recent(item) => format!(
+                "{}/{} {} {}",
+                item.owner,
+                item.repo,
+                item.workflow_name,
+                item.job.as_ref().map_or("", |job| job.name.as_str())
+            ),
+        }
+    }
+}
+
+/// Whether a run or job with this conclusion failed.
+pub fn is_failure(conclusion: Option<RunConclusion>) -> bool {
+    matches!(
+        conclusion,
+        Some(RunConclusion::Failure | RunConclusion::TimedOut)
+    )
+}
+
+/// Workflows whose latest run among `runs` (newest first) failed, newest first.
+pub fn failing_runs(runs: Vec<WorkflowRun>) -> Vec<WorkflowRun> {
+    let mut failing: Vec<WorkflowRun> = latest_by_workflow(runs)
+        .into_values()
+        .filter(|run| is_failure(run.conclusion))
+        .collect();
+    failing.sort_by_key(|run| std::cmp::Reverse(run.updated_at));
+    failing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+    use chrono::DateTime;
+
+    fn run(id: u64, workflow_id: u64, conclusion: RunConclusion) -> WorkflowRun {
+        let at = DateTime::from_timestamp(id as i64, 0).unwrap();
+        WorkflowRun {
+            id,
+            name: None,
+            run_number: id,
+            run_attempt: None,
+            status: RunStatus::Completed,
+            conclusion: Some(conclusion),
+            workflow_id,
+            head_branch: None,
+            head_sha: String::new(),
+            event: String::new(),
+            created_at: at,
+            updated_at: at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_failing_runs_only_latest_per_workflow() {
+        let runs = vec![
+            run(5, 1, RunConclusion::Success),
+            run(4, 2, RunConclusion::Failure),
+            run(3, 3, RunConclusion::TimedOut),
+            run(2, 1, RunConclusion::Failure),
+        ];
+        // Workflow 1 recovered after failing
+        let ids: Vec<u64> = failing_runs(runs).iter().map(|run| run.id).collect();
+        assert_eq!(ids, [4, 3]);
+    }
+}
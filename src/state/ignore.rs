@@ -0,0 +1,135 @@
+// Runs hidden by config-defined ignore rules.
+// Keeps bot-triggered and scheduled runs (e.g., Dependabot updates or nightly
+// builds) from drowning out human activity in run lists.
+
+use crate::config::IgnoreRule;
+use crate::github::WorkflowRun;
+
+use super::workflows::SelectableList;
+
+/// Whether any rule hides `run`.
+pub fn is_ignored(rules: &[IgnoreRule], run: &WorkflowRun) -> bool {
+    rules.iter().any(|rule| rule_matches(rule, run))
+}
+
+/// Number of loaded runs the rules hide.
+pub fn ignored_count(rules: &[IgnoreRule], list: &SelectableList<WorkflowRun>) -> usize {
+    list.data.data().map_or(0, |runs| {
+        runs.items
+            .iter()
+            .filter(|run| is_ignored(rules, run))
+            .count()
+    })
+}
+
+/// Whether every field the rule sets matches. A rule setting none matches
+/// nothing, so an empty entry in the config doesn't hide every run.
+fn rule_matches(rule: &IgnoreRule, run: &WorkflowRun) -> bool {
+    let actor = run.actor.as_ref().map(|actor| actor.login.as_str());
+    let fields = [
+        (rule.actor.as_deref(), actor),
+        (rule.event.as_deref(), Some(run.event.as_str())),
+        (rule.workflow.as_deref(), run.name.as_deref()),
+    ];
+    let mut any_set = false;
+    for (pattern, value) in fields {
+        if let Some(pattern) = pattern {
+            any_set = true;
+            if !value.is_some_and(|value| wildcard_match(pattern, value)) {
+                return false;
+            }
+        }
+    }
+    any_set
+}
+
+/// Whether `text` matches `pattern`, where `*` matches any run of characters.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard: the whole text must match
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::{Owner, OwnerType, RunStatus};
+
+    fn run(actor: &str, event: &str, workflow: &str) -> WorkflowRun {
+        let now = chrono::Utc::now();
+        WorkflowRun {
+            id: 1,
+            name: Some(workflow.to_string()),
+            run_number: 1,
+            run_attempt: None,
+            status: RunStatus::Completed,
+            conclusion: None,
+            workflow_id: 1,
+            head_branch: None,
+            head_sha: String::new(),
+            event: event.to_string(),
+            created_at: now,
+            updated_at: now,
+            run_started_at: None,
+            actor: Some(Owner {
+                id: 0,
+                login: actor.to_string(),
+                owner_type: OwnerType::Bot,
+                avatar_url: None,
+            }),
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_ignore_rules() {
+        let rules = [
+            IgnoreRule {
+                actor: Some("dependabot[bot]".to_string()),
+                ..IgnoreRule::default()
+            },
+            IgnoreRule {
+                event: Some("schedule".to_string()),
+                workflow: Some("Nightly *".to_string()),
+                ..IgnoreRule::default()
+            },
+            IgnoreRule::default(),
+        ];
+        assert!(is_ignored(&rules, &run("dependabot[bot]", "push", "CI")));
+        assert!(is_ignored(
+            &rules,
+            &run("octocat", "schedule", "Nightly build")
+        ));
+        // Every field of a rule has to match
+        assert!(!is_ignored(&rules, &run("octocat", "schedule", "CI")));
+        assert!(!is_ignored(
+            &rules,
+            &run("octocat", "push", "Nightly build")
+        ));
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*[bot]", "renovate[bot]"));
+        assert!(wildcard_match("a*c*e", "abcde"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("a*c", "abcd"));
+        assert!(!wildcard_match("push", "pushed"));
+    }
+}
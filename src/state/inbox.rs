@@ -0,0 +1,64 @@
+// Pull request checks inbox.
+// Orders PRs awaiting the user so the ones with failing checks come first.
+
+use std::cmp::Reverse;
+
+use crate::github::{CheckState, InboxPullRequest};
+
+use super::workflows::text_id;
+
+/// Sort rank for a PR's checks: red, then running, then no checks, then green.
+fn check_rank(checks: Option<CheckState>) -> u8 {
+    match checks {
+        Some(CheckState::Failure | CheckState::Error) => 0,
+        Some(CheckState::Pending | CheckState::Expected) => 1,
+        None | Some(CheckState::Unknown) => 2,
+        Some(CheckState::Success) => 3,
+    }
+}
+
+/// Sort the inbox red first, most recently updated first within each state.
+pub fn sort_inbox(prs: &mut [InboxPullRequest]) {
+    prs.sort_by_key(|pr| (check_rank(pr.checks), Reverse(pr.updated_at)));
+}
+
+/// Identity of a PR in the inbox. Numbers repeat across repositories, so the
+/// PR's URL identifies it.
+pub fn inbox_id(pr: &InboxPullRequest) -> u64 {
+    text_id(&pr.url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::InboxReason;
+    use chrono::DateTime;
+
+    fn pr(number: u64, checks: Option<CheckState>, updated: i64) -> InboxPullRequest {
+        InboxPullRequest {
+            number,
+            title: String::new(),
+            url: String::new(),
+            repository: "phatblat/jolt".to_string(),
+            author: None,
+            is_draft: false,
+            updated_at: DateTime::from_timestamp(updated, 0).unwrap(),
+            checks,
+            reason: InboxReason::Authored,
+        }
+    }
+
+    #[test]
+    fn test_sort_inbox_red_first() {
+        let mut prs = vec![
+            pr(1, Some(CheckState::Success), 50),
+            pr(2, None, 40),
+            pr(3, Some(CheckState::Failure), 10),
+            pr(4, Some(CheckState::Pending), 30),
+            pr(5, Some(CheckState::Error), 20),
+        ];
+        sort_inbox(&mut prs);
+        let order: Vec<u64> = prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(order, [5, 3, 4, 2, 1]);
+    }
+}
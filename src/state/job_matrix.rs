@@ -0,0 +1,231 @@
+// Matrix jobs grouped under their base job.
+// GitHub names each matrix variant after the job with its matrix values in
+// parentheses (e.g., "test (ubuntu, 1.75)"); grouping nests the variants under
+// one header with their combined status.
+
+use std::collections::HashSet;
+
+use crate::github::{Job, RunConclusion, RunStatus};
+
+use super::workflows::SelectableList;
+
+/// Base job name and matrix values of a matrix variant's name, e.g.
+/// ("test", "ubuntu, 1.75") for "test (ubuntu, 1.75)". None for other jobs.
+pub fn matrix_parts(name: &str) -> Option<(&str, &str)> {
+    let inner = name.strip_suffix(')')?;
+    let (base, values) = inner.rsplit_once(" (")?;
+    (!base.is_empty() && !values.is_empty()).then_some((base, values))
+}
+
+/// Name of the job a job is a variant of, or its own name.
+pub fn base_name(name: &str) -> &str {
+    matrix_parts(name).map_or(name, |(base, _)| base)
+}
+
+/// Whether a job ended in failure.
+fn failed(job: &Job) -> bool {
+    matches!(
+        job.conclusion,
+        Some(RunConclusion::Failure | RunConclusion::TimedOut)
+    )
+}
+
+/// Variants of a matrix job, with their combined status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixGroup {
+    pub base: String,
+    pub variants: usize,
+    pub failed: usize,
+    pub status: RunStatus,
+    pub conclusion: Option<RunConclusion>,
+}
+
+/// Matrix groups of the loaded jobs, including variants hidden by folding or
+/// the failed-only filter, in the order their first variant was loaded.
+pub fn matrix_groups(list: &SelectableList<Job>) -> Vec<MatrixGroup> {
+    let jobs = list
+        .data
+        .data()
+        .map(|data| data.items.as_slice())
+        .unwrap_or_default();
+    let mut groups: Vec<(&str, Vec<&Job>)> = Vec::new();
+    for job in jobs {
+        let Some((base, _)) = matrix_parts(&job.name) else {
+            continue;
+        };
+        match groups.iter_mut().find(|(name, _)| *name == base) {
+            Some((_, variants)) => variants.push(job),
+            None => groups.push((base, vec![job])),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(base, variants)| {
+            let (status, conclusion) = combined_status(&variants);
+            MatrixGroup {
+                base: base.to_string(),
+                variants: variants.len(),
+                failed: variants.iter().filter(|job| failed(job)).count(),
+                status,
+                conclusion,
+            }
+        })
+        .collect()
+}
+
+/// Status of a set of jobs as one: failed if any failed, else running while
+/// any is unfinished, else successful if any succeeded.
+fn combined_status(jobs: &[&Job]) -> (RunStatus, Option<RunConclusion>) {
+    if let Some(job) = jobs.iter().find(|job| failed(job)) {
+        return (RunStatus::Completed, job.conclusion);
+    }
+    if let Some(job) = jobs.iter().find(|job| job.status != RunStatus::Completed) {
+        return (job.status, None);
+    }
+    let conclusion = [
+        RunConclusion::Cancelled,
+        RunConclusion::Success,
+        RunConclusion::Skipped,
+    ]
+    .into_iter()
+    .find(|c| jobs.iter().any(|job| job.conclusion == Some(*c)))
+    .or_else(|| jobs.first().and_then(|job| job.conclusion));
+    (RunStatus::Completed, conclusion)
+}
+
+/// Order jobs so each matrix job's variants are together, where its first
+/// variant was. Variants of groups in `collapsed` are folded down to one, the
+/// first that failed if any did; with `failed_only`, only failed variants
+/// are shown.
+pub fn group_jobs(list: &mut SelectableList<Job>, collapsed: &HashSet<String>, failed_only: bool) {
+    let first_seen: Vec<String> = list
+        .data
+        .data()
+        .map(|data| {
+            let mut seen = Vec::new();
+            for job in &data.items {
+                let base = base_name(&job.name).to_string();
+                if !seen.contains(&base) {
+                    seen.push(base);
+                }
+            }
+            seen
+        })
+        .unwrap_or_default();
+    let rank = |job: &Job| {
+        let base = base_name(&job.name);
+        first_seen.iter().position(|seen| seen == base)
+    };
+    list.sort_by(|a, b| rank(a).cmp(&rank(b)));
+
+    // The variant kept for each folded group
+    let mut kept = HashSet::new();
+    for group in matrix_groups(list) {
+        if !collapsed.contains(&group.base) {
+            continue;
+        }
+        let variants: Vec<&Job> = list
+            .sorted_items()
+            .into_iter()
+            .filter(|job| matrix_parts(&job.name).is_some_and(|(base, _)| base == group.base))
+            .collect();
+        let shown = variants.iter().find(|job| failed(job)).or(variants.first());
+        kept.extend(shown.map(|job| job.id));
+    }
+    list.set_hidden(|job| match matrix_parts(&job.name) {
+        Some((base, _)) => {
+            (failed_only && !failed(job)) || (collapsed.contains(base) && !kept.contains(&job.id))
+        }
+        None => false,
+    });
+}
+
+/// Restore load order and show every job.
+pub fn ungroup_jobs(list: &mut SelectableList<Job>) {
+    list.sort_by(|_, _| std::cmp::Ordering::Equal);
+    list.set_hidden(|_| false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(id: u64, name: &str, conclusion: Option<RunConclusion>) -> Job {
+        let status = match conclusion {
+            Some(_) => RunStatus::Completed,
+            None => RunStatus::InProgress,
+        };
+        Job {
+            id,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: name.to_string(),
+            status,
+            conclusion,
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    fn list() -> SelectableList<Job> {
+        let mut list = SelectableList::new();
+        list.set_loaded(
+            vec![
+                job(1, "test (ubuntu, 1.75)", Some(RunConclusion::Success)),
+                job(2, "lint", Some(RunConclusion::Success)),
+                job(3, "test (macos, 1.75)", Some(RunConclusion::Failure)),
+                job(4, "test (ubuntu, stable)", None),
+            ],
+            4,
+        );
+        list
+    }
+
+    fn ids(list: &SelectableList<Job>) -> Vec<u64> {
+        list.sorted_items().iter().map(|job| job.id).collect()
+    }
+
+    #[test]
+    fn test_matrix_parts() {
+        assert_eq!(
+            matrix_parts("test (ubuntu, 1.75)"),
+            Some(("test", "ubuntu, 1.75"))
+        );
+        assert_eq!(
+            matrix_parts("ci / build (x86_64)"),
+            Some(("ci / build", "x86_64"))
+        );
+        assert_eq!(matrix_parts("lint"), None);
+        assert_eq!(matrix_parts("(draft)"), None);
+        assert_eq!(base_name("lint"), "lint");
+    }
+
+    #[test]
+    fn test_group_jobs_nests_variants() {
+        let mut list = list();
+        group_jobs(&mut list, &HashSet::new(), false);
+        assert_eq!(ids(&list), [1, 3, 4, 2]);
+
+        let groups = matrix_groups(&list);
+        assert_eq!(groups.len(), 1);
+        assert_eq!((groups[0].variants, groups[0].failed), (3, 1));
+        assert_eq!(groups[0].conclusion, Some(RunConclusion::Failure));
+
+        // Folded to the failed variant, or narrowed to failed variants
+        let collapsed = HashSet::from(["test".to_string()]);
+        group_jobs(&mut list, &collapsed, false);
+        assert_eq!(ids(&list), [3, 2]);
+        group_jobs(&mut list, &HashSet::new(), true);
+        assert_eq!(ids(&list), [3, 2]);
+
+        ungroup_jobs(&mut list);
+        assert_eq!(ids(&list), [1, 2, 3, 4]);
+    }
+}
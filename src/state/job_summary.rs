@@ -0,0 +1,112 @@
+// Job summaries published through GITHUB_STEP_SUMMARY.
+// Many actions report their key results (e.g., test counts or coverage) there
+// rather than in the log; GitHub serves the summary as the output of the job's
+// check run.
+
+use crossterm::event::KeyCode;
+
+use crate::github::CheckRunOutput;
+
+use super::popup::PopupKey;
+
+/// Lines scrolled by a page in a job summary.
+pub const SUMMARY_PAGE_LINES: usize = 20;
+
+/// Summary of a job, shown in the summary popup.
+#[derive(Debug)]
+pub struct JobSummaryView {
+    pub job_name: String,
+    /// Markdown of the summary; empty when the job published none.
+    pub markdown: String,
+    pub scroll: usize,
+    /// Furthest the summary scrolls, from the lines it took when last drawn
+    /// (wrapped, with tables and code blocks laid out).
+    pub max_scroll: usize,
+}
+
+impl JobSummaryView {
+    pub fn new(job_name: String, markdown: String) -> Self {
+        Self {
+            job_name,
+            markdown,
+            scroll: 0,
+            max_scroll: 0,
+        }
+    }
+
+    /// Scroll by `delta` lines, staying within the summary as drawn.
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(delta)
+            .min(self.max_scroll);
+    }
+
+    /// Handle a key press: arrows and pages scroll, and Esc closes.
+    pub fn update(&mut self, code: KeyCode) -> PopupKey {
+        let page = SUMMARY_PAGE_LINES as isize;
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-page),
+            KeyCode::PageDown => self.scroll_by(page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::End | KeyCode::Char('G') => self.scroll_by(isize::MAX),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('u') => return PopupKey::Close,
+            _ => {}
+        }
+        PopupKey::Stay
+    }
+}
+
+/// Markdown of a check run's output: its title as a heading, then the summary
+/// and details, skipping parts that are missing or blank.
+pub fn summary_markdown(output: &CheckRunOutput) -> String {
+    let title = output
+        .title
+        .as_deref()
+        .filter(|title| !title.trim().is_empty())
+        .map(|title| format!("# {}", title.trim()));
+    [
+        title.as_deref(),
+        output.summary.as_deref(),
+        output.text.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(str::trim)
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>()
+    .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_markdown() {
+        let output = CheckRunOutput {
+            title: Some("Tests".to_string()),
+            summary: Some("## Results\n| Passed | Failed |\n".to_string()),
+            text: Some("  ".to_string()),
+        };
+        assert_eq!(
+            summary_markdown(&output),
+            "# Tests\n\n## Results\n| Passed | Failed |"
+        );
+        assert_eq!(summary_markdown(&CheckRunOutput::default()), "");
+    }
+
+    #[test]
+    fn test_scroll_stays_within_drawn_lines() {
+        let mut view = JobSummaryView::new("build".to_string(), "one\ntwo".to_string());
+        view.max_scroll = 30;
+        view.scroll_by(SUMMARY_PAGE_LINES as isize);
+        assert_eq!(view.scroll, 20);
+        view.update(KeyCode::End);
+        assert_eq!(view.scroll, 30);
+        view.scroll_by(-100);
+        assert_eq!(view.scroll, 0);
+    }
+}
@@ -0,0 +1,85 @@
+// Latest run of each workflow, for badges in the Workflows list.
+// Picked out of a repository's recent runs so one request covers most workflows.
+
+use std::collections::HashMap;
+
+use crate::github::{Workflow, WorkflowRun, WorkflowState};
+
+/// Most workflows missing from the repository's recent runs to fetch on their own.
+pub const MAX_SINGLE_FETCHES: usize = 20;
+
+/// The newest run of each workflow among `runs` (newest first, as the API lists them).
+pub fn latest_by_workflow(runs: Vec<WorkflowRun>) -> HashMap<u64, WorkflowRun> {
+    let mut latest = HashMap::new();
+    for run in runs {
+        latest.entry(run.workflow_id).or_insert(run);
+    }
+    latest
+}
+
+/// Active workflows without a run in `latest`, to fetch individually.
+pub fn missing_workflows(workflows: &[Workflow], latest: &HashMap<u64, WorkflowRun>) -> Vec<u64> {
+    workflows
+        .iter()
+        .filter(|w| w.state == WorkflowState::Active && !latest.contains_key(&w.id))
+        .map(|w| w.id)
+        .take(MAX_SINGLE_FETCHES)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+    use chrono::DateTime;
+
+    fn run(id: u64, workflow_id: u64) -> WorkflowRun {
+        let at = DateTime::from_timestamp(0, 0).unwrap();
+        WorkflowRun {
+            id,
+            name: None,
+            run_number: id,
+            run_attempt: None,
+            status: RunStatus::Completed,
+            conclusion: None,
+            workflow_id,
+            head_branch: None,
+            head_sha: String::new(),
+            event: String::new(),
+            created_at: at,
+            updated_at: at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn workflow(id: u64, state: WorkflowState) -> Workflow {
+        let at = DateTime::from_timestamp(0, 0).unwrap();
+        Workflow {
+            id,
+            name: format!("w{}", id),
+            path: String::new(),
+            state,
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    #[test]
+    fn test_latest_run_per_workflow() {
+        let latest = latest_by_workflow(vec![run(9, 1), run(8, 2), run(7, 1)]);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[&1].id, 9);
+        assert_eq!(latest[&2].id, 8);
+
+        let workflows = [
+            workflow(1, WorkflowState::Active),
+            workflow(3, WorkflowState::Active),
+            workflow(4, WorkflowState::DisabledManually),
+        ];
+        assert_eq!(missing_workflows(&workflows, &latest), [3]);
+    }
+}
@@ -0,0 +1,83 @@
+// Live view of in-progress runs.
+// Aggregates running workflow runs across favorite repositories.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::github::WorkflowRun;
+
+use super::filter::Filterable;
+
+/// In-progress run of a favorite repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveRun {
+    pub owner: String,
+    pub repo: String,
+    pub run: WorkflowRun,
+}
+
+impl LiveRun {
+    /// When the run's current attempt started.
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.run.run_started_at.unwrap_or(self.run.created_at)
+    }
+}
+
+impl Filterable for LiveRun {
+    fn filter_text(&self) -> String {
+        format!(
+            "{}/{} {} {}",
+            self.owner,
+            self.repo,
+            self.run.name.as_deref().unwrap_or_default(),
+            self.run.head_branch.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// Sort runs by start time, longest running first.
+pub fn sort_live(runs: &mut [LiveRun]) {
+    runs.sort_by_key(|live| live.started_at());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    fn live(repo: &str, created: i64, started: Option<i64>) -> LiveRun {
+        let at = |secs: i64| DateTime::from_timestamp(secs, 0).unwrap();
+        LiveRun {
+            owner: "o".to_string(),
+            repo: repo.to_string(),
+            run: WorkflowRun {
+                id: 1,
+                name: None,
+                run_number: 1,
+                run_attempt: None,
+                status: RunStatus::InProgress,
+                conclusion: None,
+                workflow_id: 1,
+                head_branch: None,
+                head_sha: String::new(),
+                event: String::new(),
+                created_at: at(created),
+                updated_at: at(created),
+                run_started_at: started.map(at),
+                actor: None,
+                triggering_actor: None,
+                html_url: String::new(),
+                pull_requests: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_sort_live_longest_running_first() {
+        // A re-run attempt started after the newer run was created
+        let mut runs = vec![live("a", 100, Some(300)), live("b", 200, None)];
+        sort_live(&mut runs);
+        let repos: Vec<&str> = runs.iter().map(|live| live.repo.as_str()).collect();
+        assert_eq!(repos, ["b", "a"]);
+    }
+}
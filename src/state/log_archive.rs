@@ -0,0 +1,207 @@
+// Log archives of whole runs.
+// Extracts the zip of every job's logs into the cache and lists its files, so
+// a run's logs (including setup and cleanup steps) stay readable after GitHub
+// expires them.
+
+use std::fs;
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+
+use crossterm::event::KeyCode;
+
+use super::popup::PopupKey;
+use super::workflows::SelectableList;
+
+/// Lines scrolled by a page in an archived log.
+pub const ARCHIVE_PAGE_LINES: usize = 20;
+
+/// A log file in a run's archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveFile {
+    /// Path within the archive (e.g., "build/1_Set up job.txt").
+    pub name: String,
+    pub size: u64,
+}
+
+/// An archived log being read.
+#[derive(Debug)]
+pub struct OpenArchiveFile {
+    pub name: String,
+    pub lines: Vec<String>,
+    pub scroll: usize,
+}
+
+impl OpenArchiveFile {
+    /// Scroll by `delta` lines, staying within the file.
+    pub fn scroll_by(&mut self, delta: isize) {
+        let last = self.lines.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(last);
+    }
+}
+
+/// Log archive of a run, shown in the archive popup.
+#[derive(Debug)]
+pub struct LogArchiveView {
+    pub owner: String,
+    pub repo: String,
+    pub run_number: u64,
+    /// Where the archive is extracted.
+    pub dir: PathBuf,
+    pub files: SelectableList<ArchiveFile>,
+    /// File opened from the list, if any.
+    pub open: Option<OpenArchiveFile>,
+}
+
+impl LogArchiveView {
+    pub fn new(owner: String, repo: String, run_number: u64, dir: PathBuf) -> Self {
+        Self {
+            owner,
+            repo,
+            run_number,
+            dir,
+            files: SelectableList::new(),
+            open: None,
+        }
+    }
+
+    /// Handle a key press. In the file list arrows move and Enter reads the
+    /// selected file; while reading, arrows and pages scroll and Esc returns
+    /// to the files.
+    pub fn update(&mut self, code: KeyCode) -> io::Result<PopupKey> {
+        let page = ARCHIVE_PAGE_LINES as isize;
+        if let Some(file) = self.open.as_mut() {
+            match code {
+                KeyCode::Up | KeyCode::Char('k') => file.scroll_by(-1),
+                KeyCode::Down | KeyCode::Char('j') => file.scroll_by(1),
+                KeyCode::PageUp => file.scroll_by(-page),
+                KeyCode::PageDown => file.scroll_by(page),
+                KeyCode::Home | KeyCode::Char('g') => file.scroll = 0,
+                KeyCode::End | KeyCode::Char('G') => file.scroll_by(isize::MAX),
+                KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => self.open = None,
+                KeyCode::Char('q') | KeyCode::Char('L') => return Ok(PopupKey::Close),
+                _ => {}
+            }
+            return Ok(PopupKey::Stay);
+        }
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.files.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => self.files.select_next(),
+            KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => self.open_selected()?,
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('L') => {
+                return Ok(PopupKey::Close);
+            }
+            _ => {}
+        }
+        Ok(PopupKey::Stay)
+    }
+
+    /// Read the selected file.
+    pub fn open_selected(&mut self) -> io::Result<()> {
+        let Some(file) = self.files.selected_item() else {
+            return Ok(());
+        };
+        let text = fs::read(self.dir.join(&file.name))?;
+        self.open = Some(OpenArchiveFile {
+            name: file.name.clone(),
+            lines: String::from_utf8_lossy(&text)
+                .lines()
+                .map(str::to_string)
+                .collect(),
+            scroll: 0,
+        });
+        Ok(())
+    }
+}
+
+/// Extract a run's log archive into `dir`, replacing what was there.
+pub fn extract_archive(bytes: &[u8], dir: &Path) -> io::Result<()> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(io::Error::other)?;
+    // Extract next to the old copy so a failed download doesn't lose it
+    let temp_dir = dir.with_extension("tmp");
+    if temp_dir.exists() {
+        fs::remove_dir_all(&temp_dir)?;
+    }
+    archive.extract(&temp_dir).map_err(io::Error::other)?;
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::rename(&temp_dir, dir)
+}
+
+/// Files of an extracted archive, jobs before their steps, in run order.
+pub fn list_archive(dir: &Path) -> io::Result<Vec<ArchiveFile>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(&current)? {
+            let entry = entry?;
+            let path = entry.path();
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                pending.push(path);
+            } else if let Ok(relative) = path.strip_prefix(dir) {
+                let name = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.push(ArchiveFile {
+                    name,
+                    size: metadata.len(),
+                });
+            }
+        }
+    }
+    files.sort_by_cached_key(|file| sort_key(&file.name));
+    Ok(files)
+}
+
+/// Order of an archive path: by depth, then by the number GitHub prefixes to
+/// job and step files (e.g., "10_Run tests.txt" after "2_Checkout.txt").
+fn sort_key(name: &str) -> Vec<(u64, String)> {
+    name.split('/')
+        .map(|part| {
+            let digits: String = part.chars().take_while(char::is_ascii_digit).collect();
+            let number = digits.parse().unwrap_or(u64::MAX);
+            (number, part.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_extract_and_list_archive() {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, text) in [
+            ("build/10_Run tests.txt", "ok"),
+            ("0_build.txt", "all"),
+            ("build/2_Checkout.txt", "checkout"),
+        ] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(text.as_bytes()).unwrap();
+        }
+        let bytes = zip.finish().unwrap().into_inner();
+
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("logs");
+        extract_archive(&bytes, &dir).unwrap();
+        let names: Vec<String> = list_archive(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|file| file.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "0_build.txt",
+                "build/2_Checkout.txt",
+                "build/10_Run tests.txt"
+            ]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// Progressive loading of a job log.
+// Reads a large log's response body a little at a time so its first lines can
+// be shown while the rest downloads.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use reqwest::Response;
+
+use crate::error::{JoltError, Result};
+
+use super::caches::format_size;
+
+/// A job log being downloaded, shown as far as it has arrived.
+#[derive(Debug)]
+pub struct LogStream {
+    pub job_id: u64,
+    /// Where to cache the complete log, if anywhere.
+    pub cache_path: Option<PathBuf>,
+    /// Whether to scroll to the first error once the log is complete.
+    pub jump_to_error: bool,
+    response: Response,
+    body: Vec<u8>,
+    /// Bytes of `body` already handed out as complete lines.
+    shown: usize,
+    /// Size of the log, when the server sends one.
+    total: Option<u64>,
+}
+
+impl LogStream {
+    pub fn new(
+        job_id: u64,
+        response: Response,
+        cache_path: Option<PathBuf>,
+        jump_to_error: bool,
+    ) -> Self {
+        let total = response.content_length();
+        Self {
+            job_id,
+            cache_path,
+            jump_to_error,
+            response,
+            body: Vec::new(),
+            shown: 0,
+            total,
+        }
+    }
+
+    /// Read the body until `budget` runs out, even while the server is slow to
+    /// send the next chunk. Returns whether the log is complete.
+    pub async fn read(&mut self, budget: Duration) -> Result<bool> {
+        let deadline = Instant::now() + budget;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(false);
+            }
+            match tokio::time::timeout(remaining, self.response.chunk()).await {
+                Ok(chunk) => match chunk.map_err(JoltError::Api)? {
+                    Some(chunk) => self.body.extend_from_slice(&chunk),
+                    None => return Ok(true),
+                },
+                Err(_) => return Ok(false),
+            }
+        }
+    }
+
+    /// Lines completed since the last call, to append to those shown. A line
+    /// cut off mid-chunk waits for the next read.
+    pub fn new_lines(&mut self) -> String {
+        let end = self.shown + complete_lines(&self.body[self.shown..]).len();
+        let lines = String::from_utf8_lossy(&self.body[self.shown..end]).into_owned();
+        self.shown = end;
+        lines
+    }
+
+    /// The whole log, once complete.
+    pub fn into_text(self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Bytes loaded so far, out of the total when known (e.g., "1.2 MB of 4.0 MB").
+    pub fn progress(&self) -> String {
+        let loaded = format_size(self.body.len() as u64);
+        match self.total {
+            Some(total) => format!("{} of {}", loaded, format_size(total)),
+            None => loaded,
+        }
+    }
+}
+
+/// Bytes up to and including the last line break.
+fn complete_lines(body: &[u8]) -> &[u8] {
+    let end = body.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    &body[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_lines() {
+        assert_eq!(complete_lines(b"one\ntwo\nthr"), b"one\ntwo\n");
+        assert_eq!(complete_lines(b"one\n"), b"one\n");
+        assert_eq!(complete_lines(b"partial"), b"");
+        // A multi-byte character split across chunks waits for the rest
+        assert_eq!(complete_lines("ok\n✅".as_bytes()[..5].as_ref()), b"ok\n");
+    }
+
+    #[tokio::test]
+    async fn test_new_lines_are_handed_out_once() {
+        let response = Response::from(http::Response::new("one\ntw"));
+        let mut stream = LogStream::new(1, response, None, false);
+        assert!(stream.read(Duration::from_secs(1)).await.unwrap());
+        assert_eq!(stream.new_lines(), "one\n");
+        assert_eq!(stream.new_lines(), "");
+
+        stream.body.extend_from_slice(b"o\nthree\n");
+        assert_eq!(stream.new_lines(), "two\nthree\n");
+        assert_eq!(stream.into_text(), "one\ntwo\nthree\n");
+    }
+}
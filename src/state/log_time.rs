@@ -0,0 +1,173 @@
+// Timestamps of job log lines.
+// Hides them or replaces them with time elapsed since the line's step started.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::github::Step;
+use crate::state::test_report::strip_timestamp;
+
+/// Gap after a line, before the next one is logged, that marks it as slow.
+const SLOW_LINE_SECS: i64 = 10;
+
+/// How the timestamps starting each log line are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimestampMode {
+    /// As logged by GitHub.
+    #[default]
+    Shown,
+    /// Stripped from the line.
+    Hidden,
+    /// Time since the line's step (or else the job) started.
+    Elapsed,
+}
+
+impl TimestampMode {
+    /// The mode after this one, wrapping around.
+    pub fn next(&self) -> Self {
+        match self {
+            TimestampMode::Shown => TimestampMode::Hidden,
+            TimestampMode::Hidden => TimestampMode::Elapsed,
+            TimestampMode::Elapsed => TimestampMode::Shown,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimestampMode::Shown => "timestamps",
+            TimestampMode::Hidden => "no timestamps",
+            TimestampMode::Elapsed => "elapsed",
+        }
+    }
+}
+
+/// Timing of a log line within its step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineElapsed {
+    /// Time since the line's step started.
+    pub since_start: Duration,
+    /// Whether the next line came at least `SLOW_LINE_SECS` later.
+    pub slow: bool,
+}
+
+/// Split a log line into its timestamp and the rest of the line.
+pub fn split_timestamp(line: &str) -> Option<(DateTime<Utc>, &str)> {
+    let rest = strip_timestamp(line);
+    let stamp = line.strip_suffix(rest)?.strip_suffix(' ')?;
+    Some((stamp.parse().ok()?, rest))
+}
+
+/// Elapsed time of each log line since its step started, or since the job's
+/// first line for lines before any known step. Lines without a timestamp get None.
+pub fn elapsed_times(logs: &str, steps: &[Step]) -> Vec<Option<LineElapsed>> {
+    let times: Vec<Option<DateTime<Utc>>> = logs
+        .lines()
+        .map(|line| split_timestamp(line).map(|(time, _)| time))
+        .collect();
+    let Some(job_start) = times.iter().flatten().next().copied() else {
+        return vec![None; times.len()];
+    };
+    let slow = Duration::seconds(SLOW_LINE_SECS);
+
+    times
+        .iter()
+        .enumerate()
+        .map(|(i, time)| {
+            let time = (*time)?;
+            // Step times are whole seconds, so compare at that precision
+            let whole = DateTime::from_timestamp(time.timestamp(), 0)?;
+            let start = steps
+                .iter()
+                .filter_map(|step| step.started_at)
+                .filter(|start| *start <= whole)
+                .max()
+                .unwrap_or(job_start);
+            let next = times[i + 1..].iter().flatten().next();
+            Some(LineElapsed {
+                since_start: (time - start).max(Duration::zero()),
+                slow: next.is_some_and(|next| *next - time >= slow),
+            })
+        })
+        .collect()
+}
+
+/// Format an elapsed time compactly (e.g., "4.2s", "3m07s", "1h05m").
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.num_seconds();
+    if secs < 60 {
+        format!("{:.1}s", elapsed.num_milliseconds() as f64 / 1000.0)
+    } else if secs < 3600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    const LOGS: &str = "\
+2024-05-01T10:00:00.5000000Z Current runner version: '2.316.0'
+2024-05-01T10:00:01.0000000Z Syncing repository
+no timestamp
+2024-05-01T10:00:05.2500000Z ##[group]Run cargo build
+2024-05-01T10:00:30.0000000Z    Compiling jolt v0.1.0";
+
+    fn step(number: u64, started: &str) -> Step {
+        Step {
+            name: format!("step {}", number),
+            status: RunStatus::Completed,
+            conclusion: None,
+            number,
+            started_at: started.parse().ok(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_elapsed_since_step_start() {
+        let steps = vec![
+            step(1, "2024-05-01T10:00:00Z"),
+            step(2, "2024-05-01T10:00:05Z"),
+        ];
+        let elapsed = elapsed_times(LOGS, &steps);
+        assert_eq!(elapsed.len(), 5);
+        assert_eq!(
+            elapsed[1].map(|e| e.since_start),
+            Some(Duration::seconds(1))
+        );
+        assert_eq!(elapsed[2], None);
+        assert_eq!(
+            elapsed[3].map(|e| e.since_start),
+            Some(Duration::milliseconds(250))
+        );
+        // The build step's first line waited 25 seconds for the next one
+        assert!(elapsed[3].is_some_and(|e| e.slow));
+        assert!(!elapsed[1].is_some_and(|e| e.slow));
+    }
+
+    #[test]
+    fn test_elapsed_without_steps_counts_from_job_start() {
+        let elapsed = elapsed_times(LOGS, &[]);
+        assert_eq!(
+            elapsed[4].map(|e| e.since_start),
+            Some(Duration::milliseconds(29500))
+        );
+    }
+
+    #[test]
+    fn test_split_timestamp() {
+        let (_, rest) = split_timestamp("2024-05-01T10:00:01.0000000Z Syncing").unwrap();
+        assert_eq!(rest, "Syncing");
+        assert!(split_timestamp("Syncing repository").is_none());
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::milliseconds(4200)), "4.2s");
+        assert_eq!(format_elapsed(Duration::seconds(187)), "3m07s");
+        assert_eq!(format_elapsed(Duration::seconds(3900)), "1h05m");
+    }
+}
@@ -0,0 +1,134 @@
+// Per-job log viewer state kept across visits and sessions.
+// Holds where a log was scrolled, what it was searched for, and bookmarked
+// lines, so returning to a log resumes where it was left.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Most jobs whose log viewer state is kept; the least recently viewed go first.
+const MAX_LOG_VIEWS: usize = 200;
+
+/// Log viewer state of one job.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogViewState {
+    /// Bookmarked lines (0-based).
+    #[serde(default)]
+    pub bookmarks: BTreeSet<usize>,
+    /// Scroll position when the log was last viewed.
+    #[serde(default)]
+    pub scroll_x: u16,
+    #[serde(default)]
+    pub scroll_y: u16,
+    /// Last search query, searched again when the log is reopened.
+    #[serde(default)]
+    pub search: String,
+    /// When the log was last viewed (None in older saved state).
+    #[serde(default)]
+    pub viewed_at: Option<DateTime<Utc>>,
+}
+
+impl LogViewState {
+    /// Bookmark `line`, or remove its bookmark. Returns whether it's now bookmarked.
+    pub fn toggle_bookmark(&mut self, line: usize) -> bool {
+        if self.bookmarks.remove(&line) {
+            false
+        } else {
+            self.bookmarks.insert(line);
+            true
+        }
+    }
+
+    /// First bookmark below `line`, wrapping around to the first one.
+    pub fn next_bookmark(&self, line: usize) -> Option<usize> {
+        self.bookmarks
+            .range(line + 1..)
+            .next()
+            .or_else(|| self.bookmarks.first())
+            .copied()
+    }
+
+    /// Last bookmark above `line`, wrapping around to the last one.
+    pub fn prev_bookmark(&self, line: usize) -> Option<usize> {
+        self.bookmarks
+            .range(..line)
+            .next_back()
+            .or_else(|| self.bookmarks.last())
+            .copied()
+    }
+
+    /// Whether there's nothing worth saving.
+    pub fn is_empty(&self) -> bool {
+        self.bookmarks.is_empty()
+            && self.scroll_x == 0
+            && self.scroll_y == 0
+            && self.search.is_empty()
+    }
+}
+
+/// Forget the state of the least recently viewed logs beyond `MAX_LOG_VIEWS`.
+pub fn prune_log_views(views: &mut HashMap<u64, LogViewState>) {
+    let excess = views.len().saturating_sub(MAX_LOG_VIEWS);
+    if excess == 0 {
+        return;
+    }
+    let mut by_age: Vec<(Option<DateTime<Utc>>, u64)> = views
+        .iter()
+        .map(|(job_id, state)| (state.viewed_at, *job_id))
+        .collect();
+    by_age.sort_unstable();
+    for (_, job_id) in &by_age[..excess] {
+        views.remove(job_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookmarks_wrap_around() {
+        let mut state = LogViewState::default();
+        assert_eq!(state.next_bookmark(0), None);
+        assert!(state.toggle_bookmark(10));
+        assert!(state.toggle_bookmark(40));
+        assert_eq!(state.next_bookmark(10), Some(40));
+        assert_eq!(state.next_bookmark(40), Some(10));
+        assert_eq!(state.prev_bookmark(40), Some(10));
+        assert_eq!(state.prev_bookmark(5), Some(40));
+        assert!(!state.toggle_bookmark(10));
+        assert_eq!(state.next_bookmark(40), Some(40));
+        assert!(!state.toggle_bookmark(40));
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_older_state_loads_with_defaults() {
+        let state: LogViewState = serde_json::from_str(r#"{"bookmarks": [3]}"#).unwrap();
+        assert_eq!(state.bookmarks.len(), 1);
+        assert_eq!((state.scroll_x, state.scroll_y), (0, 0));
+        assert!(state.search.is_empty());
+        assert!(state.viewed_at.is_none());
+    }
+
+    #[test]
+    fn test_least_recently_viewed_are_pruned() {
+        let start = DateTime::from_timestamp(0, 0).unwrap();
+        let mut views: HashMap<u64, LogViewState> = (0..MAX_LOG_VIEWS as u64 + 2)
+            .map(|job_id| {
+                let state = LogViewState {
+                    scroll_y: 1,
+                    viewed_at: Some(start + chrono::Duration::seconds(job_id as i64)),
+                    ..LogViewState::default()
+                };
+                (job_id, state)
+            })
+            .collect();
+        views.get_mut(&5).unwrap().viewed_at = None;
+        prune_log_views(&mut views);
+        assert_eq!(views.len(), MAX_LOG_VIEWS);
+        assert!(!views.contains_key(&5) && !views.contains_key(&0));
+        assert!(views.contains_key(&1));
+    }
+}
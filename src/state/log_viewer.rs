@@ -0,0 +1,113 @@
+// Log viewer state shared by the Workflows and Runners tabs.
+// Both tabs show a job's log the same way, so loading and scrolling live here
+// once and each tab keeps one of these for its current log.
+
+use super::workflows::{LoadingState, first_error_line};
+
+/// Columns scrolled by Left and Right.
+const COLUMN_STEP: u16 = 4;
+
+/// Lines scrolled by Page Up and Page Down.
+const PAGE_LINES: u16 = 20;
+
+/// Lines left visible above the end when scrolling to the end.
+const END_CONTEXT_LINES: u16 = 10;
+
+/// The current log and where it's scrolled.
+#[derive(Debug, Default)]
+pub struct LogViewerState {
+    /// Log content for the current job.
+    pub content: LoadingState<String>,
+    /// Horizontal scroll offset.
+    pub scroll_x: u16,
+    /// Vertical scroll offset.
+    pub scroll_y: u16,
+}
+
+impl LogViewerState {
+    /// Drop the log and scroll back to the start.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Scroll up by `lines`.
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.scroll_y = self.scroll_y.saturating_sub(lines);
+    }
+
+    /// Scroll down by `lines`.
+    pub fn scroll_down(&mut self, lines: u16) {
+        self.scroll_y = self.scroll_y.saturating_add(lines);
+    }
+
+    /// Scroll left by a few columns.
+    pub fn scroll_left(&mut self) {
+        self.scroll_x = self.scroll_x.saturating_sub(COLUMN_STEP);
+    }
+
+    /// Scroll right by a few columns.
+    pub fn scroll_right(&mut self) {
+        self.scroll_x = self.scroll_x.saturating_add(COLUMN_STEP);
+    }
+
+    /// Scroll up by a page.
+    pub fn page_up(&mut self) {
+        self.scroll_up(PAGE_LINES);
+    }
+
+    /// Scroll down by a page.
+    pub fn page_down(&mut self) {
+        self.scroll_down(PAGE_LINES);
+    }
+
+    /// Scroll back to the first line and column.
+    pub fn scroll_to_start(&mut self) {
+        self.scroll_y = 0;
+        self.scroll_x = 0;
+    }
+
+    /// Scroll to the last page of a loaded log.
+    pub fn scroll_to_end(&mut self) {
+        if let LoadingState::Loaded(logs) = &self.content {
+            let line_count = logs.lines().count() as u16;
+            self.scroll_y = line_count.saturating_sub(END_CONTEXT_LINES);
+        }
+    }
+
+    /// Scroll so `line` is at the top, back at the first column.
+    pub fn scroll_to_line(&mut self, line: usize) {
+        self.scroll_y = line as u16;
+        self.scroll_x = 0;
+    }
+
+    /// Scroll a freshly opened log to its first error line.
+    pub fn scroll_to_first_error(&mut self) {
+        if self.scroll_y != 0 {
+            return;
+        }
+        if let Some(line) = self.content.data().and_then(|logs| first_error_line(logs)) {
+            self.scroll_to_line(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_stays_in_bounds() {
+        let mut log = LogViewerState::default();
+        log.page_up();
+        log.scroll_left();
+        assert_eq!((log.scroll_x, log.scroll_y), (0, 0));
+
+        let logs: Vec<String> = (0..30).map(|i| format!("line {}", i)).collect();
+        log.content = LoadingState::Loaded(logs.join("\n"));
+        log.scroll_to_end();
+        assert_eq!(log.scroll_y, 20);
+        log.scroll_right();
+        log.scroll_to_start();
+        assert_eq!((log.scroll_x, log.scroll_y), (0, 0));
+    }
+}
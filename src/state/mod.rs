@@ -3,10 +3,46 @@
 
 #![allow(dead_code)]
 
+pub mod branches;
+pub mod caches;
+pub mod checks;
+pub mod churn;
+pub mod concurrency;
+pub mod filter;
+pub mod flaky;
+pub mod git_remote;
+pub mod history;
+pub mod home;
+pub mod ignore;
+pub mod inbox;
+pub mod job_matrix;
+pub mod job_summary;
+pub mod latest_runs;
+pub mod live;
+pub mod log_archive;
+pub mod log_stream;
+pub mod log_time;
+pub mod log_view;
+pub mod log_viewer;
 pub mod navigation;
+pub mod permalink;
+pub mod policy;
+pub mod popup;
+pub mod prefetch;
+pub mod queue;
+pub mod recent;
+pub mod run_groups;
 pub mod runners;
+pub mod schedule;
+pub mod secrets;
+pub mod sorted_view;
+pub mod test_report;
+pub mod usage;
+pub mod utilization;
 pub mod workflows;
+pub mod workspace;
 
-pub use navigation::{NavigationStack, ViewLevel};
-pub use runners::{RunnersNavStack, RunnersTabState, RunnersViewLevel};
+pub use history::JobHistory;
+pub use navigation::{NavContext, NavigationStack, ViewLevel};
+pub use runners::{RunnerRow, RunnersNavStack, RunnersTabState, RunnersViewLevel};
 pub use workflows::{LoadingState, SelectableList, WorkflowsTabState};
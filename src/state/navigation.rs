@@ -14,6 +14,15 @@ pub struct BreadcrumbNode {
     pub level: ViewLevel,
 }
 
+/// Repository, branch, and run the user is currently looking at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NavContext {
+    pub owner: Option<String>,
+    pub repo: Option<String>,
+    pub branch: Option<String>,
+    pub run_number: Option<u64>,
+}
+
 /// The current view level in the navigation hierarchy.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ViewLevel {
@@ -23,6 +32,14 @@ pub enum ViewLevel {
     Repositories { owner: String },
     /// Workflows for a specific repository
     Workflows { owner: String, repo: String },
+    /// Deployment environments for a specific repository
+    Environments { owner: String, repo: String },
+    /// Recent deployments to an environment
+    Deployments {
+        owner: String,
+        repo: String,
+        environment: String,
+    },
     /// Runs for a specific workflow
     Runs {
         owner: String,
@@ -58,6 +75,10 @@ impl ViewLevel {
             ViewLevel::Owners => "Owners".to_string(),
             ViewLevel::Repositories { owner } => format!("{} / Repositories", owner),
             ViewLevel::Workflows { owner, repo } => format!("{}/{} / Workflows", owner, repo),
+            ViewLevel::Environments { owner, repo } => {
+                format!("{}/{} / Environments", owner, repo)
+            }
+            ViewLevel::Deployments { environment, .. } => format!("{} / Deployments", environment),
             ViewLevel::Runs { workflow_name, .. } => format!("{} / Runs", workflow_name),
             ViewLevel::Jobs { run_number, .. } => format!("Run #{} / Jobs", run_number),
             ViewLevel::Logs { job_name, .. } => format!("{} / Logs", job_name),
@@ -70,6 +91,8 @@ impl ViewLevel {
             ViewLevel::Owners => "Owners".to_string(),
             ViewLevel::Repositories { owner } => owner.clone(),
             ViewLevel::Workflows { repo, .. } => repo.clone(),
+            ViewLevel::Environments { .. } => "Environments".to_string(),
+            ViewLevel::Deployments { environment, .. } => environment.clone(),
             ViewLevel::Runs { workflow_name, .. } => workflow_name.clone(),
             ViewLevel::Jobs { run_number, .. } => format!("#{}", run_number),
             ViewLevel::Logs { job_name, .. } => job_name.clone(),
@@ -114,6 +137,11 @@ impl NavigationStack {
         }
     }
 
+    /// Levels from root to current.
+    pub fn levels(&self) -> &[ViewLevel] {
+        &self.stack
+    }
+
     /// Check if we can go back (not at root).
     pub fn can_go_back(&self) -> bool {
         self.stack.len() > 1
@@ -127,6 +155,36 @@ impl NavigationStack {
             .collect()
     }
 
+    /// Collect the owner, repo, and run number from the levels on the stack.
+    pub fn context(&self) -> NavContext {
+        let mut context = NavContext::default();
+        for level in &self.stack {
+            match level {
+                ViewLevel::Owners => {}
+                ViewLevel::Repositories { owner } => context.owner = Some(owner.clone()),
+                ViewLevel::Workflows { owner, repo }
+                | ViewLevel::Environments { owner, repo }
+                | ViewLevel::Deployments { owner, repo, .. }
+                | ViewLevel::Runs { owner, repo, .. }
+                | ViewLevel::Logs { owner, repo, .. } => {
+                    context.owner = Some(owner.clone());
+                    context.repo = Some(repo.clone());
+                }
+                ViewLevel::Jobs {
+                    owner,
+                    repo,
+                    run_number,
+                    ..
+                } => {
+                    context.owner = Some(owner.clone());
+                    context.repo = Some(repo.clone());
+                    context.run_number = Some(*run_number);
+                }
+            }
+        }
+        context
+    }
+
     /// Reset to root level.
     pub fn reset(&mut self) {
         self.stack.truncate(1);
@@ -199,4 +257,30 @@ mod tests {
         assert_eq!(breadcrumbs[1].label, "phatblat");
         assert_eq!(breadcrumbs[2].label, "jolt");
     }
+
+    #[test]
+    fn test_context() {
+        let mut nav = NavigationStack::default();
+        assert_eq!(nav.context(), NavContext::default());
+
+        nav.push(ViewLevel::Repositories {
+            owner: "phatblat".to_string(),
+        });
+        nav.push(ViewLevel::Workflows {
+            owner: "phatblat".to_string(),
+            repo: "jolt".to_string(),
+        });
+        nav.push(ViewLevel::Jobs {
+            owner: "phatblat".to_string(),
+            repo: "jolt".to_string(),
+            workflow_id: 1,
+            run_id: 99,
+            run_number: 42,
+        });
+
+        let context = nav.context();
+        assert_eq!(context.owner.as_deref(), Some("phatblat"));
+        assert_eq!(context.repo.as_deref(), Some("jolt"));
+        assert_eq!(context.run_number, Some(42));
+    }
 }
@@ -0,0 +1,93 @@
+// Pasted GitHub Actions URLs.
+// Parses run, job, and log line permalinks so they can be opened in the Workflows tab.
+
+/// Run, job, and log line referenced by a GitHub Actions URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permalink {
+    pub owner: String,
+    pub repo: String,
+    pub run_id: u64,
+    pub job_id: Option<u64>,
+    /// Step number and 1-based line within the step, from a "#step:3:12" anchor.
+    pub step_line: Option<(u64, usize)>,
+}
+
+impl Permalink {
+    /// Parse a run URL (".../actions/runs/1"), job URL (".../runs/1/job/2"), or
+    /// log line permalink (".../job/2#step:3:12"). The scheme and host are optional.
+    pub fn parse(url: &str) -> Option<Self> {
+        let url = url.trim();
+        let (url, fragment) = match url.split_once('#') {
+            Some((url, fragment)) => (url, Some(fragment)),
+            None => (url, None),
+        };
+        let url = url.split('?').next().unwrap_or(url);
+        let path = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_start_matches("www.")
+            .trim_start_matches("github.com/");
+
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let [owner, repo, "actions", "runs", run_id, rest @ ..] = segments.as_slice() else {
+            return None;
+        };
+        let job_id = match rest {
+            ["job", job_id, ..] => Some(job_id.parse().ok()?),
+            _ => None,
+        };
+        let step_line = fragment
+            .and_then(|fragment| fragment.strip_prefix("step:"))
+            .and_then(|anchor| {
+                let (step, line) = anchor.split_once(':')?;
+                Some((step.parse().ok()?, line.parse().ok()?))
+            })
+            .filter(|_| job_id.is_some());
+
+        Some(Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            run_id: run_id.parse().ok()?,
+            job_id,
+            step_line,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_run_url() {
+        let link = Permalink::parse("https://github.com/phatblat/jolt/actions/runs/123/attempts/2")
+            .unwrap();
+        assert_eq!(link.owner, "phatblat");
+        assert_eq!(link.repo, "jolt");
+        assert_eq!(link.run_id, 123);
+        assert_eq!(link.job_id, None);
+    }
+
+    #[test]
+    fn test_parse_log_line_permalink() {
+        let link =
+            Permalink::parse(" github.com/phatblat/jolt/actions/runs/123/job/456?pr=7#step:3:12 ")
+                .unwrap();
+        assert_eq!(link.run_id, 123);
+        assert_eq!(link.job_id, Some(456));
+        assert_eq!(link.step_line, Some((3, 12)));
+    }
+
+    #[test]
+    fn test_parse_rejects_other_urls() {
+        assert_eq!(
+            Permalink::parse("https://github.com/phatblat/jolt/pull/5"),
+            None
+        );
+        assert_eq!(
+            Permalink::parse("https://github.com/phatblat/jolt/actions/runs/latest"),
+            None
+        );
+        assert_eq!(Permalink::parse(""), None);
+    }
+}
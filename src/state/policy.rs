@@ -0,0 +1,176 @@
+// Actions permissions and policy of a repository and its organization.
+// A read-only summary for debugging "action not allowed by policy" failures.
+
+use crate::github::{ActionsPermissions, ForkPrApproval, SelectedActions, WorkflowPermissions};
+
+/// A policy setting and its value in words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicySetting {
+    pub name: &'static str,
+    pub value: String,
+}
+
+/// Policy settings fetched for a repository or organization; None where the
+/// token couldn't read them.
+#[derive(Debug, Default)]
+pub struct PolicyParts {
+    pub permissions: Option<ActionsPermissions>,
+    pub selected: Option<SelectedActions>,
+    pub workflow: Option<WorkflowPermissions>,
+    pub fork_approval: Option<ForkPrApproval>,
+}
+
+impl PolicyParts {
+    /// Settings in display order, skipping those that weren't read.
+    pub fn settings(&self) -> Vec<PolicySetting> {
+        let mut settings = Vec::new();
+        let mut push = |name, value: String| settings.push(PolicySetting { name, value });
+        if let Some(permissions) = &self.permissions {
+            if let Some(enabled) = permissions.enabled {
+                push(
+                    "Actions",
+                    if enabled { "enabled" } else { "disabled" }.into(),
+                );
+            }
+            if let Some(repositories) = &permissions.enabled_repositories {
+                push("Repositories", enabled_repositories_label(repositories));
+            }
+            if let Some(allowed) = &permissions.allowed_actions {
+                push("Allowed actions", allowed_actions_label(allowed));
+            }
+        }
+        if let Some(selected) = &self.selected {
+            push(
+                "GitHub-owned actions",
+                allowed_label(selected.github_owned_allowed),
+            );
+            push(
+                "Verified creators",
+                allowed_label(selected.verified_allowed),
+            );
+            let patterns = if selected.patterns_allowed.is_empty() {
+                "none".to_string()
+            } else {
+                selected.patterns_allowed.join(", ")
+            };
+            push("Allowed patterns", patterns);
+        }
+        if let Some(workflow) = &self.workflow {
+            push(
+                "GITHUB_TOKEN",
+                workflow_permissions_label(&workflow.default_workflow_permissions),
+            );
+            push(
+                "Approve pull requests",
+                allowed_label(workflow.can_approve_pull_request_reviews),
+            );
+        }
+        if let Some(fork_approval) = &self.fork_approval {
+            push(
+                "Fork PR approval",
+                approval_policy_label(&fork_approval.approval_policy),
+            );
+        }
+        settings
+    }
+}
+
+/// Policy of one level, with notes on settings that couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicySection {
+    pub title: String,
+    pub settings: Vec<PolicySetting>,
+    pub notes: Vec<String>,
+}
+
+/// Actions policy of a repository and its organization, shown in a popup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyView {
+    pub owner: String,
+    pub repo: String,
+    pub sections: Vec<PolicySection>,
+}
+
+fn allowed_label(allowed: bool) -> String {
+    if allowed { "allowed" } else { "not allowed" }.to_string()
+}
+
+fn enabled_repositories_label(value: &str) -> String {
+    match value {
+        "all" => "all repositories".to_string(),
+        "none" => "no repositories".to_string(),
+        "selected" => "selected repositories".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn allowed_actions_label(value: &str) -> String {
+    match value {
+        "all" => "all actions and reusable workflows".to_string(),
+        "local_only" => "only the owner's own actions and reusable workflows".to_string(),
+        "selected" => "selected actions and reusable workflows".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn workflow_permissions_label(value: &str) -> String {
+    match value {
+        "read" => "read repository contents and packages".to_string(),
+        "write" => "read and write".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn approval_policy_label(value: &str) -> String {
+    match value {
+        "first_time_contributors_new_to_github" => "contributors new to GitHub".to_string(),
+        "first_time_contributors" => "first-time contributors".to_string(),
+        "all_external_contributors" => "all outside contributors".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_settings() {
+        let parts = PolicyParts {
+            permissions: Some(ActionsPermissions {
+                enabled: Some(true),
+                enabled_repositories: None,
+                allowed_actions: Some("selected".to_string()),
+            }),
+            selected: Some(SelectedActions {
+                github_owned_allowed: true,
+                verified_allowed: false,
+                patterns_allowed: vec!["octo-org/*".to_string()],
+            }),
+            workflow: None,
+            fork_approval: Some(ForkPrApproval {
+                approval_policy: "first_time_contributors".to_string(),
+            }),
+        };
+        let settings: Vec<(&str, String)> = parts
+            .settings()
+            .into_iter()
+            .map(|s| (s.name, s.value))
+            .collect();
+        assert_eq!(
+            settings,
+            [
+                ("Actions", "enabled".to_string()),
+                (
+                    "Allowed actions",
+                    "selected actions and reusable workflows".to_string()
+                ),
+                ("GitHub-owned actions", "allowed".to_string()),
+                ("Verified creators", "not allowed".to_string()),
+                ("Allowed patterns", "octo-org/*".to_string()),
+                ("Fork PR approval", "first-time contributors".to_string()),
+            ]
+        );
+        assert!(PolicyParts::default().settings().is_empty());
+    }
+}
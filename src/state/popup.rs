@@ -0,0 +1,14 @@
+// Key handling of popups.
+// Popups update their own state from key presses and tell the app what's
+// left to do, such as closing them or acting on their selection.
+
+/// What a popup leaves to the app after handling a key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PopupKey<T = ()> {
+    /// The popup stays open.
+    Stay,
+    /// The popup closes.
+    Close,
+    /// The popup's selection is acted on.
+    Act(T),
+}
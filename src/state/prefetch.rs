@@ -0,0 +1,132 @@
+// Log prefetching for failed jobs.
+// Opening a run queues its failed jobs, whose logs are then downloaded into
+// the cache one per idle tick, so opening a failing job is instant.
+
+use std::collections::VecDeque;
+
+use crate::github::{Job, RunConclusion};
+
+/// Failed job whose log is to be downloaded, with the workflow its cache path needs.
+#[derive(Debug, Clone)]
+pub struct PrefetchJob {
+    pub owner: String,
+    pub repo: String,
+    pub workflow_id: u64,
+    pub job: Job,
+}
+
+/// Failed jobs of the current run whose logs are still to be prefetched.
+#[derive(Debug, Default)]
+pub struct LogPrefetch {
+    queue: VecDeque<PrefetchJob>,
+}
+
+impl LogPrefetch {
+    /// Queue the jobs of a run that failed and whose logs aren't `cached` yet,
+    /// replacing what another run left queued.
+    pub fn queue<'a>(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        workflow_id: u64,
+        jobs: impl IntoIterator<Item = &'a Job>,
+        cached: impl Fn(&Job) -> bool,
+    ) {
+        self.queue = jobs
+            .into_iter()
+            .filter(|job| job.conclusion == Some(RunConclusion::Failure) && !cached(job))
+            .map(|job| PrefetchJob {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                workflow_id,
+                job: job.clone(),
+            })
+            .collect();
+    }
+
+    /// The next job to download, once keys have been idle so requests the
+    /// user asked for go first.
+    pub fn next(&mut self, idle: bool) -> Option<PrefetchJob> {
+        if !idle {
+            return None;
+        }
+        self.queue.pop_front()
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    fn job(id: u64, conclusion: RunConclusion) -> Job {
+        Job {
+            id,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: format!("job-{}", id),
+            status: RunStatus::Completed,
+            conclusion: Some(conclusion),
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_queues_only_uncached_failures() {
+        let jobs = [
+            job(1, RunConclusion::Success),
+            job(2, RunConclusion::Failure),
+            job(3, RunConclusion::TimedOut),
+            job(4, RunConclusion::Cancelled),
+            job(5, RunConclusion::Failure),
+            job(6, RunConclusion::Failure),
+        ];
+        let mut prefetch = LogPrefetch::default();
+        prefetch.queue("o", "r", 9, &jobs, |job| job.id == 6);
+        let queued: Vec<u64> = std::iter::from_fn(|| prefetch.next(true))
+            .map(|queued| queued.job.id)
+            .collect();
+        assert_eq!(queued, [2, 5]);
+    }
+
+    #[test]
+    fn test_drains_one_job_per_idle_tick() {
+        let jobs = [
+            job(1, RunConclusion::Failure),
+            job(2, RunConclusion::Failure),
+        ];
+        let mut prefetch = LogPrefetch::default();
+        prefetch.queue("o", "r", 9, &jobs, |_| false);
+
+        assert!(prefetch.next(false).is_none());
+        assert_eq!(prefetch.len(), 2);
+
+        let first = prefetch.next(true).unwrap();
+        assert_eq!((first.owner.as_str(), first.workflow_id), ("o", 9));
+        assert_eq!(first.job.id, 1);
+        assert_eq!(prefetch.len(), 1);
+
+        assert_eq!(prefetch.next(true).unwrap().job.id, 2);
+        assert!(prefetch.next(true).is_none());
+    }
+}
@@ -0,0 +1,154 @@
+// Job queue times.
+// Measures how long jobs waited for a runner, to diagnose capacity shortages.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fs;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::cache;
+use crate::github::Job;
+
+/// How far back cached jobs count toward queue times per label.
+const QUEUE_WINDOW_DAYS: i64 = 7;
+
+/// How long a job waited for a runner: until it started, or until `now` while queued.
+pub fn queue_time(job: &Job, now: DateTime<Utc>) -> Option<Duration> {
+    let created = job.created_at?;
+    let waited = job.started_at.unwrap_or(now) - created;
+    (waited >= Duration::zero()).then_some(waited)
+}
+
+/// Queue times of jobs requesting a runner label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelQueueTimes {
+    pub label: String,
+    pub jobs: usize,
+    pub average: Duration,
+    pub max: Duration,
+}
+
+/// Queue times per requested runner label, longest average first.
+pub fn queue_times_by_label<'a>(jobs: impl IntoIterator<Item = &'a Job>) -> Vec<LabelQueueTimes> {
+    let mut waits: BTreeMap<&str, Vec<Duration>> = BTreeMap::new();
+    for job in jobs {
+        // Jobs still waiting have no final queue time yet
+        if job.started_at.is_none() {
+            continue;
+        }
+        let Some(waited) = queue_time(job, Utc::now()) else {
+            continue;
+        };
+        for label in &job.labels {
+            waits.entry(label).or_default().push(waited);
+        }
+    }
+    let mut times: Vec<LabelQueueTimes> = waits
+        .into_iter()
+        .map(|(label, waits)| LabelQueueTimes {
+            label: label.to_string(),
+            jobs: waits.len(),
+            average: waits.iter().sum::<Duration>() / waits.len() as i32,
+            max: waits.iter().copied().max().unwrap_or_default(),
+        })
+        .collect();
+    times.sort_by_key(|t| Reverse(t.average));
+    times
+}
+
+/// Queue times per label from cached jobs of a repository's recent runs.
+pub fn load_queue_times(owner: &str, repo: &str, now: DateTime<Utc>) -> Vec<LabelQueueTimes> {
+    let Some(workflows_dir) = cache::workflows_dir(owner, repo) else {
+        return Vec::new();
+    };
+    let Ok(workflows) = fs::read_dir(workflows_dir) else {
+        return Vec::new();
+    };
+    let cutoff = now - Duration::days(QUEUE_WINDOW_DAYS);
+    let mut jobs = Vec::new();
+    for workflow in workflows.flatten() {
+        let Ok(runs) = fs::read_dir(workflow.path().join("runs")) else {
+            continue;
+        };
+        for run in runs.flatten() {
+            let path = run.path().join("jobs.json");
+            if let Ok(Some(cached)) = cache::read_cached::<Vec<Job>>(&path) {
+                jobs.extend(
+                    cached
+                        .data
+                        .into_iter()
+                        .filter(|job| job.started_at.is_some_and(|t| t >= cutoff)),
+                );
+            }
+        }
+    }
+    queue_times_by_label(&jobs)
+}
+
+/// Queue time as "1m 05s" or "42s".
+pub fn format_queue_time(waited: Duration) -> String {
+    let secs = waited.num_seconds();
+    if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+
+    fn job(labels: &[&str], queued_secs: i64) -> Job {
+        let created = DateTime::from_timestamp(1_000_000, 0).unwrap();
+        Job {
+            id: 1,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: "build".to_string(),
+            status: RunStatus::Completed,
+            conclusion: None,
+            created_at: Some(created),
+            started_at: Some(created + Duration::seconds(queued_secs)),
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: None,
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_queue_times_by_label() {
+        let jobs = [
+            job(&["self-hosted", "gpu"], 600),
+            job(&["self-hosted", "gpu"], 200),
+            job(&["ubuntu-latest"], 10),
+        ];
+        let times = queue_times_by_label(&jobs);
+        let summary: Vec<(&str, usize, i64, i64)> = times
+            .iter()
+            .map(|t| {
+                (
+                    t.label.as_str(),
+                    t.jobs,
+                    t.average.num_seconds(),
+                    t.max.num_seconds(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("gpu", 2, 400, 600),
+                ("self-hosted", 2, 400, 600),
+                ("ubuntu-latest", 1, 10, 10),
+            ]
+        );
+        assert_eq!(format_queue_time(Duration::seconds(65)), "1m 05s");
+    }
+}
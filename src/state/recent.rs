@@ -0,0 +1,199 @@
+// Recently viewed runs and jobs.
+// Kept most recent first for a week, so they can be reopened from the Recent picker.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::github::{RunConclusion, RunStatus};
+
+use super::navigation::ViewLevel;
+
+/// How long viewed runs and jobs are remembered.
+const RECENT_DAYS: i64 = 7;
+
+/// Maximum number of remembered runs and jobs.
+const MAX_RECENT: usize = 100;
+
+/// A job viewed in the log viewer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentJob {
+    pub id: u64,
+    pub name: String,
+    pub status: RunStatus,
+    pub conclusion: Option<RunConclusion>,
+}
+
+/// A run, or one of its jobs, that was viewed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentItem {
+    pub owner: String,
+    pub repo: String,
+    pub workflow_id: u64,
+    pub workflow_name: String,
+    pub run_id: u64,
+    pub run_number: u64,
+    pub job: Option<RecentJob>,
+    pub viewed_at: DateTime<Utc>,
+}
+
+impl RecentItem {
+    /// Build an item from a Workflows tab navigation path ending at a run or job.
+    pub fn from_levels(levels: &[ViewLevel], now: DateTime<Utc>) -> Option<Self> {
+        let workflow_name = levels.iter().find_map(|level| match level {
+            ViewLevel::Runs { workflow_name, .. } => Some(workflow_name.clone()),
+            _ => None,
+        })?;
+        let (owner, repo, workflow_id, run_id, run_number) =
+            levels.iter().find_map(|level| match level {
+                ViewLevel::Jobs {
+                    owner,
+                    repo,
+                    workflow_id,
+                    run_id,
+                    run_number,
+                } => Some((
+                    owner.clone(),
+                    repo.clone(),
+                    *workflow_id,
+                    *run_id,
+                    *run_number,
+                )),
+                _ => None,
+            })?;
+        let job = levels.iter().find_map(|level| match level {
+            ViewLevel::Logs {
+                job_id,
+                job_name,
+                job_status,
+                job_conclusion,
+                ..
+            } => Some(RecentJob {
+                id: *job_id,
+                name: job_name.clone(),
+                status: *job_status,
+                conclusion: *job_conclusion,
+            }),
+            _ => None,
+        });
+        Some(Self {
+            owner,
+            repo,
+            workflow_id,
+            workflow_name,
+            run_id,
+            run_number,
+            job,
+            viewed_at: now,
+        })
+    }
+
+    /// Workflows tab navigation path that reopens this run or job.
+    pub fn levels(&self) -> Vec<ViewLevel> {
+        let mut levels = vec![
+            ViewLevel::Owners,
+            ViewLevel::Repositories {
+                owner: self.owner.clone(),
+            },
+            ViewLevel::Workflows {
+                owner: self.owner.clone(),
+                repo: self.repo.clone(),
+            },
+            ViewLevel::Runs {
+                owner: self.owner.clone(),
+                repo: self.repo.clone(),
+                workflow_id: self.workflow_id,
+                workflow_name: self.workflow_name.clone(),
+            },
+            ViewLevel::Jobs {
+                owner: self.owner.clone(),
+                repo: self.repo.clone(),
+                workflow_id: self.workflow_id,
+                run_id: self.run_id,
+                run_number: self.run_number,
+            },
+        ];
+        if let Some(job) = &self.job {
+            levels.push(ViewLevel::Logs {
+                owner: self.owner.clone(),
+                repo: self.repo.clone(),
+                workflow_id: self.workflow_id,
+                run_id: self.run_id,
+                job_id: job.id,
+                job_name: job.name.clone(),
+                job_status: job.status,
+                job_conclusion: job.conclusion,
+            });
+        }
+        levels
+    }
+
+    /// Whether two items refer to the same run or job.
+    fn same_target(&self, other: &RecentItem) -> bool {
+        self.run_id == other.run_id
+            && self.job.as_ref().map(|j| j.id) == other.job.as_ref().map(|j| j.id)
+    }
+}
+
+/// Move an item to the front of the history, dropping entries older than a week.
+pub fn record_recent(recent: &mut Vec<RecentItem>, item: RecentItem) {
+    let cutoff = item.viewed_at - Duration::days(RECENT_DAYS);
+    recent.retain(|existing| !existing.same_target(&item) && existing.viewed_at >= cutoff);
+    recent.insert(0, item);
+    recent.truncate(MAX_RECENT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(run_id: u64, job_id: Option<u64>, viewed_at: DateTime<Utc>) -> RecentItem {
+        RecentItem {
+            owner: "phatblat".to_string(),
+            repo: "jolt".to_string(),
+            workflow_id: 1,
+            workflow_name: "CI".to_string(),
+            run_id,
+            run_number: run_id,
+            job: job_id.map(|id| RecentJob {
+                id,
+                name: "build".to_string(),
+                status: RunStatus::Completed,
+                conclusion: Some(RunConclusion::Failure),
+            }),
+            viewed_at,
+        }
+    }
+
+    #[test]
+    fn test_record_recent() {
+        let now = DateTime::from_timestamp(1_000_000, 0).unwrap();
+        let mut recent = vec![
+            item(2, None, now - Duration::hours(1)),
+            item(1, Some(10), now - Duration::hours(2)),
+            item(3, None, now - Duration::days(8)),
+        ];
+        record_recent(&mut recent, item(1, Some(10), now));
+
+        // Revisited job moves to the front; the week-old run is forgotten
+        let targets: Vec<(u64, Option<u64>)> = recent
+            .iter()
+            .map(|i| (i.run_id, i.job.as_ref().map(|j| j.id)))
+            .collect();
+        assert_eq!(targets, [(1, Some(10)), (2, None)]);
+    }
+
+    #[test]
+    fn test_levels_round_trip() {
+        let now = DateTime::from_timestamp(0, 0).unwrap();
+        let original = item(7, Some(70), now);
+        let levels = original.levels();
+        assert_eq!(levels.len(), 6);
+        assert_eq!(RecentItem::from_levels(&levels, now), Some(original));
+        // A run without a job stops at the Jobs level
+        assert_eq!(
+            RecentItem::from_levels(&levels[..5], now).and_then(|i| i.job),
+            None
+        );
+        assert_eq!(RecentItem::from_levels(&levels[..4], now), None);
+    }
+}
@@ -0,0 +1,157 @@
+// Runs grouped by trigger event.
+// Orders a Runs list so each event's runs are together, with collapsed
+// events folded down to their latest run.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::github::WorkflowRun;
+
+use super::workflows::SelectableList;
+
+/// Events listed first, in this order; others follow alphabetically.
+const EVENT_ORDER: [&str; 4] = ["push", "pull_request", "schedule", "workflow_dispatch"];
+
+/// Sort key placing runs of the same event together.
+fn event_key(event: &str) -> (usize, &str) {
+    let rank = EVENT_ORDER
+        .iter()
+        .position(|e| *e == event)
+        .unwrap_or(EVENT_ORDER.len());
+    (rank, event)
+}
+
+/// Group header text for an event (e.g., "pull request").
+pub fn event_label(event: &str) -> String {
+    if event.is_empty() {
+        "unknown event".to_string()
+    } else {
+        event.replace('_', " ")
+    }
+}
+
+/// Order runs by event, keeping newest first within each event, and show only
+/// the latest run of events in `collapsed`. Runs `ignored` accepts stay hidden.
+pub fn group_runs(
+    list: &mut SelectableList<WorkflowRun>,
+    collapsed: &HashSet<String>,
+    ignored: impl Fn(&WorkflowRun) -> bool,
+) {
+    list.sort_by(|a, b| event_key(&a.event).cmp(&event_key(&b.event)));
+    let mut seen = HashSet::new();
+    let latest: HashSet<u64> = list
+        .data
+        .data()
+        .map(|runs| {
+            runs.items
+                .iter()
+                .filter(|run| !ignored(run) && seen.insert(run.event.clone()))
+                .map(|run| run.id)
+                .collect()
+        })
+        .unwrap_or_default();
+    list.set_hidden(|run| {
+        ignored(run) || (collapsed.contains(&run.event) && !latest.contains(&run.id))
+    });
+}
+
+/// Restore load order and show every run.
+pub fn ungroup_runs(list: &mut SelectableList<WorkflowRun>) {
+    list.sort_by(|_, _| Ordering::Equal);
+    list.set_hidden(|_| false);
+}
+
+/// Number of loaded runs per event, including those hidden in collapsed groups.
+pub fn event_counts(list: &SelectableList<WorkflowRun>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for run in list
+        .data
+        .data()
+        .map(|runs| runs.items.as_slice())
+        .unwrap_or_default()
+    {
+        match counts.iter_mut().find(|(event, _)| *event == run.event) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((run.event.clone(), 1)),
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunStatus;
+    use chrono::DateTime;
+
+    fn run(id: u64, event: &str) -> WorkflowRun {
+        let at = DateTime::from_timestamp(0, 0).unwrap();
+        WorkflowRun {
+            id,
+            name: None,
+            run_number: id,
+            run_attempt: None,
+            status: RunStatus::Completed,
+            conclusion: None,
+            workflow_id: 1,
+            head_branch: None,
+            head_sha: String::new(),
+            event: event.to_string(),
+            created_at: at,
+            updated_at: at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn list() -> SelectableList<WorkflowRun> {
+        let mut list = SelectableList::new();
+        list.set_loaded(
+            vec![
+                run(6, "workflow_dispatch"),
+                run(5, "pull_request"),
+                run(4, "push"),
+                run(3, "pull_request"),
+                run(2, "release"),
+                run(1, "push"),
+            ],
+            6,
+        );
+        list
+    }
+
+    fn ids(list: &SelectableList<WorkflowRun>) -> Vec<u64> {
+        list.sorted_items().iter().map(|run| run.id).collect()
+    }
+
+    #[test]
+    fn test_group_runs_by_event() {
+        let mut list = list();
+        group_runs(&mut list, &HashSet::new(), |_| false);
+        assert_eq!(ids(&list), [4, 1, 5, 3, 6, 2]);
+        ungroup_runs(&mut list);
+        assert_eq!(ids(&list), [6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_collapsed_event_keeps_latest_run() {
+        let mut list = list();
+        group_runs(&mut list, &HashSet::new(), |_| false);
+        // Select the older pull_request run, then collapse its group
+        list.list_state.select(Some(3));
+        let collapsed = HashSet::from(["pull_request".to_string()]);
+        group_runs(&mut list, &collapsed, |_| false);
+        assert_eq!(ids(&list), [4, 1, 5, 6, 2]);
+        assert_eq!(list.selected_item().map(|run| run.id), Some(5));
+        assert_eq!(event_counts(&list)[1], ("pull_request".to_string(), 2));
+    }
+
+    #[test]
+    fn test_event_label() {
+        assert_eq!(event_label("workflow_dispatch"), "workflow dispatch");
+        assert_eq!(event_label(""), "unknown event");
+    }
+}
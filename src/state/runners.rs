@@ -1,11 +1,25 @@
 // Runners tab state management.
 // Handles navigation and data for the runners tab.
 
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::github::{Job, Repository, RunConclusion, RunStatus, Runner, WorkflowRun};
+use crate::diag::RunnerHealth;
+use crate::github::{
+    Job, Repository, RunConclusion, RunStatus, Runner, RunnerGroup, RunnerGroupVisibility,
+    WorkflowRun,
+};
 
-use super::workflows::{LoadingState, SelectableList};
+use super::churn::LabelChurn;
+use super::history::JobHistory;
+use super::log_viewer::LogViewerState;
+use super::navigation::NavContext;
+use super::prefetch::LogPrefetch;
+use super::queue::LabelQueueTimes;
+use super::workflows::{LoadingState, SelectableList, text_id};
+use crate::error::ErrorKind;
 
 /// Navigation level for the Runners tab.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -26,6 +40,9 @@ pub enum RunnersViewLevel {
         repo: String,
         run_id: u64,
         run_number: u64,
+        /// Workflow of the run, for its cache paths (None in older saved state)
+        #[serde(default)]
+        workflow_id: Option<u64>,
     },
     /// Log viewer for a specific job
     Logs {
@@ -36,6 +53,23 @@ pub enum RunnersViewLevel {
         job_name: String,
         job_status: RunStatus,
         job_conclusion: Option<RunConclusion>,
+        /// Workflow of the run, for the log's cache path (None in older saved state)
+        #[serde(default)]
+        workflow_id: Option<u64>,
+    },
+    /// Diagnostic logs of a self-hosted runner, read from its install directory
+    DiagLogs {
+        owner: String,
+        repo: String,
+        runner_name: String,
+    },
+    /// Runner groups of an organization
+    RunnerGroups { org: String },
+    /// Runners in an organization runner group
+    GroupRunners {
+        org: String,
+        group_id: u64,
+        group_name: String,
     },
 }
 
@@ -56,9 +90,24 @@ impl RunnersViewLevel {
             }
             RunnersViewLevel::Jobs { run_number, .. } => format!("Run #{} / Jobs", run_number),
             RunnersViewLevel::Logs { job_name, .. } => format!("{} / Logs", job_name),
+            RunnersViewLevel::DiagLogs { runner_name, .. } => {
+                format!("{} / Diagnostics", runner_name)
+            }
+            RunnersViewLevel::RunnerGroups { org } => format!("{} / Runner Groups", org),
+            RunnersViewLevel::GroupRunners { group_name, .. } => {
+                format!("{} / Runners", group_name)
+            }
         }
     }
 
+    /// Whether this level shows a log viewer.
+    pub fn is_log_view(&self) -> bool {
+        matches!(
+            self,
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. }
+        )
+    }
+
     /// Create a breadcrumb label for this level.
     pub fn breadcrumb_label(&self) -> String {
         match self {
@@ -69,6 +118,9 @@ impl RunnersViewLevel {
             }
             RunnersViewLevel::Jobs { run_number, .. } => format!("#{}", run_number),
             RunnersViewLevel::Logs { job_name, .. } => job_name.clone(),
+            RunnersViewLevel::DiagLogs { runner_name, .. } => format!("{} (diag)", runner_name),
+            RunnersViewLevel::RunnerGroups { org } => format!("{} groups", org),
+            RunnersViewLevel::GroupRunners { group_name, .. } => group_name.clone(),
         }
     }
 }
@@ -115,6 +167,11 @@ impl RunnersNavStack {
         }
     }
 
+    /// Get the depth of the navigation stack.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
     /// Get the breadcrumb trail.
     pub fn breadcrumbs(&self) -> Vec<RunnersBreadcrumb> {
         self.stack
@@ -125,6 +182,181 @@ impl RunnersNavStack {
             })
             .collect()
     }
+
+    /// Collect the owner, repo, and run number from the levels on the stack.
+    pub fn context(&self) -> NavContext {
+        let mut context = NavContext::default();
+        for level in &self.stack {
+            match level {
+                RunnersViewLevel::Repositories => {}
+                RunnersViewLevel::Runners { owner, repo }
+                | RunnersViewLevel::Runs { owner, repo, .. }
+                | RunnersViewLevel::Logs { owner, repo, .. }
+                | RunnersViewLevel::DiagLogs { owner, repo, .. } => {
+                    context.owner = Some(owner.clone());
+                    context.repo = Some(repo.clone());
+                }
+                RunnersViewLevel::Jobs {
+                    owner,
+                    repo,
+                    run_number,
+                    ..
+                } => {
+                    context.owner = Some(owner.clone());
+                    context.repo = Some(repo.clone());
+                    context.run_number = Some(*run_number);
+                }
+                RunnersViewLevel::RunnerGroups { org }
+                | RunnersViewLevel::GroupRunners { org, .. } => {
+                    context.owner = Some(org.clone());
+                    context.repo = None;
+                }
+            }
+        }
+        context
+    }
+}
+
+/// A row in the runners list: a single runner, or the ephemeral runners of
+/// an actions-runner-controller (ARC) scale set collapsed into one entry.
+#[derive(Debug, Clone)]
+pub enum RunnerRow {
+    Runner(Runner),
+    ScaleSet { name: String, runners: Vec<Runner> },
+}
+
+impl RunnerRow {
+    /// Runner name, or scale set name for a group.
+    pub fn name(&self) -> &str {
+        match self {
+            RunnerRow::Runner(runner) => &runner.name,
+            RunnerRow::ScaleSet { name, .. } => name,
+        }
+    }
+
+    /// Runner ID, or for a scale set a hash of its name, which is unique among
+    /// the repository's scale sets.
+    pub fn id(&self) -> u64 {
+        match self {
+            RunnerRow::Runner(runner) => runner.id,
+            RunnerRow::ScaleSet { name, .. } => text_id(name),
+        }
+    }
+
+    /// The runner, or the runners of a scale set.
+    pub fn runners(&self) -> &[Runner] {
+        match self {
+            RunnerRow::Runner(runner) => std::slice::from_ref(runner),
+            RunnerRow::ScaleSet { runners, .. } => runners,
+        }
+    }
+}
+
+/// Job a busy runner is working on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunnerJob {
+    pub workflow: String,
+    pub run_number: u64,
+    pub job_name: String,
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+/// The in-progress job of each runner, from in-progress runs and their jobs.
+pub fn jobs_by_runner(runs: &[(WorkflowRun, Vec<Job>)]) -> HashMap<String, RunnerJob> {
+    let mut jobs = HashMap::new();
+    for (run, run_jobs) in runs {
+        for job in run_jobs {
+            if job.status != RunStatus::InProgress {
+                continue;
+            }
+            let Some(runner) = &job.runner_name else {
+                continue;
+            };
+            jobs.insert(
+                runner.clone(),
+                RunnerJob {
+                    workflow: run.name.clone().unwrap_or_default(),
+                    run_number: run.run_number,
+                    job_name: job.name.clone(),
+                    started_at: job.started_at,
+                },
+            );
+        }
+    }
+    jobs
+}
+
+/// Group ARC ephemeral runners by scale set, keeping other runners as-is.
+/// A scale set needs at least two runners so a lone look-alike name isn't collapsed.
+pub fn group_runners(runners: Vec<Runner>) -> Vec<RunnerRow> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for runner in &runners {
+        if let Some(scale_set) = scale_set_name(&runner.name) {
+            *counts.entry(scale_set.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut rows: Vec<RunnerRow> = Vec::new();
+    let mut groups: HashMap<String, usize> = HashMap::new();
+    for runner in runners {
+        let scale_set = scale_set_name(&runner.name)
+            .filter(|name| counts.get(*name).is_some_and(|&count| count > 1))
+            .map(str::to_string);
+        match scale_set {
+            Some(name) => match groups.get(&name) {
+                Some(&index) => {
+                    if let RunnerRow::ScaleSet { runners, .. } = &mut rows[index] {
+                        runners.push(runner);
+                    }
+                }
+                None => {
+                    groups.insert(name.clone(), rows.len());
+                    rows.push(RunnerRow::ScaleSet {
+                        name,
+                        runners: vec![runner],
+                    });
+                }
+            },
+            None => rows.push(RunnerRow::Runner(runner)),
+        }
+    }
+    rows
+}
+
+/// Scale set name parsed from an ARC runner name.
+///
+/// Scale set runners are named `<scale-set>-<5 chars>-runner-<5 chars>`;
+/// legacy RunnerDeployment runners are `<deployment>-<5 chars>-<5 chars>`.
+fn scale_set_name(runner_name: &str) -> Option<&str> {
+    let is_suffix = |s: &str| s.len() == 5 && s.chars().all(|c| c.is_ascii_alphanumeric());
+
+    let (rest, last) = runner_name.rsplit_once('-')?;
+    if !is_suffix(last) {
+        return None;
+    }
+    let rest = rest.strip_suffix("-runner").unwrap_or(rest);
+    let (prefix, hash) = rest.rsplit_once('-')?;
+    (is_suffix(hash) && !prefix.is_empty()).then_some(prefix)
+}
+
+/// Which repositories can use a runner group, and any further restrictions.
+pub fn group_access(group: &RunnerGroup) -> String {
+    let mut access = match group.visibility {
+        RunnerGroupVisibility::All => "all repositories".to_string(),
+        RunnerGroupVisibility::Selected => "selected repositories".to_string(),
+        RunnerGroupVisibility::Private => "private repositories".to_string(),
+        RunnerGroupVisibility::Unknown => "unknown access".to_string(),
+    };
+    if group.allows_public_repositories && group.visibility != RunnerGroupVisibility::Private {
+        access.push_str(", public allowed");
+    }
+    if group.restricted_to_workflows {
+        access.push_str(&format!(
+            ", {} workflows only",
+            group.selected_workflows.len()
+        ));
+    }
+    access
 }
 
 /// Complete state for the runners tab.
@@ -134,31 +366,67 @@ pub struct RunnersTabState {
     pub nav: RunnersNavStack,
     /// Repositories with runners.
     pub repositories: SelectableList<Repository>,
-    /// Runners list for current repository.
-    pub runners: SelectableList<Runner>,
+    /// Runners list for current repository, with ARC scale sets grouped.
+    pub runners: SelectableList<RunnerRow>,
+    /// Recent runner registrations and removals per label.
+    pub churn: Vec<LabelChurn>,
+    /// Queue times per requested runner label, from cached jobs. Worked out
+    /// once when the repository's runners are first shown, since it reads
+    /// every cached jobs list.
+    pub queue_times: Option<Vec<LabelQueueTimes>>,
+    /// Health of runners installed on this machine, by runner name.
+    pub health: HashMap<String, RunnerHealth>,
+    /// Jobs busy runners are working on, by runner name.
+    pub runner_jobs: HashMap<String, RunnerJob>,
+    /// Whether the runners' jobs are still to be fetched, after the list is shown.
+    pub enrichment_loading: bool,
+    /// Runner groups of the current organization.
+    pub runner_groups: SelectableList<RunnerGroup>,
+    /// Runners in the current runner group, with ARC scale sets grouped.
+    pub group_runners: SelectableList<RunnerRow>,
+    /// Repositories allowed to use the current runner group, when access is selected.
+    pub group_repos: Vec<String>,
     /// Workflow runs list.
     pub runs: SelectableList<WorkflowRun>,
+    /// Branch the Runs list is narrowed to, if any.
+    pub current_branch: Option<String>,
+    /// Whether the branch filter was set for the current Runs list, so opening
+    /// it doesn't narrow it to the default branch again.
+    pub branch_chosen: bool,
     /// Jobs list for current run.
     pub jobs: SelectableList<Job>,
-    /// Log content for current job.
-    pub log_content: LoadingState<String>,
-    /// Horizontal scroll offset for log viewer.
-    pub log_scroll_x: u16,
-    /// Vertical scroll offset for log viewer.
-    pub log_scroll_y: u16,
+    /// Durations of the current run's jobs in earlier runs, for ETAs.
+    pub job_history: JobHistory,
+    /// Names of the current run's jobs that failed and passed on retry.
+    pub flaky_jobs: HashSet<String>,
+    /// Failed jobs of the current run whose logs are still to be prefetched.
+    pub log_prefetch: LogPrefetch,
+    /// Log of the current job and where it's scrolled.
+    pub log: LogViewerState,
 }
 
 impl Default for RunnersTabState {
     fn default() -> Self {
         Self {
             nav: RunnersNavStack::default(),
-            repositories: SelectableList::new(),
-            runners: SelectableList::new(),
-            runs: SelectableList::new(),
-            jobs: SelectableList::new(),
-            log_content: LoadingState::Idle,
-            log_scroll_x: 0,
-            log_scroll_y: 0,
+            repositories: SelectableList::with_id_of(|item| item.id),
+            runners: SelectableList::with_id_of(RunnerRow::id),
+            churn: Vec::new(),
+            queue_times: None,
+            health: HashMap::new(),
+            runner_jobs: HashMap::new(),
+            enrichment_loading: false,
+            runner_groups: SelectableList::with_id_of(|item| item.id),
+            group_runners: SelectableList::with_id_of(RunnerRow::id),
+            group_repos: Vec::new(),
+            runs: SelectableList::with_id_of(|item| item.id),
+            current_branch: None,
+            branch_chosen: false,
+            jobs: SelectableList::with_id_of(|item| item.id),
+            job_history: JobHistory::default(),
+            flaky_jobs: HashSet::new(),
+            log_prefetch: LogPrefetch::default(),
+            log: LogViewerState::default(),
         }
     }
 }
@@ -173,6 +441,15 @@ impl RunnersTabState {
         self.nav.current()
     }
 
+    /// Whether the token can't list the current repository's runners. GitHub
+    /// answers tokens without admin access with a 403, or hides the endpoint with a 404.
+    pub fn lacks_runner_access(&self) -> bool {
+        matches!(
+            &self.runners.data,
+            LoadingState::Error(e) if matches!(e.kind, ErrorKind::Forbidden | ErrorKind::NotFound)
+        )
+    }
+
     /// Navigate back (Escape key).
     /// Clears all child list data so fresh data loads when drilling down again.
     pub fn go_back(&mut self) -> bool {
@@ -182,24 +459,41 @@ impl RunnersTabState {
         if popped {
             match current {
                 RunnersViewLevel::Runners { .. } => {
-                    self.runners = SelectableList::new();
-                    self.runs = SelectableList::new();
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.runners.reset();
+                    self.churn = Vec::new();
+                    self.queue_times = None;
+                    self.health.clear();
+                    self.runner_jobs.clear();
+                    self.enrichment_loading = false;
+                    self.runs.reset();
+                    self.current_branch = None;
+                    self.branch_chosen = false;
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
                 RunnersViewLevel::Runs { .. } => {
-                    self.runs = SelectableList::new();
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.runs.reset();
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
                 RunnersViewLevel::Jobs { .. } => {
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
-                RunnersViewLevel::Logs { .. } => {
-                    self.log_content = LoadingState::Idle;
-                    self.log_scroll_x = 0;
-                    self.log_scroll_y = 0;
+                RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => {
+                    self.log.clear();
+                }
+                RunnersViewLevel::RunnerGroups { .. } => {
+                    self.runner_groups.reset();
+                    self.group_runners.reset();
+                    self.group_repos = Vec::new();
+                }
+                RunnersViewLevel::GroupRunners { .. } => {
+                    self.group_runners.reset();
+                    self.group_repos = Vec::new();
                 }
                 RunnersViewLevel::Repositories => {}
             }
@@ -214,12 +508,41 @@ impl RunnersTabState {
             RunnersViewLevel::Runners { .. } => self.runners.select_prev(),
             RunnersViewLevel::Runs { .. } => self.runs.select_prev(),
             RunnersViewLevel::Jobs { .. } => self.jobs.select_prev(),
-            RunnersViewLevel::Logs { .. } => {
-                self.log_scroll_y = self.log_scroll_y.saturating_sub(1);
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.select_prev(),
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.select_prev(),
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => {
+                self.log.scroll_up(1)
             }
         }
     }
 
+    /// Filter the current list. Returns false if the current view isn't a list.
+    pub fn set_list_filter(&mut self, query: Option<String>) -> bool {
+        match self.nav.current() {
+            RunnersViewLevel::Repositories => self.repositories.set_filter(query),
+            RunnersViewLevel::Runners { .. } => self.runners.set_filter(query),
+            RunnersViewLevel::Runs { .. } => self.runs.set_filter(query),
+            RunnersViewLevel::Jobs { .. } => self.jobs.set_filter(query),
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.set_filter(query),
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.set_filter(query),
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => return false,
+        }
+        true
+    }
+
+    /// Filter applied to the current list.
+    pub fn list_filter(&self) -> Option<&str> {
+        match self.nav.current() {
+            RunnersViewLevel::Repositories => self.repositories.filter.as_deref(),
+            RunnersViewLevel::Runners { .. } => self.runners.filter.as_deref(),
+            RunnersViewLevel::Runs { .. } => self.runs.filter.as_deref(),
+            RunnersViewLevel::Jobs { .. } => self.jobs.filter.as_deref(),
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.filter.as_deref(),
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.filter.as_deref(),
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => None,
+        }
+    }
+
     /// Handle down arrow key.
     pub fn select_next(&mut self) {
         match self.nav.current() {
@@ -227,71 +550,236 @@ impl RunnersTabState {
             RunnersViewLevel::Runners { .. } => self.runners.select_next(),
             RunnersViewLevel::Runs { .. } => self.runs.select_next(),
             RunnersViewLevel::Jobs { .. } => self.jobs.select_next(),
-            RunnersViewLevel::Logs { .. } => {
-                self.log_scroll_y = self.log_scroll_y.saturating_add(1);
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.select_next(),
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.select_next(),
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => {
+                self.log.scroll_down(1)
             }
         }
     }
 
     /// Handle left arrow key (horizontal scroll in logs).
     pub fn scroll_left(&mut self) {
-        if matches!(self.nav.current(), RunnersViewLevel::Logs { .. }) {
-            self.log_scroll_x = self.log_scroll_x.saturating_sub(4);
+        if self.nav.current().is_log_view() {
+            self.log.scroll_left();
         }
     }
 
     /// Handle right arrow key (horizontal scroll in logs).
     pub fn scroll_right(&mut self) {
-        if matches!(self.nav.current(), RunnersViewLevel::Logs { .. }) {
-            self.log_scroll_x = self.log_scroll_x.saturating_add(4);
+        if self.nav.current().is_log_view() {
+            self.log.scroll_right();
         }
     }
 
     /// Handle Page Up key.
     pub fn page_up(&mut self) {
-        if matches!(self.nav.current(), RunnersViewLevel::Logs { .. }) {
-            self.log_scroll_y = self.log_scroll_y.saturating_sub(20);
+        if self.nav.current().is_log_view() {
+            self.log.page_up();
         }
     }
 
     /// Handle Page Down key.
     pub fn page_down(&mut self) {
-        if matches!(self.nav.current(), RunnersViewLevel::Logs { .. }) {
-            self.log_scroll_y = self.log_scroll_y.saturating_add(20);
+        if self.nav.current().is_log_view() {
+            self.log.page_down();
         }
     }
 
     /// Scroll to start of logs.
     pub fn scroll_to_start(&mut self) {
-        if matches!(self.nav.current(), RunnersViewLevel::Logs { .. }) {
-            self.log_scroll_y = 0;
-            self.log_scroll_x = 0;
+        if self.nav.current().is_log_view() {
+            self.log.scroll_to_start();
         }
     }
 
     /// Scroll to end of logs.
-    #[allow(clippy::collapsible_if)]
     pub fn scroll_to_end(&mut self) {
-        if matches!(self.nav.current(), RunnersViewLevel::Logs { .. }) {
-            if let LoadingState::Loaded(logs) = &self.log_content {
-                let line_count = logs.lines().count() as u16;
-                self.log_scroll_y = line_count.saturating_sub(10);
-            }
+        if self.nav.current().is_log_view() {
+            self.log.scroll_to_end();
         }
     }
 
     /// Clear current list data (for refresh).
     pub fn clear_current(&mut self) {
         match self.nav.current() {
-            RunnersViewLevel::Repositories => self.repositories = SelectableList::new(),
-            RunnersViewLevel::Runners { .. } => self.runners = SelectableList::new(),
-            RunnersViewLevel::Runs { .. } => self.runs = SelectableList::new(),
-            RunnersViewLevel::Jobs { .. } => self.jobs = SelectableList::new(),
-            RunnersViewLevel::Logs { .. } => {
-                self.log_content = LoadingState::Idle;
-                self.log_scroll_x = 0;
-                self.log_scroll_y = 0;
+            RunnersViewLevel::Repositories => self.repositories.clear_for_reload(),
+            RunnersViewLevel::Runners { .. } => self.runners.clear_for_reload(),
+            RunnersViewLevel::Runs { .. } => self.runs.clear_for_reload(),
+            RunnersViewLevel::Jobs { .. } => self.jobs.clear_for_reload(),
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.clear_for_reload(),
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.clear_for_reload(),
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => {
+                self.log.clear();
             }
         }
     }
+
+    /// Mark the current list for revalidation (logs are left alone).
+    pub fn mark_current_stale(&mut self) {
+        match self.nav.current() {
+            RunnersViewLevel::Repositories => self.repositories.mark_stale(),
+            RunnersViewLevel::Runners { .. } => self.runners.mark_stale(),
+            RunnersViewLevel::Runs { .. } => self.runs.mark_stale(),
+            RunnersViewLevel::Jobs { .. } => self.jobs.mark_stale(),
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.mark_stale(),
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.mark_stale(),
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => {}
+        }
+    }
+
+    /// Mark every loaded list for revalidation. Lists below the current level are
+    /// cleared when navigating back, so these are the lists on the nav path.
+    pub fn mark_path_stale(&mut self) {
+        self.repositories.mark_stale();
+        self.runners.mark_stale();
+        self.runs.mark_stale();
+        self.jobs.mark_stale();
+        self.runner_groups.mark_stale();
+        self.group_runners.mark_stale();
+    }
+
+    /// Whether the current list is showing stale data.
+    pub fn current_is_stale(&self) -> bool {
+        match self.nav.current() {
+            RunnersViewLevel::Repositories => self.repositories.stale,
+            RunnersViewLevel::Runners { .. } => self.runners.stale,
+            RunnersViewLevel::Runs { .. } => self.runs.stale,
+            RunnersViewLevel::Jobs { .. } => self.jobs.stale,
+            RunnersViewLevel::RunnerGroups { .. } => self.runner_groups.stale,
+            RunnersViewLevel::GroupRunners { .. } => self.group_runners.stale,
+            RunnersViewLevel::Logs { .. } | RunnersViewLevel::DiagLogs { .. } => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::JoltError;
+    use crate::github::RunnerStatus;
+
+    fn runner(name: &str) -> Runner {
+        Runner {
+            id: 0,
+            name: name.to_string(),
+            os: "Linux".to_string(),
+            status: RunnerStatus::Online,
+            busy: false,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_group_access() {
+        let mut group = RunnerGroup {
+            id: 1,
+            name: "Build".to_string(),
+            visibility: RunnerGroupVisibility::Selected,
+            default: false,
+            inherited: false,
+            allows_public_repositories: false,
+            restricted_to_workflows: false,
+            selected_workflows: Vec::new(),
+        };
+        assert_eq!(group_access(&group), "selected repositories");
+        group.visibility = RunnerGroupVisibility::All;
+        group.allows_public_repositories = true;
+        group.restricted_to_workflows = true;
+        group.selected_workflows = vec!["o/r/.github/workflows/ci.yml@main".to_string()];
+        assert_eq!(
+            group_access(&group),
+            "all repositories, public allowed, 1 workflows only"
+        );
+    }
+
+    #[test]
+    fn test_scale_set_name() {
+        assert_eq!(
+            scale_set_name("arc-linux-x8zfn-runner-2m9ql"),
+            Some("arc-linux")
+        );
+        assert_eq!(scale_set_name("ci-deploy-abcde-fghij"), Some("ci-deploy"));
+        assert_eq!(scale_set_name("build-mac-01"), None);
+        assert_eq!(scale_set_name("mac-mini"), None);
+    }
+
+    #[test]
+    fn test_group_runners() {
+        let rows = group_runners(vec![
+            runner("arc-linux-x8zfn-runner-2m9ql"),
+            runner("build-mac-01"),
+            runner("arc-linux-x8zfn-runner-7kd2p"),
+            runner("lonely-abcde-fghij"),
+        ]);
+        let names: Vec<&str> = rows.iter().map(|row| row.name()).collect();
+        assert_eq!(names, ["arc-linux", "build-mac-01", "lonely-abcde-fghij"]);
+        assert!(matches!(&rows[0], RunnerRow::ScaleSet { runners, .. } if runners.len() == 2));
+    }
+
+    #[test]
+    fn test_jobs_by_runner() {
+        let at = DateTime::from_timestamp(0, 0).unwrap();
+        let run = WorkflowRun {
+            id: 7,
+            name: Some("CI".to_string()),
+            run_number: 42,
+            run_attempt: None,
+            status: RunStatus::InProgress,
+            conclusion: None,
+            workflow_id: 1,
+            head_branch: None,
+            head_sha: String::new(),
+            event: String::new(),
+            created_at: at,
+            updated_at: at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        };
+        let job = |name: &str, status: RunStatus, runner: Option<&str>| Job {
+            id: 0,
+            run_id: 7,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: name.to_string(),
+            status,
+            conclusion: None,
+            created_at: None,
+            started_at: Some(at),
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: runner.map(str::to_string),
+            labels: Vec::new(),
+        };
+        let jobs = jobs_by_runner(&[(
+            run,
+            vec![
+                job("lint", RunStatus::Completed, Some("mac-mini")),
+                job("build", RunStatus::InProgress, Some("mac-mini")),
+                job("test", RunStatus::Queued, None),
+            ],
+        )]);
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs["mac-mini"].job_name, "build");
+        assert_eq!(jobs["mac-mini"].run_number, 42);
+    }
+
+    #[test]
+    fn test_lacks_runner_access() {
+        let mut state = RunnersTabState::new();
+        assert!(!state.lacks_runner_access());
+        state.runners.set_error(&JoltError::NotFound(
+            "/repos/o/r/actions/runners".to_string(),
+        ));
+        assert!(state.lacks_runner_access());
+        state
+            .runners
+            .set_error(&JoltError::Other("boom".to_string()));
+        assert!(!state.lacks_runner_access());
+    }
 }
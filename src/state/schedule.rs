@@ -0,0 +1,301 @@
+// Scheduled workflow forecasting.
+// Parses cron triggers from workflow files to predict the next scheduled runs.
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Days searched for the next matching time before giving up (e.g., "0 0 30 2 *").
+const SEARCH_DAYS: i64 = 366;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Cron triggers of a workflow, cached alongside the workflow.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowSchedule {
+    pub name: String,
+    /// Cron expressions from the workflow's `on.schedule` trigger.
+    pub crons: Vec<String>,
+}
+
+impl WorkflowSchedule {
+    pub fn from_file(name: String, yaml: &str) -> Self {
+        Self {
+            name,
+            crons: cron_expressions(yaml),
+        }
+    }
+
+    /// Next `count` scheduled times after `now`, across all of the workflow's crons.
+    pub fn upcoming(&self, now: DateTime<Utc>, count: usize) -> Vec<DateTime<Utc>> {
+        let schedules: Vec<CronSchedule> = self
+            .crons
+            .iter()
+            .filter_map(|cron| CronSchedule::parse(cron))
+            .collect();
+        let mut times = Vec::new();
+        let mut after = now;
+        while times.len() < count {
+            let Some(next) = schedules.iter().filter_map(|s| s.next_after(after)).min() else {
+                break;
+            };
+            times.push(next);
+            after = next;
+        }
+        times
+    }
+
+    /// Next scheduled time after `now`.
+    pub fn next_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.upcoming(now, 1).into_iter().next()
+    }
+}
+
+/// Scheduled run of a favorite workflow, shown in the schedule overview.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpcomingRun {
+    pub at: DateTime<Utc>,
+    pub owner: String,
+    pub repo: String,
+    pub workflow: String,
+}
+
+/// Cron expressions of a workflow file's `on.schedule` trigger.
+/// Follows keys by indentation rather than parsing the whole YAML document,
+/// so `cron:` keys elsewhere, such as inputs or step scripts, are skipped.
+pub fn cron_expressions(yaml: &str) -> Vec<String> {
+    // Keys enclosing the current line, with their indentation
+    let mut path: Vec<(usize, &str)> = Vec::new();
+    let mut crons = Vec::new();
+    for line in yaml.lines() {
+        let mut content = line.trim_start();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+        // A list item's first key is indented past its dash
+        if let Some(item) = content.strip_prefix('-') {
+            content = item.trim_start();
+        }
+        let indent = line.len() - content.len();
+        let Some((key, value)) = content.split_once(':') else {
+            continue;
+        };
+        while path.last().is_some_and(|&(outer, _)| outer >= indent) {
+            path.pop();
+        }
+        let key = key.trim().trim_matches(|c| c == '\'' || c == '"');
+        let in_schedule = matches!(path[..], [(0, "on"), (_, "schedule")]);
+        if in_schedule && key == "cron" {
+            let value = value.split(" #").next().unwrap_or(value).trim();
+            let value = value.trim_matches(|c| c == '\'' || c == '"').trim();
+            if !value.is_empty() {
+                crons.push(value.to_string());
+            }
+        }
+        path.push((indent, key));
+    }
+    crons
+}
+
+/// Parsed five-field cron expression, evaluated in UTC like GitHub's scheduler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Whether day of month and day of week were both restricted; either then matches.
+    either_day: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Option<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return None;
+        };
+        let mut weekdays = parse_field(weekday, 0, 7, &WEEKDAY_NAMES)?;
+        // Both 0 and 7 mean Sunday
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Some(Self {
+            minutes: parse_field(minute, 0, 59, &[])?,
+            hours: parse_field(hour, 0, 23, &[])?,
+            days: parse_field(day, 1, 31, &[])?,
+            months: parse_field(month, 1, 12, &MONTH_NAMES)?,
+            weekdays,
+            either_day: day != "*" && weekday != "*",
+        })
+    }
+
+    /// First matching minute strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        for offset in 0..=SEARCH_DAYS {
+            let date = start.date_naive() + Duration::days(offset);
+            let day_of_month = self.days & (1 << date.day()) != 0;
+            let day_of_week = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+            let day_matches = if self.either_day {
+                day_of_month || day_of_week
+            } else {
+                day_of_month && day_of_week
+            };
+            if self.months & (1 << date.month()) == 0 || !day_matches {
+                continue;
+            }
+            let first = if offset == 0 {
+                start.time()
+            } else {
+                NaiveTime::MIN
+            };
+            for hour in first.hour()..24 {
+                if self.hours & (1 << hour) == 0 {
+                    continue;
+                }
+                let first_minute = if hour == first.hour() {
+                    first.minute()
+                } else {
+                    0
+                };
+                if let Some(minute) = (first_minute..60).find(|m| self.minutes & (1 << m) != 0) {
+                    let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
+                    return Some(date.and_time(time).and_utc());
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Bitmask of the values a cron field matches (e.g., "*/15", "1-5", "mon,wed").
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Option<u64> {
+    let value = |s: &str| -> Option<u32> {
+        let n = match s.parse() {
+            Ok(n) => n,
+            Err(_) => {
+                let lower = s.to_ascii_lowercase();
+                let index = names.iter().position(|name| *name == lower)? as u32;
+                // Month names start at 1, weekday names at 0
+                index + min
+            }
+        };
+        (min..=max).contains(&n).then_some(n)
+    };
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse().ok().filter(|&s: &u32| s > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (value(start)?, value(end)?)
+        } else {
+            let start = value(range)?;
+            (start, if part.contains('/') { max } else { start })
+        };
+        if start > end {
+            return None;
+        }
+        for n in (start..=end).step_by(step as usize) {
+            mask |= 1 << n;
+        }
+    }
+    Some(mask)
+}
+
+/// Time until a scheduled run (e.g., "3h 12m", "2d 4h", "45m").
+pub fn format_until(duration: Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        "<1m".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text).unwrap().to_utc()
+    }
+
+    #[test]
+    fn test_cron_expressions() {
+        let yaml = r#"
+on:
+  push:
+  schedule:
+    - cron: '30 2 * * 1-5' # weekday nightly
+    - cron: "0 */6 * * *"
+"#;
+        assert_eq!(cron_expressions(yaml), ["30 2 * * 1-5", "0 */6 * * *"]);
+    }
+
+    #[test]
+    fn test_cron_expressions_only_from_schedule_trigger() {
+        let yaml = r#"
+"on":
+  workflow_dispatch:
+    inputs:
+      cron:
+        description: Cron to test
+  schedule:
+    - cron: '0 1 * * *'
+jobs:
+  check:
+    steps:
+      - run: echo "cron: 5 4 * * *"
+      - with:
+          cron: '0 0 * * 0'
+"#;
+        assert_eq!(cron_expressions(yaml), ["0 1 * * *"]);
+    }
+
+    #[test]
+    fn test_next_after() {
+        // Friday 2026-10-16 10:05 UTC
+        let now = at("2026-10-16T10:05:30Z");
+        let nightly = CronSchedule::parse("30 2 * * mon-fri").unwrap();
+        assert_eq!(nightly.next_after(now), Some(at("2026-10-19T02:30:00Z")));
+        let every_six = CronSchedule::parse("0 */6 * * *").unwrap();
+        assert_eq!(every_six.next_after(now), Some(at("2026-10-16T12:00:00Z")));
+        let first_or_sunday = CronSchedule::parse("0 0 1 * 0").unwrap();
+        assert_eq!(
+            first_or_sunday.next_after(now),
+            Some(at("2026-10-18T00:00:00Z"))
+        );
+        assert!(CronSchedule::parse("0 0 * *").is_none());
+        assert!(CronSchedule::parse("61 0 * * *").is_none());
+    }
+
+    #[test]
+    fn test_upcoming_merges_crons() {
+        let schedule = WorkflowSchedule {
+            name: "Nightly".to_string(),
+            crons: vec!["0 12 * * *".to_string(), "0 6 * * *".to_string()],
+        };
+        let now = at("2026-10-16T10:00:00Z");
+        assert_eq!(
+            schedule.upcoming(now, 3),
+            [
+                at("2026-10-16T12:00:00Z"),
+                at("2026-10-17T06:00:00Z"),
+                at("2026-10-17T12:00:00Z"),
+            ]
+        );
+        assert_eq!(format_until(Duration::minutes(192)), "3h 12m");
+    }
+}
@@ -0,0 +1,172 @@
+// Actions secrets and variables inventory.
+// Lists what a repository can use, without visiting the web settings page.
+
+use chrono::{DateTime, Utc};
+use crossterm::event::KeyCode;
+
+use crate::github::{ActionsSecret, ActionsVariable};
+
+use super::popup::PopupKey;
+use super::workflows::SelectableList;
+
+/// Where a secret or variable is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigScope {
+    Repository,
+    Organization,
+}
+
+impl ConfigScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigScope::Repository => "repo",
+            ConfigScope::Organization => "org",
+        }
+    }
+}
+
+/// Secret or variable available to a repository's workflows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigEntry {
+    pub scope: ConfigScope,
+    pub name: String,
+    /// Variable value; None for secrets, whose values can't be read.
+    pub value: Option<String>,
+    pub updated_at: DateTime<Utc>,
+    /// Whether a repository entry with the same name takes precedence.
+    pub shadowed: bool,
+}
+
+impl ConfigEntry {
+    pub fn is_secret(&self) -> bool {
+        self.value.is_none()
+    }
+
+    fn secret(scope: ConfigScope, secret: ActionsSecret) -> Self {
+        Self {
+            scope,
+            name: secret.name,
+            value: None,
+            updated_at: secret.updated_at,
+            shadowed: false,
+        }
+    }
+
+    fn variable(scope: ConfigScope, variable: ActionsVariable) -> Self {
+        Self {
+            scope,
+            name: variable.name,
+            value: Some(variable.value),
+            updated_at: variable.updated_at,
+            shadowed: false,
+        }
+    }
+}
+
+/// Secrets and variables fetched for each scope.
+#[derive(Debug, Default)]
+pub struct Inventory {
+    pub repo_secrets: Vec<ActionsSecret>,
+    pub repo_variables: Vec<ActionsVariable>,
+    pub org_secrets: Vec<ActionsSecret>,
+    pub org_variables: Vec<ActionsVariable>,
+}
+
+impl Inventory {
+    /// Secrets then variables, each by name with repository entries first.
+    /// Organization entries overridden by a repository entry are marked shadowed.
+    pub fn entries(self) -> Vec<ConfigEntry> {
+        let secrets = self
+            .repo_secrets
+            .into_iter()
+            .map(|s| ConfigEntry::secret(ConfigScope::Repository, s))
+            .chain(
+                self.org_secrets
+                    .into_iter()
+                    .map(|s| ConfigEntry::secret(ConfigScope::Organization, s)),
+            );
+        let variables = self
+            .repo_variables
+            .into_iter()
+            .map(|v| ConfigEntry::variable(ConfigScope::Repository, v))
+            .chain(
+                self.org_variables
+                    .into_iter()
+                    .map(|v| ConfigEntry::variable(ConfigScope::Organization, v)),
+            );
+        let mut entries: Vec<ConfigEntry> = secrets.chain(variables).collect();
+        entries.sort_by(|a, b| {
+            (a.value.is_some(), &a.name, a.scope).cmp(&(b.value.is_some(), &b.name, b.scope))
+        });
+        for i in 1..entries.len() {
+            let (prev, entry) = (&entries[i - 1], &entries[i]);
+            entries[i].shadowed = entry.scope == ConfigScope::Organization
+                && prev.scope == ConfigScope::Repository
+                && prev.name == entry.name
+                && prev.is_secret() == entry.is_secret();
+        }
+        entries
+    }
+}
+
+/// Secrets and variables of a repository, shown in the inventory popup.
+#[derive(Debug)]
+pub struct SecretsView {
+    pub owner: String,
+    pub repo: String,
+    pub entries: SelectableList<ConfigEntry>,
+    /// Scopes that couldn't be listed and why.
+    pub notes: Vec<String>,
+}
+
+impl SecretsView {
+    /// Handle a key press: arrows move and any other key closes.
+    pub fn update(&mut self, code: KeyCode) -> PopupKey {
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.entries.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => self.entries.select_next(),
+            _ => return PopupKey::Close,
+        }
+        PopupKey::Stay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(name: &str) -> ActionsSecret {
+        ActionsSecret {
+            name: name.to_string(),
+            updated_at: DateTime::from_timestamp(0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_entries_mark_shadowed_org_secrets() {
+        let inventory = Inventory {
+            repo_secrets: vec![secret("NPM_TOKEN")],
+            repo_variables: vec![ActionsVariable {
+                name: "DEPLOY_ENV".to_string(),
+                value: "staging".to_string(),
+                updated_at: DateTime::from_timestamp(0, 0).unwrap(),
+            }],
+            org_secrets: vec![secret("SLACK_WEBHOOK"), secret("NPM_TOKEN")],
+            org_variables: Vec::new(),
+        };
+        let entries = inventory.entries();
+        let summary: Vec<(&str, &str, bool)> = entries
+            .iter()
+            .map(|e| (e.scope.label(), e.name.as_str(), e.shadowed))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("repo", "NPM_TOKEN", false),
+                ("org", "NPM_TOKEN", true),
+                ("org", "SLACK_WEBHOOK", false),
+                ("repo", "DEPLOY_ENV", false),
+            ]
+        );
+    }
+}
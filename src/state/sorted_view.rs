@@ -0,0 +1,67 @@
+// Display order of a list.
+// The one mapping from the rows a list shows to its loaded items. Rendering and
+// key handlers both go through it, so however a list is sorted or collapsed,
+// the highlighted row is the item that actions apply to.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Display order of a list's items: a permutation of indices into the loaded
+/// items, less the ones kept out of view.
+#[derive(Debug, Clone, Default)]
+pub struct SortedView {
+    order: Vec<usize>,
+    hidden: HashSet<usize>,
+}
+
+impl SortedView {
+    /// Load order of `len` items, with none hidden.
+    pub fn unsorted(len: usize) -> Self {
+        Self {
+            order: (0..len).collect(),
+            hidden: HashSet::new(),
+        }
+    }
+
+    /// Indices of the shown items among `len` loaded ones, in display order.
+    /// Falls back to load order if the items changed since the last sort.
+    pub fn indices(&self, len: usize) -> impl Iterator<Item = usize> + '_ {
+        let order: Box<dyn Iterator<Item = usize>> = if self.order.len() == len {
+            Box::new(self.order.iter().copied())
+        } else {
+            Box::new(0..len)
+        };
+        order.filter(|i| !self.hidden.contains(i))
+    }
+
+    /// Order `items` by `compare`. Equal items keep their load order.
+    pub fn sort_by<T>(&mut self, items: &[T], mut compare: impl FnMut(&T, &T) -> Ordering) {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        order.sort_by(|&a, &b| compare(&items[a], &items[b]));
+        self.order = order;
+    }
+
+    /// Keep the items at `hidden` out of view, showing the rest.
+    pub fn set_hidden(&mut self, hidden: HashSet<usize>) {
+        self.hidden = hidden;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_view_orders_and_hides() {
+        let items = ["charlie", "alpha", "bravo", "delta"];
+        let mut view = SortedView::unsorted(items.len());
+        view.sort_by(&items, |a, b| a.cmp(b));
+        assert_eq!(view.indices(4).collect::<Vec<_>>(), [1, 2, 0, 3]);
+
+        view.set_hidden(HashSet::from([2]));
+        assert_eq!(view.indices(4).collect::<Vec<_>>(), [1, 0, 3]);
+
+        // Items reloaded with a different count show in load order until sorted again
+        assert_eq!(view.indices(3).collect::<Vec<_>>(), [0, 1]);
+    }
+}
@@ -0,0 +1,248 @@
+// Test summaries parsed from job logs.
+// Recognizes cargo test and pytest output, plus JUnit XML report paths.
+
+/// Test runner whose output was recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFramework {
+    Cargo,
+    Pytest,
+    JUnit,
+}
+
+impl TestFramework {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TestFramework::Cargo => "cargo test",
+            TestFramework::Pytest => "pytest",
+            TestFramework::JUnit => "JUnit",
+        }
+    }
+}
+
+/// Failed test and the log line showing its failure (0-indexed).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestFailure {
+    pub name: String,
+    pub line: usize,
+}
+
+/// Test results found in a job log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestReport {
+    pub framework: TestFramework,
+    pub passed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    pub failures: Vec<TestFailure>,
+    /// JUnit XML report files mentioned in the log.
+    pub junit_paths: Vec<String>,
+}
+
+impl TestReport {
+    /// Parse test output from a job log. Returns None if no tests were found.
+    pub fn parse(logs: &str) -> Option<Self> {
+        let mut framework = None;
+        let mut sections = Vec::new();
+        let mut report = TestReport {
+            framework: TestFramework::JUnit,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            failures: Vec::new(),
+            junit_paths: Vec::new(),
+        };
+
+        for (i, raw) in logs.lines().enumerate() {
+            let line = strip_timestamp(raw).trim();
+
+            if let Some(name) = cargo_failed_test(line) {
+                framework.get_or_insert(TestFramework::Cargo);
+                report.add_failure(name, i, false);
+            } else if let Some(name) = delimited(line, "---- ", " stdout ----") {
+                // The panic output is more useful than the one-line result
+                report.add_failure(name, i, true);
+            } else if let Some(counts) = line.strip_prefix("test result: ") {
+                framework.get_or_insert(TestFramework::Cargo);
+                report.add_counts(counts);
+            } else if let Some(rest) = line.strip_prefix("FAILED ") {
+                framework.get_or_insert(TestFramework::Pytest);
+                let name = rest.split(" - ").next().unwrap_or(rest).trim();
+                report.add_failure(name, i, false);
+            } else if let Some(name) = pytest_section(line) {
+                sections.push((name, i));
+            } else if is_pytest_summary(line) {
+                framework.get_or_insert(TestFramework::Pytest);
+                report.add_counts(line.trim_matches('='));
+            }
+
+            for path in line.split_whitespace().filter(|word| is_junit_path(word)) {
+                let path = path.trim_matches(|c| c == '\'' || c == '"');
+                if !report.junit_paths.iter().any(|p| p == path) {
+                    report.junit_paths.push(path.to_string());
+                }
+            }
+        }
+
+        // pytest prints tracebacks under "____ Class.test ____" headers before
+        // the summary, naming tests without their file path
+        for (name, line) in sections {
+            let suffix = format!("::{}", name.replace('.', "::"));
+            if let Some(failure) = report
+                .failures
+                .iter_mut()
+                .find(|f| f.name.ends_with(&suffix))
+            {
+                failure.line = line;
+            }
+        }
+
+        if framework.is_none() && report.junit_paths.is_empty() {
+            return None;
+        }
+        report.framework = framework.unwrap_or(TestFramework::JUnit);
+        Some(report)
+    }
+
+    /// Record a failed test, optionally moving an existing entry to `line`.
+    fn add_failure(&mut self, name: &str, line: usize, relocate: bool) {
+        match self.failures.iter_mut().find(|f| f.name == name) {
+            Some(failure) if relocate => failure.line = line,
+            Some(_) => {}
+            None if relocate => {}
+            None => self.failures.push(TestFailure {
+                name: name.to_string(),
+                line,
+            }),
+        }
+    }
+
+    /// Add counts from a summary like "3 passed; 1 failed; 2 ignored" or
+    /// "1 failed, 10 passed, 2 skipped in 0.50s".
+    fn add_counts(&mut self, summary: &str) {
+        let words: Vec<&str> = summary
+            .split(|c: char| c.is_whitespace() || c == ';' || c == ',' || c == '.')
+            .filter(|w| !w.is_empty())
+            .collect();
+        for pair in words.windows(2) {
+            let Ok(count) = pair[0].parse::<u32>() else {
+                continue;
+            };
+            match pair[1] {
+                "passed" => self.passed += count,
+                "failed" => self.failed += count,
+                "ignored" | "skipped" => self.skipped += count,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Line text without the timestamp GitHub prefixes to job log lines.
+pub fn strip_timestamp(line: &str) -> &str {
+    match line.split_once(' ') {
+        Some((stamp, rest))
+            if stamp.ends_with('Z') && stamp.parse::<chrono::DateTime<chrono::Utc>>().is_ok() =>
+        {
+            rest
+        }
+        _ => line,
+    }
+}
+
+/// Name of a failed test from cargo's "test name ... FAILED" line.
+fn cargo_failed_test(line: &str) -> Option<&str> {
+    delimited(line, "test ", " ... FAILED")
+}
+
+/// Text between a prefix and a suffix.
+fn delimited<'a>(line: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    line.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+/// Test name from a pytest failure section header ("____ test_name ____").
+fn pytest_section(line: &str) -> Option<&str> {
+    let name = line
+        .strip_prefix("___")?
+        .strip_suffix("___")?
+        .trim_matches('_');
+    let name = name.trim();
+    (!name.is_empty() && !name.contains(' ')).then_some(name)
+}
+
+/// Whether a line is pytest's final summary ("=== 1 failed, 2 passed in 0.5s ===").
+fn is_pytest_summary(line: &str) -> bool {
+    line.starts_with("==")
+        && line.ends_with("==")
+        && (line.contains(" passed") || line.contains(" failed"))
+        && line.contains(" in ")
+}
+
+/// Whether a word looks like a JUnit XML report path.
+fn is_junit_path(word: &str) -> bool {
+    let word = word.trim_matches(|c| c == '\'' || c == '"');
+    let lower = word.to_lowercase();
+    lower.ends_with(".xml") && (lower.contains("junit") || lower.contains("test-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cargo() {
+        let logs = "\
+2024-05-01T10:00:00.0000000Z running 3 tests
+2024-05-01T10:00:01.0000000Z test cache::tests::roundtrip ... ok
+2024-05-01T10:00:01.0000000Z test app::tests::refresh ... FAILED
+2024-05-01T10:00:01.0000000Z failures:
+2024-05-01T10:00:01.0000000Z ---- app::tests::refresh stdout ----
+2024-05-01T10:00:01.0000000Z assertion failed
+2024-05-01T10:00:01.0000000Z test result: FAILED. 2 passed; 1 failed; 1 ignored; 0 measured";
+        let report = TestReport::parse(logs).unwrap();
+        assert_eq!(report.framework, TestFramework::Cargo);
+        assert_eq!((report.passed, report.failed, report.skipped), (2, 1, 1));
+        assert_eq!(
+            report.failures,
+            vec![TestFailure {
+                name: "app::tests::refresh".to_string(),
+                line: 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_pytest() {
+        let logs = "\
+____________________ TestApi.test_login ____________________
+    assert 401 == 200
+=========================== short test summary info ============================
+FAILED tests/test_api.py::TestApi::test_login - assert 401 == 200
+======================= 1 failed, 10 passed, 2 skipped in 0.52s =======================
+Generated XML report: /tmp/test-results/junit.xml";
+        let report = TestReport::parse(logs).unwrap();
+        assert_eq!(report.framework, TestFramework::Pytest);
+        assert_eq!((report.passed, report.failed, report.skipped), (10, 1, 2));
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(
+            report.failures[0].name,
+            "tests/test_api.py::TestApi::test_login"
+        );
+        // Jumps to the traceback rather than the summary line
+        assert_eq!(report.failures[0].line, 0);
+        assert_eq!(report.junit_paths, ["/tmp/test-results/junit.xml"]);
+    }
+
+    #[test]
+    fn test_parse_without_tests() {
+        assert_eq!(TestReport::parse("Compiling jolt\nFinished"), None);
+    }
+
+    #[test]
+    fn test_strip_timestamp() {
+        assert_eq!(
+            strip_timestamp("2024-05-01T10:00:00.1234567Z hello"),
+            "hello"
+        );
+        assert_eq!(strip_timestamp("hello world"), "hello world");
+    }
+}
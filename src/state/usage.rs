@@ -0,0 +1,101 @@
+// API usage history.
+// Counts requests and log downloads per day across sessions, kept in the
+// cache directory, so heavy users can see their usage over time in the
+// Console tab.
+
+use std::collections::BTreeMap;
+
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::cache;
+
+/// Days of usage kept, and charted in the Console tab.
+pub const USAGE_DAYS: usize = 14;
+
+/// Requests and log downloads of one day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayUsage {
+    pub requests: u64,
+    pub log_downloads: u64,
+}
+
+/// Usage per day, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageHistory {
+    days: BTreeMap<NaiveDate, DayUsage>,
+}
+
+impl UsageHistory {
+    /// Load the usage recorded by earlier sessions.
+    pub fn load() -> Self {
+        cache::usage_path()
+            .and_then(|path| cache::read_cached::<Self>(&path).ok().flatten())
+            .map(|cached| cached.data)
+            .unwrap_or_default()
+    }
+
+    /// Save the usage for later sessions.
+    pub fn save(&self) {
+        if let Some(path) = cache::usage_path() {
+            let _ = cache::write_cached(&path, self, false);
+        }
+    }
+
+    /// Add requests and log downloads to `today`, dropping days past `USAGE_DAYS`.
+    pub fn record(&mut self, requests: u64, log_downloads: u64, today: NaiveDate) {
+        let day = self.days.entry(today).or_default();
+        day.requests += requests;
+        day.log_downloads += log_downloads;
+        if let Some(start) = first_day(today) {
+            self.days.retain(|date, _| *date >= start);
+        }
+    }
+
+    /// Usage of each of the last `USAGE_DAYS` days up to `today`, oldest
+    /// first, with days without requests at zero.
+    pub fn recent(&self, today: NaiveDate) -> Vec<DayUsage> {
+        let Some(start) = first_day(today) else {
+            return Vec::new();
+        };
+        start
+            .iter_days()
+            .take(USAGE_DAYS)
+            .map(|date| self.days.get(&date).copied().unwrap_or_default())
+            .collect()
+    }
+}
+
+/// Oldest day kept when it's `today`.
+fn first_day(today: NaiveDate) -> Option<NaiveDate> {
+    today.checked_sub_days(Days::new(USAGE_DAYS as u64 - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_per_day_within_window() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let mut usage = UsageHistory::default();
+        usage.record(5, 1, day(1));
+        usage.record(3, 0, day(2));
+        usage.record(4, 2, day(2));
+        usage.record(1, 0, day(15));
+
+        let recent = usage.recent(day(15));
+        assert_eq!(recent.len(), USAGE_DAYS);
+        // October 1st fell out of the window once the 15th was recorded
+        assert!(!usage.days.contains_key(&day(1)));
+        assert_eq!(
+            recent[0],
+            DayUsage {
+                requests: 7,
+                log_downloads: 2
+            }
+        );
+        assert_eq!(recent[1], DayUsage::default());
+        assert_eq!(recent[USAGE_DAYS - 1].requests, 1);
+    }
+}
@@ -0,0 +1,112 @@
+// Runner utilization over the last hour.
+// Samples whether each runner is busy whenever its Runners list is fetched,
+// for a sparkline per runner showing under- or over-provisioning.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::github::Runner;
+
+/// How far back utilization is shown.
+pub const UTILIZATION_WINDOW_MINUTES: i64 = 60;
+
+/// Time buckets in a utilization sparkline.
+pub const UTILIZATION_BUCKETS: usize = 12;
+
+/// Busy samples per runner id, oldest first, kept for the session.
+#[derive(Debug, Clone, Default)]
+pub struct RunnerUtilization {
+    samples: HashMap<u64, Vec<(DateTime<Utc>, bool)>>,
+}
+
+impl RunnerUtilization {
+    /// Record whether each runner is busy, dropping samples past the window.
+    pub fn record(&mut self, runners: &[Runner], now: DateTime<Utc>) {
+        let start = now - Duration::minutes(UTILIZATION_WINDOW_MINUTES);
+        for runner in runners {
+            let samples = self.samples.entry(runner.id).or_default();
+            samples.retain(|(at, _)| *at >= start);
+            samples.push((now, runner.busy));
+        }
+    }
+
+    /// Percent of samples the runner was busy in each bucket of the window,
+    /// oldest first; None for buckets without samples. None if the runner
+    /// wasn't sampled in the window.
+    pub fn buckets(&self, runner_id: u64, now: DateTime<Utc>) -> Option<Vec<Option<u8>>> {
+        let window = Duration::minutes(UTILIZATION_WINDOW_MINUTES);
+        let start = now - window;
+        let bucket_secs = window.num_seconds() / UTILIZATION_BUCKETS as i64;
+        let mut counts = vec![(0u32, 0u32); UTILIZATION_BUCKETS];
+        let mut sampled = false;
+        for (at, busy) in self.samples.get(&runner_id)? {
+            if *at < start || *at > now {
+                continue;
+            }
+            let bucket =
+                (((*at - start).num_seconds() / bucket_secs) as usize).min(UTILIZATION_BUCKETS - 1);
+            counts[bucket].0 += u32::from(*busy);
+            counts[bucket].1 += 1;
+            sampled = true;
+        }
+        sampled.then(|| {
+            counts
+                .into_iter()
+                .map(|(busy, total)| (total > 0).then(|| (busy * 100 / total) as u8))
+                .collect()
+        })
+    }
+
+    /// Percent of the window's samples the runner was busy.
+    pub fn busy_percent(&self, runner_id: u64, now: DateTime<Utc>) -> Option<u8> {
+        let start = now - Duration::minutes(UTILIZATION_WINDOW_MINUTES);
+        let samples: Vec<bool> = self
+            .samples
+            .get(&runner_id)?
+            .iter()
+            .filter(|(at, _)| *at >= start && *at <= now)
+            .map(|(_, busy)| *busy)
+            .collect();
+        let busy = samples.iter().filter(|busy| **busy).count();
+        (!samples.is_empty()).then(|| (busy * 100 / samples.len()) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::RunnerStatus;
+
+    fn runner(busy: bool) -> Runner {
+        Runner {
+            id: 1,
+            name: "mac-mini".to_string(),
+            os: "macOS".to_string(),
+            status: RunnerStatus::Online,
+            busy,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_utilization_buckets_last_hour() {
+        let now = DateTime::from_timestamp(10_000, 0).unwrap();
+        let mut utilization = RunnerUtilization::default();
+        // Over an hour ago, then idle and busy in the last five minutes
+        utilization.record(&[runner(true)], now - Duration::minutes(90));
+        utilization.record(&[runner(false)], now - Duration::minutes(2));
+        utilization.record(&[runner(true)], now - Duration::minutes(1));
+
+        let buckets = utilization.buckets(1, now).unwrap();
+        assert_eq!(buckets.len(), UTILIZATION_BUCKETS);
+        assert_eq!(buckets[UTILIZATION_BUCKETS - 1], Some(50));
+        assert!(
+            buckets[..UTILIZATION_BUCKETS - 1]
+                .iter()
+                .all(Option::is_none)
+        );
+        assert_eq!(utilization.busy_percent(1, now), Some(50));
+        assert_eq!(utilization.buckets(2, now), None);
+    }
+}
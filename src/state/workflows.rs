@@ -1,11 +1,27 @@
 // Workflows tab state management.
 // Handles data loading, caching, and list state for the workflows tab.
 
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
 use ratatui::widgets::ListState;
 
-use crate::github::{Job, Owner, Repository, Workflow, WorkflowRun};
+use crate::error::ViewError;
+use crate::github::{
+    Annotation, Deployment, Environment, Job, Owner, Repository, Workflow, WorkflowRun,
+};
 
+use super::filter::{Filterable, fuzzy_match};
+use super::history::JobHistory;
+use super::log_viewer::LogViewerState;
 use super::navigation::{NavigationStack, ViewLevel};
+use super::prefetch::LogPrefetch;
+use super::schedule::WorkflowSchedule;
+use super::sorted_view::SortedView;
+use super::test_report::{TestFailure, TestReport, strip_timestamp};
 
 /// Loading state for async data.
 #[derive(Debug, Clone, Default)]
@@ -14,7 +30,7 @@ pub enum LoadingState<T> {
     Idle,
     Loading,
     Loaded(T),
-    Error(String),
+    Error(ViewError),
 }
 
 impl<T> LoadingState<T> {
@@ -86,11 +102,34 @@ impl<T> PaginatedList<T> {
 }
 
 /// State for a selectable list with keyboard navigation.
+///
+/// Items are displayed in the order of `view`, narrowed to those matching
+/// `filter`. Rendering and selection both go through this mapping so the row
+/// the user sees highlighted is always the item that handlers act on.
 #[derive(Debug, Clone)]
 pub struct SelectableList<T> {
     pub data: LoadingState<PaginatedList<T>>,
     pub list_state: ListState,
+    /// Text typed to narrow the list (fuzzy matched).
     pub filter: Option<String>,
+    /// Text of an item that the filter is matched against.
+    filter_text: Option<fn(&T) -> String>,
+    /// Whether the loaded items came from an expired cache entry and
+    /// should be revalidated against the API.
+    pub stale: bool,
+    /// IDs of items marked for bulk actions.
+    pub marked: HashSet<u64>,
+    /// Display order of the loaded items, less those kept out of view, such as
+    /// runs in a collapsed group.
+    view: SortedView,
+    /// Identity of an item, used to keep it selected when the list reloads.
+    id_of: Option<fn(&T) -> u64>,
+    /// Selected item ID and scroll offset from before the list started
+    /// reloading, restored once it has loaded.
+    retained: Option<(u64, usize)>,
+    /// Display indices worked out from the items, `view`, and `filter`, kept
+    /// until one of them changes.
+    display: OnceCell<Vec<usize>>,
 }
 
 impl<T> Default for SelectableList<T> {
@@ -99,6 +138,13 @@ impl<T> Default for SelectableList<T> {
             data: LoadingState::Idle,
             list_state: ListState::default(),
             filter: None,
+            filter_text: None,
+            stale: false,
+            marked: HashSet::new(),
+            view: SortedView::default(),
+            id_of: None,
+            retained: None,
+            display: OnceCell::new(),
         }
     }
 }
@@ -108,6 +154,31 @@ impl<T> SelectableList<T> {
         Self::default()
     }
 
+    /// A list that keeps the selected item selected across reloads,
+    /// recognizing it by `id_of`.
+    pub fn with_id_of(id_of: fn(&T) -> u64) -> Self {
+        Self {
+            id_of: Some(id_of),
+            ..Self::default()
+        }
+    }
+
+    /// Clear the list back to its initial state, keeping its identity hook.
+    pub fn reset(&mut self) {
+        *self = Self {
+            id_of: self.id_of,
+            ..Self::default()
+        };
+    }
+
+    /// Clear the list for a refresh, remembering the selected item to select
+    /// it again once the list has reloaded.
+    pub fn clear_for_reload(&mut self) {
+        let retained = self.selected_identity().or(self.retained);
+        self.reset();
+        self.retained = retained;
+    }
+
     /// Get the currently selected index.
     pub fn selected(&self) -> Option<usize> {
         self.list_state.selected()
@@ -115,13 +186,14 @@ impl<T> SelectableList<T> {
 
     /// Select the next item in the list.
     pub fn select_next(&mut self) {
-        if let Some(items) = self.data.data() {
-            if items.is_empty() {
+        if self.data.is_loaded() {
+            let len = self.visible_len();
+            if len == 0 {
                 return;
             }
             let i = match self.list_state.selected() {
                 Some(i) => {
-                    if i >= items.len() - 1 {
+                    if i >= len - 1 {
                         i // Stay at end
                     } else {
                         i + 1
@@ -135,8 +207,8 @@ impl<T> SelectableList<T> {
 
     /// Select the previous item in the list.
     pub fn select_prev(&mut self) {
-        if let Some(items) = self.data.data() {
-            if items.is_empty() {
+        if self.data.is_loaded() {
+            if self.visible_len() == 0 {
                 return;
             }
             let i = match self.list_state.selected() {
@@ -157,7 +229,132 @@ impl<T> SelectableList<T> {
     pub fn selected_item(&self) -> Option<&T> {
         let index = self.list_state.selected()?;
         let items = self.data.data()?;
-        items.items.get(index)
+        let item_index = self.item_index(index)?;
+        items.items.get(item_index)
+    }
+
+    /// Get the shown items in display order.
+    pub fn sorted_items(&self) -> Vec<&T> {
+        match self.data.data() {
+            Some(items) => self
+                .display_indices()
+                .iter()
+                .filter_map(|&i| items.items.get(i))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Number of items shown after filtering.
+    pub fn visible_len(&self) -> usize {
+        self.display_indices().len()
+    }
+
+    /// Indices into the loaded items, in display order and narrowed by the filter.
+    /// Falls back to load order if the items changed since the last sort.
+    fn display_indices(&self) -> &[usize] {
+        self.display.get_or_init(|| {
+            let Some(items) = self.data.data() else {
+                return Vec::new();
+            };
+            let indices = self.view.indices(items.len());
+            match (self.filter.as_deref(), self.filter_text) {
+                (Some(query), Some(text)) => indices
+                    .filter(|&i| fuzzy_match(query, &text(&items.items[i])).is_some())
+                    .collect(),
+                _ => indices.collect(),
+            }
+        })
+    }
+
+    /// Forget the display indices after the items, order, or filter changed.
+    fn invalidate_display(&mut self) {
+        self.display.take();
+    }
+
+    /// Map a display index to an index into the loaded items.
+    fn item_index(&self, display_index: usize) -> Option<usize> {
+        self.display_indices().get(display_index).copied()
+    }
+
+    /// Map an index into the loaded items to its display index, if it's shown.
+    fn display_index(&self, item_index: usize) -> Option<usize> {
+        self.display_indices().iter().position(|&i| i == item_index)
+    }
+
+    /// Sort the display order, keeping the selected item selected.
+    pub fn sort_by(&mut self, compare: impl FnMut(&T, &T) -> Ordering) {
+        let Some(items) = self.data.data() else {
+            return;
+        };
+        let selected = self.list_state.selected().and_then(|i| self.item_index(i));
+        self.view.sort_by(&items.items, compare);
+        self.invalidate_display();
+
+        if let Some(selected) = selected {
+            self.list_state.select(self.display_index(selected));
+        }
+    }
+
+    /// Sort favorites first, then by the given key.
+    pub fn sort_favorites_first<K: Ord>(
+        &mut self,
+        is_favorite: impl Fn(&T) -> bool,
+        key: impl Fn(&T) -> K,
+    ) {
+        self.sort_by(|a, b| match (is_favorite(a), is_favorite(b)) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => key(a).cmp(&key(b)),
+        });
+    }
+
+    /// Hide the items `hide` accepts and show the rest. If the selected item is
+    /// hidden, the nearest shown item above it is selected instead.
+    pub fn set_hidden(&mut self, hide: impl Fn(&T) -> bool) {
+        let Some(items) = self.data.data() else {
+            return;
+        };
+        let hidden = items
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| hide(item))
+            .map(|(i, _)| i)
+            .collect();
+        let before = self.display_indices().to_vec();
+        self.view.set_hidden(hidden);
+        self.invalidate_display();
+        let Some(selected) = self.list_state.selected() else {
+            return;
+        };
+        let after = self.display_indices();
+        let position = before
+            .iter()
+            .take(selected + 1)
+            .rev()
+            .find_map(|i| after.iter().position(|j| j == i));
+        match position {
+            Some(position) => self.list_state.select(Some(position)),
+            None => self.reset_selection(),
+        }
+    }
+
+    /// Clear the filter, keeping the selected item selected.
+    pub fn clear_filter(&mut self) {
+        let selected = self.list_state.selected().and_then(|i| self.item_index(i));
+        self.filter = None;
+        self.invalidate_display();
+        if let Some(selected) = selected {
+            self.list_state.select(self.display_index(selected));
+        }
+    }
+
+    /// Mark or unmark an item for bulk actions.
+    pub fn toggle_mark(&mut self, id: u64) {
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
     }
 
     /// Check if we're near the end of the list (for pagination trigger).
@@ -171,8 +368,8 @@ impl<T> SelectableList<T> {
 
     /// Reset selection to first item.
     pub fn reset_selection(&mut self) {
-        if let Some(items) = self.data.data() {
-            if !items.is_empty() {
+        if self.data.is_loaded() {
+            if self.visible_len() > 0 {
                 self.list_state.select(Some(0));
             } else {
                 self.list_state.select(None);
@@ -182,20 +379,122 @@ impl<T> SelectableList<T> {
         }
     }
 
-    /// Set loaded data.
+    /// Set loaded data. If the list has an identity hook and the previously
+    /// selected item is still there, it stays selected and the list keeps its
+    /// scroll offset; otherwise the first item is selected.
     pub fn set_loaded(&mut self, items: Vec<T>, total_count: u64) {
+        self.load(items, total_count);
+    }
+
+    /// Replace stale data with freshly fetched items, keeping the selected
+    /// item, or the selected row if that item is gone.
+    pub fn set_refreshed(&mut self, items: Vec<T>, total_count: u64) {
+        let selected = self.list_state.selected();
+        if self.load(items, total_count) {
+            return;
+        }
+        let len = self.visible_len();
+        if let Some(i) = selected.filter(|_| len > 0) {
+            self.list_state.select(Some(i.min(len - 1)));
+        }
+    }
+
+    /// Replace the loaded items. Returns whether the selected item was found
+    /// among the new items and selected again.
+    fn load(&mut self, items: Vec<T>, total_count: u64) -> bool {
+        let retained = self.selected_identity().or(self.retained.take());
+        self.view = SortedView::unsorted(items.len());
         self.data = LoadingState::Loaded(PaginatedList::new(items, total_count));
+        self.invalidate_display();
+        self.stale = false;
         self.reset_selection();
+        match retained {
+            Some((id, offset)) => self.select_id(id, offset),
+            None => false,
+        }
     }
 
-    /// Set loading state.
+    /// ID of the selected item and the scroll offset, if the list has an
+    /// identity hook.
+    fn selected_identity(&self) -> Option<(u64, usize)> {
+        let id_of = self.id_of?;
+        let item = self.selected_item()?;
+        Some((id_of(item), self.list_state.offset()))
+    }
+
+    /// Select the shown item with ID `id` and restore the scroll offset.
+    /// Returns whether the item is shown.
+    fn select_id(&mut self, id: u64, offset: usize) -> bool {
+        let (Some(id_of), Some(items)) = (self.id_of, self.data.data()) else {
+            return false;
+        };
+        let position = self
+            .display_indices()
+            .iter()
+            .position(|&i| id_of(&items.items[i]) == id);
+        match position {
+            Some(position) => {
+                self.list_state.select(Some(position));
+                *self.list_state.offset_mut() = offset.min(position);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the loaded item matching `is_item` in place, keeping the display
+    /// order and selection. Returns whether an item was replaced.
+    pub fn replace_item(&mut self, is_item: impl Fn(&T) -> bool, item: T) -> bool {
+        let LoadingState::Loaded(data) = &mut self.data else {
+            return false;
+        };
+        match data.items.iter_mut().find(|existing| is_item(existing)) {
+            Some(existing) => {
+                *existing = item;
+                // The filter may no longer match it
+                self.invalidate_display();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Mark loaded data for revalidation, keeping it visible meanwhile.
+    pub fn mark_stale(&mut self) {
+        if self.data.is_loaded() {
+            self.stale = true;
+        }
+    }
+
+    /// Set loading state, remembering the selected item to restore it once
+    /// the list has loaded.
     pub fn set_loading(&mut self) {
+        if let Some(identity) = self.selected_identity() {
+            self.retained = Some(identity);
+        }
         self.data = LoadingState::Loading;
+        self.invalidate_display();
     }
 
     /// Set error state.
-    pub fn set_error(&mut self, error: String) {
-        self.data = LoadingState::Error(error);
+    pub fn set_error(&mut self, error: impl Into<ViewError>) {
+        self.data = LoadingState::Error(error.into());
+        self.invalidate_display();
+    }
+}
+
+impl<T: Filterable> SelectableList<T> {
+    /// Narrow the list to items fuzzy matching `query`.
+    /// Keeps the selected item selected if it still matches, else selects the first.
+    pub fn set_filter(&mut self, query: Option<String>) {
+        let selected = self.list_state.selected().and_then(|i| self.item_index(i));
+        self.filter = query;
+        self.filter_text = Some(T::filter_text);
+        self.invalidate_display();
+        match selected.and_then(|selected| self.display_index(selected)) {
+            Some(position) => self.list_state.select(Some(position)),
+            None => self.reset_selection(),
+        }
     }
 }
 
@@ -210,30 +509,73 @@ pub struct WorkflowsTabState {
     pub repositories: SelectableList<Repository>,
     /// Workflows list for current repository.
     pub workflows: SelectableList<Workflow>,
+    /// Cron triggers of the current repository's scheduled workflows, by workflow ID.
+    pub schedules: HashMap<u64, WorkflowSchedule>,
+    /// Latest run of each of the current repository's workflows, by workflow ID.
+    pub latest_runs: HashMap<u64, WorkflowRun>,
     /// Workflow runs list for current workflow.
     pub runs: SelectableList<WorkflowRun>,
+    /// Branch the Runs list is narrowed to, if any.
+    pub current_branch: Option<String>,
+    /// Whether the branch filter was set for the current Runs list, so opening
+    /// it doesn't narrow it to the default branch again.
+    pub branch_chosen: bool,
     /// Jobs list for current run.
     pub jobs: SelectableList<Job>,
-    /// Log content for current job.
-    pub log_content: LoadingState<String>,
-    /// Horizontal scroll offset for log viewer.
-    pub log_scroll_x: u16,
-    /// Vertical scroll offset for log viewer.
-    pub log_scroll_y: u16,
+    /// Deployment environments of the current repository.
+    pub environments: SelectableList<Environment>,
+    /// Recent deployments to the current environment.
+    pub deployments: SelectableList<Deployment>,
+    /// Durations of the current run's jobs in earlier runs, for ETAs.
+    pub job_history: JobHistory,
+    /// Names of the current run's jobs that failed and passed on retry.
+    pub flaky_jobs: HashSet<String>,
+    /// Jobs of the run selected in the Runs list, for the preview pane.
+    pub preview_jobs: SelectableList<Job>,
+    /// Run whose jobs are in the preview pane.
+    pub preview_run_id: Option<u64>,
+    /// Failed jobs of the current run whose logs are still to be prefetched.
+    pub log_prefetch: LogPrefetch,
+    /// Log of the current job and where it's scrolled.
+    pub log: LogViewerState,
+    /// Check-run annotations for current job.
+    pub annotations: SelectableList<Annotation>,
+    /// Whether arrow keys move through annotations instead of scrolling logs.
+    pub annotations_focused: bool,
+    /// Test results parsed from the current job's log.
+    pub test_report: Option<TestReport>,
+    /// Failed tests from the test report, for selection.
+    pub test_failures: SelectableList<TestFailure>,
+    /// Whether arrow keys move through failed tests instead of scrolling logs.
+    pub tests_focused: bool,
 }
 
 impl Default for WorkflowsTabState {
     fn default() -> Self {
         Self {
             nav: NavigationStack::default(),
-            owners: SelectableList::new(),
-            repositories: SelectableList::new(),
-            workflows: SelectableList::new(),
-            runs: SelectableList::new(),
-            jobs: SelectableList::new(),
-            log_content: LoadingState::Idle,
-            log_scroll_x: 0,
-            log_scroll_y: 0,
+            owners: SelectableList::with_id_of(|item| item.id),
+            repositories: SelectableList::with_id_of(|item| item.id),
+            workflows: SelectableList::with_id_of(|item| item.id),
+            schedules: HashMap::new(),
+            latest_runs: HashMap::new(),
+            runs: SelectableList::with_id_of(|item| item.id),
+            current_branch: None,
+            branch_chosen: false,
+            jobs: SelectableList::with_id_of(|item| item.id),
+            environments: SelectableList::with_id_of(|item| item.id),
+            deployments: SelectableList::with_id_of(|item| item.id),
+            job_history: JobHistory::default(),
+            flaky_jobs: HashSet::new(),
+            preview_jobs: SelectableList::new(),
+            preview_run_id: None,
+            log_prefetch: LogPrefetch::default(),
+            log: LogViewerState::default(),
+            annotations: SelectableList::new(),
+            annotations_focused: false,
+            test_report: None,
+            test_failures: SelectableList::new(),
+            tests_focused: false,
         }
     }
 }
@@ -258,34 +600,52 @@ impl WorkflowsTabState {
             // Clear all lists below the level we came from
             match current {
                 ViewLevel::Repositories { .. } => {
-                    self.repositories = SelectableList::new();
-                    self.workflows = SelectableList::new();
-                    self.runs = SelectableList::new();
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.repositories.reset();
+                    self.workflows.reset();
+                    self.schedules.clear();
+                    self.latest_runs.clear();
+                    self.runs.reset();
+                    self.current_branch = None;
+                    self.branch_chosen = false;
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
                 ViewLevel::Workflows { .. } => {
-                    self.workflows = SelectableList::new();
-                    self.runs = SelectableList::new();
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.workflows.reset();
+                    self.schedules.clear();
+                    self.latest_runs.clear();
+                    self.runs.reset();
+                    self.current_branch = None;
+                    self.branch_chosen = false;
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
                 ViewLevel::Runs { .. } => {
-                    self.runs = SelectableList::new();
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.runs.reset();
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
                 ViewLevel::Jobs { .. } => {
-                    self.jobs = SelectableList::new();
-                    self.log_content = LoadingState::Idle;
+                    self.jobs.reset();
+                    self.log_prefetch.clear();
+                    self.log.clear();
                 }
+                ViewLevel::Environments { .. } => {
+                    self.environments.reset();
+                    self.deployments.reset();
+                }
+                ViewLevel::Deployments { .. } => self.deployments.reset(),
                 ViewLevel::Logs { .. } => {
-                    self.log_content = LoadingState::Idle;
-                    self.log_scroll_x = 0;
-                    self.log_scroll_y = 0;
+                    self.log.clear();
                 }
                 ViewLevel::Owners => {}
             }
+            self.annotations.reset();
+            self.annotations_focused = false;
+            self.clear_test_report();
         }
         popped
     }
@@ -298,9 +658,40 @@ impl WorkflowsTabState {
             ViewLevel::Workflows { .. } => self.workflows.select_prev(),
             ViewLevel::Runs { .. } => self.runs.select_prev(),
             ViewLevel::Jobs { .. } => self.jobs.select_prev(),
-            ViewLevel::Logs { .. } => {
-                self.log_scroll_y = self.log_scroll_y.saturating_sub(1);
-            }
+            ViewLevel::Environments { .. } => self.environments.select_prev(),
+            ViewLevel::Deployments { .. } => self.deployments.select_prev(),
+            ViewLevel::Logs { .. } if self.annotations_focused => self.annotations.select_prev(),
+            ViewLevel::Logs { .. } if self.tests_focused => self.test_failures.select_prev(),
+            ViewLevel::Logs { .. } => self.log.scroll_up(1),
+        }
+    }
+
+    /// Filter the current list. Returns false if the current view isn't a list.
+    pub fn set_list_filter(&mut self, query: Option<String>) -> bool {
+        match self.nav.current() {
+            ViewLevel::Owners => self.owners.set_filter(query),
+            ViewLevel::Repositories { .. } => self.repositories.set_filter(query),
+            ViewLevel::Workflows { .. } => self.workflows.set_filter(query),
+            ViewLevel::Runs { .. } => self.runs.set_filter(query),
+            ViewLevel::Jobs { .. } => self.jobs.set_filter(query),
+            ViewLevel::Environments { .. } => self.environments.set_filter(query),
+            ViewLevel::Deployments { .. } => self.deployments.set_filter(query),
+            ViewLevel::Logs { .. } => return false,
+        }
+        true
+    }
+
+    /// Filter applied to the current list.
+    pub fn list_filter(&self) -> Option<&str> {
+        match self.nav.current() {
+            ViewLevel::Owners => self.owners.filter.as_deref(),
+            ViewLevel::Repositories { .. } => self.repositories.filter.as_deref(),
+            ViewLevel::Workflows { .. } => self.workflows.filter.as_deref(),
+            ViewLevel::Runs { .. } => self.runs.filter.as_deref(),
+            ViewLevel::Jobs { .. } => self.jobs.filter.as_deref(),
+            ViewLevel::Environments { .. } => self.environments.filter.as_deref(),
+            ViewLevel::Deployments { .. } => self.deployments.filter.as_deref(),
+            ViewLevel::Logs { .. } => None,
         }
     }
 
@@ -312,72 +703,342 @@ impl WorkflowsTabState {
             ViewLevel::Workflows { .. } => self.workflows.select_next(),
             ViewLevel::Runs { .. } => self.runs.select_next(),
             ViewLevel::Jobs { .. } => self.jobs.select_next(),
-            ViewLevel::Logs { .. } => {
-                self.log_scroll_y = self.log_scroll_y.saturating_add(1);
-            }
+            ViewLevel::Environments { .. } => self.environments.select_next(),
+            ViewLevel::Deployments { .. } => self.deployments.select_next(),
+            ViewLevel::Logs { .. } if self.annotations_focused => self.annotations.select_next(),
+            ViewLevel::Logs { .. } if self.tests_focused => self.test_failures.select_next(),
+            ViewLevel::Logs { .. } => self.log.scroll_down(1),
         }
     }
 
     /// Handle left arrow key (horizontal scroll in logs).
     pub fn scroll_left(&mut self) {
         if matches!(self.nav.current(), ViewLevel::Logs { .. }) {
-            self.log_scroll_x = self.log_scroll_x.saturating_sub(4);
+            self.log.scroll_left();
         }
     }
 
     /// Handle right arrow key (horizontal scroll in logs).
     pub fn scroll_right(&mut self) {
         if matches!(self.nav.current(), ViewLevel::Logs { .. }) {
-            self.log_scroll_x = self.log_scroll_x.saturating_add(4);
+            self.log.scroll_right();
         }
     }
 
     /// Handle Page Up key (scroll logs by page).
     pub fn page_up(&mut self) {
         if matches!(self.nav.current(), ViewLevel::Logs { .. }) {
-            self.log_scroll_y = self.log_scroll_y.saturating_sub(20);
+            self.log.page_up();
         }
     }
 
     /// Handle Page Down key (scroll logs by page).
     pub fn page_down(&mut self) {
         if matches!(self.nav.current(), ViewLevel::Logs { .. }) {
-            self.log_scroll_y = self.log_scroll_y.saturating_add(20);
+            self.log.page_down();
         }
     }
 
     /// Scroll to start of logs.
     pub fn scroll_to_start(&mut self) {
         if matches!(self.nav.current(), ViewLevel::Logs { .. }) {
-            self.log_scroll_y = 0;
-            self.log_scroll_x = 0;
+            self.log.scroll_to_start();
         }
     }
 
     /// Scroll to end of logs.
-    #[allow(clippy::collapsible_if)]
     pub fn scroll_to_end(&mut self) {
         if matches!(self.nav.current(), ViewLevel::Logs { .. }) {
-            if let LoadingState::Loaded(logs) = &self.log_content {
-                let line_count = logs.lines().count() as u16;
-                self.log_scroll_y = line_count.saturating_sub(10);
-            }
+            self.log.scroll_to_end();
         }
     }
 
     /// Clear current list data (for refresh).
     pub fn clear_current(&mut self) {
         match self.nav.current() {
-            ViewLevel::Owners => self.owners = SelectableList::new(),
-            ViewLevel::Repositories { .. } => self.repositories = SelectableList::new(),
-            ViewLevel::Workflows { .. } => self.workflows = SelectableList::new(),
-            ViewLevel::Runs { .. } => self.runs = SelectableList::new(),
-            ViewLevel::Jobs { .. } => self.jobs = SelectableList::new(),
+            ViewLevel::Owners => self.owners.clear_for_reload(),
+            ViewLevel::Repositories { .. } => self.repositories.clear_for_reload(),
+            ViewLevel::Workflows { .. } => {
+                self.workflows.clear_for_reload();
+                self.latest_runs.clear();
+            }
+            ViewLevel::Runs { .. } => self.runs.clear_for_reload(),
+            ViewLevel::Jobs { .. } => self.jobs.clear_for_reload(),
+            ViewLevel::Environments { .. } => self.environments.clear_for_reload(),
+            ViewLevel::Deployments { .. } => self.deployments.clear_for_reload(),
             ViewLevel::Logs { .. } => {
-                self.log_content = LoadingState::Idle;
-                self.log_scroll_x = 0;
-                self.log_scroll_y = 0;
+                self.log.clear();
+                self.annotations.reset();
+                self.annotations_focused = false;
+                self.clear_test_report();
             }
         }
     }
+
+    /// Scroll the log to the line for the selected annotation.
+    pub fn jump_to_annotation(&mut self) {
+        self.annotations_focused = false;
+        let (Some(annotation), Some(logs)) =
+            (self.annotations.selected_item(), self.log.content.data())
+        else {
+            return;
+        };
+        if let Some(line) = annotation_log_line(logs, annotation) {
+            // Leave a little context above the line
+            self.log.scroll_to_line(line.saturating_sub(2));
+        }
+    }
+
+    /// Show loaded logs and parse any test results from them.
+    pub fn set_logs(&mut self, logs: String) {
+        self.test_report = TestReport::parse(&logs);
+        let failures = self
+            .test_report
+            .as_ref()
+            .map(|report| report.failures.clone())
+            .unwrap_or_default();
+        let count = failures.len() as u64;
+        self.test_failures.set_loaded(failures, count);
+        self.log.content = LoadingState::Loaded(logs);
+    }
+
+    fn clear_test_report(&mut self) {
+        self.test_report = None;
+        self.test_failures.reset();
+        self.tests_focused = false;
+    }
+
+    /// Scroll the log to the selected failed test's output.
+    pub fn jump_to_test_failure(&mut self) {
+        self.tests_focused = false;
+        if let Some(failure) = self.test_failures.selected_item() {
+            self.log.scroll_to_line(failure.line.saturating_sub(2));
+        }
+    }
+
+    /// Mark the current list for revalidation (logs are left alone).
+    pub fn mark_current_stale(&mut self) {
+        match self.nav.current() {
+            ViewLevel::Owners => self.owners.mark_stale(),
+            ViewLevel::Repositories { .. } => self.repositories.mark_stale(),
+            ViewLevel::Workflows { .. } => self.workflows.mark_stale(),
+            ViewLevel::Runs { .. } => self.runs.mark_stale(),
+            ViewLevel::Jobs { .. } => self.jobs.mark_stale(),
+            ViewLevel::Environments { .. } => self.environments.mark_stale(),
+            ViewLevel::Deployments { .. } => self.deployments.mark_stale(),
+            ViewLevel::Logs { .. } => {}
+        }
+    }
+
+    /// Mark every loaded list for revalidation. Lists below the current level are
+    /// cleared when navigating back, so these are the lists on the nav path.
+    pub fn mark_path_stale(&mut self) {
+        self.owners.mark_stale();
+        self.repositories.mark_stale();
+        self.workflows.mark_stale();
+        self.runs.mark_stale();
+        self.jobs.mark_stale();
+        self.environments.mark_stale();
+        self.deployments.mark_stale();
+    }
+
+    /// Whether the current list is showing stale data.
+    pub fn current_is_stale(&self) -> bool {
+        match self.nav.current() {
+            ViewLevel::Owners => self.owners.stale,
+            ViewLevel::Repositories { .. } => self.repositories.stale,
+            ViewLevel::Workflows { .. } => self.workflows.stale,
+            ViewLevel::Runs { .. } => self.runs.stale,
+            ViewLevel::Jobs { .. } => self.jobs.stale,
+            ViewLevel::Environments { .. } => self.environments.stale,
+            ViewLevel::Deployments { .. } => self.deployments.stale,
+            ViewLevel::Logs { .. } => false,
+        }
+    }
+}
+
+/// Line prefixes of tool output that reports an error, for logs without `##[error]` lines.
+const ERROR_PREFIXES: [&str; 4] = ["error:", "error[", "Error:", "fatal:"];
+
+/// ID for a list item identified by text rather than a numeric ID, for
+/// `SelectableList::with_id_of`.
+pub fn text_id(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Find the first line where a log reports an error: an `##[error]` line Actions
+/// printed, or tool output such as "error[E0308]" that usually precedes it.
+pub fn first_error_line(logs: &str) -> Option<usize> {
+    logs.lines().position(|line| {
+        let text = strip_timestamp(line).trim_start();
+        text.starts_with("##[error]")
+            || ERROR_PREFIXES.iter().any(|prefix| text.starts_with(prefix))
+            || is_error_token(text)
+    })
+}
+
+/// Whether a line starts with an "ERROR" level token, as in "ERROR: ..." or
+/// "ERROR [main] ...", rather than a word like "ERRORS".
+fn is_error_token(text: &str) -> bool {
+    text.strip_prefix("ERROR")
+        .is_some_and(|rest| rest.starts_with([':', ' ', '\t', ']', '[']))
+}
+
+/// Find the log line an annotation was reported from.
+/// Actions prints annotations into the log, so match on the message text.
+fn annotation_log_line(logs: &str, annotation: &Annotation) -> Option<usize> {
+    let message = annotation.message.lines().next()?.trim();
+    if message.is_empty() {
+        return None;
+    }
+    logs.lines().position(|line| line.contains(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded(names: &[&str]) -> SelectableList<String> {
+        let mut list = SelectableList::new();
+        let items: Vec<String> = names.iter().map(|n| n.to_string()).collect();
+        let count = items.len() as u64;
+        list.set_loaded(items, count);
+        list
+    }
+
+    #[test]
+    fn test_sort_favorites_first() {
+        let mut list = loaded(&["charlie", "alpha", "bravo"]);
+        list.sort_favorites_first(|name| name == "charlie", |name| name.clone());
+
+        let sorted: Vec<&String> = list.sorted_items();
+        assert_eq!(sorted, ["charlie", "alpha", "bravo"]);
+
+        list.select_next();
+        assert_eq!(list.selected_item().map(String::as_str), Some("alpha"));
+    }
+
+    #[test]
+    fn test_filter_narrows_and_keeps_selection() {
+        let mut list = loaded(&["build", "lint", "bundle"]);
+        list.select_next();
+        list.set_filter(Some("bl".to_string()));
+        assert_eq!(list.sorted_items(), ["build", "bundle"]);
+        // "lint" no longer matches, so the first match is selected
+        assert_eq!(list.selected_item().map(String::as_str), Some("build"));
+
+        list.select_next();
+        list.clear_filter();
+        assert_eq!(list.visible_len(), 3);
+        assert_eq!(list.selected_item().map(String::as_str), Some("bundle"));
+    }
+
+    #[test]
+    fn test_display_order_follows_changes() {
+        let mut list = loaded(&["build", "lint", "bundle"]);
+        list.set_filter(Some("bl".to_string()));
+        assert_eq!(list.visible_len(), 2);
+        // A replaced item is matched against the filter again
+        list.replace_item(|name| name == "lint", "blint".to_string());
+        assert_eq!(list.sorted_items(), ["build", "blint", "bundle"]);
+        list.sort_by(|a, b| a.cmp(b));
+        assert_eq!(list.sorted_items(), ["blint", "build", "bundle"]);
+        list.set_loaded(vec!["deploy".to_string()], 1);
+        assert_eq!(list.visible_len(), 0);
+    }
+
+    #[test]
+    fn test_replace_item_keeps_selection() {
+        let mut list = loaded(&["build", "lint", "test"]);
+        list.select_next();
+        assert!(list.replace_item(|name| name == "lint", "lint (done)".to_string()));
+        assert!(!list.replace_item(|name| name == "deploy", "deploy".to_string()));
+        assert_eq!(list.sorted_items(), ["build", "lint (done)", "test"]);
+        assert_eq!(
+            list.selected_item().map(String::as_str),
+            Some("lint (done)")
+        );
+    }
+
+    #[test]
+    fn test_annotation_log_line() {
+        let logs = "setup\ncompiling\n##[error]expected `;`, found `}`\ndone";
+        let annotation = Annotation {
+            path: "src/main.rs".to_string(),
+            start_line: 3,
+            annotation_level: crate::github::AnnotationLevel::Failure,
+            message: "expected `;`, found `}`\nmore detail".to_string(),
+            title: None,
+        };
+        assert_eq!(annotation_log_line(logs, &annotation), Some(2));
+    }
+
+    #[test]
+    fn test_first_error_line() {
+        let logs = "2026-10-16T10:00:00.0000000Z Compiling jolt\n\
+                    2026-10-16T10:00:01.0000000Z error[E0308]: mismatched types\n\
+                    2026-10-16T10:00:02.0000000Z ##[error]Process completed with exit code 101.";
+        assert_eq!(first_error_line(logs), Some(1));
+        let logs = "Compiling jolt\n##[error]Process completed with exit code 1.";
+        assert_eq!(first_error_line(logs), Some(1));
+        assert_eq!(first_error_line("all good\nerrors: 0"), None);
+        assert_eq!(first_error_line("ERRORS: 0\nWARNINGS: 2"), None);
+        assert_eq!(first_error_line("ok\nERROR: disk full"), Some(1));
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_row() {
+        let mut list = loaded(&["alpha", "bravo", "charlie"]);
+        list.stale = true;
+        list.select_next();
+        list.select_next();
+
+        list.set_refreshed(vec!["alpha".to_string(), "bravo".to_string()], 2);
+        assert!(!list.stale);
+        assert_eq!(list.selected(), Some(1));
+        assert_eq!(list.selected_item().map(String::as_str), Some("bravo"));
+    }
+
+    #[test]
+    fn test_reload_keeps_selected_item_by_id() {
+        let names =
+            |names: &[&str]| -> Vec<String> { names.iter().map(|n| n.to_string()).collect() };
+        let mut list = SelectableList::with_id_of(|name: &String| text_id(name));
+        list.set_loaded(names(&["alpha", "bravo", "charlie", "delta"]), 4);
+        list.select_next();
+        list.select_next();
+        *list.list_state.offset_mut() = 1;
+
+        // A new item above the selected one shifts its row
+        list.set_loading();
+        list.set_loaded(names(&["zulu", "alpha", "bravo", "charlie", "delta"]), 5);
+        assert_eq!(list.selected(), Some(3));
+        assert_eq!(list.selected_item().map(String::as_str), Some("charlie"));
+        assert_eq!(list.list_state.offset(), 1);
+
+        // Cleared for a refresh, the list still reselects it
+        list.clear_for_reload();
+        list.set_loaded(names(&["charlie", "delta"]), 2);
+        assert_eq!(list.selected_item().map(String::as_str), Some("charlie"));
+
+        // Reset forgets it
+        list.reset();
+        list.set_loaded(names(&["alpha", "charlie"]), 2);
+        assert_eq!(list.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_sort_keeps_selected_item() {
+        let mut list = loaded(&["alpha", "bravo", "charlie"]);
+        list.sort_favorites_first(|_| false, |name| name.clone());
+        list.select_next();
+        assert_eq!(list.selected_item().map(String::as_str), Some("bravo"));
+
+        // Favoriting the selected item moves it to the top and selection follows
+        list.sort_favorites_first(|name| name == "bravo", |name| name.clone());
+        assert_eq!(list.selected(), Some(0));
+        assert_eq!(list.selected_item().map(String::as_str), Some("bravo"));
+    }
 }
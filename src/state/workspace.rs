@@ -0,0 +1,118 @@
+// Named workspace snapshots.
+// Saves the full investigation context so it can be restored days later.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::Tab;
+use crate::cache;
+
+use super::navigation::NavigationStack;
+use super::runners::RunnersNavStack;
+
+/// App state saved under a name: both tabs' navigation, the open log, filters, and favorites.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub name: String,
+    pub saved_at: DateTime<Utc>,
+    pub active_tab: Tab,
+    pub workflows_nav: NavigationStack,
+    pub runners_nav: RunnersNavStack,
+    /// Filter of the Workflows tab's current list.
+    #[serde(default)]
+    pub workflows_filter: Option<String>,
+    /// Filter of the Runners tab's current list.
+    #[serde(default)]
+    pub runners_filter: Option<String>,
+    /// Scroll offsets (x, y) of the log open in the Workflows tab.
+    #[serde(default)]
+    pub workflows_log_scroll: (u16, u16),
+    /// Scroll offsets (x, y) of the log open in the Runners tab.
+    #[serde(default)]
+    pub runners_log_scroll: (u16, u16),
+    /// Favorites, which make up the watchlist of the Live tab.
+    #[serde(default)]
+    pub favorite_owners: HashSet<String>,
+    #[serde(default)]
+    pub favorite_repos: HashSet<String>,
+    #[serde(default)]
+    pub favorite_workflows: HashSet<String>,
+    #[serde(default)]
+    pub favorite_runners: HashSet<String>,
+}
+
+/// Add a workspace, replacing any with the same name, and keep the most recent first.
+pub fn upsert_workspace(workspaces: &mut Vec<Workspace>, workspace: Workspace) {
+    workspaces.retain(|w| w.name != workspace.name);
+    workspaces.insert(0, workspace);
+}
+
+/// Load saved workspaces, most recently saved first.
+pub fn load_workspaces() -> Vec<Workspace> {
+    cache::workspaces_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save workspaces to disk.
+pub fn save_workspaces(workspaces: &[Workspace]) -> std::io::Result<()> {
+    let Some(path) = cache::workspaces_path() else {
+        return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(workspaces)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ViewLevel;
+
+    fn workspace(name: &str, repo: &str) -> Workspace {
+        let mut workflows_nav = NavigationStack::default();
+        workflows_nav.push(ViewLevel::Workflows {
+            owner: "o".to_string(),
+            repo: repo.to_string(),
+        });
+        Workspace {
+            name: name.to_string(),
+            saved_at: Utc::now(),
+            active_tab: Tab::Workflows,
+            workflows_nav,
+            runners_nav: RunnersNavStack::default(),
+            workflows_filter: Some("deploy".to_string()),
+            runners_filter: None,
+            workflows_log_scroll: (0, 0),
+            runners_log_scroll: (0, 0),
+            favorite_owners: HashSet::new(),
+            favorite_repos: HashSet::from(["o/r".to_string()]),
+            favorite_workflows: HashSet::new(),
+            favorite_runners: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_replaces_by_name() {
+        let mut workspaces = vec![workspace("flake", "a"), workspace("outage", "b")];
+        upsert_workspace(&mut workspaces, workspace("outage", "c"));
+        let names: Vec<&str> = workspaces.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, ["outage", "flake"]);
+        assert_eq!(
+            workspaces[0].workflows_nav.current(),
+            &ViewLevel::Workflows {
+                owner: "o".to_string(),
+                repo: "c".to_string(),
+            }
+        );
+
+        // Round-trips through JSON
+        let json = serde_json::to_string(&workspaces).unwrap();
+        let restored: Vec<Workspace> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored[1].workflows_filter.as_deref(), Some("deploy"));
+    }
+}
@@ -0,0 +1,256 @@
+// Background work of the TUI.
+// Requests that would hold up the event loop, such as revalidating a list,
+// run in spawned tasks on forked clients. Their results come back over a
+// channel and are applied to the state between frames.
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::github::GitHubClient;
+
+/// Kinds of background work. At most one task of each kind runs at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskKind {
+    /// Fetching fresh rows for a list shown from an expired cache entry.
+    Revalidate,
+    /// Fetching the jobs of the run previewed beside the Runs list.
+    Preview,
+    /// Fetching the attempts of a workflow's retried runs for the flaky jobs report.
+    FlakyReport,
+    /// Probing the health of runners installed on this machine.
+    HealthProbe,
+    /// Reading the cron triggers of the listed workflows.
+    Schedules,
+    /// Fetching the in-progress runs of favorite repositories for the Live tab.
+    Live,
+    /// Fetching the failing and running runs and offline runners of favorites
+    /// for the Home tab.
+    Home,
+    /// Fetching a page of branches for the branch picker.
+    Branches,
+    /// Fetching the latest run of each listed workflow for the Workflows list.
+    LatestRuns,
+    /// Fetching the jobs busy runners are working on.
+    RunnerJobs,
+    /// Refetching the jobs of the open Jobs list while some are in progress.
+    JobTick,
+    /// Fetching the default branch of a repository whose Runs list opened.
+    DefaultBranch,
+    /// Fetching the listed runners to sample which are busy.
+    RunnerSample,
+    /// Downloading a failed job's log into the cache ahead of it being opened.
+    LogPrefetch,
+}
+
+/// What a background task sent back.
+pub struct Done<M> {
+    pub kind: TaskKind,
+    /// Client the task made requests with, to absorb into the app's.
+    pub fork: Option<GitHubClient>,
+    /// A result of the task, if it sent one.
+    pub message: Option<M>,
+    /// Whether the task stopped without finishing, because it panicked.
+    pub panicked: bool,
+    /// Whether the task is over, rather than reporting progress.
+    finished: bool,
+}
+
+/// Sends results of a task back while it keeps running.
+pub struct Progress<M> {
+    kind: TaskKind,
+    sender: UnboundedSender<Done<M>>,
+}
+
+impl<M> Progress<M> {
+    pub fn send(&self, message: M) {
+        let _ = self.sender.send(Done {
+            kind: self.kind,
+            fork: None,
+            message: Some(message),
+            panicked: false,
+            finished: false,
+        });
+    }
+}
+
+/// Tells the app a task is over, with its final result, or that it panicked
+/// if it's dropped before then.
+struct Finish<M> {
+    kind: TaskKind,
+    sender: UnboundedSender<Done<M>>,
+    finished: bool,
+}
+
+impl<M> Finish<M> {
+    fn send(mut self, fork: Option<GitHubClient>, message: Option<M>) {
+        self.finished = true;
+        let _ = self.sender.send(Done {
+            kind: self.kind,
+            fork,
+            message,
+            panicked: false,
+            finished: true,
+        });
+    }
+}
+
+impl<M> Drop for Finish<M> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.sender.send(Done {
+                kind: self.kind,
+                fork: None,
+                message: None,
+                panicked: true,
+                finished: true,
+            });
+        }
+    }
+}
+
+/// Background tasks and the channel their results come back on.
+pub struct Tasks<M> {
+    sender: UnboundedSender<Done<M>>,
+    receiver: UnboundedReceiver<Done<M>>,
+    running: HashSet<TaskKind>,
+}
+
+impl<M> Default for Tasks<M> {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            sender,
+            receiver,
+            running: HashSet::new(),
+        }
+    }
+}
+
+impl<M: Send + 'static> Tasks<M> {
+    /// Whether a task of this kind is running.
+    pub fn is_running(&self, kind: TaskKind) -> bool {
+        self.running.contains(&kind)
+    }
+
+    /// Run `task` on a fork of `client`, unless a task of its kind is already
+    /// running. The task hands the fork back with its result. Returns whether
+    /// the task was started.
+    pub fn spawn<F, Fut>(&mut self, kind: TaskKind, client: &GitHubClient, task: F) -> bool
+    where
+        F: FnOnce(GitHubClient) -> Fut,
+        Fut: Future<Output = (GitHubClient, M)> + Send + 'static,
+    {
+        let Some(finish) = self.start(kind) else {
+            return false;
+        };
+        let future = task(client.fork().in_background());
+        tokio::spawn(async move {
+            let (fork, message) = future.await;
+            finish.send(Some(fork), Some(message));
+        });
+        true
+    }
+
+    /// Run `task` without a client, such as local probes, unless a task of
+    /// its kind is already running. The task sends its results as they come
+    /// in. Returns whether the task was started.
+    pub fn spawn_local<F, Fut>(&mut self, kind: TaskKind, task: F) -> bool
+    where
+        F: FnOnce(Progress<M>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let Some(finish) = self.start(kind) else {
+            return false;
+        };
+        let future = task(Progress {
+            kind,
+            sender: self.sender.clone(),
+        });
+        tokio::spawn(async move {
+            future.await;
+            finish.send(None, None);
+        });
+        true
+    }
+
+    fn start(&mut self, kind: TaskKind) -> Option<Finish<M>> {
+        if !self.running.insert(kind) {
+            return None;
+        }
+        Some(Finish {
+            kind,
+            sender: self.sender.clone(),
+            finished: false,
+        })
+    }
+
+    /// Next result sent back, if any, without waiting.
+    pub fn next(&mut self) -> Option<Done<M>> {
+        let done = self.receiver.try_recv().ok()?;
+        if done.finished {
+            self.running.remove(&done.kind);
+        }
+        Some(done)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HttpConfig;
+
+    /// Wait for the next result, for tests whose tasks are still running.
+    async fn recv(tasks: &mut Tasks<u32>) -> Done<u32> {
+        loop {
+            if let Some(done) = tasks.next() {
+                return done;
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_one_task_of_a_kind_at_a_time() {
+        let client = GitHubClient::anonymous(&HttpConfig::default()).unwrap();
+        let mut tasks = Tasks::default();
+        assert!(tasks.spawn(TaskKind::Revalidate, &client, |fork| async { (fork, 1) }));
+        assert!(!tasks.spawn(TaskKind::Revalidate, &client, |fork| async { (fork, 2) }));
+        assert!(tasks.is_running(TaskKind::Revalidate));
+
+        let done = recv(&mut tasks).await;
+        assert_eq!(done.message, Some(1));
+        assert!(done.fork.is_some());
+        assert!(!tasks.is_running(TaskKind::Revalidate));
+        assert!(tasks.spawn(TaskKind::Revalidate, &client, |fork| async { (fork, 3) }));
+    }
+
+    #[tokio::test]
+    async fn test_panicked_task_is_reported() {
+        let client = GitHubClient::anonymous(&HttpConfig::default()).unwrap();
+        let mut tasks: Tasks<u32> = Tasks::default();
+        tasks.spawn(TaskKind::Revalidate, &client, |_| async {
+            panic!("request failed")
+        });
+        let done = recv(&mut tasks).await;
+        assert!(done.panicked && done.message.is_none() && done.fork.is_none());
+        assert!(!tasks.is_running(TaskKind::Revalidate));
+    }
+
+    #[tokio::test]
+    async fn test_local_task_reports_progress() {
+        let mut tasks: Tasks<u32> = Tasks::default();
+        tasks.spawn_local(TaskKind::HealthProbe, |progress| async move {
+            progress.send(1);
+            progress.send(2);
+        });
+        assert_eq!(recv(&mut tasks).await.message, Some(1));
+        assert!(tasks.is_running(TaskKind::HealthProbe));
+        assert_eq!(recv(&mut tasks).await.message, Some(2));
+
+        let done = recv(&mut tasks).await;
+        assert!(done.message.is_none() && !done.panicked);
+        assert!(!tasks.is_running(TaskKind::HealthProbe));
+    }
+}
@@ -0,0 +1,153 @@
+// Terminal window title showing the state of the run being viewed.
+// Also publishes the title as a tmux user option for status lines.
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use crate::config::WindowTitle;
+use crate::github::{Job, RunConclusion, RunStatus};
+
+/// Title shown when no run is open.
+pub const IDLE_TITLE: &str = "jolt";
+
+/// tmux user option holding the title, for `#{@jolt_status}` in status lines.
+/// It's set on jolt's pane, so other panes and sessions running jolt each
+/// show their own run.
+const TMUX_OPTION: &str = "@jolt_status";
+
+/// Icon summarizing a run from its jobs: in progress until every job has
+/// finished, then failed if any job failed.
+fn run_icon(jobs: &[Job]) -> &'static str {
+    if jobs.iter().any(|job| job.status != RunStatus::Completed) {
+        return "⏳";
+    }
+    let failed = jobs.iter().any(|job| {
+        matches!(
+            job.conclusion,
+            Some(RunConclusion::Failure | RunConclusion::TimedOut | RunConclusion::StartupFailure)
+        )
+    });
+    let cancelled = jobs
+        .iter()
+        .any(|job| job.conclusion == Some(RunConclusion::Cancelled));
+    if failed {
+        "❌"
+    } else if cancelled {
+        "⚪"
+    } else {
+        "✅"
+    }
+}
+
+/// Title for a run (e.g., "jolt ⏳ build #123"). Until its jobs load the icon is left out.
+pub fn run_title(name: &str, run_number: u64, jobs: Option<&[Job]>) -> String {
+    match jobs {
+        Some(jobs) if !jobs.is_empty() => {
+            format!("{} {} {} #{}", IDLE_TITLE, run_icon(jobs), name, run_number)
+        }
+        _ => format!("{} {} #{}", IDLE_TITLE, name, run_number),
+    }
+}
+
+/// Save the terminal's title so it can be restored on exit.
+pub fn push() -> io::Result<()> {
+    write_sequence("\x1b[22;0t")
+}
+
+/// Restore the title saved by `push`, and clear the tmux option, even if
+/// the setting was turned off since it was set.
+pub fn pop() -> io::Result<()> {
+    if let Some(pane) = tmux_pane() {
+        let _ = tmux(&["set-option", "-pqu", "-t", &pane, TMUX_OPTION]);
+    }
+    write_sequence("\x1b[23;0t")
+}
+
+/// Show `title` as the window title, and in tmux when enabled.
+pub fn set(title: &str, mode: WindowTitle) -> io::Result<()> {
+    if mode == WindowTitle::Off {
+        return Ok(());
+    }
+    if let (WindowTitle::TitleAndTmux, Some(pane)) = (mode, tmux_pane()) {
+        tmux(&["set-option", "-pq", "-t", &pane, TMUX_OPTION, title])?;
+    }
+    write_sequence(&title_sequence(title))
+}
+
+/// OSC 2 escape sequence setting the window title, without control characters
+/// that would end it early.
+fn title_sequence(title: &str) -> String {
+    let title: String = title.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]2;{}\x07", title)
+}
+
+fn write_sequence(sequence: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    stdout.write_all(sequence.as_bytes())?;
+    stdout.flush()
+}
+
+/// The tmux pane jolt runs in, when inside tmux.
+fn tmux_pane() -> Option<String> {
+    std::env::var_os("TMUX")?;
+    std::env::var("TMUX_PANE").ok()
+}
+
+fn tmux(args: &[&str]) -> io::Result<()> {
+    Command::new("tmux")
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(status: RunStatus, conclusion: Option<RunConclusion>) -> Job {
+        Job {
+            id: 1,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: "build".to_string(),
+            status,
+            conclusion,
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_run_title() {
+        let done = job(RunStatus::Completed, Some(RunConclusion::Success));
+        let failed = job(RunStatus::Completed, Some(RunConclusion::Failure));
+        let running = job(RunStatus::InProgress, None);
+        assert_eq!(
+            run_title("build", 123, Some(&[done.clone(), running])),
+            "jolt ⏳ build #123"
+        );
+        assert_eq!(
+            run_title("build", 123, Some(std::slice::from_ref(&done))),
+            "jolt ✅ build #123"
+        );
+        assert_eq!(
+            run_title("build", 123, Some(&[done, failed])),
+            "jolt ❌ build #123"
+        );
+        assert_eq!(run_title("build", 123, None), "jolt build #123");
+    }
+
+    #[test]
+    fn test_title_sequence_strips_control_characters() {
+        assert_eq!(title_sequence("a\x07b\nc"), "\x1b]2;abc\x07");
+    }
+}
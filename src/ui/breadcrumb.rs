@@ -6,25 +6,55 @@ use ratatui::{prelude::*, widgets::*};
 use crate::state::navigation::BreadcrumbNode;
 use crate::state::runners::RunnersBreadcrumb;
 
-/// Render the breadcrumb trail.
-pub fn draw_breadcrumb(frame: &mut Frame, breadcrumbs: &[BreadcrumbNode], area: Rect) {
+/// Separator between breadcrumb segments.
+const SEPARATOR: &str = " > ";
+
+/// Placeholder for collapsed breadcrumb segments.
+const ELLIPSIS: &str = "…";
+
+/// Render the breadcrumb trail, with an optional badge on the right.
+pub fn draw_breadcrumb(
+    frame: &mut Frame,
+    breadcrumbs: &[BreadcrumbNode],
+    badge: Option<Span>,
+    area: Rect,
+) {
+    let labels: Vec<&str> = breadcrumbs.iter().map(|n| n.label.as_str()).collect();
+    draw_labels(frame, &labels, badge, area);
+}
+
+/// Render the breadcrumb trail for Runners tab.
+pub fn draw_runners_breadcrumb(frame: &mut Frame, breadcrumbs: &[RunnersBreadcrumb], area: Rect) {
+    let labels: Vec<&str> = breadcrumbs.iter().map(|n| n.label.as_str()).collect();
+    draw_labels(frame, &labels, None, area);
+}
+
+/// Render breadcrumb labels, truncated to fit beside the badge.
+fn draw_labels(frame: &mut Frame, labels: &[&str], badge: Option<Span>, area: Rect) {
+    let badge_width = badge.as_ref().map_or(0, |b| b.content.chars().count());
+    let segments = fit_labels(labels, (area.width as usize).saturating_sub(badge_width));
     let mut spans = Vec::new();
 
-    for (i, node) in breadcrumbs.iter().enumerate() {
+    for (i, segment) in segments.iter().enumerate() {
         if i > 0 {
-            spans.push(Span::styled(" > ", Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(
+                SEPARATOR,
+                Style::default().fg(Color::DarkGray),
+            ));
         }
 
-        let style = if i == breadcrumbs.len() - 1 {
+        let style = if i == segments.len() - 1 {
             // Current level is highlighted
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD)
+        } else if segment == ELLIPSIS {
+            Style::default().fg(Color::DarkGray)
         } else {
             Style::default().fg(Color::White)
         };
 
-        spans.push(Span::styled(node.label.clone(), style));
+        spans.push(Span::styled(segment.clone(), style));
     }
 
     let breadcrumb_line = Line::from(spans);
@@ -37,36 +67,170 @@ pub fn draw_breadcrumb(frame: &mut Frame, breadcrumbs: &[BreadcrumbNode], area:
         .style(Style::default());
 
     frame.render_widget(paragraph, area);
+
+    if let Some(badge) = badge {
+        let badge_area = Rect { height: 1, ..area };
+        frame.render_widget(
+            Paragraph::new(badge).alignment(Alignment::Right),
+            badge_area,
+        );
+    }
 }
 
-/// Render the breadcrumb trail for Runners tab.
-pub fn draw_runners_breadcrumb(frame: &mut Frame, breadcrumbs: &[RunnersBreadcrumb], area: Rect) {
-    let mut spans = Vec::new();
+/// Fit breadcrumb labels into `width` columns.
+///
+/// Keeps the first and last labels, collapsing middle labels into a single
+/// ellipsis segment (dropping those nearest the root first). If even that
+/// doesn't fit, the first and last labels are shortened themselves.
+fn fit_labels(labels: &[&str], width: usize) -> Vec<String> {
+    let all: Vec<String> = labels.iter().map(|l| l.to_string()).collect();
+    if labels.len() <= 1 || total_width(&all) <= width {
+        if let [only] = labels {
+            return vec![truncate(only, width)];
+        }
+        return all;
+    }
 
-    for (i, node) in breadcrumbs.iter().enumerate() {
-        if i > 0 {
-            spans.push(Span::styled(" > ", Style::default().fg(Color::DarkGray)));
+    let first = labels[0];
+    let last = labels[labels.len() - 1];
+    let middle = &labels[1..labels.len() - 1];
+
+    // Keep as many middle labels as fit, preferring those closest to the current level
+    for keep in (0..middle.len()).rev() {
+        let mut segments = vec![first.to_string(), ELLIPSIS.to_string()];
+        segments.extend(middle[middle.len() - keep..].iter().map(|l| l.to_string()));
+        segments.push(last.to_string());
+        if total_width(&segments) <= width {
+            return segments;
         }
+    }
+
+    // Shorten the ends: the current level gets whatever the root leaves over
+    let fixed = 2 * SEPARATOR.chars().count() + ELLIPSIS.chars().count();
+    let available = width.saturating_sub(fixed);
+    let first_width = first.chars().count().min(available / 3).max(1);
+    let last_width = available.saturating_sub(first_width);
+    vec![
+        truncate(first, first_width),
+        ELLIPSIS.to_string(),
+        truncate(last, last_width),
+    ]
+}
+
+/// Total display width of segments joined by separators.
+fn total_width(segments: &[String]) -> usize {
+    let labels: usize = segments.iter().map(|s| s.chars().count()).sum();
+    labels + SEPARATOR.chars().count() * segments.len().saturating_sub(1)
+}
+
+/// Truncate a label to `width` characters, ending with an ellipsis if shortened.
+fn truncate(label: &str, width: usize) -> String {
+    if label.chars().count() <= width {
+        return label.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut truncated: String = label.chars().take(width - 1).collect();
+    truncated.push_str(ELLIPSIS);
+    truncated
+}
 
-        let style = if i == breadcrumbs.len() - 1 {
+/// Draw a popup listing the full navigation path, one level per line.
+/// Levels are numbered for jumping back to them; `selected` is highlighted.
+pub fn draw_path_popup(frame: &mut Frame, labels: &[String], selected: usize) {
+    let area = frame.area();
+
+    let longest = labels
+        .iter()
+        .enumerate()
+        .map(|(depth, label)| depth * 2 + label.chars().count() + 3)
+        .max()
+        .unwrap_or(0);
+    let popup_width = (longest as u16 + 8).clamp(30, area.width);
+    let popup_height = (labels.len() as u16 + 4).min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![Line::from("")];
+    for (depth, label) in labels.iter().enumerate() {
+        let prefix = if depth == 0 {
+            " ".to_string()
+        } else {
+            format!(" {}└ ", "  ".repeat(depth - 1))
+        };
+        let mut style = if depth == labels.len() - 1 {
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default().fg(Color::White)
         };
+        if depth == selected {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        let number = if depth < 9 {
+            format!("{} ", depth + 1)
+        } else {
+            "  ".to_string()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(number, Style::default().fg(Color::Yellow)),
+            Span::styled(prefix, Style::default().fg(Color::DarkGray)),
+            Span::styled(label.clone(), style),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Path · 1-9 to jump ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: [&str; 6] = ["Owners", "phatblat", "jolt", "CI", "#42", "build (ubuntu)"];
 
-        spans.push(Span::styled(node.label.clone(), style));
+    #[test]
+    fn test_fits_without_truncation() {
+        let segments = fit_labels(&PATH, 200);
+        assert_eq!(segments, PATH);
     }
 
-    let breadcrumb_line = Line::from(spans);
-    let block = Block::default()
-        .borders(Borders::BOTTOM)
-        .border_style(Style::default().fg(Color::DarkGray));
+    #[test]
+    fn test_collapses_middle_nodes() {
+        // Full path is 52 columns; dropping "phatblat" and "jolt" leaves room for the rest
+        let segments = fit_labels(&PATH, 40);
+        assert_eq!(segments, ["Owners", "…", "CI", "#42", "build (ubuntu)"]);
+        assert!(total_width(&segments) <= 40);
+    }
 
-    let paragraph = Paragraph::new(breadcrumb_line)
-        .block(block)
-        .style(Style::default());
+    #[test]
+    fn test_truncates_ends_when_narrow() {
+        let segments = fit_labels(&PATH, 20);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[1], ELLIPSIS);
+        assert!(segments[2].starts_with("build"));
+        assert!(total_width(&segments) <= 20);
+    }
 
-    frame.render_widget(paragraph, area);
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("abcdef", 10), "abcdef");
+        assert_eq!(truncate("abcdef", 4), "abc…");
+    }
 }
@@ -0,0 +1,411 @@
+// Terminal capability detection and style degradation.
+// Rewrites drawn styles the terminal can't show, so minimal terminals stay legible.
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Color, Modifier};
+use ratatui::text::Span;
+
+/// Plain replacements of the emoji and status glyphs the UI draws. Each is cut
+/// or padded to the width the glyph took, so layouts don't shift.
+const ASCII_GLYPHS: &[(&str, &str)] = &[
+    ("✅", "ok"),
+    ("❌", "x"),
+    ("✖", "x"),
+    ("✗", "x"),
+    ("✓", "v"),
+    ("🔄", "~"),
+    ("⏳", "~"),
+    ("⏱", "t"),
+    ("⏰", "t"),
+    ("⏭", "-"),
+    ("⚪", "o"),
+    ("⚫", "*"),
+    ("🟢", "o"),
+    ("●", "*"),
+    ("○", "o"),
+    ("◌", "o"),
+    ("⭐", "*"),
+    ("⚠", "!"),
+    ("ℹ", "i"),
+    ("❓", "?"),
+    ("🔒", "#"),
+    ("🌐", "@"),
+    ("🌿", "b"),
+    ("🎲", "?"),
+    ("🏢", "O"),
+    ("👤", "U"),
+    ("👥", "U"),
+    ("🐞", "D"),
+    ("📄", "="),
+    ("📝", "="),
+    ("🤖", "R"),
+    ("🚦", "!"),
+    ("☐", "-"),
+    ("☑", "+"),
+];
+
+/// Colors a terminal can display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// No colors (e.g., NO_COLOR set or TERM=dumb).
+    None,
+    /// The 8 basic ANSI colors.
+    Basic,
+    /// The 8 basic colors and their bright variants.
+    Bright,
+    /// The 256-color palette.
+    Palette,
+    /// 24-bit RGB.
+    TrueColor,
+}
+
+/// Styling a terminal supports, detected at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TermCaps {
+    pub colors: ColorSupport,
+    pub italic: bool,
+    pub dim: bool,
+    /// Draw emoji and status glyphs as plain characters (from the config or
+    /// --ascii; never detected).
+    pub ascii: bool,
+}
+
+/// Log text drawn on a frame, whose glyphs are kept as the log has them.
+#[derive(Debug, Default)]
+pub struct LogText(Vec<(Position, Cell)>);
+
+impl LogText {
+    /// Remember the log text drawn in `area` of `buffer`.
+    pub fn capture(&mut self, buffer: &Buffer, area: Rect) {
+        let area = area.intersection(buffer.area);
+        self.0.extend(
+            area.positions()
+                .map(|position| (position, buffer[position].clone())),
+        );
+    }
+}
+
+impl Default for TermCaps {
+    fn default() -> Self {
+        Self {
+            colors: ColorSupport::TrueColor,
+            italic: true,
+            dim: true,
+            ascii: false,
+        }
+    }
+}
+
+impl TermCaps {
+    /// Detect capabilities from the environment.
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    /// Detect capabilities from environment variables looked up by `var`.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let term = var("TERM").unwrap_or_default();
+        let colorterm = var("COLORTERM").unwrap_or_default();
+        let no_color = var("NO_COLOR").is_some_and(|v| !v.is_empty());
+        // Windows consoles and some launchers don't set TERM at all
+        if term.is_empty() {
+            return Self {
+                colors: if no_color {
+                    ColorSupport::None
+                } else {
+                    ColorSupport::TrueColor
+                },
+                ..Self::default()
+            };
+        }
+
+        let colors = if no_color || term == "dumb" {
+            ColorSupport::None
+        } else if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") {
+            ColorSupport::TrueColor
+        } else if term.contains("256color") {
+            ColorSupport::Palette
+        } else if term == "linux" || term.starts_with("vt") || term.starts_with("ansi") {
+            ColorSupport::Basic
+        } else {
+            ColorSupport::Bright
+        };
+        // Consoles and old emulators show italics as reverse video or not at all
+        let minimal =
+            term == "dumb" || term == "linux" || term.starts_with("vt") || term.starts_with("ansi");
+        Self {
+            colors,
+            italic: !minimal && !term.starts_with("screen"),
+            dim: !minimal,
+            ascii: false,
+        }
+    }
+
+    /// Whether every style and glyph can be drawn as is.
+    pub fn is_full(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Rewrite the styles and glyphs of drawn cells the terminal can't show.
+    /// Glyphs of `log_text` are left alone where nothing was drawn over it.
+    pub fn degrade(&self, buffer: &mut Buffer, log_text: &LogText) {
+        if self.is_full() {
+            return;
+        }
+        if self.ascii {
+            let kept: Vec<&(Position, Cell)> = log_text
+                .0
+                .iter()
+                .filter(|(position, cell)| !cell.symbol().is_ascii() && buffer[*position] == *cell)
+                .collect();
+            replace_glyphs(buffer);
+            for (position, cell) in kept {
+                buffer[*position].set_symbol(cell.symbol());
+                // Blank the cells a wide glyph covers again
+                let width = Span::raw(cell.symbol()).width() as u16;
+                for x in position.x + 1..position.x + width {
+                    if let Some(covered) = buffer.cell_mut((x, position.y)) {
+                        covered.set_symbol(" ");
+                    }
+                }
+            }
+        }
+        for cell in buffer.content.iter_mut() {
+            let mut modifier = cell.modifier;
+            if !self.italic && modifier.contains(Modifier::ITALIC) {
+                modifier = (modifier - Modifier::ITALIC) | Modifier::BOLD;
+            }
+            if !self.dim {
+                modifier -= Modifier::DIM;
+            }
+            if self.colors == ColorSupport::None {
+                // Highlighted rows keep standing out through reverse video
+                if cell.bg != Color::Reset {
+                    modifier |= Modifier::REVERSED;
+                }
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            } else {
+                cell.fg = self.color(cell.fg);
+                let bg = self.color(cell.bg);
+                if bg == Color::Reset && cell.bg != Color::Reset {
+                    modifier |= Modifier::REVERSED;
+                }
+                cell.bg = bg;
+            }
+            cell.modifier = modifier;
+        }
+    }
+
+    /// Nearest color the terminal supports.
+    fn color(&self, color: Color) -> Color {
+        let color = match color {
+            Color::Rgb(r, g, b) if self.colors < ColorSupport::TrueColor => nearest_ansi(r, g, b),
+            Color::Indexed(i) if self.colors < ColorSupport::Palette => indexed_to_ansi(i),
+            color => color,
+        };
+        if self.colors >= ColorSupport::Bright {
+            return color;
+        }
+        match color {
+            // Bright black is often invisible on 8-color terminals
+            Color::DarkGray => Color::Reset,
+            Color::Gray => Color::White,
+            Color::LightRed => Color::Red,
+            Color::LightGreen => Color::Green,
+            Color::LightYellow => Color::Yellow,
+            Color::LightBlue => Color::Blue,
+            Color::LightMagenta => Color::Magenta,
+            Color::LightCyan => Color::Cyan,
+            color => color,
+        }
+    }
+}
+
+/// Replace known glyphs in drawn cells with plain characters. Other text,
+/// including non-Latin scripts in logs, is left alone.
+fn replace_glyphs(buffer: &mut Buffer) {
+    let row_width = buffer.area.width as usize;
+    let cells = &mut buffer.content;
+    let mut i = 0;
+    while i < cells.len() {
+        let symbol = cells[i].symbol();
+        if symbol.is_ascii() {
+            i += 1;
+            continue;
+        }
+        // A wide glyph is followed by blank cells it covers
+        let width = Span::raw(symbol).width().max(1);
+        let end = (i + width).min(i - i % row_width + row_width);
+        let Some(plain) = ascii_glyph(symbol) else {
+            i = end;
+            continue;
+        };
+        let mut chars = plain.chars();
+        for cell in &mut cells[i..end] {
+            cell.set_char(chars.next().unwrap_or(' '));
+        }
+        i = end;
+    }
+}
+
+/// Plain replacement of a glyph, ignoring emoji presentation selectors.
+fn ascii_glyph(symbol: &str) -> Option<&'static str> {
+    let base = symbol.trim_end_matches('\u{fe0f}');
+    ASCII_GLYPHS
+        .iter()
+        .find(|(glyph, _)| *glyph == base)
+        .map(|(_, plain)| *plain)
+}
+
+/// Nearest of the 16 ANSI colors to an RGB color.
+fn nearest_ansi(r: u8, g: u8, b: u8) -> Color {
+    const ANSI: [(Color, (u8, u8, u8)); 16] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+        (Color::DarkGray, (127, 127, 127)),
+        (Color::LightRed, (255, 0, 0)),
+        (Color::LightGreen, (0, 255, 0)),
+        (Color::LightYellow, (255, 255, 0)),
+        (Color::LightBlue, (92, 92, 255)),
+        (Color::LightMagenta, (255, 0, 255)),
+        (Color::LightCyan, (0, 255, 255)),
+        (Color::White, (255, 255, 255)),
+    ];
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, cr) + d(g, cg) + d(b, cb)
+    };
+    ANSI.iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// ANSI color nearest to an entry of the 256-color palette.
+fn indexed_to_ansi(index: u8) -> Color {
+    match index {
+        0..=15 => nearest_ansi_index(index),
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            nearest_ansi(level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            nearest_ansi(gray, gray, gray)
+        }
+    }
+}
+
+/// ANSI color of one of the first 16 palette entries.
+fn nearest_ansi_index(index: u8) -> Color {
+    [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ][index as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Style;
+
+    fn caps(term: &str, colorterm: Option<&str>) -> TermCaps {
+        TermCaps::from_env(|name| match name {
+            "TERM" => Some(term.to_string()),
+            "COLORTERM" => colorterm.map(String::from),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            caps("xterm-256color", Some("truecolor")),
+            TermCaps::default()
+        );
+        assert_eq!(caps("xterm-256color", None).colors, ColorSupport::Palette);
+        let console = caps("linux", None);
+        assert_eq!(console.colors, ColorSupport::Basic);
+        assert!(!console.italic);
+        assert_eq!(caps("dumb", None).colors, ColorSupport::None);
+        // An unset TERM, as on Windows consoles, isn't taken for a dumb terminal
+        assert_eq!(caps("", None), TermCaps::default());
+    }
+
+    #[test]
+    fn test_degrade_for_console() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer.set_style(
+            Rect::new(0, 0, 1, 1),
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::ITALIC),
+        );
+        buffer.set_style(Rect::new(1, 0, 1, 1), Style::default().bg(Color::DarkGray));
+        caps("linux", None).degrade(&mut buffer, &LogText::default());
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Cyan);
+        assert_eq!(buffer[(0, 0)].modifier, Modifier::BOLD);
+        assert_eq!(buffer[(1, 0)].bg, Color::Reset);
+        assert_eq!(buffer[(1, 0)].modifier, Modifier::REVERSED);
+    }
+
+    #[test]
+    fn test_degrade_glyphs_to_ascii() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        buffer.set_string(0, 0, "✅ ⏭️ ●日本", Style::default());
+        let caps = TermCaps {
+            ascii: true,
+            ..TermCaps::default()
+        };
+        caps.degrade(&mut buffer, &LogText::default());
+
+        let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(text, "ok -  *日 本  ");
+    }
+
+    #[test]
+    fn test_log_text_keeps_its_glyphs() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(0, 0, "● ✅", Style::default());
+        buffer.set_string(0, 1, "● ✅", Style::default());
+        let mut log_text = LogText::default();
+        log_text.capture(&buffer, Rect::new(2, 0, 4, 2));
+        // An overlay drawn over the log is chrome again
+        buffer.set_string(2, 1, "✅", Style::default().fg(Color::Red));
+        buffer.set_string(4, 1, "⚠", Style::default());
+        let caps = TermCaps {
+            ascii: true,
+            ..TermCaps::default()
+        };
+        caps.degrade(&mut buffer, &log_text);
+
+        let text: Vec<String> = (0..2)
+            .map(|y| (0..6).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(text, ["* ✅   ", "* ok! "]);
+    }
+}
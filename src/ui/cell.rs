@@ -0,0 +1,52 @@
+// Display-width-aware list cells.
+// Pads text by the columns it takes on screen rather than its length in chars,
+// so columns after emoji icons line up however wide each glyph is.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Columns an icon cell reserves for its glyph, the width of an emoji.
+pub const ICON_WIDTH: usize = 2;
+
+/// Columns `text` takes on screen.
+pub fn width(text: &str) -> usize {
+    text.width()
+}
+
+/// `text` padded with spaces, or cut, to exactly `columns` columns.
+pub fn pad(text: &str, columns: usize) -> String {
+    let mut cell = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let c_width = c.width().unwrap_or(0);
+        if used + c_width > columns {
+            break;
+        }
+        cell.push(c);
+        used += c_width;
+    }
+    cell.extend(std::iter::repeat_n(' ', columns - used));
+    cell
+}
+
+/// An icon and the space after it, always `ICON_WIDTH + 1` columns. Emoji
+/// presentation selectors are dropped: terminals disagree on whether they
+/// widen a narrow symbol (e.g., "⏭️"), but not on the symbol alone.
+pub fn icon(glyph: &str) -> String {
+    let glyph = glyph.replace('\u{fe0f}', "");
+    pad(&glyph, ICON_WIDTH + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_pad_by_display_width() {
+        assert_eq!(pad("ab", 4), "ab  ");
+        assert_eq!(pad("日本語", 5), "日本 ");
+        assert_eq!(pad("✅", 1), " ");
+        for glyph in ["✅", "⏭️", "⚠", "ok", "x", ""] {
+            assert_eq!(width(&icon(glyph)), ICON_WIDTH + 1, "{:?}", glyph);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// Log highlights from config-defined rules.
+// Compiles each rule's regular expression once and styles matching log text.
+
+use std::str::FromStr;
+
+use ratatui::prelude::*;
+use regex::Regex;
+
+use crate::config::HighlightRule;
+
+#[derive(Debug)]
+struct CompiledRule {
+    regex: Regex,
+    style: Style,
+    line: bool,
+}
+
+/// Compiled highlight rules for the log viewer.
+#[derive(Debug, Default)]
+pub struct Highlighter {
+    rules: Vec<CompiledRule>,
+}
+
+impl Highlighter {
+    /// Compile `rules`, skipping invalid ones. Returns a message for each rule skipped.
+    pub fn new(rules: &[HighlightRule]) -> (Self, Vec<String>) {
+        let mut compiled = Vec::new();
+        let mut errors = Vec::new();
+        for rule in rules {
+            match compile(rule) {
+                Ok(rule) => compiled.push(rule),
+                Err(e) => errors.push(format!("Log highlight '{}': {}", rule.pattern, e)),
+            }
+        }
+        (Self { rules: compiled }, errors)
+    }
+
+    /// Spans for a line of log text. `base` (e.g., the search highlight) takes
+    /// precedence over rule styles.
+    pub fn spans<'a>(&self, text: &'a str, base: Style) -> Vec<Span<'a>> {
+        if let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| rule.line && rule.regex.is_match(text))
+        {
+            return vec![Span::styled(text, rule.style.patch(base))];
+        }
+
+        // Matched ranges, where earlier rules win over later overlapping ones
+        let mut ranges: Vec<(usize, usize, Style)> = Vec::new();
+        for rule in self.rules.iter().filter(|rule| !rule.line) {
+            for m in rule.regex.find_iter(text).filter(|m| !m.is_empty()) {
+                let overlaps = ranges
+                    .iter()
+                    .any(|(start, end, _)| m.start() < *end && *start < m.end());
+                if !overlaps {
+                    ranges.push((m.start(), m.end(), rule.style));
+                }
+            }
+        }
+        ranges.sort_by_key(|(start, _, _)| *start);
+
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        for (start, end, style) in ranges {
+            if pos < start {
+                spans.push(Span::styled(&text[pos..start], base));
+            }
+            spans.push(Span::styled(&text[start..end], style.patch(base)));
+            pos = end;
+        }
+        if pos < text.len() || spans.is_empty() {
+            spans.push(Span::styled(&text[pos..], base));
+        }
+        spans
+    }
+}
+
+fn compile(rule: &HighlightRule) -> Result<CompiledRule, String> {
+    let regex = Regex::new(&rule.pattern).map_err(|e| e.to_string())?;
+    let mut style = Style::default();
+    if let Some(fg) = &rule.fg {
+        style = style.fg(parse_color(fg)?);
+    }
+    if let Some(bg) = &rule.bg {
+        style = style.bg(parse_color(bg)?);
+    }
+    if rule.bold {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    Ok(CompiledRule {
+        regex,
+        style,
+        line: rule.line,
+    })
+}
+
+fn parse_color(value: &str) -> Result<Color, String> {
+    Color::from_str(value).map_err(|_| format!("unknown color '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, fg: &str, line: bool) -> HighlightRule {
+        HighlightRule {
+            pattern: pattern.to_string(),
+            fg: Some(fg.to_string()),
+            line,
+            ..HighlightRule::default()
+        }
+    }
+
+    fn highlighter(rules: &[HighlightRule]) -> Highlighter {
+        let (highlighter, errors) = Highlighter::new(rules);
+        assert!(errors.is_empty(), "{:?}", errors);
+        highlighter
+    }
+
+    #[test]
+    fn test_match_rules_split_text() {
+        let highlighter = highlighter(&[
+            rule(r"\d+\.\d+s", "cyan", false),
+            rule(r"test \S+", "green", false),
+        ]);
+        let spans = highlighter.spans("test ui::list ... ok 0.52s", Style::default());
+        let parts: Vec<(&str, Option<Color>)> = spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style.fg))
+            .collect();
+        assert_eq!(
+            parts,
+            [
+                ("test ui::list", Some(Color::Green)),
+                (" ... ok ", None),
+                ("0.52s", Some(Color::Cyan)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_rule_and_search_precedence() {
+        let highlighter = highlighter(&[rule("^WARN", "yellow", true)]);
+        let spans = highlighter.spans("WARN disk almost full", Style::default());
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].style.fg, Some(Color::Yellow));
+
+        let search = Style::default().bg(Color::Yellow).fg(Color::Black);
+        let spans = highlighter.spans("WARN disk almost full", search);
+        assert_eq!(spans[0].style.fg, Some(Color::Black));
+
+        let spans = highlighter.spans("all good", Style::default());
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].style, Style::default());
+    }
+
+    #[test]
+    fn test_invalid_rules_are_reported() {
+        let (highlighter, errors) = Highlighter::new(&[
+            rule("(unclosed", "red", false),
+            rule("ok", "not-a-color", false),
+            rule("ERROR", "#ff8800", false),
+        ]);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(highlighter.rules.len(), 1);
+        assert_eq!(highlighter.rules[0].style.fg, Some(Color::Rgb(255, 136, 0)));
+    }
+}
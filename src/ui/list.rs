@@ -1,16 +1,38 @@
 // Generic list rendering for selectable items.
 // Provides styled list views with loading and empty states.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
 use ratatui::{prelude::*, widgets::*};
 
+use crate::config::Theme;
+use crate::diag::RunnerHealth;
+use crate::error::ViewError;
 use crate::github::{
-    Job, Owner, OwnerType, Repository, RunConclusion, RunStatus, Runner, RunnerStatus, Workflow,
-    WorkflowRun,
+    ActionsCache, Annotation, AnnotationLevel, Branch, CheckRun, CheckState, Deployment,
+    DeploymentState, Environment, InboxPullRequest, InboxReason, Job, Owner, OwnerType, Repository,
+    RunConclusion, RunStatus, Runner, RunnerGroup, RunnerStatus, Workflow, WorkflowRun,
 };
-use crate::state::{LoadingState, SelectableList};
+use crate::state::caches::format_size;
+use crate::state::checks::{check_app_name, is_actions_check};
+use crate::state::churn::LabelChurn;
+use crate::state::filter::fuzzy_match;
+use crate::state::history::format_eta;
+use crate::state::home::{HomeItem, HomeSection};
+use crate::state::job_matrix::{MatrixGroup, base_name, matrix_groups, matrix_parts};
+use crate::state::live::LiveRun;
+use crate::state::log_archive::ArchiveFile;
+use crate::state::queue::{LabelQueueTimes, format_queue_time, queue_time};
+use crate::state::run_groups::{event_counts, event_label};
+use crate::state::runners::{RunnerJob, group_access};
+use crate::state::schedule::{WorkflowSchedule, format_until};
+use crate::state::secrets::ConfigEntry;
+use crate::state::test_report::{TestFailure, TestReport};
+use crate::state::utilization::RunnerUtilization;
+use crate::state::{JobHistory, LoadingState, RunnerRow, SelectableList};
+
+use super::cell;
 
 /// Format a timestamp as relative time (e.g., "2h ago").
 pub fn format_relative_time(dt: &DateTime<Utc>) -> String {
@@ -40,6 +62,49 @@ fn status_color(status: &RunStatus) -> Color {
     }
 }
 
+/// Checkbox for bulk selection, shown only once something in the list is marked.
+fn mark_span(marked: &HashSet<u64>, id: u64) -> Span<'static> {
+    if marked.is_empty() {
+        Span::raw("")
+    } else if marked.contains(&id) {
+        Span::styled("☑ ", Style::default().fg(Color::Cyan))
+    } else {
+        Span::raw("☐ ")
+    }
+}
+
+/// Favorite star, left blank on other rows so their columns line up, and left
+/// out when nothing in the list is a favorite.
+fn star_cell(is_fav: bool, any_fav: bool) -> String {
+    match (any_fav, is_fav) {
+        (false, _) => String::new(),
+        (true, true) => cell::icon("⭐"),
+        (true, false) => cell::icon(""),
+    }
+}
+
+/// Spans for `text` with the characters matching the list filter emphasized.
+fn filter_spans(text: &str, filter: Option<&str>, style: Style) -> Vec<Span<'static>> {
+    let positions = filter
+        .filter(|query| !query.trim().is_empty())
+        .and_then(|query| fuzzy_match(query, text));
+    let Some(positions) = positions else {
+        return vec![Span::styled(text.to_string(), style)];
+    };
+    let matched = style.fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    text.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let style = if positions.contains(&i) {
+                matched
+            } else {
+                style
+            };
+            Span::styled(c.to_string(), style)
+        })
+        .collect()
+}
+
 /// Get color for run conclusion.
 fn conclusion_color(conclusion: &Option<RunConclusion>) -> Color {
     match conclusion {
@@ -57,6 +122,19 @@ fn conclusion_color(conclusion: &Option<RunConclusion>) -> Color {
     }
 }
 
+/// Style for the selected row of a list.
+pub fn highlight_style(theme: Theme) -> Style {
+    match theme {
+        Theme::Dark => Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+        Theme::Light => Style::default()
+            .bg(Color::Gray)
+            .fg(Color::Black)
+            .add_modifier(Modifier::BOLD),
+    }
+}
+
 /// Render a loading indicator.
 pub fn render_loading(frame: &mut Frame, area: Rect, message: &str) {
     let text = Paragraph::new(format!("⏳ {}...", message))
@@ -66,10 +144,21 @@ pub fn render_loading(frame: &mut Frame, area: Rect, message: &str) {
 }
 
 /// Render an error message.
-pub fn render_error(frame: &mut Frame, area: Rect, error: &str) {
-    let text = Paragraph::new(format!("❌ {}", error))
+pub fn render_error(frame: &mut Frame, area: Rect, error: &ViewError) {
+    let mut lines = vec![Line::from(Span::styled(
+        format!("❌ {}", error),
+        Style::default().fg(Color::Red),
+    ))];
+    if let Some(hint) = error.hint(Utc::now()) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            hint,
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    let text = Paragraph::new(lines)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Red));
+        .wrap(Wrap { trim: true });
     frame.render_widget(text, area);
 }
 
@@ -86,6 +175,7 @@ pub fn render_owners_list(
     frame: &mut Frame,
     list: &mut SelectableList<Owner>,
     favorites: &HashSet<String>,
+    theme: Theme,
     area: Rect,
 ) {
     match &list.data {
@@ -96,40 +186,34 @@ pub fn render_owners_list(
             if data.is_empty() {
                 render_empty(frame, area, "No accessible owners found");
             } else {
-                // Sort: favorites first, then alphabetically
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_fav = favorites.contains(&a.login);
-                    let b_fav = favorites.contains(&b.login);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.login.cmp(&b.login),
-                    }
-                });
-
-                let items: Vec<ListItem> = sorted
+                let any_fav = data
+                    .items
                     .iter()
+                    .any(|owner| favorites.contains(&owner.login));
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
                     .map(|owner| {
-                        let is_fav = favorites.contains(&owner.login);
-                        let star = if is_fav { "⭐ " } else { "" };
+                        let star = star_cell(favorites.contains(&owner.login), any_fav);
                         let type_indicator = match owner.owner_type {
                             OwnerType::User => "👤",
                             OwnerType::Organization => "🏢",
                             OwnerType::Bot => "🤖",
                             OwnerType::Unknown => "❓",
                         };
-                        ListItem::new(format!("{}{} {}", star, type_indicator, owner.login))
+                        let mut spans = vec![Span::raw(star + &cell::icon(type_indicator))];
+                        spans.extend(filter_spans(
+                            &owner.login,
+                            list.filter.as_deref(),
+                            Style::default(),
+                        ));
+                        ListItem::new(Line::from(spans))
                     })
                     .collect();
 
                 let list_widget = List::new(items)
                     .block(Block::default().borders(Borders::ALL).title(" Owners "))
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
-                    )
+                    .highlight_style(highlight_style(theme))
                     .highlight_symbol("> ");
 
                 frame.render_stateful_widget(list_widget, area, &mut list.list_state);
@@ -144,6 +228,7 @@ pub fn render_repositories_list(
     list: &mut SelectableList<Repository>,
     favorites: &HashSet<String>,
     owner: &str,
+    theme: Theme,
     area: Rect,
 ) {
     match &list.data {
@@ -154,36 +239,29 @@ pub fn render_repositories_list(
             if data.is_empty() {
                 render_empty(frame, area, "No repositories found");
             } else {
-                // Sort: favorites first, then by name
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", owner, a.name);
-                    let b_key = format!("{}/{}", owner, b.name);
-                    let a_fav = favorites.contains(&a_key);
-                    let b_fav = favorites.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-
-                let items: Vec<ListItem> = sorted
+                let any_fav = data
+                    .items
                     .iter()
+                    .any(|repo| favorites.contains(&format!("{}/{}", owner, repo.name)));
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
                     .map(|repo| {
                         let key = format!("{}/{}", owner, repo.name);
-                        let is_fav = favorites.contains(&key);
-                        let star = if is_fav { "⭐ " } else { "" };
+                        let star = star_cell(favorites.contains(&key), any_fav);
                         let visibility = if repo.private { "🔒" } else { "🌐" };
                         let updated = format_relative_time(&repo.updated_at);
-                        ListItem::new(Line::from(vec![
-                            Span::raw(format!("{}{} ", star, visibility)),
-                            Span::styled(&repo.name, Style::default().fg(Color::Cyan)),
-                            Span::styled(
-                                format!("  {}", updated),
-                                Style::default().fg(Color::DarkGray),
-                            ),
-                        ]))
+                        let mut spans = vec![Span::raw(star + &cell::icon(visibility))];
+                        spans.extend(filter_spans(
+                            &repo.name,
+                            list.filter.as_deref(),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                        spans.push(Span::styled(
+                            format!("  {}", updated),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
                     })
                     .collect();
 
@@ -193,11 +271,7 @@ pub fn render_repositories_list(
                             .borders(Borders::ALL)
                             .title(" Repositories "),
                     )
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
-                    )
+                    .highlight_style(highlight_style(theme))
                     .highlight_symbol("> ");
 
                 frame.render_stateful_widget(list_widget, area, &mut list.list_state);
@@ -211,6 +285,8 @@ pub fn render_runner_repositories_list(
     frame: &mut Frame,
     list: &mut SelectableList<Repository>,
     favorites: &HashSet<String>,
+    hidden: usize,
+    theme: Theme,
     area: Rect,
 ) {
     match &list.data {
@@ -221,53 +297,40 @@ pub fn render_runner_repositories_list(
             if data.is_empty() {
                 render_empty(frame, area, "No repositories found");
             } else {
-                // Sort: favorites first, then by name
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}", a.owner.login, a.name);
-                    let b_key = format!("{}/{}", b.owner.login, b.name);
-                    let a_fav = favorites.contains(&a_key);
-                    let b_fav = favorites.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a_key.cmp(&b_key),
-                    }
-                });
-
-                let items: Vec<ListItem> = sorted
+                let any_fav = data
+                    .items
                     .iter()
+                    .any(|repo| favorites.contains(&format!("{}/{}", repo.owner.login, repo.name)));
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
                     .map(|repo| {
                         let key = format!("{}/{}", repo.owner.login, repo.name);
-                        let is_fav = favorites.contains(&key);
-                        let star = if is_fav { "⭐ " } else { "" };
+                        let star = star_cell(favorites.contains(&key), any_fav);
                         let visibility = if repo.private { "🔒" } else { "🌐" };
                         let updated = format_relative_time(&repo.updated_at);
-                        ListItem::new(Line::from(vec![
-                            Span::raw(format!("{}{} ", star, visibility)),
-                            Span::styled(
-                                format!("{}/{}", repo.owner.login, repo.name),
-                                Style::default().fg(Color::Cyan),
-                            ),
-                            Span::styled(
-                                format!("  {}", updated),
-                                Style::default().fg(Color::DarkGray),
-                            ),
-                        ]))
+                        let mut spans = vec![Span::raw(star + &cell::icon(visibility))];
+                        spans.extend(filter_spans(
+                            &format!("{}/{}", repo.owner.login, repo.name),
+                            list.filter.as_deref(),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                        spans.push(Span::styled(
+                            format!("  {}", updated),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
                     })
                     .collect();
 
+                let title = if hidden > 0 {
+                    format!(" Repositories · {} hidden (X shows) ", hidden)
+                } else {
+                    " Repositories ".to_string()
+                };
                 let list_widget = List::new(items)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(" Repositories "),
-                    )
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
-                    )
+                    .block(Block::default().borders(Borders::ALL).title(title))
+                    .highlight_style(highlight_style(theme))
                     .highlight_symbol("> ");
 
                 frame.render_stateful_widget(list_widget, area, &mut list.list_state);
@@ -276,13 +339,47 @@ pub fn render_runner_repositories_list(
     }
 }
 
+/// Explain that listing a repository's runners needs admin access, in place of the list.
+pub fn render_runner_access_needed(frame: &mut Frame, repo_path: &str, area: Rect) {
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("🔒 The token can't list the runners of {}", repo_path),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+        Line::from("Self-hosted runners are only visible to repository admins."),
+        Line::from(
+            "Classic tokens need the repo scope, and fine-grained tokens the Administration (read) permission.",
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press x to hide this repository from the Runners tab, or Esc to go back",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let text = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(" Runners "));
+    frame.render_widget(text, area);
+}
+
+/// What is known about each workflow beyond the list itself, by workflow ID.
+pub struct WorkflowBadges<'a> {
+    /// Cron triggers of scheduled workflows.
+    pub schedules: &'a HashMap<u64, WorkflowSchedule>,
+    /// Latest run of each workflow.
+    pub latest_runs: &'a HashMap<u64, WorkflowRun>,
+}
+
 /// Render workflows list.
 pub fn render_workflows_list(
     frame: &mut Frame,
     list: &mut SelectableList<Workflow>,
     favorites: &HashSet<String>,
-    owner: &str,
-    repo: &str,
+    badges: WorkflowBadges,
+    repo_path: &str,
+    theme: Theme,
     area: Rect,
 ) {
     match &list.data {
@@ -293,46 +390,63 @@ pub fn render_workflows_list(
             if data.is_empty() {
                 render_empty(frame, area, "No workflows in this repository");
             } else {
-                // Sort: favorites first, then by name
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.id);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.id);
-                    let a_fav = favorites.contains(&a_key);
-                    let b_fav = favorites.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-
-                let items: Vec<ListItem> = sorted
+                let now = Utc::now();
+                let any_fav = data
+                    .items
                     .iter()
+                    .any(|workflow| favorites.contains(&format!("{}/{}", repo_path, workflow.id)));
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
                     .map(|workflow| {
-                        let key = format!("{}/{}/{}", owner, repo, workflow.id);
-                        let is_fav = favorites.contains(&key);
-                        let star = if is_fav { "⭐ " } else { "" };
+                        let key = format!("{}/{}", repo_path, workflow.id);
+                        let star = star_cell(favorites.contains(&key), any_fav);
                         // Extract just the filename from path (e.g., "ci.yml" from ".github/workflows/ci.yml")
                         let filename = workflow.path.rsplit('/').next().unwrap_or(&workflow.path);
-                        ListItem::new(Line::from(vec![
-                            Span::raw(star),
-                            Span::styled(&workflow.name, Style::default().fg(Color::Cyan)),
-                            Span::styled(
-                                format!("  {}", filename),
+                        let mut spans = vec![Span::raw(star)];
+                        spans.extend(filter_spans(
+                            &workflow.name,
+                            list.filter.as_deref(),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                        spans.push(Span::styled(
+                            format!("  {}", filename),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        if let Some(run) = badges.latest_runs.get(&workflow.id) {
+                            spans
+                                .push(Span::raw(format!("  {}", cell::icon(run_status_icon(run)))));
+                            if let Some(branch) = &run.head_branch {
+                                spans.push(Span::styled(
+                                    format!("{} · ", branch),
+                                    Style::default().fg(Color::Magenta),
+                                ));
+                            }
+                            spans.push(Span::styled(
+                                format_relative_time(&run.created_at),
                                 Style::default().fg(Color::DarkGray),
-                            ),
-                        ]))
+                            ));
+                        }
+                        let next_run = badges
+                            .schedules
+                            .get(&workflow.id)
+                            .and_then(|schedule| schedule.next_run(now));
+                        if let Some(next_run) = next_run {
+                            spans.push(Span::styled(
+                                format!(
+                                    "  ⏰ next scheduled run in {}",
+                                    format_until(next_run - now)
+                                ),
+                                Style::default().fg(Color::Yellow),
+                            ));
+                        }
+                        ListItem::new(Line::from(spans))
                     })
                     .collect();
 
                 let list_widget = List::new(items)
                     .block(Block::default().borders(Borders::ALL).title(" Workflows "))
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
-                    )
+                    .highlight_style(highlight_style(theme))
                     .highlight_symbol("> ");
 
                 frame.render_stateful_widget(list_widget, area, &mut list.list_state);
@@ -341,8 +455,27 @@ pub fn render_workflows_list(
     }
 }
 
+/// How a Runs list is narrowed and grouped.
+pub struct RunsListOptions<'a> {
+    /// Whether only the user's own runs are shown.
+    pub mine_only: bool,
+    /// Branch the runs are narrowed to.
+    pub branch: Option<&'a str>,
+    /// Events whose group is folded, when runs are grouped by event.
+    pub grouped: Option<&'a HashSet<String>>,
+    /// Number of runs hidden by ignore rules.
+    pub ignored: usize,
+}
+
 /// Render workflow runs list.
-pub fn render_runs_list(frame: &mut Frame, list: &mut SelectableList<WorkflowRun>, area: Rect) {
+pub fn render_runs_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<WorkflowRun>,
+    options: RunsListOptions,
+    debug_runs: &HashSet<String>,
+    theme: Theme,
+    area: Rect,
+) {
     match &list.data {
         LoadingState::Idle => render_empty(frame, area, "Press Enter to load"),
         LoadingState::Loading => render_loading(frame, area, "Loading workflow runs"),
@@ -351,81 +484,255 @@ pub fn render_runs_list(frame: &mut Frame, list: &mut SelectableList<WorkflowRun
             if data.is_empty() {
                 render_empty(frame, area, "No workflow runs found");
             } else {
-                let items: Vec<ListItem> = data
-                    .items
-                    .iter()
-                    .map(|run| {
-                        let status_icon = match run.conclusion {
-                            Some(RunConclusion::Success) => "✅",
-                            Some(RunConclusion::Failure) => "❌",
-                            Some(RunConclusion::Cancelled) => "⚪",
-                            Some(RunConclusion::Skipped) => "⏭️",
-                            _ => match run.status {
-                                RunStatus::InProgress => "🔄",
-                                RunStatus::Queued | RunStatus::Waiting => "⏳",
-                                _ => "❓",
-                            },
-                        };
+                let runs = list.sorted_items();
+                let counts = event_counts(list);
+                let mut rows = Vec::new();
+                for (i, run) in runs.iter().enumerate() {
+                    let first_of_event = i == 0 || runs[i - 1].event != run.event;
+                    let header = options.grouped.filter(|_| first_of_event).map(|folded| {
+                        let count = counts
+                            .iter()
+                            .find(|(event, _)| *event == run.event)
+                            .map_or(0, |(_, count)| *count);
+                        event_header(&run.event, count, folded.contains(&run.event))
+                    });
+                    let item = run_item(run, &list.marked, list.filter.as_deref(), debug_runs);
+                    rows.push((header, item));
+                }
 
-                        let color = conclusion_color(&run.conclusion);
-                        let time = format_relative_time(&run.created_at);
+                let mut filters = Vec::new();
+                if options.mine_only {
+                    filters.push("mine");
+                }
+                filters.extend(options.branch);
+                let ignored = format!("{} ignored", options.ignored);
+                if options.ignored > 0 {
+                    filters.push(&ignored);
+                }
+                let title = if filters.is_empty() {
+                    " Workflow Runs ".to_string()
+                } else {
+                    format!(" Workflow Runs ({}) ", filters.join(" · "))
+                };
+                let block = Block::default().borders(Borders::ALL).title(title);
+                let offset = render_with_headers(frame, rows, &list.list_state, block, theme, area);
+                *list.list_state.offset_mut() = offset;
+            }
+        }
+    }
+}
 
-                        let mut spans = vec![
-                            Span::raw(format!("{} ", status_icon)),
-                            Span::styled(
-                                format!("#{}", run.run_number),
-                                Style::default().fg(color),
-                            ),
-                            Span::styled(
-                                format!("  {}", time),
-                                Style::default().fg(Color::DarkGray),
-                            ),
-                        ];
+/// Header row of a trigger event's runs, with the number of runs in the group.
+fn event_header(event: &str, count: usize, folded: bool) -> ListItem<'static> {
+    let icon = if folded { "▸" } else { "▾" };
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            format!("{} {}", icon, event_label(event)),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" · {}", count),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]))
+}
 
-                        if let Some(branch) = &run.head_branch {
-                            spans.push(Span::styled(
-                                format!("  {}", branch),
-                                Style::default().fg(Color::Magenta),
-                            ));
-                        }
+/// Icon for a run's conclusion, or its status while unfinished.
+fn run_status_icon(run: &WorkflowRun) -> &'static str {
+    status_icon(run.status, run.conclusion)
+}
 
-                        if !run.pull_requests.is_empty() {
-                            let pr_nums: Vec<String> = run
-                                .pull_requests
-                                .iter()
-                                .map(|pr| format!("#{}", pr.number))
-                                .collect();
-                            spans.push(Span::styled(
-                                format!("  PR {}", pr_nums.join(", ")),
-                                Style::default().fg(Color::Blue),
-                            ));
-                        }
+/// Icon for a conclusion, or the status while unfinished.
+fn status_icon(status: RunStatus, conclusion: Option<RunConclusion>) -> &'static str {
+    match conclusion {
+        Some(RunConclusion::Success) => "✅",
+        Some(RunConclusion::Failure) => "❌",
+        Some(RunConclusion::Cancelled) => "⚪",
+        Some(RunConclusion::Skipped) => "⏭️",
+        _ => match status {
+            RunStatus::InProgress => "🔄",
+            RunStatus::Queued | RunStatus::Waiting => "⏳",
+            _ => "❓",
+        },
+    }
+}
 
-                        ListItem::new(Line::from(spans))
-                    })
-                    .collect();
+/// Seconds a run's current attempt took, or has taken so far while running.
+fn run_duration(run: &WorkflowRun, now: DateTime<Utc>) -> Option<i64> {
+    let start = run.run_started_at.unwrap_or(run.created_at);
+    let end = match run.status {
+        RunStatus::Completed => run.updated_at,
+        RunStatus::InProgress => now,
+        _ => return None,
+    };
+    Some(end.signed_duration_since(start).num_seconds().max(0))
+}
 
-                let list_widget = List::new(items)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(" Workflow Runs "),
-                    )
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                    .highlight_symbol("> ");
+/// Row of a workflow run in a Runs list.
+fn run_item(
+    run: &WorkflowRun,
+    marked: &HashSet<u64>,
+    filter: Option<&str>,
+    debug_runs: &HashSet<String>,
+) -> ListItem<'static> {
+    let status_icon = run_status_icon(run);
 
-                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
-            }
-        }
+    let color = conclusion_color(&run.conclusion);
+    let time = format_relative_time(&run.created_at);
+
+    let mut spans = vec![
+        mark_span(marked, run.id),
+        Span::raw(cell::icon(status_icon)),
+        Span::styled(format!("#{}", run.run_number), Style::default().fg(color)),
+        Span::styled(format!("  {}", time), Style::default().fg(Color::DarkGray)),
+    ];
+
+    if let Some(secs) = run_duration(run, Utc::now()) {
+        spans.push(Span::styled(
+            format!("  ⏱ {}m {}s", secs / 60, secs % 60),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    if let Some(branch) = &run.head_branch {
+        spans.push(Span::raw("  "));
+        spans.extend(filter_spans(
+            branch,
+            filter,
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+
+    if let Some(actor) = &run.actor {
+        spans.push(Span::raw("  "));
+        spans.extend(filter_spans(
+            &format!("@{}", actor.login),
+            filter,
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if let Some(rerun_by) = run
+        .triggering_actor
+        .as_ref()
+        .filter(|t| run.actor.as_ref().is_some_and(|a| a.login != t.login))
+    {
+        spans.push(Span::styled(
+            format!(" (re-run by @{})", rerun_by.login),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let attempt = format!("{}/{}", run.id, run.run_attempt.unwrap_or(1));
+    if debug_runs.contains(&attempt) {
+        spans.push(Span::styled(
+            "  🐞 debug",
+            Style::default().fg(Color::LightRed),
+        ));
+    }
+
+    if !run.pull_requests.is_empty() {
+        let pr_nums: Vec<String> = run
+            .pull_requests
+            .iter()
+            .map(|pr| format!("#{}", pr.number))
+            .collect();
+        spans.push(Span::styled(
+            format!("  PR {}", pr_nums.join(", ")),
+            Style::default().fg(Color::Blue),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+/// Short description of a run's state (e.g., "in progress", "failure").
+fn run_state_label(status: RunStatus, conclusion: Option<RunConclusion>) -> &'static str {
+    match conclusion {
+        Some(RunConclusion::Success) => "success",
+        Some(RunConclusion::Failure) => "failure",
+        Some(RunConclusion::Cancelled) => "cancelled",
+        Some(RunConclusion::Skipped) => "skipped",
+        Some(RunConclusion::TimedOut) => "timed out",
+        Some(RunConclusion::ActionRequired) => "action required",
+        Some(RunConclusion::Neutral) => "neutral",
+        Some(RunConclusion::Stale) => "stale",
+        Some(RunConclusion::StartupFailure) => "startup failure",
+        Some(RunConclusion::Unknown) => "unknown",
+        None => match status {
+            RunStatus::Queued => "queued",
+            RunStatus::InProgress => "in progress",
+            RunStatus::Completed => "completed",
+            RunStatus::Waiting => "waiting",
+            RunStatus::Requested => "requested",
+            RunStatus::Pending => "pending",
+            RunStatus::Unknown => "unknown",
+        },
+    }
+}
+
+/// Render a summary of a workflow run for the preview pane.
+pub fn render_run_summary(frame: &mut Frame, run: Option<&WorkflowRun>, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(" Run ");
+    let Some(run) = run else {
+        frame.render_widget(Paragraph::new("No run selected").block(block), area);
+        return;
+    };
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!("#{} ", run.run_number),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            run_state_label(run.status, run.conclusion),
+            Style::default().fg(conclusion_color(&run.conclusion)),
+        ),
+        Span::styled(
+            format!("  {}", format_relative_time(&run.created_at)),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])];
+
+    let mut source = Vec::new();
+    if let Some(branch) = &run.head_branch {
+        source.push(Span::styled(
+            format!("{}  ", branch),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    source.push(Span::styled(
+        run.head_sha.chars().take(7).collect::<String>(),
+        Style::default().fg(Color::DarkGray),
+    ));
+    lines.push(Line::from(source));
+
+    if let Some(name) = &run.name {
+        lines.push(Line::from(Span::raw(name.clone())));
     }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// How a Jobs list groups matrix variants.
+pub struct JobsListOptions<'a> {
+    /// Matrix jobs whose variants are folded, when variants are grouped.
+    pub grouped: Option<&'a HashSet<String>>,
+    /// Whether only failed matrix variants are shown.
+    pub failed_only: bool,
 }
 
 /// Render jobs list.
-pub fn render_jobs_list(frame: &mut Frame, list: &mut SelectableList<Job>, area: Rect) {
+pub fn render_jobs_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<Job>,
+    options: JobsListOptions,
+    history: &JobHistory,
+    flaky: &HashSet<String>,
+    theme: Theme,
+    area: Rect,
+) {
     match &list.data {
         LoadingState::Idle => render_empty(frame, area, "Press Enter to load"),
         LoadingState::Loading => render_loading(frame, area, "Loading jobs"),
@@ -434,21 +741,11 @@ pub fn render_jobs_list(frame: &mut Frame, list: &mut SelectableList<Job>, area:
             if data.is_empty() {
                 render_empty(frame, area, "No jobs in this run");
             } else {
-                let items: Vec<ListItem> = data
-                    .items
+                let jobs = list.sorted_items();
+                let rows: Vec<ListItem> = jobs
                     .iter()
                     .map(|job| {
-                        let status_icon = match job.conclusion {
-                            Some(RunConclusion::Success) => "✅",
-                            Some(RunConclusion::Failure) => "❌",
-                            Some(RunConclusion::Cancelled) => "⚪",
-                            Some(RunConclusion::Skipped) => "⏭️",
-                            _ => match job.status {
-                                RunStatus::InProgress => "🔄",
-                                RunStatus::Queued | RunStatus::Waiting => "⏳",
-                                _ => "❓",
-                            },
-                        };
+                        let status_icon = status_icon(job.status, job.conclusion);
 
                         let color = conclusion_color(&job.conclusion);
                         let is_in_progress = job.status == RunStatus::InProgress;
@@ -474,13 +771,39 @@ pub fn render_jobs_list(frame: &mut Frame, list: &mut SelectableList<Job>, area:
                         };
 
                         let mut first_line = vec![
-                            Span::raw(format!("{} ", status_icon)),
-                            Span::styled(&job.name, Style::default().fg(color)),
-                            Span::styled(
-                                format!("  {}", duration),
-                                Style::default().fg(Color::DarkGray),
-                            ),
+                            mark_span(&list.marked, job.id),
+                            Span::raw(cell::icon(status_icon)),
                         ];
+                        first_line.extend(filter_spans(
+                            &job.name,
+                            list.filter.as_deref(),
+                            Style::default().fg(color),
+                        ));
+                        if flaky.contains(&job.name) {
+                            first_line.push(Span::styled(
+                                " 🎲 flaky",
+                                Style::default().fg(Color::Magenta),
+                            ));
+                        }
+                        first_line.push(Span::styled(
+                            format!("  {}", duration),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        // Only jobs still waiting for a runner show how long they've waited
+                        let waiting = matches!(job.status, RunStatus::Queued | RunStatus::Waiting);
+                        if let (true, Some(waited)) = (waiting, queue_time(job, chrono::Utc::now()))
+                        {
+                            first_line.push(Span::styled(
+                                format!("  queued {}", format_queue_time(waited)),
+                                Style::default().fg(Color::Yellow),
+                            ));
+                        }
+                        if let Some(eta) = history.eta(job, chrono::Utc::now()) {
+                            first_line.push(Span::styled(
+                                format!("  {}", format_eta(eta)),
+                                Style::default().fg(Color::Yellow),
+                            ));
+                        }
 
                         // For in-progress jobs, show additional info on separate lines
                         if is_in_progress {
@@ -491,7 +814,7 @@ pub fn render_jobs_list(frame: &mut Frame, list: &mut SelectableList<Job>, area:
                                 lines.push(Line::from(vec![
                                     Span::raw("     "),
                                     Span::styled("@ ", Style::default().fg(Color::Cyan)),
-                                    Span::styled(runner, Style::default().fg(Color::Cyan)),
+                                    Span::styled(runner.clone(), Style::default().fg(Color::Cyan)),
                                 ]));
                             }
 
@@ -500,7 +823,7 @@ pub fn render_jobs_list(frame: &mut Frame, list: &mut SelectableList<Job>, area:
                                 .steps
                                 .iter()
                                 .find(|s| s.status == RunStatus::InProgress)
-                                .map(|s| s.name.as_str());
+                                .map(|s| s.name.clone());
 
                             if let Some(step_name) = current_step {
                                 lines.push(Line::from(vec![
@@ -524,28 +847,237 @@ pub fn render_jobs_list(frame: &mut Frame, list: &mut SelectableList<Job>, area:
                     })
                     .collect();
 
-                let list_widget = List::new(items)
-                    .block(Block::default().borders(Borders::ALL).title(" Jobs "))
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                    .highlight_symbol("> ");
+                let groups = matrix_groups(list);
+                let show_headers = options.grouped.is_some() || options.failed_only;
+                let mut headed_rows = Vec::new();
+                for (i, (job, row)) in jobs.iter().zip(rows).enumerate() {
+                    let first_of_group =
+                        i == 0 || base_name(&jobs[i - 1].name) != base_name(&job.name);
+                    let header = matrix_parts(&job.name)
+                        .map(|(base, _)| base)
+                        .filter(|_| show_headers && first_of_group)
+                        .and_then(|base| groups.iter().find(|group| group.base == base))
+                        .map(|group| {
+                            let folded = options
+                                .grouped
+                                .is_some_and(|folded| folded.contains(&group.base));
+                            matrix_header(group, folded)
+                        });
+                    headed_rows.push((header, row));
+                }
 
-                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+                let title = if options.failed_only {
+                    " Jobs (failed variants) "
+                } else {
+                    " Jobs "
+                };
+                let block = Block::default().borders(Borders::ALL).title(title);
+                let offset =
+                    render_with_headers(frame, headed_rows, &list.list_state, block, theme, area);
+                *list.list_state.offset_mut() = offset;
             }
         }
     }
 }
 
+/// Draw a list whose items may each be led by an unselectable header row.
+/// Headers only shift where items are drawn: the selection in `state` and
+/// the scroll offset returned stay item positions, as in lists without
+/// headers.
+fn render_with_headers<'a>(
+    frame: &mut Frame,
+    rows: Vec<(Option<ListItem<'a>>, ListItem<'a>)>,
+    state: &ListState,
+    block: Block<'a>,
+    theme: Theme,
+    area: Rect,
+) -> usize {
+    // Row each item is drawn at, and the row its header (if any) is drawn at
+    let mut items = Vec::new();
+    let mut item_rows = Vec::new();
+    let mut first_rows = Vec::new();
+    for (header, item) in rows {
+        first_rows.push(items.len());
+        items.extend(header);
+        item_rows.push(items.len());
+        items.push(item);
+    }
+    let selected_row = state.selected().and_then(|i| item_rows.get(i).copied());
+    // Scrolled to an item, its header shows above it
+    let offset_row = first_rows.get(state.offset()).copied().unwrap_or(0);
+
+    let list_widget = List::new(items)
+        .block(block)
+        .highlight_style(highlight_style(theme))
+        .highlight_symbol("> ");
+    let mut row_state = ListState::default()
+        .with_selected(selected_row)
+        .with_offset(offset_row);
+    frame.render_stateful_widget(list_widget, area, &mut row_state);
+    // The first item whose header or row is in view
+    item_rows.partition_point(|&row| row < row_state.offset())
+}
+
+/// Header row of a matrix job, with its combined status and variant counts.
+fn matrix_header(group: &MatrixGroup, folded: bool) -> ListItem<'static> {
+    let icon = if folded { "▸" } else { "▾" };
+    let mut counts = format!(" · {} variants", group.variants);
+    if group.failed > 0 {
+        counts.push_str(&format!(" · {} failed", group.failed));
+    }
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            format!("{} ", icon),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(cell::icon(status_icon(group.status, group.conclusion))),
+        Span::styled(
+            group.base.clone(),
+            Style::default()
+                .fg(conclusion_color(&group.conclusion))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(counts, Style::default().fg(Color::DarkGray)),
+    ]))
+}
+
+/// Render check-run annotations above the log viewer.
+pub fn render_annotations_list(
+    frame: &mut Frame,
+    list: &SelectableList<Annotation>,
+    focused: bool,
+    theme: Theme,
+    area: Rect,
+) {
+    let items: Vec<ListItem> = list
+        .sorted_items()
+        .into_iter()
+        .map(|annotation| {
+            let (icon, color) = match annotation.annotation_level {
+                AnnotationLevel::Failure => ("✖", Color::Red),
+                AnnotationLevel::Warning => ("⚠", Color::Yellow),
+                AnnotationLevel::Notice | AnnotationLevel::Unknown => ("ℹ", Color::Blue),
+            };
+            let message = annotation.message.lines().next().unwrap_or_default();
+            ListItem::new(Line::from(vec![
+                Span::styled(cell::icon(icon), Style::default().fg(color)),
+                Span::styled(
+                    format!("{}:{}", annotation.path, annotation.start_line),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(format!("  {}", message)),
+            ]))
+        })
+        .collect();
+
+    let (title, border) = if focused {
+        (
+            format!(" Annotations ({}) · ↵ jump to log ", items.len()),
+            Color::Cyan,
+        )
+    } else {
+        (
+            format!(" Annotations ({}) · a to select ", items.len()),
+            Color::DarkGray,
+        )
+    };
+    let list_widget = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border))
+                .title(title),
+        )
+        .highlight_style(if focused {
+            highlight_style(theme)
+        } else {
+            Style::default()
+        })
+        .highlight_symbol(if focused { "> " } else { "  " });
+
+    // Rendering only; selection changes go through the list itself
+    let mut state = list.list_state.clone();
+    frame.render_stateful_widget(list_widget, area, &mut state);
+}
+
+/// Render the test summary parsed from a job log.
+pub fn render_test_report(
+    frame: &mut Frame,
+    report: &TestReport,
+    failures: &SelectableList<TestFailure>,
+    focused: bool,
+    theme: Theme,
+    area: Rect,
+) {
+    let items: Vec<ListItem> = if report.failures.is_empty() {
+        report
+            .junit_paths
+            .iter()
+            .map(|path| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(cell::icon("📄"), Style::default().fg(Color::Blue)),
+                    Span::styled(path.clone(), Style::default().fg(Color::Cyan)),
+                ]))
+            })
+            .collect()
+    } else {
+        failures
+            .sorted_items()
+            .into_iter()
+            .map(|failure| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(cell::icon("✖"), Style::default().fg(Color::Red)),
+                    Span::raw(failure.name.clone()),
+                ]))
+            })
+            .collect()
+    };
+
+    let summary = format!(
+        " Tests ({}): {} passed, {} failed, {} skipped ",
+        report.framework.name(),
+        report.passed,
+        report.failed,
+        report.skipped
+    );
+    let (title, border) = if focused {
+        (format!("{}· ↵ jump to log ", summary), Color::Cyan)
+    } else if !report.failures.is_empty() {
+        (format!("{}· t to select ", summary), Color::DarkGray)
+    } else {
+        (summary, Color::DarkGray)
+    };
+    let list_widget = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border))
+                .title(title),
+        )
+        .highlight_style(if focused {
+            highlight_style(theme)
+        } else {
+            Style::default()
+        })
+        .highlight_symbol(if focused { "> " } else { "  " });
+
+    // Rendering only; selection changes go through the list itself
+    let mut state = failures.list_state.clone();
+    frame.render_stateful_widget(list_widget, area, &mut state);
+}
+
 /// Render runners list.
+#[allow(clippy::too_many_arguments)]
 pub fn render_runners_list(
     frame: &mut Frame,
-    list: &mut SelectableList<Runner>,
+    list: &mut SelectableList<RunnerRow>,
     favorites: &HashSet<String>,
-    owner: &str,
-    repo: &str,
+    repo_path: &str,
+    runner_jobs: &HashMap<String, RunnerJob>,
+    utilization: &RunnerUtilization,
+    theme: Theme,
     area: Rect,
 ) {
     match &list.data {
@@ -556,67 +1088,87 @@ pub fn render_runners_list(
             if data.is_empty() {
                 render_empty(frame, area, "No runners found");
             } else {
-                // Sort: favorites first, then by name
-                let mut sorted: Vec<_> = data.items.iter().collect();
-                sorted.sort_by(|a, b| {
-                    let a_key = format!("{}/{}/{}", owner, repo, a.name);
-                    let b_key = format!("{}/{}/{}", owner, repo, b.name);
-                    let a_fav = favorites.contains(&a_key);
-                    let b_fav = favorites.contains(&b_key);
-                    match (a_fav, b_fav) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                });
-
-                let items: Vec<ListItem> = sorted
+                let any_fav = data
+                    .items
                     .iter()
-                    .map(|runner| {
-                        let key = format!("{}/{}/{}", owner, repo, runner.name);
-                        let is_fav = favorites.contains(&key);
-                        let star = if is_fav { "⭐ " } else { "" };
-
-                        let (status_icon, status_color) = match runner.status {
-                            RunnerStatus::Online => ("🟢", Color::Green),
-                            RunnerStatus::Offline => ("⚫", Color::DarkGray),
-                            RunnerStatus::Unknown => ("❓", Color::Gray),
-                        };
+                    .any(|row| favorites.contains(&format!("{}/{}", repo_path, row.name())));
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|row| {
+                        let key = format!("{}/{}", repo_path, row.name());
+                        let star = star_cell(favorites.contains(&key), any_fav);
+                        match row {
+                            RunnerRow::Runner(runner) => runner_item(
+                                runner,
+                                runner_jobs.get(&runner.name),
+                                utilization,
+                                &star,
+                                list.filter.as_deref(),
+                            ),
+                            RunnerRow::ScaleSet { name, runners } => {
+                                scale_set_item(name, runners, &star, list.filter.as_deref())
+                            }
+                        }
+                    })
+                    .collect();
 
-                        let busy_indicator = if runner.busy { " (busy)" } else { "" };
+                let list_widget = List::new(items)
+                    .block(Block::default().borders(Borders::ALL).title(" Runners "))
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
 
-                        let labels: Vec<&str> = runner
-                            .labels
-                            .iter()
-                            .take(3)
-                            .map(|l| l.name.as_str())
-                            .collect();
-                        let labels_str = if labels.is_empty() {
-                            String::new()
-                        } else {
-                            format!("  [{}]", labels.join(", "))
-                        };
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
 
-                        ListItem::new(Line::from(vec![
-                            Span::raw(format!("{}{} ", star, status_icon)),
-                            Span::styled(&runner.name, Style::default().fg(status_color)),
-                            Span::styled(busy_indicator, Style::default().fg(Color::Yellow)),
-                            Span::styled(
-                                format!("  {}", runner.os),
-                                Style::default().fg(Color::Cyan),
-                            ),
-                            Span::styled(labels_str, Style::default().fg(Color::DarkGray)),
-                        ]))
+/// Render an organization's runner groups with their repository access.
+pub fn render_runner_groups_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<RunnerGroup>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press Enter to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading runner groups"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No runner groups in this organization");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|group| {
+                        let mut spans = filter_spans(
+                            &group.name,
+                            list.filter.as_deref(),
+                            Style::default().fg(Color::Cyan),
+                        );
+                        if group.default {
+                            spans.push(Span::raw(" (default)"));
+                        }
+                        if group.inherited {
+                            spans.push(Span::raw(" (enterprise)"));
+                        }
+                        spans.push(Span::styled(
+                            format!("  {}", group_access(group)),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
                     })
                     .collect();
 
                 let list_widget = List::new(items)
-                    .block(Block::default().borders(Borders::ALL).title(" Runners "))
-                    .highlight_style(
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .add_modifier(Modifier::BOLD),
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(" Runner Groups "),
                     )
+                    .highlight_style(highlight_style(theme))
                     .highlight_symbol("> ");
 
                 frame.render_stateful_widget(list_widget, area, &mut list.list_state);
@@ -624,3 +1176,946 @@ pub fn render_runners_list(
         }
     }
 }
+
+/// Render the repositories allowed to use a runner group with selected access.
+pub fn render_group_repos(frame: &mut Frame, repos: &[String], area: Rect) {
+    let paragraph = Paragraph::new(format!(" {}", repos.join(", ")))
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Repository access ({}) ", repos.len())),
+        );
+    frame.render_widget(paragraph, area);
+}
+
+/// Render pull requests awaiting the user with their check status.
+pub fn render_inbox_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<InboxPullRequest>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press r to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading pull requests"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "Inbox zero: no open pull requests need you");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|pr| {
+                        let (icon, color) = match pr.checks {
+                            Some(CheckState::Failure | CheckState::Error) => ("❌", Color::Red),
+                            Some(CheckState::Pending | CheckState::Expected) => {
+                                ("🔄", Color::Yellow)
+                            }
+                            Some(CheckState::Success) => ("✅", Color::Green),
+                            None | Some(CheckState::Unknown) => ("⚪", Color::DarkGray),
+                        };
+                        let reason = match pr.reason {
+                            InboxReason::ReviewRequested => "review requested",
+                            InboxReason::Authored => "yours",
+                        };
+                        let title_style = if pr.is_draft {
+                            Style::default().fg(Color::DarkGray)
+                        } else {
+                            Style::default()
+                        };
+
+                        let mut spans = vec![
+                            Span::raw(cell::icon(icon)),
+                            Span::styled(
+                                format!("{}#{}", pr.repository, pr.number),
+                                Style::default().fg(color),
+                            ),
+                            Span::raw("  "),
+                        ];
+                        spans.extend(filter_spans(&pr.title, list.filter.as_deref(), title_style));
+                        if let Some(author) = &pr.author {
+                            spans.push(Span::styled(
+                                format!("  @{}", author),
+                                Style::default().fg(Color::Cyan),
+                            ));
+                        }
+                        spans.push(Span::styled(
+                            format!("  {} · {}", reason, format_relative_time(&pr.updated_at)),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(" Pull Requests "),
+                    )
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render in-progress runs across favorite repositories.
+pub fn render_live_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<LiveRun>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press r to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading in-progress runs"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No runs in progress in favorite repositories");
+            } else {
+                let now = Utc::now();
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|live| {
+                        let run = &live.run;
+                        let mut spans = vec![
+                            Span::raw(cell::icon("🔄")),
+                            Span::styled(
+                                format!("{}/{}", live.owner, live.repo),
+                                Style::default().fg(Color::Cyan),
+                            ),
+                            Span::raw("  "),
+                        ];
+                        spans.extend(filter_spans(
+                            run.name.as_deref().unwrap_or("workflow"),
+                            list.filter.as_deref(),
+                            Style::default(),
+                        ));
+                        spans.push(Span::styled(
+                            format!("  #{}", run.run_number),
+                            Style::default().fg(status_color(&run.status)),
+                        ));
+                        if let Some(branch) = &run.head_branch {
+                            spans.push(Span::raw("  "));
+                            spans.extend(filter_spans(
+                                branch,
+                                list.filter.as_deref(),
+                                Style::default().fg(Color::Magenta),
+                            ));
+                        }
+                        spans.push(Span::styled(
+                            format!("  running {}", format_until(now - live.started_at())),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .block(Block::default().borders(Borders::ALL).title(" Live Runs "))
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render the Home dashboard, each section under a header.
+pub fn render_home_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<HomeItem>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press r to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading favorites"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(
+                    frame,
+                    area,
+                    "Nothing needs attention: favorite repositories and runners to watch them here",
+                );
+            } else {
+                let now = Utc::now();
+                let shown = list.sorted_items();
+
+                let mut rows = Vec::new();
+                for (i, item) in shown.iter().enumerate() {
+                    let section = item.section();
+                    let header = (i == 0 || shown[i - 1].section() != section).then(|| {
+                        let count = shown.iter().filter(|s| s.section() == section).count();
+                        home_header(section, count)
+                    });
+                    rows.push((header, home_item(item, list.filter.as_deref(), now)));
+                }
+
+                let block = Block::default().borders(Borders::ALL).title(" Home ");
+                let offset = render_with_headers(frame, rows, &list.list_state, block, theme, area);
+                *list.list_state.offset_mut() = offset;
+            }
+        }
+    }
+}
+
+/// Header row of a Home section.
+fn home_header(section: HomeSection, count: usize) -> ListItem<'static> {
+    ListItem::new(Line::from(Span::styled(
+        format!("{} ({})", section.title(), count),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )))
+}
+
+/// Row of a Home item.
+fn home_item(item: &HomeItem, filter: Option<&str>, now: DateTime<Utc>) -> ListItem<'static> {
+    let repo_spans = |owner: &str, repo: &str| {
+        filter_spans(
+            &format!("{}/{}", owner, repo),
+            filter,
+            Style::default().fg(Color::Cyan),
+        )
+    };
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut spans = Vec::new();
+    match item {
+        HomeItem::FailingRun { owner, repo, run } => {
+            spans.push(Span::raw(format!("  {}", cell::icon(run_status_icon(run)))));
+            spans.extend(repo_spans(owner, repo));
+            spans.push(Span::raw("  "));
+            spans.extend(filter_spans(
+                run.name.as_deref().unwrap_or("workflow"),
+                filter,
+                Style::default(),
+            ));
+            spans.push(Span::styled(
+                format!("  #{}", run.run_number),
+                Style::default().fg(Color::Red),
+            ));
+            if let Some(branch) = &run.head_branch {
+                spans.push(Span::raw("  "));
+                spans.extend(filter_spans(
+                    branch,
+                    filter,
+                    Style::default().fg(Color::Magenta),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("  {}", format_relative_time(&run.updated_at)),
+                dim,
+            ));
+        }
+        HomeItem::RunningRun { owner, repo, run } => {
+            spans.push(Span::raw(format!("  {}", cell::icon("🔄"))));
+            spans.extend(repo_spans(owner, repo));
+            spans.push(Span::raw("  "));
+            spans.extend(filter_spans(
+                run.name.as_deref().unwrap_or("workflow"),
+                filter,
+                Style::default(),
+            ));
+            spans.push(Span::raw(format!("  #{}", run.run_number)));
+            if let Some(branch) = &run.head_branch {
+                spans.push(Span::raw("  "));
+                spans.extend(filter_spans(
+                    branch,
+                    filter,
+                    Style::default().fg(Color::Magenta),
+                ));
+            }
+            let started = run.run_started_at.unwrap_or(run.created_at);
+            spans.push(Span::styled(
+                format!("  running {}", format_until(now - started)),
+                dim,
+            ));
+        }
+        HomeItem::OfflineRunner {
+            owner,
+            repo,
+            runner,
+        } => {
+            spans.push(Span::raw(format!("  {}", cell::icon("⚫"))));
+            spans.extend(repo_spans(owner, repo));
+            spans.push(Span::raw("  "));
+            spans.extend(filter_spans(&runner.name, filter, Style::default()));
+            spans.push(Span::styled(format!("  {} · offline", runner.os), dim));
+        }
+        HomeItem::Recent(recent) => {
+            let icon = recent
+                .job
+                .as_ref()
+                .map_or("🕘", |job| status_icon(job.status, job.conclusion));
+            spans.push(Span::raw(format!("  {}", cell::icon(icon))));
+            spans.extend(repo_spans(&recent.owner, &recent.repo));
+            spans.push(Span::raw("  "));
+            spans.extend(filter_spans(
+                &recent.workflow_name,
+                filter,
+                Style::default(),
+            ));
+            spans.push(Span::raw(format!(" #{}", recent.run_number)));
+            if let Some(job) = &recent.job {
+                spans.push(Span::raw(" › "));
+                spans.extend(filter_spans(&job.name, filter, Style::default()));
+            }
+            spans.push(Span::styled(
+                format!("  viewed {}", format_relative_time(&recent.viewed_at)),
+                dim,
+            ));
+        }
+    }
+    ListItem::new(Line::from(spans))
+}
+
+/// Render a repository's Actions cache entries.
+pub fn render_actions_caches(
+    frame: &mut Frame,
+    list: &mut SelectableList<ActionsCache>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press C to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading caches"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No Actions caches");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|cache| {
+                        let git_ref = cache
+                            .git_ref
+                            .strip_prefix("refs/heads/")
+                            .unwrap_or(&cache.git_ref);
+                        let accessed = cache
+                            .last_accessed_at
+                            .map(|t| format_relative_time(&t))
+                            .unwrap_or_else(|| "-".to_string());
+                        ListItem::new(Line::from(vec![
+                            Span::styled(
+                                format!("{:>9}  ", format_size(cache.size_in_bytes)),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::raw(cache.key.clone()),
+                            Span::styled(
+                                format!("  {}", git_ref),
+                                Style::default().fg(Color::Magenta),
+                            ),
+                            Span::styled(
+                                format!("  {}", accessed),
+                                Style::default().fg(Color::DarkGray),
+                            ),
+                        ]))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render a commit's check runs, naming the app that reported each.
+pub fn render_check_runs(
+    frame: &mut Frame,
+    list: &mut SelectableList<CheckRun>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press K to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading checks"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No checks reported for this commit");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|check| {
+                        let app_color = if is_actions_check(check) {
+                            Color::DarkGray
+                        } else {
+                            Color::Magenta
+                        };
+                        ListItem::new(Line::from(vec![
+                            Span::raw(cell::icon(status_icon(check.status, check.conclusion))),
+                            Span::styled(
+                                check.name.clone(),
+                                Style::default().fg(conclusion_color(&check.conclusion)),
+                            ),
+                            Span::styled(
+                                format!("  {}", check_app_name(check)),
+                                Style::default().fg(app_color),
+                            ),
+                        ]))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render the files of a run's log archive with their sizes.
+pub fn render_archive_files(
+    frame: &mut Frame,
+    list: &mut SelectableList<ArchiveFile>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press L to load"),
+        LoadingState::Loading => render_loading(frame, area, "Downloading log archive"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "Log archive is empty");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|file| {
+                        // Step logs sit in a folder named after their job
+                        let (folder, name) = match file.name.rsplit_once('/') {
+                            Some((folder, name)) => (format!("{}/", folder), name),
+                            None => (String::new(), file.name.as_str()),
+                        };
+                        ListItem::new(Line::from(vec![
+                            Span::styled(
+                                format!("{:>9}  ", format_size(file.size)),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(folder, Style::default().fg(Color::DarkGray)),
+                            Span::raw(name.to_string()),
+                        ]))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render a repository's branches for the branch picker, `current` marked.
+pub fn render_branches(
+    frame: &mut Frame,
+    list: &mut SelectableList<Branch>,
+    current: Option<&str>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press s to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading branches"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No branches found");
+            } else if list.visible_len() == 0 {
+                render_empty(frame, area, "No matching branches, ↵ uses the typed name");
+            } else {
+                let filter = list.filter.as_deref();
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|branch| {
+                        let icon = if Some(branch.name.as_str()) == current {
+                            "● "
+                        } else {
+                            "  "
+                        };
+                        let mut spans = vec![Span::styled(icon, Style::default().fg(Color::Cyan))];
+                        spans.extend(filter_spans(&branch.name, filter, Style::default()));
+                        if branch.protected {
+                            spans.push(Span::styled(
+                                "  protected",
+                                Style::default().fg(Color::DarkGray),
+                            ));
+                        }
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Short description of the rules gating deployments to an environment.
+fn protection_summary(env: &Environment) -> String {
+    let mut parts = Vec::new();
+    for rule in &env.protection_rules {
+        match rule.rule_type.as_str() {
+            "wait_timer" => {
+                if let Some(minutes) = rule.wait_timer.filter(|&m| m > 0) {
+                    parts.push(format!("⏱ {}m wait", minutes));
+                }
+            }
+            "required_reviewers" => parts.push(format!("👥 {} reviewers", rule.reviewers.len())),
+            "branch_policy" => {}
+            other => parts.push(other.replace('_', " ")),
+        }
+    }
+    match &env.deployment_branch_policy {
+        Some(policy) if policy.protected_branches => parts.push("🌿 protected branches".into()),
+        Some(policy) if policy.custom_branch_policies => parts.push("🌿 custom branches".into()),
+        _ => {}
+    }
+    if parts.is_empty() {
+        "unprotected".to_string()
+    } else {
+        parts.join("  ")
+    }
+}
+
+/// Render deployment environments of a repository.
+pub fn render_environments_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<Environment>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press Enter to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading environments"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No environments in this repository");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|env| {
+                        let mut spans = filter_spans(
+                            &env.name,
+                            list.filter.as_deref(),
+                            Style::default().fg(Color::Cyan),
+                        );
+                        spans.push(Span::styled(
+                            format!("  {}", protection_summary(env)),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(" Environments "),
+                    )
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render recent deployments to an environment with their latest status.
+pub fn render_deployments_list(
+    frame: &mut Frame,
+    list: &mut SelectableList<Deployment>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press Enter to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading deployments"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No deployments to this environment");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|deployment| {
+                        let (icon, color) = match deployment.state {
+                            Some(DeploymentState::Success) => ("✓", Color::Green),
+                            Some(DeploymentState::Failure | DeploymentState::Error) => {
+                                ("✗", Color::Red)
+                            }
+                            Some(DeploymentState::InProgress) => ("●", Color::Yellow),
+                            Some(DeploymentState::Queued | DeploymentState::Pending) => {
+                                ("○", Color::Yellow)
+                            }
+                            Some(DeploymentState::Inactive) => ("◌", Color::DarkGray),
+                            Some(DeploymentState::Unknown) | None => ("?", Color::DarkGray),
+                        };
+                        let git_ref = deployment
+                            .git_ref
+                            .strip_prefix("refs/heads/")
+                            .unwrap_or(&deployment.git_ref);
+                        let short_sha = &deployment.sha[..deployment.sha.len().min(7)];
+                        let creator = deployment
+                            .creator
+                            .as_ref()
+                            .map(|c| format!("  {}", c.login))
+                            .unwrap_or_default();
+                        let mut spans =
+                            vec![Span::styled(cell::icon(icon), Style::default().fg(color))];
+                        spans.extend(filter_spans(
+                            git_ref,
+                            list.filter.as_deref(),
+                            Style::default().fg(Color::Magenta),
+                        ));
+                        spans.push(Span::styled(
+                            format!("  {}", short_sha),
+                            Style::default().fg(Color::Yellow),
+                        ));
+                        spans.push(Span::raw(creator));
+                        spans.push(Span::styled(
+                            format!("  {}", format_relative_time(&deployment.created_at)),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(" Deployments "),
+                    )
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render secrets and variables available to a repository.
+pub fn render_config_entries(
+    frame: &mut Frame,
+    list: &mut SelectableList<ConfigEntry>,
+    theme: Theme,
+    area: Rect,
+) {
+    match &list.data {
+        LoadingState::Idle => render_empty(frame, area, "Press S to load"),
+        LoadingState::Loading => render_loading(frame, area, "Loading secrets"),
+        LoadingState::Error(e) => render_error(frame, area, e),
+        LoadingState::Loaded(data) => {
+            if data.is_empty() {
+                render_empty(frame, area, "No secrets or variables");
+            } else {
+                let items: Vec<ListItem> = list
+                    .sorted_items()
+                    .into_iter()
+                    .map(|entry| {
+                        let name_style = if entry.shadowed {
+                            Style::default().fg(Color::DarkGray)
+                        } else {
+                            Style::default()
+                        };
+                        let mut spans = vec![
+                            Span::raw(cell::icon(if entry.is_secret() { "🔒" } else { "📝" })),
+                            Span::styled(entry.name.clone(), name_style),
+                        ];
+                        if let Some(value) = &entry.value {
+                            spans.push(Span::styled(
+                                format!(" = {}", value),
+                                Style::default().fg(Color::Green),
+                            ));
+                        }
+                        spans.push(Span::styled(
+                            format!("  {}", entry.scope.label()),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                        if entry.shadowed {
+                            spans.push(Span::styled(
+                                " (overridden)",
+                                Style::default().fg(Color::DarkGray),
+                            ));
+                        }
+                        spans.push(Span::styled(
+                            format!("  updated {}", format_relative_time(&entry.updated_at)),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                let list_widget = List::new(items)
+                    .highlight_style(highlight_style(theme))
+                    .highlight_symbol("> ");
+
+                frame.render_stateful_widget(list_widget, area, &mut list.list_state);
+            }
+        }
+    }
+}
+
+/// Render recent runner registrations and removals per label.
+pub fn render_runner_churn(frame: &mut Frame, churn: &[LabelChurn], area: Rect) {
+    let label_width = churn
+        .iter()
+        .map(|c| cell::width(&c.label))
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<Line> = churn
+        .iter()
+        .map(|c| {
+            Line::from(vec![
+                Span::raw(format!(" {}  ", cell::pad(&c.label, label_width))),
+                Span::styled(
+                    format!("+{:<3}", c.added),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::styled(
+                    format!("-{:<3} ", c.removed),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::styled(sparkline(&c.buckets), Style::default().fg(Color::Yellow)),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Runner churn (24h) "),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Render average and longest queue times per requested runner label.
+pub fn render_queue_times(frame: &mut Frame, times: &[LabelQueueTimes], area: Rect) {
+    let label_width = times
+        .iter()
+        .map(|t| cell::width(&t.label))
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<Line> = times
+        .iter()
+        .map(|t| {
+            Line::from(vec![
+                Span::raw(format!(" {}  ", cell::pad(&t.label, label_width))),
+                Span::styled(
+                    format!("avg {:>7}", format_queue_time(t.average)),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!("  max {:>7}", format_queue_time(t.max)),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::styled(
+                    format!("  {} jobs", t.jobs),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Queue time by label (7d) "),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Render local health checks for a runner installed on this machine.
+pub fn render_runner_health(frame: &mut Frame, health: &RunnerHealth, area: Rect) {
+    let process = match health.process_up {
+        Some(true) => Span::styled("running", Style::default().fg(Color::Green)),
+        Some(false) => Span::styled("not running", Style::default().fg(Color::Red)),
+        None => Span::styled("unknown", Style::default().fg(Color::DarkGray)),
+    };
+    let disk = match health.disk_free_kb {
+        Some(kb) => format!("{:.1} GiB free", kb as f64 / (1024.0 * 1024.0)),
+        None => "unknown".to_string(),
+    };
+    let lines = vec![
+        Line::from(vec![Span::raw(" Listener  "), process]),
+        Line::from(format!(" Disk      {}", disk)),
+        Line::from(format!(
+            " Probe     {}",
+            health.probe.as_deref().unwrap_or("disabled")
+        )),
+    ];
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Local health "),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Bar characters scaled to the largest value (e.g., "▁▁▅█▁").
+pub fn sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or(0).max(1);
+    values
+        .iter()
+        .map(|&v| BARS[(v * (BARS.len() as u64 - 1) / max) as usize])
+        .collect()
+}
+
+/// Bar characters for percentages, with "·" for gaps (e.g., "··▁▄█").
+fn percent_sparkline(values: &[Option<u8>]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    values
+        .iter()
+        .map(|v| match v {
+            Some(percent) => BARS[usize::from(*percent.min(&100)) * (BARS.len() - 1) / 100],
+            None => '·',
+        })
+        .collect()
+}
+
+/// List row for a single runner.
+fn runner_item(
+    runner: &Runner,
+    job: Option<&RunnerJob>,
+    utilization: &RunnerUtilization,
+    star: &str,
+    filter: Option<&str>,
+) -> ListItem<'static> {
+    let (status_icon, status_color) = match runner.status {
+        RunnerStatus::Online => ("🟢", Color::Green),
+        RunnerStatus::Offline => ("⚫", Color::DarkGray),
+        RunnerStatus::Unknown => ("❓", Color::Gray),
+    };
+
+    let busy_indicator = if runner.busy { " (busy)" } else { "" };
+
+    let mut spans = vec![Span::raw(format!("{}{}", star, cell::icon(status_icon)))];
+    spans.extend(filter_spans(
+        &runner.name,
+        filter,
+        Style::default().fg(status_color),
+    ));
+    spans.extend([
+        Span::styled(busy_indicator, Style::default().fg(Color::Yellow)),
+        Span::styled(format!("  {}", runner.os), Style::default().fg(Color::Cyan)),
+        Span::styled(labels_summary(runner), Style::default().fg(Color::DarkGray)),
+    ]);
+    let now = Utc::now();
+    if let (Some(buckets), Some(percent)) = (
+        utilization.buckets(runner.id, now),
+        utilization.busy_percent(runner.id, now),
+    ) {
+        spans.push(Span::styled(
+            format!("  {} {}%", percent_sparkline(&buckets), percent),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(job) = job.filter(|_| runner.busy) {
+        let elapsed = job
+            .started_at
+            .map(|start| format!(" {}", format_until(Utc::now() - start)))
+            .unwrap_or_default();
+        spans.push(Span::styled(
+            format!(
+                "  ▶ {} #{} › {}{}",
+                job.workflow, job.run_number, job.job_name, elapsed
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+/// List row for an ARC scale set, summarizing its ephemeral runners.
+fn scale_set_item(
+    name: &str,
+    runners: &[Runner],
+    star: &str,
+    filter: Option<&str>,
+) -> ListItem<'static> {
+    let busy = runners.iter().filter(|r| r.busy).count();
+    let offline = runners
+        .iter()
+        .filter(|r| r.status != RunnerStatus::Online)
+        .count();
+    let color = if offline == runners.len() {
+        Color::DarkGray
+    } else {
+        Color::Green
+    };
+
+    let mut spans = vec![Span::raw(format!("{}{}", star, cell::icon("⎈")))];
+    spans.extend(filter_spans(name, filter, Style::default().fg(color)));
+    spans.push(Span::styled(
+        format!("  {} runners", runners.len()),
+        Style::default().fg(Color::Cyan),
+    ));
+    if busy > 0 {
+        spans.push(Span::styled(
+            format!(" · {} busy", busy),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if offline > 0 {
+        spans.push(Span::styled(
+            format!(" · {} offline", offline),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if let Some(runner) = runners.first() {
+        spans.push(Span::styled(
+            labels_summary(runner),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+/// First few labels of a runner (e.g., "  [self-hosted, linux, x64]").
+fn labels_summary(runner: &Runner) -> String {
+    let labels: Vec<&str> = runner
+        .labels
+        .iter()
+        .take(3)
+        .map(|l| l.name.as_str())
+        .collect();
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("  [{}]", labels.join(", "))
+    }
+}
@@ -0,0 +1,323 @@
+// Log viewer widget shared by the Workflows and Runners tabs.
+// Draws a job's log with line numbers, search matches, bookmarks, timestamps,
+// and highlight rules, or the job's state while there's no log to show.
+
+use ratatui::{prelude::*, widgets::*};
+
+use crate::app::{App, Tab};
+use crate::error::ViewError;
+use crate::github::{Job, RunConclusion, RunStatus};
+use crate::state::log_time::{self, LineElapsed, TimestampMode, format_elapsed};
+use crate::state::log_view::LogViewState;
+use crate::state::log_viewer::LogViewerState;
+use crate::state::{LoadingState, SelectableList};
+
+/// Job whose log is open.
+#[derive(Debug, Clone, Copy)]
+pub struct LogJob {
+    pub id: u64,
+    pub status: RunStatus,
+    pub conclusion: Option<RunConclusion>,
+}
+
+/// A tab's log viewer.
+pub struct LogViewer<'a> {
+    /// Log and scroll position to show.
+    pub state: &'a LogViewerState,
+    /// Jobs of the run, for the in-progress job's steps and elapsed times.
+    pub jobs: &'a SelectableList<Job>,
+    /// Job the log belongs to; None for logs that aren't a job's.
+    pub job: Option<LogJob>,
+    /// Tab the viewer is in, for the progress of a streaming log.
+    pub tab: Tab,
+}
+
+impl LogViewer<'_> {
+    /// Draw the log, with the search input below it while searching.
+    /// Returns where the log's text was drawn, if it's loaded.
+    pub fn render(&self, frame: &mut Frame, app: &App, area: Rect) -> Option<Rect> {
+        let (log_area, search_area) = if app.search_active {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .split(area);
+            (chunks[0], Some(chunks[1]))
+        } else {
+            (area, None)
+        };
+
+        let block = Block::default().borders(Borders::ALL).title(" Logs ");
+        let inner = block.inner(log_area);
+        let mut text_area = None;
+        match &self.state.content {
+            LoadingState::Idle => {
+                let text = Paragraph::new("Press Enter to load logs")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(Color::DarkGray))
+                    .block(block);
+                frame.render_widget(text, log_area);
+            }
+            LoadingState::Loading => {
+                let text = Paragraph::new("⏳ Loading logs...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(Color::Yellow))
+                    .block(block);
+                frame.render_widget(text, log_area);
+            }
+            LoadingState::Error(e) => {
+                let text = match self.job {
+                    Some(job)
+                        if job.status == RunStatus::InProgress
+                            && job.conclusion != Some(RunConclusion::Skipped) =>
+                    {
+                        // Left-aligned for the steps list
+                        Paragraph::new(self.in_progress_lines(job.id))
+                    }
+                    _ => Paragraph::new(self.unavailable_lines(e)).alignment(Alignment::Center),
+                };
+                frame.render_widget(text.block(block), log_area);
+            }
+            LoadingState::Loaded(logs) => {
+                self.render_log(frame, app, logs, log_area);
+                text_area = Some(inner);
+            }
+        }
+
+        if let Some(search_area) = search_area {
+            let search_line = Line::from(vec![
+                Span::styled("/", Style::default().fg(Color::Yellow)),
+                Span::raw(&app.search_query),
+                Span::styled("█", Style::default().fg(Color::Yellow)),
+            ]);
+            let search_widget =
+                Paragraph::new(search_line).style(Style::default().bg(Color::DarkGray));
+            frame.render_widget(search_widget, search_area);
+        }
+        text_area
+    }
+
+    /// Why there's no log: the job was skipped or hasn't started, or the error.
+    fn unavailable_lines(&self, error: &ViewError) -> Vec<Line<'static>> {
+        let conclusion = self.job.and_then(|job| job.conclusion);
+        let status = self.job.map(|job| job.status);
+        let (message, hint) = if conclusion == Some(RunConclusion::Skipped) {
+            (
+                Span::styled("⏭️  This job was skipped", Style::default().fg(Color::Gray)),
+                None,
+            )
+        } else if matches!(
+            status,
+            Some(RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending)
+        ) {
+            (
+                Span::styled(
+                    "⏳ This job is queued and waiting to run",
+                    Style::default().fg(Color::Blue),
+                ),
+                None,
+            )
+        } else {
+            (
+                Span::styled(format!("❌ {}", error), Style::default().fg(Color::Red)),
+                error.hint(chrono::Utc::now()),
+            )
+        };
+        vec![
+            Line::from(message),
+            Line::from(""),
+            Line::from(Span::styled(
+                hint.unwrap_or_else(|| "Press 'o' to view in browser".to_string()),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ]
+    }
+
+    /// The in-progress job's steps, in place of a log GitHub serves only
+    /// once the job completes.
+    fn in_progress_lines(&self, job_id: u64) -> Vec<Line<'_>> {
+        let mut lines = vec![
+            Line::from(Span::styled(
+                "🔄 This job is in progress",
+                Style::default().fg(Color::Yellow),
+            )),
+            Line::from(""),
+        ];
+        if let Some(job) = self.find_job(Some(job_id)) {
+            lines.push(Line::from(Span::styled(
+                "Steps:",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for step in &job.steps {
+                let (icon, color) = match (&step.status, &step.conclusion) {
+                    (_, Some(RunConclusion::Success)) => ("✅", Color::Green),
+                    (_, Some(RunConclusion::Failure)) => ("❌", Color::Red),
+                    (_, Some(RunConclusion::Skipped)) => ("⏭️", Color::Gray),
+                    (RunStatus::InProgress, _) => ("🔄", Color::Yellow),
+                    (RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending, _) => {
+                        ("⏳", Color::Blue)
+                    }
+                    _ => ("⚪", Color::DarkGray),
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(format!("  {} ", icon)),
+                    Span::styled(&step.name, Style::default().fg(color)),
+                ]));
+            }
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Press 'o' to view in browser",
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines
+    }
+
+    /// Draw a loaded log with line numbers and search matches highlighted.
+    fn render_log(&self, frame: &mut Frame, app: &App, logs: &str, area: Rect) {
+        let line_count = logs.lines().count();
+        let scroll_y = self.state.scroll_y as usize;
+        let last_shown = (scroll_y + area.height.saturating_sub(2) as usize).min(line_count);
+
+        // Title with line info and search match count
+        let mut title = format!(" Logs [{}-{}/{}] ", scroll_y + 1, last_shown, line_count);
+        if !app.search_matches.is_empty() {
+            title = format!(
+                "{}Match {}/{} ",
+                title,
+                app.search_match_index + 1,
+                app.search_matches.len()
+            );
+        }
+        title = format!("{}· {} ", title, app.log_timestamps.label());
+        if let Some(progress) = app.log_stream_progress(self.tab) {
+            title = format!("{}· ⏳ {} ", title, progress);
+        }
+        let block = Block::default().borders(Borders::ALL).title(title);
+        let elapsed = self.elapsed_times(app, logs);
+
+        // Add line numbers and highlight matching lines, for only the lines in
+        // view since highlight rules run on each one
+        let query_lower = app.search_query.to_lowercase();
+        let bookmarks = app.log_bookmarks();
+        // The gutter is in the log's text area, whose glyphs ASCII mode keeps
+        let marker = if app.term_caps.ascii { '*' } else { '●' };
+        let numbered_lines: Vec<Line> = logs
+            .lines()
+            .enumerate()
+            .skip(scroll_y)
+            .take(area.height.saturating_sub(2) as usize)
+            .map(|(i, line)| {
+                let is_match =
+                    !query_lower.is_empty() && line.to_lowercase().contains(&query_lower);
+                let is_current_match = app.search_matches.get(app.search_match_index) == Some(&i);
+
+                let line_style = if is_current_match {
+                    Style::default().bg(Color::Yellow).fg(Color::Black)
+                } else if is_match {
+                    Style::default().bg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+
+                let (stamp, text) =
+                    log_line_text(line, app.log_timestamps, elapsed.get(i).copied().flatten());
+                let mut spans = vec![log_gutter(i + 1, bookmarks, i, marker)];
+                spans.extend(stamp);
+                spans.extend(highlighted_log_text(app, text, line_style));
+                Line::from(spans)
+            })
+            .collect();
+
+        let text = Paragraph::new(numbered_lines)
+            .block(block)
+            .scroll((0, self.state.scroll_x));
+        frame.render_widget(text, area);
+    }
+
+    /// The job among the run's loaded jobs.
+    fn find_job(&self, job_id: Option<u64>) -> Option<&Job> {
+        self.jobs
+            .data
+            .data()
+            .and_then(|data| data.items.iter().find(|job| Some(job.id) == job_id))
+    }
+
+    /// Elapsed times of the log's lines within their steps, if shown.
+    fn elapsed_times(&self, app: &App, logs: &str) -> Vec<Option<LineElapsed>> {
+        if app.log_timestamps != TimestampMode::Elapsed {
+            return Vec::new();
+        }
+        let steps = self
+            .find_job(self.job.map(|job| job.id))
+            .map(|job| job.steps.as_slice())
+            .unwrap_or_default();
+        log_time::elapsed_times(logs, steps)
+    }
+}
+
+/// Line number column of a log line, marking bookmarked lines with `marker`.
+fn log_gutter(
+    line_num: usize,
+    bookmarks: Option<&LogViewState>,
+    index: usize,
+    marker: char,
+) -> Span<'static> {
+    if bookmarks.is_some_and(|state| state.bookmarks.contains(&index)) {
+        Span::styled(
+            format!("{:>6} {} ", line_num, marker),
+            Style::default().fg(Color::Magenta),
+        )
+    } else {
+        Span::styled(
+            format!("{:>6} │ ", line_num),
+            Style::default().fg(Color::DarkGray),
+        )
+    }
+}
+
+/// Spans for a log line's text with the config's highlight rules applied.
+/// A leading timestamp is left out of matching so rules can anchor on `^`.
+fn highlighted_log_text<'a>(app: &App, text: &'a str, style: Style) -> Vec<Span<'a>> {
+    let (stamp, rest) = match log_time::split_timestamp(text) {
+        Some((_, rest)) => text.split_at(text.len() - rest.len()),
+        None => ("", text),
+    };
+    let mut spans = Vec::new();
+    if !stamp.is_empty() {
+        spans.push(Span::styled(stamp, style));
+    }
+    spans.extend(app.log_highlighter.spans(rest, style));
+    spans
+}
+
+/// A log line's timestamp column, if any, and the text shown after it.
+fn log_line_text(
+    line: &str,
+    mode: TimestampMode,
+    elapsed: Option<LineElapsed>,
+) -> (Option<Span<'static>>, &str) {
+    let text = log_time::split_timestamp(line).map_or(line, |(_, rest)| rest);
+    match mode {
+        TimestampMode::Shown => (None, line),
+        TimestampMode::Hidden => (None, text),
+        TimestampMode::Elapsed => {
+            let stamp = match elapsed {
+                Some(elapsed) => {
+                    let color = if elapsed.slow {
+                        Color::Yellow
+                    } else {
+                        Color::DarkGray
+                    };
+                    Span::styled(
+                        format!("{:>8} ", format_elapsed(elapsed.since_start)),
+                        Style::default().fg(color),
+                    )
+                }
+                None => Span::raw(" ".repeat(9)),
+            };
+            (Some(stamp), text)
+        }
+    }
+}
@@ -0,0 +1,288 @@
+// Markdown rendered as styled terminal lines.
+// Covers what job summaries commonly use: headings, lists, quotes, code blocks,
+// tables, and inline emphasis. HTML tags are dropped, keeping their text.
+
+use ratatui::prelude::*;
+
+use super::cell;
+
+/// Styled lines of a Markdown document.
+pub fn markdown_lines(markdown: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut table: Vec<Vec<String>> = Vec::new();
+    let mut in_code = false;
+    for raw in markdown.lines() {
+        let trimmed = raw.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            lines.push(Line::styled(
+                format!("  {}", raw),
+                Style::default().fg(Color::Yellow),
+            ));
+            continue;
+        }
+
+        let text = strip_html(trimmed);
+        if text.starts_with('|') {
+            if !is_table_separator(&text) {
+                table.push(table_cells(&text));
+            }
+            continue;
+        }
+        if !table.is_empty() {
+            lines.extend(table_lines(&std::mem::take(&mut table)));
+        }
+
+        let indent = " ".repeat(raw.len() - raw.trim_start().len());
+        let heading_level = text.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&heading_level) && text[heading_level..].starts_with(' ') {
+            let mut style = Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD);
+            if heading_level == 1 {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            lines.push(Line::from(inline_spans(
+                text[heading_level..].trim(),
+                style,
+            )));
+        } else if is_rule(&text) {
+            lines.push(Line::styled(
+                "─".repeat(40),
+                Style::default().fg(Color::DarkGray),
+            ));
+        } else if let Some(quote) = text.strip_prefix('>') {
+            let mut spans = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+            spans.extend(inline_spans(
+                quote.trim_start(),
+                Style::default().fg(Color::DarkGray),
+            ));
+            lines.push(Line::from(spans));
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| text.strip_prefix(bullet))
+        {
+            let mut spans = vec![Span::raw(format!("{}  • ", indent))];
+            spans.extend(inline_spans(item, Style::default()));
+            lines.push(Line::from(spans));
+        } else if text.is_empty() && !trimmed.is_empty() {
+            // A line of only HTML tags (e.g., "<details>") adds nothing
+        } else {
+            let mut spans = vec![Span::raw(indent)];
+            spans.extend(inline_spans(&text, Style::default()));
+            lines.push(Line::from(spans));
+        }
+    }
+    if !table.is_empty() {
+        lines.extend(table_lines(&table));
+    }
+    lines
+}
+
+/// Spans of a line's inline Markdown: **bold**, `code`, and links, which
+/// show their text.
+fn inline_spans(text: &str, base: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some((span, after)) = emphasis(rest, base)
+            .or_else(|| code(rest))
+            .or_else(|| link(rest, base))
+        {
+            if !plain.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut plain), base));
+            }
+            spans.push(span);
+            rest = after;
+        } else {
+            plain.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, base));
+    }
+    spans
+}
+
+/// Bold text starting `text`, and what follows it.
+fn emphasis(text: &str, base: Style) -> Option<(Span<'static>, &str)> {
+    let inner = text
+        .strip_prefix("**")
+        .or_else(|| text.strip_prefix("__"))?;
+    let end = inner.find(&text[..2])?;
+    let span = Span::styled(inner[..end].to_string(), base.add_modifier(Modifier::BOLD));
+    Some((span, &inner[end + 2..]))
+}
+
+/// Inline code starting `text`, and what follows it.
+fn code(text: &str) -> Option<(Span<'static>, &str)> {
+    let inner = text.strip_prefix('`')?;
+    let end = inner.find('`')?;
+    let span = Span::styled(inner[..end].to_string(), Style::default().fg(Color::Yellow));
+    Some((span, &inner[end + 1..]))
+}
+
+/// Link or image starting `text`, shown as its text, and what follows it.
+fn link(text: &str, base: Style) -> Option<(Span<'static>, &str)> {
+    let inner = text.strip_prefix("![").or_else(|| text.strip_prefix('['))?;
+    let (label, after) = inner.split_once("](")?;
+    let end = after.find(')')?;
+    let span = Span::styled(
+        label.to_string(),
+        base.fg(Color::Blue).add_modifier(Modifier::UNDERLINED),
+    );
+    Some((span, &after[end + 1..]))
+}
+
+/// Text with HTML tags removed and common entities decoded.
+fn strip_html(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        stripped.push_str(&rest[..start]);
+        let tag = &rest[start..];
+        // A '<' that doesn't open a tag (e.g., "a < b") is kept
+        let is_tag = tag[1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        match tag.find('>').filter(|_| is_tag) {
+            Some(end) => rest = &tag[end + 1..],
+            None => {
+                stripped.push('<');
+                rest = &tag[1..];
+            }
+        }
+    }
+    stripped.push_str(rest);
+    stripped
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+/// Whether a line is a thematic break (e.g., "---").
+fn is_rule(text: &str) -> bool {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&mark| compact.chars().all(|c| c == mark))
+}
+
+/// Whether a table row is the line under its header (e.g., "|---|:-:|").
+fn is_table_separator(row: &str) -> bool {
+    row.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+/// Cells of a table row, without the outer pipes.
+fn table_cells(row: &str) -> Vec<String> {
+    let row = row.trim_start_matches('|').trim_end_matches('|');
+    row.split('|').map(|cell| cell.trim().to_string()).collect()
+}
+
+/// A table's rows with their columns padded to line up, the header in bold.
+fn table_lines(rows: &[Vec<String>]) -> Vec<Line<'static>> {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    // Widths of the cells as shown, so with inline Markdown removed
+    let shown = |text: &str| -> String {
+        inline_spans(text, Style::default())
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    };
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|text| cell::width(&shown(text)))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    rows.iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let base = if row_index == 0 {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut spans = Vec::new();
+            for (i, width) in widths.iter().enumerate() {
+                let text = row.get(i).map_or("", String::as_str);
+                spans.push(Span::styled(
+                    if i == 0 { "  " } else { " │ " },
+                    Style::default().fg(Color::DarkGray),
+                ));
+                spans.extend(inline_spans(text, base));
+                let padding = width - cell::width(&shown(text));
+                spans.push(Span::raw(" ".repeat(padding)));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_markdown_lines() {
+        let markdown = "## Test results ✅\n\
+                        <details><summary>Coverage</summary>\n\
+                        \n\
+                        | Suite | Passed |\n\
+                        |:------|-------:|\n\
+                        | **unit** | 120 |\n\
+                        | e2e | 8 |\n\
+                        - See [the report](https://example.com) for `flaky` tests\n\
+                        ```\n\
+                        cargo test\n\
+                        ```\n\
+                        </details>";
+        let lines = markdown_lines(markdown);
+        assert_eq!(
+            text(&lines),
+            [
+                "Test results ✅",
+                "Coverage",
+                "",
+                "  Suite │ Passed",
+                "  unit  │ 120   ",
+                "  e2e   │ 8     ",
+                "  • See the report for flaky tests",
+                "  cargo test",
+            ]
+        );
+        assert!(
+            lines[0].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+        assert!(
+            lines[4].spans[1]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+    }
+}
@@ -2,17 +2,65 @@
 // Contains widgets for tabs, breadcrumbs, lists, and log viewer.
 
 mod breadcrumb;
+pub mod caps;
+mod cell;
+pub mod highlight;
 mod list;
+mod log_viewer;
+mod markdown;
+mod settings;
 mod tabs;
 
+#[cfg(test)]
+mod snapshot;
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
 use ratatui::{prelude::*, widgets::*};
 
-use crate::app::{App, ConsoleLevel, Tab};
-use crate::github::{RunConclusion, RunStatus};
-use crate::state::{LoadingState, RunnersViewLevel, ViewLevel};
+use crate::app::{App, ConsoleKind, ConsoleLevel, PendingBulk, PendingRerun, Tab};
+use crate::bulk::BulkAction;
+use crate::cache::{self, stats::CacheCounts};
+use crate::changelog::{CURRENT_VERSION, Feature, Release};
+use crate::clipboard::CopyMode;
+use crate::config::Theme;
+use crate::export::ExportFormat;
+use crate::github::timing::{EndpointTiming, SLOW_THRESHOLD};
+use crate::github::{GitHubClient, RunConclusion, RunStatus, budget};
+use crate::keymap::Action;
+use crate::logging;
+use crate::state::branches::BranchPicker;
+use crate::state::caches::{CACHE_LIMIT_BYTES, CachesView, format_size};
+use crate::state::checks::{ChecksView, is_actions_check};
+use crate::state::concurrency::RunQueue;
+use crate::state::flaky::FlakyReport;
+use crate::state::history::success_rate;
+use crate::state::ignore::ignored_count;
+use crate::state::job_summary::JobSummaryView;
+use crate::state::log_archive::LogArchiveView;
+use crate::state::policy::PolicyView;
+use crate::state::schedule::{UpcomingRun, format_until};
+use crate::state::secrets::SecretsView;
+use crate::state::usage::DayUsage;
+use crate::state::{RunnerRow, RunnersViewLevel, ViewLevel};
+
+use caps::LogText;
+use log_viewer::{LogJob, LogViewer};
+
+/// Number of recent runs counted for the workflow success rate.
+const SUCCESS_RATE_RUNS: usize = 50;
+
+/// Endpoints listed in the Console's request timings, slowest first.
+const CONSOLE_ENDPOINTS: usize = 8;
+
+/// Projected time until the rate limit runs out that's shown in red.
+const RATE_WARNING: Duration = Duration::from_secs(10 * 60);
 
 /// Main draw function that renders the entire UI.
 pub fn draw(frame: &mut Frame, app: &mut App) {
+    app.log_text_areas.clear();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -30,13 +78,13 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     match app.active_tab {
         Tab::Workflows => {
             let breadcrumbs = app.workflows.nav.breadcrumbs();
-            breadcrumb::draw_breadcrumb(frame, &breadcrumbs, chunks[1]);
+            breadcrumb::draw_breadcrumb(frame, &breadcrumbs, success_rate_badge(app), chunks[1]);
         }
         Tab::Runners => {
             let breadcrumbs = app.runners.nav.breadcrumbs();
             breadcrumb::draw_runners_breadcrumb(frame, &breadcrumbs, chunks[1]);
         }
-        Tab::Console => {
+        Tab::Home | Tab::Inbox | Tab::Live | Tab::Console => {
             let block = Block::default()
                 .borders(Borders::BOTTOM)
                 .border_style(Style::default().fg(Color::DarkGray));
@@ -50,17 +98,171 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     // Status bar
     draw_status_bar(frame, app, chunks[3]);
 
-    // Help overlay (rendered last, on top of everything)
+    // Log text keeps its glyphs in ASCII mode, where no overlay covers it
+    let mut log_text = LogText::default();
+    if app.term_caps.ascii {
+        for area in &app.log_text_areas {
+            log_text.capture(frame.buffer_mut(), *area);
+        }
+    }
+
+    // Overlays (rendered last, on top of everything)
+    if app.show_path {
+        let labels: Vec<String> = match app.active_tab {
+            Tab::Workflows => app
+                .workflows
+                .nav
+                .breadcrumbs()
+                .into_iter()
+                .map(|node| node.label)
+                .collect(),
+            Tab::Runners => app
+                .runners
+                .nav
+                .breadcrumbs()
+                .into_iter()
+                .map(|node| node.label)
+                .collect(),
+            Tab::Home | Tab::Inbox | Tab::Live | Tab::Console => Vec::new(),
+        };
+        breadcrumb::draw_path_popup(frame, &labels, app.path_selected);
+    }
+    if app.show_recent {
+        draw_recent_picker(frame, app);
+    }
+    if app.show_workspaces {
+        draw_workspace_picker(frame, app);
+    }
+    if let Some(input) = &app.permalink_input {
+        draw_permalink_input(frame, input);
+    }
+    if app.show_copy_menu {
+        draw_copy_menu(frame);
+    }
+    if app.show_export_menu {
+        draw_export_menu(frame);
+    }
+    if let Some(rerun) = &app.pending_rerun {
+        draw_rerun_confirm(frame, rerun);
+    }
+    if app.show_bulk_menu {
+        draw_bulk_menu(frame, app);
+    }
+    if let Some(bulk) = &app.pending_bulk {
+        draw_bulk_confirm(frame, bulk);
+    }
+    if let Some(releases) = &app.whats_new {
+        draw_whats_new(frame, releases);
+    } else if app.crashed_session.is_some() {
+        draw_restore_offer(frame);
+    } else if let Some((owner, repo)) = &app.repo_offer {
+        draw_repo_offer(frame, owner, repo);
+    }
+    if let Some(report) = &app.flaky_report {
+        draw_flaky_report(frame, report);
+    }
+    if let Some(queue) = &app.run_queue {
+        draw_run_queue(frame, queue);
+    }
+    if let Some(view) = &app.policy_view {
+        draw_policy_popup(frame, view);
+    }
+    if let Some(upcoming) = &app.schedule_overview {
+        draw_schedule_overview(frame, upcoming);
+    }
+    let theme = app.config.theme;
+    if let Some(view) = app.caches_view.as_mut() {
+        draw_caches_popup(frame, view, theme);
+    }
+    if let Some(view) = app.checks_view.as_mut() {
+        draw_checks_popup(frame, view, theme);
+    }
+    if let Some(view) = app.log_archive.as_mut() {
+        let text_area = draw_log_archive_popup(frame, view, theme);
+        if let Some(area) = text_area.filter(|_| app.term_caps.ascii) {
+            log_text.capture(frame.buffer_mut(), area);
+        }
+    }
+    if let Some(view) = app.job_summary.as_mut() {
+        draw_job_summary_popup(frame, view);
+    }
+    if let Some(view) = app.secrets_view.as_mut() {
+        draw_secrets_popup(frame, view, theme);
+    }
+    let current_branch = app.current_branch().map(String::from);
+    if let Some(picker) = app.branch_picker.as_mut() {
+        draw_branch_picker(frame, picker, current_branch.as_deref(), theme);
+    }
+    if app.show_settings {
+        settings::draw_settings_overlay(frame, app);
+    }
+    if app.show_debug_log {
+        draw_debug_log(frame);
+    }
     if app.show_help {
-        draw_help_overlay(frame);
+        draw_help_overlay(frame, &mut app.help_scroll);
+    }
+
+    app.term_caps.degrade(frame.buffer_mut(), &log_text);
+}
+
+/// Recent success rate of the current workflow, shown while inside its runs.
+fn success_rate_badge(app: &App) -> Option<Span<'static>> {
+    if matches!(
+        app.workflows.nav.current(),
+        ViewLevel::Owners
+            | ViewLevel::Repositories { .. }
+            | ViewLevel::Workflows { .. }
+            | ViewLevel::Environments { .. }
+            | ViewLevel::Deployments { .. }
+    ) {
+        return None;
     }
+    let runs = app.workflows.runs.data.data()?;
+    let (percent, count) = success_rate(&runs.items, SUCCESS_RATE_RUNS)?;
+    let color = if percent >= 90 {
+        Color::Green
+    } else if percent >= 70 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
+    Some(Span::styled(
+        format!("{}% last {} runs ", percent, count),
+        Style::default().fg(color),
+    ))
 }
 
 /// Draw the main content area based on active tab.
 fn draw_content(frame: &mut Frame, app: &mut App, area: Rect) {
+    // Split area for the list filter if one is set
+    let area = match app.list_filter() {
+        Some(query) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .split(area);
+            let mut spans = vec![
+                Span::styled("filter: ", Style::default().fg(Color::Yellow)),
+                Span::raw(query.to_string()),
+            ];
+            if app.filter_active {
+                spans.push(Span::styled("█", Style::default().fg(Color::Yellow)));
+            }
+            let filter_widget =
+                Paragraph::new(Line::from(spans)).style(Style::default().bg(Color::DarkGray));
+            frame.render_widget(filter_widget, chunks[1]);
+            chunks[0]
+        }
+        None => area,
+    };
+
     match app.active_tab {
         Tab::Runners => draw_runners_tab(frame, app, area),
         Tab::Workflows => draw_workflows_tab(frame, app, area),
+        Tab::Inbox => list::render_inbox_list(frame, &mut app.inbox, app.config.theme, area),
+        Tab::Live => list::render_live_list(frame, &mut app.live, app.config.theme, area),
+        Tab::Home => list::render_home_list(frame, &mut app.home, app.config.theme, area),
         Tab::Console => draw_console_tab(frame, app, area),
     }
 }
@@ -73,6 +275,8 @@ fn draw_runners_tab(frame: &mut Frame, app: &mut App, area: Rect) {
                 frame,
                 &mut app.runners.repositories,
                 &app.favorite_repos,
+                app.hidden_runner_repos.len(),
+                app.config.theme,
                 area,
             );
         }
@@ -80,250 +284,189 @@ fn draw_runners_tab(frame: &mut Frame, app: &mut App, area: Rect) {
             ref owner,
             ref repo,
         } => {
+            // Churn is only worth the space when the fleet has been changing
+            let area = if app.runners.churn.is_empty() {
+                area
+            } else {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Min(1),
+                        Constraint::Length(app.runners.churn.len().min(5) as u16 + 2),
+                    ])
+                    .split(area);
+                list::render_runner_churn(frame, &app.runners.churn, chunks[1]);
+                chunks[0]
+            };
+            let queue_times = app.runners.queue_times.as_deref().unwrap_or_default();
+            let area = if queue_times.is_empty() {
+                area
+            } else {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Min(1),
+                        Constraint::Length(queue_times.len().min(5) as u16 + 2),
+                    ])
+                    .split(area);
+                list::render_queue_times(frame, queue_times, chunks[1]);
+                chunks[0]
+            };
+            // Local health checks for the selected runner, if it's installed here
+            let health = match app.runners.runners.selected_item() {
+                Some(RunnerRow::Runner(runner)) => app.runners.health.get(&runner.name).cloned(),
+                _ => None,
+            };
+            let area = match health {
+                Some(health) => {
+                    let chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Min(1), Constraint::Length(5)])
+                        .split(area);
+                    list::render_runner_health(frame, &health, chunks[1]);
+                    chunks[0]
+                }
+                None => area,
+            };
+            if app.runners.lacks_runner_access() {
+                list::render_runner_access_needed(frame, &format!("{}/{}", owner, repo), area);
+                return;
+            }
             list::render_runners_list(
                 frame,
                 &mut app.runners.runners,
                 &app.favorite_runners,
-                owner,
-                repo,
+                &format!("{}/{}", owner, repo),
+                &app.runners.runner_jobs,
+                &app.runner_utilization,
+                app.config.theme,
                 area,
             );
         }
         RunnersViewLevel::Runs { .. } => {
-            list::render_runs_list(frame, &mut app.runners.runs, area);
+            let ignored = ignored_count(&app.config.ignore_runs, &app.runners.runs);
+            list::render_runs_list(
+                frame,
+                &mut app.runners.runs,
+                list::RunsListOptions {
+                    mine_only: app.my_runs_only,
+                    branch: app.runners.current_branch.as_deref(),
+                    grouped: app.group_runs_by_event.then_some(&app.collapsed_events),
+                    ignored,
+                },
+                &app.debug_runs,
+                app.config.theme,
+                area,
+            );
         }
         RunnersViewLevel::Jobs { .. } => {
-            list::render_jobs_list(frame, &mut app.runners.jobs, area);
+            list::render_jobs_list(
+                frame,
+                &mut app.runners.jobs,
+                list::JobsListOptions {
+                    grouped: app.group_jobs_by_matrix.then_some(&app.collapsed_matrix),
+                    failed_only: app.failed_variants_only,
+                },
+                &app.runners.job_history,
+                &app.runners.flaky_jobs,
+                app.config.theme,
+                area,
+            );
         }
-        RunnersViewLevel::Logs { .. } => {
-            draw_runners_log_viewer(frame, app, area);
+        RunnersViewLevel::Logs {
+            job_id,
+            job_status,
+            job_conclusion,
+            ..
+        } => {
+            let job = LogJob {
+                id: job_id,
+                status: job_status,
+                conclusion: job_conclusion,
+            };
+            let text_area = LogViewer {
+                state: &app.runners.log,
+                jobs: &app.runners.jobs,
+                job: Some(job),
+                tab: Tab::Runners,
+            }
+            .render(frame, app, area);
+            app.log_text_areas.extend(text_area);
         }
-    }
-}
-
-/// Draw the log viewer for the Runners tab.
-fn draw_runners_log_viewer(frame: &mut Frame, app: &App, area: Rect) {
-    // Split area for search input if active
-    let (log_area, search_area) = if app.search_active {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(1)])
-            .split(area);
-        (chunks[0], Some(chunks[1]))
-    } else {
-        (area, None)
-    };
-
-    match &app.runners.log_content {
-        LoadingState::Idle => {
-            let block = Block::default().borders(Borders::ALL).title(" Logs ");
-            let text = Paragraph::new("Press Enter to load logs")
-                .alignment(Alignment::Center)
-                .style(Style::default().fg(Color::DarkGray))
-                .block(block);
-            frame.render_widget(text, log_area);
+        RunnersViewLevel::DiagLogs { .. } => {
+            let text_area = LogViewer {
+                state: &app.runners.log,
+                jobs: &app.runners.jobs,
+                job: None,
+                tab: Tab::Runners,
+            }
+            .render(frame, app, area);
+            app.log_text_areas.extend(text_area);
         }
-        LoadingState::Loading => {
-            let block = Block::default().borders(Borders::ALL).title(" Logs ");
-            let text = Paragraph::new("⏳ Loading logs...")
-                .alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow))
-                .block(block);
-            frame.render_widget(text, log_area);
+        RunnersViewLevel::RunnerGroups { .. } => {
+            list::render_runner_groups_list(
+                frame,
+                &mut app.runners.runner_groups,
+                app.config.theme,
+                area,
+            );
         }
-        LoadingState::Error(e) => {
-            let block = Block::default().borders(Borders::ALL).title(" Logs ");
-            // Check job status/conclusion for special states
-            let (is_skipped, is_waiting, is_in_progress, job_id) = match app.runners.nav.current() {
-                RunnersViewLevel::Logs {
-                    job_status,
-                    job_conclusion,
-                    job_id,
-                    ..
-                } => (
-                    matches!(job_conclusion, Some(RunConclusion::Skipped)),
-                    matches!(
-                        job_status,
-                        RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending
-                    ),
-                    matches!(job_status, RunStatus::InProgress),
-                    Some(*job_id),
-                ),
-                _ => (false, false, false, None),
-            };
-            let lines = if is_skipped {
-                vec![
-                    Line::from(Span::styled(
-                        "⏭️  This job was skipped",
-                        Style::default().fg(Color::Gray),
-                    )),
-                    Line::from(""),
-                    Line::from(Span::styled(
-                        "Press 'o' to view in browser",
-                        Style::default().fg(Color::DarkGray),
-                    )),
-                ]
-            } else if is_waiting {
-                vec![
-                    Line::from(Span::styled(
-                        "⏳ This job is queued and waiting to run",
-                        Style::default().fg(Color::Blue),
-                    )),
-                    Line::from(""),
-                    Line::from(Span::styled(
-                        "Press 'o' to view in browser",
-                        Style::default().fg(Color::DarkGray),
-                    )),
-                ]
-            } else if is_in_progress {
-                // Look up job to get steps
-                let job = job_id.and_then(|id| {
-                    app.runners
-                        .jobs
-                        .data
-                        .data()
-                        .and_then(|data| data.items.iter().find(|j| j.id == id))
-                });
-                let mut lines = vec![
-                    Line::from(Span::styled(
-                        "🔄 This job is in progress",
-                        Style::default().fg(Color::Yellow),
-                    )),
-                    Line::from(""),
-                ];
-                if let Some(job) = job {
-                    lines.push(Line::from(Span::styled(
-                        "Steps:",
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    )));
-                    for step in &job.steps {
-                        let (icon, color) = match (&step.status, &step.conclusion) {
-                            (_, Some(RunConclusion::Success)) => ("✅", Color::Green),
-                            (_, Some(RunConclusion::Failure)) => ("❌", Color::Red),
-                            (_, Some(RunConclusion::Skipped)) => ("⏭️", Color::Gray),
-                            (RunStatus::InProgress, _) => ("🔄", Color::Yellow),
-                            (RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending, _) => {
-                                ("⏳", Color::Blue)
-                            }
-                            _ => ("⚪", Color::DarkGray),
-                        };
-                        lines.push(Line::from(vec![
-                            Span::raw(format!("  {} ", icon)),
-                            Span::styled(&step.name, Style::default().fg(color)),
-                        ]));
-                    }
-                }
-                lines.push(Line::from(""));
-                lines.push(Line::from(Span::styled(
-                    "Press 'o' to view in browser",
-                    Style::default().fg(Color::DarkGray),
-                )));
-                // Render left-aligned for steps list
-                let text = Paragraph::new(lines).block(block);
-                frame.render_widget(text, log_area);
-                // Render search input if active and return early
-                if let Some(search_area) = search_area {
-                    let search_line = Line::from(vec![
-                        Span::styled("/", Style::default().fg(Color::Yellow)),
-                        Span::raw(&app.search_query),
-                        Span::styled("█", Style::default().fg(Color::Yellow)),
-                    ]);
-                    let search_widget =
-                        Paragraph::new(search_line).style(Style::default().bg(Color::DarkGray));
-                    frame.render_widget(search_widget, search_area);
-                }
-                return;
+        RunnersViewLevel::GroupRunners {
+            ref org,
+            ref group_name,
+            ..
+        } => {
+            let area = if app.runners.group_repos.is_empty() {
+                area
             } else {
-                vec![
-                    Line::from(Span::styled(
-                        format!("❌ {}", e),
-                        Style::default().fg(Color::Red),
-                    )),
-                    Line::from(""),
-                    Line::from(Span::styled(
-                        "Press 'o' to view in browser",
-                        Style::default().fg(Color::DarkGray),
-                    )),
-                ]
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(1), Constraint::Length(4)])
+                    .split(area);
+                list::render_group_repos(frame, &app.runners.group_repos, chunks[1]);
+                chunks[0]
             };
-            let text = Paragraph::new(lines)
-                .alignment(Alignment::Center)
-                .block(block);
-            frame.render_widget(text, log_area);
+            list::render_runners_list(
+                frame,
+                &mut app.runners.group_runners,
+                &app.favorite_runners,
+                &format!("{}/{}", org, group_name),
+                &HashMap::new(),
+                &app.runner_utilization,
+                app.config.theme,
+                area,
+            );
         }
-        LoadingState::Loaded(logs) => {
-            let line_count = logs.lines().count();
-            let scroll_y = app.runners.log_scroll_y as usize;
-
-            // Build title with line info and search match count
-            let title = if !app.search_matches.is_empty() {
-                format!(
-                    " Logs [{}-{}/{}] Match {}/{} ",
-                    scroll_y + 1,
-                    (scroll_y + log_area.height.saturating_sub(2) as usize).min(line_count),
-                    line_count,
-                    app.search_match_index + 1,
-                    app.search_matches.len()
-                )
-            } else {
-                format!(
-                    " Logs [{}-{}/{}] ",
-                    scroll_y + 1,
-                    (scroll_y + log_area.height.saturating_sub(2) as usize).min(line_count),
-                    line_count
-                )
-            };
+    }
+}
 
-            let block = Block::default().borders(Borders::ALL).title(title);
-
-            // Add line numbers and highlight matching lines
-            let query_lower = app.search_query.to_lowercase();
-            let numbered_lines: Vec<Line> = logs
-                .lines()
-                .enumerate()
-                .map(|(i, line)| {
-                    let line_num = i + 1;
-                    let is_match =
-                        !query_lower.is_empty() && line.to_lowercase().contains(&query_lower);
-                    let is_current_match =
-                        app.search_matches.get(app.search_match_index) == Some(&i);
-
-                    let line_style = if is_current_match {
-                        Style::default().bg(Color::Yellow).fg(Color::Black)
-                    } else if is_match {
-                        Style::default().bg(Color::DarkGray)
-                    } else {
-                        Style::default()
-                    };
-
-                    Line::from(vec![
-                        Span::styled(
-                            format!("{:>6} │ ", line_num),
-                            Style::default().fg(Color::DarkGray),
-                        ),
-                        Span::styled(line, line_style),
-                    ])
-                })
-                .collect();
+/// Draw the preview pane for the run selected in the Runs list.
+fn draw_run_preview(frame: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(1)])
+        .split(area);
 
-            let text = Paragraph::new(numbered_lines)
-                .block(block)
-                .scroll((app.runners.log_scroll_y, app.runners.log_scroll_x));
-            frame.render_widget(text, log_area);
-        }
-    }
+    let selected = app.workflows.runs.selected_item();
+    list::render_run_summary(frame, selected, chunks[0]);
 
-    // Render search input if active
-    if let Some(search_area) = search_area {
-        let search_line = Line::from(vec![
-            Span::styled("/", Style::default().fg(Color::Yellow)),
-            Span::raw(&app.search_query),
-            Span::styled("█", Style::default().fg(Color::Yellow)),
-        ]);
-        let search_widget = Paragraph::new(search_line).style(Style::default().bg(Color::DarkGray));
-        frame.render_widget(search_widget, search_area);
+    // Jobs load once the selection settles; until then show what's pending
+    let run_id = selected.map(|run| run.id);
+    if run_id.is_some() && run_id == app.workflows.preview_run_id {
+        list::render_jobs_list(
+            frame,
+            &mut app.workflows.preview_jobs,
+            list::JobsListOptions {
+                grouped: None,
+                failed_only: false,
+            },
+            &app.workflows.job_history,
+            &HashSet::new(),
+            app.config.theme,
+            chunks[1],
+        );
+    } else if run_id.is_some() {
+        list::render_loading(frame, chunks[1], "Loading jobs");
     }
 }
 
@@ -331,7 +474,13 @@ fn draw_runners_log_viewer(frame: &mut Frame, app: &App, area: Rect) {
 fn draw_workflows_tab(frame: &mut Frame, app: &mut App, area: Rect) {
     match app.workflows.nav.current().clone() {
         ViewLevel::Owners => {
-            list::render_owners_list(frame, &mut app.workflows.owners, &app.favorite_owners, area);
+            list::render_owners_list(
+                frame,
+                &mut app.workflows.owners,
+                &app.favorite_owners,
+                app.config.theme,
+                area,
+            );
         }
         ViewLevel::Repositories { ref owner } => {
             list::render_repositories_list(
@@ -339,6 +488,7 @@ fn draw_workflows_tab(frame: &mut Frame, app: &mut App, area: Rect) {
                 &mut app.workflows.repositories,
                 &app.favorite_repos,
                 owner,
+                app.config.theme,
                 area,
             );
         }
@@ -350,252 +500,213 @@ fn draw_workflows_tab(frame: &mut Frame, app: &mut App, area: Rect) {
                 frame,
                 &mut app.workflows.workflows,
                 &app.favorite_workflows,
-                owner,
-                repo,
+                list::WorkflowBadges {
+                    schedules: &app.workflows.schedules,
+                    latest_runs: &app.workflows.latest_runs,
+                },
+                &format!("{}/{}", owner, repo),
+                app.config.theme,
                 area,
             );
         }
+        ViewLevel::Runs { .. } if app.split_view => {
+            let panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            let ignored = ignored_count(&app.config.ignore_runs, &app.workflows.runs);
+            list::render_runs_list(
+                frame,
+                &mut app.workflows.runs,
+                list::RunsListOptions {
+                    mine_only: app.my_runs_only,
+                    branch: app.workflows.current_branch.as_deref(),
+                    grouped: app.group_runs_by_event.then_some(&app.collapsed_events),
+                    ignored,
+                },
+                &app.debug_runs,
+                app.config.theme,
+                panes[0],
+            );
+            draw_run_preview(frame, app, panes[1]);
+        }
         ViewLevel::Runs { .. } => {
-            list::render_runs_list(frame, &mut app.workflows.runs, area);
+            let ignored = ignored_count(&app.config.ignore_runs, &app.workflows.runs);
+            list::render_runs_list(
+                frame,
+                &mut app.workflows.runs,
+                list::RunsListOptions {
+                    mine_only: app.my_runs_only,
+                    branch: app.workflows.current_branch.as_deref(),
+                    grouped: app.group_runs_by_event.then_some(&app.collapsed_events),
+                    ignored,
+                },
+                &app.debug_runs,
+                app.config.theme,
+                area,
+            );
         }
         ViewLevel::Jobs { .. } => {
-            list::render_jobs_list(frame, &mut app.workflows.jobs, area);
+            list::render_jobs_list(
+                frame,
+                &mut app.workflows.jobs,
+                list::JobsListOptions {
+                    grouped: app.group_jobs_by_matrix.then_some(&app.collapsed_matrix),
+                    failed_only: app.failed_variants_only,
+                },
+                &app.workflows.job_history,
+                &app.workflows.flaky_jobs,
+                app.config.theme,
+                area,
+            );
         }
         ViewLevel::Logs { .. } => {
-            draw_log_viewer(frame, app, area);
+            let text_area = draw_log_viewer(frame, app, area);
+            app.log_text_areas.extend(text_area);
+        }
+        ViewLevel::Environments { .. } => {
+            list::render_environments_list(
+                frame,
+                &mut app.workflows.environments,
+                app.config.theme,
+                area,
+            );
+        }
+        ViewLevel::Deployments { .. } => {
+            list::render_deployments_list(
+                frame,
+                &mut app.workflows.deployments,
+                app.config.theme,
+                area,
+            );
         }
     }
 }
 
 /// Draw the log viewer.
-fn draw_log_viewer(frame: &mut Frame, app: &App, area: Rect) {
-    // Split area for search input if active
-    let (log_area, search_area) = if app.search_active {
+fn draw_log_viewer(frame: &mut Frame, app: &App, area: Rect) -> Option<Rect> {
+    // Annotations summarize most failures, so list them above the log
+    let annotation_count = app
+        .workflows
+        .annotations
+        .data
+        .data()
+        .map_or(0, |list| list.len());
+    let area = if annotation_count > 0 {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .constraints([
+                Constraint::Length(annotation_count.min(5) as u16 + 2),
+                Constraint::Min(1),
+            ])
             .split(area);
-        (chunks[0], Some(chunks[1]))
+        list::render_annotations_list(
+            frame,
+            &app.workflows.annotations,
+            app.workflows.annotations_focused,
+            app.config.theme,
+            chunks[0],
+        );
+        chunks[1]
     } else {
-        (area, None)
+        area
     };
 
-    match &app.workflows.log_content {
-        LoadingState::Idle => {
-            let block = Block::default().borders(Borders::ALL).title(" Logs ");
-            let text = Paragraph::new("Press Enter to load logs")
-                .alignment(Alignment::Center)
-                .style(Style::default().fg(Color::DarkGray))
-                .block(block);
-            frame.render_widget(text, log_area);
-        }
-        LoadingState::Loading => {
-            let block = Block::default().borders(Borders::ALL).title(" Logs ");
-            let text = Paragraph::new("⏳ Loading logs...")
-                .alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow))
-                .block(block);
-            frame.render_widget(text, log_area);
-        }
-        LoadingState::Error(e) => {
-            let block = Block::default().borders(Borders::ALL).title(" Logs ");
-            // Check job status/conclusion for special states
-            let (is_skipped, is_waiting, is_in_progress, job_id) = match app.workflows.nav.current()
-            {
-                ViewLevel::Logs {
-                    job_status,
-                    job_conclusion,
-                    job_id,
-                    ..
-                } => (
-                    matches!(job_conclusion, Some(RunConclusion::Skipped)),
-                    matches!(
-                        job_status,
-                        RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending
-                    ),
-                    matches!(job_status, RunStatus::InProgress),
-                    Some(*job_id),
-                ),
-                _ => (false, false, false, None),
-            };
-            let lines = if is_skipped {
-                vec![
-                    Line::from(Span::styled(
-                        "⏭️  This job was skipped",
-                        Style::default().fg(Color::Gray),
-                    )),
-                    Line::from(""),
-                    Line::from(Span::styled(
-                        "Press 'o' to view in browser",
-                        Style::default().fg(Color::DarkGray),
-                    )),
-                ]
-            } else if is_waiting {
-                vec![
-                    Line::from(Span::styled(
-                        "⏳ This job is queued and waiting to run",
-                        Style::default().fg(Color::Blue),
-                    )),
-                    Line::from(""),
-                    Line::from(Span::styled(
-                        "Press 'o' to view in browser",
-                        Style::default().fg(Color::DarkGray),
-                    )),
-                ]
-            } else if is_in_progress {
-                // Look up job to get steps
-                let job = job_id.and_then(|id| {
-                    app.workflows
-                        .jobs
-                        .data
-                        .data()
-                        .and_then(|data| data.items.iter().find(|j| j.id == id))
-                });
-                let mut lines = vec![
-                    Line::from(Span::styled(
-                        "🔄 This job is in progress",
-                        Style::default().fg(Color::Yellow),
-                    )),
-                    Line::from(""),
-                ];
-                if let Some(job) = job {
-                    lines.push(Line::from(Span::styled(
-                        "Steps:",
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    )));
-                    for step in &job.steps {
-                        let (icon, color) = match (&step.status, &step.conclusion) {
-                            (_, Some(RunConclusion::Success)) => ("✅", Color::Green),
-                            (_, Some(RunConclusion::Failure)) => ("❌", Color::Red),
-                            (_, Some(RunConclusion::Skipped)) => ("⏭️", Color::Gray),
-                            (RunStatus::InProgress, _) => ("🔄", Color::Yellow),
-                            (RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending, _) => {
-                                ("⏳", Color::Blue)
-                            }
-                            _ => ("⚪", Color::DarkGray),
-                        };
-                        lines.push(Line::from(vec![
-                            Span::raw(format!("  {} ", icon)),
-                            Span::styled(&step.name, Style::default().fg(color)),
-                        ]));
-                    }
-                }
-                lines.push(Line::from(""));
-                lines.push(Line::from(Span::styled(
-                    "Press 'o' to view in browser",
-                    Style::default().fg(Color::DarkGray),
-                )));
-                // Render left-aligned for steps list
-                let text = Paragraph::new(lines).block(block);
-                frame.render_widget(text, log_area);
-                // Render search input if active and return early
-                if let Some(search_area) = search_area {
-                    let search_line = Line::from(vec![
-                        Span::styled("/", Style::default().fg(Color::Yellow)),
-                        Span::raw(&app.search_query),
-                        Span::styled("█", Style::default().fg(Color::Yellow)),
-                    ]);
-                    let search_widget =
-                        Paragraph::new(search_line).style(Style::default().bg(Color::DarkGray));
-                    frame.render_widget(search_widget, search_area);
-                }
-                return;
-            } else {
-                vec![
-                    Line::from(Span::styled(
-                        format!("❌ {}", e),
-                        Style::default().fg(Color::Red),
-                    )),
-                    Line::from(""),
-                    Line::from(Span::styled(
-                        "Press 'o' to view in browser",
-                        Style::default().fg(Color::DarkGray),
-                    )),
-                ]
-            };
-            let text = Paragraph::new(lines)
-                .alignment(Alignment::Center)
-                .block(block);
-            frame.render_widget(text, log_area);
-        }
-        LoadingState::Loaded(logs) => {
-            let line_count = logs.lines().count();
-            let scroll_y = app.workflows.log_scroll_y as usize;
-
-            // Build title with line info and search match count
-            let title = if !app.search_matches.is_empty() {
-                format!(
-                    " Logs [{}-{}/{}] Match {}/{} ",
-                    scroll_y + 1,
-                    (scroll_y + log_area.height.saturating_sub(2) as usize).min(line_count),
-                    line_count,
-                    app.search_match_index + 1,
-                    app.search_matches.len()
-                )
-            } else {
-                format!(
-                    " Logs [{}-{}/{}] ",
-                    scroll_y + 1,
-                    (scroll_y + log_area.height.saturating_sub(2) as usize).min(line_count),
-                    line_count
-                )
-            };
-
-            let block = Block::default().borders(Borders::ALL).title(title);
-
-            // Add line numbers and highlight matching lines
-            let query_lower = app.search_query.to_lowercase();
-            let numbered_lines: Vec<Line> = logs
-                .lines()
-                .enumerate()
-                .map(|(i, line)| {
-                    let line_num = i + 1;
-                    let is_match =
-                        !query_lower.is_empty() && line.to_lowercase().contains(&query_lower);
-                    let is_current_match =
-                        app.search_matches.get(app.search_match_index) == Some(&i);
-
-                    let line_style = if is_current_match {
-                        Style::default().bg(Color::Yellow).fg(Color::Black)
-                    } else if is_match {
-                        Style::default().bg(Color::DarkGray)
-                    } else {
-                        Style::default()
-                    };
-
-                    Line::from(vec![
-                        Span::styled(
-                            format!("{:>6} │ ", line_num),
-                            Style::default().fg(Color::DarkGray),
-                        ),
-                        Span::styled(line, line_style),
-                    ])
-                })
-                .collect();
-
-            let text = Paragraph::new(numbered_lines)
-                .block(block)
-                .scroll((app.workflows.log_scroll_y, app.workflows.log_scroll_x));
-            frame.render_widget(text, log_area);
-        }
-    }
+    // Test results, with failed tests (or report paths if none failed) listed
+    let area = if let Some(report) = &app.workflows.test_report {
+        let rows = if report.failures.is_empty() {
+            report.junit_paths.len()
+        } else {
+            report.failures.len()
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(rows.min(5) as u16 + 2),
+                Constraint::Min(1),
+            ])
+            .split(area);
+        list::render_test_report(
+            frame,
+            report,
+            &app.workflows.test_failures,
+            app.workflows.tests_focused,
+            app.config.theme,
+            chunks[0],
+        );
+        chunks[1]
+    } else {
+        area
+    };
 
-    // Render search input if active
-    if let Some(search_area) = search_area {
-        let search_line = Line::from(vec![
-            Span::styled("/", Style::default().fg(Color::Yellow)),
-            Span::raw(&app.search_query),
-            Span::styled("█", Style::default().fg(Color::Yellow)),
-        ]);
-        let search_widget = Paragraph::new(search_line).style(Style::default().bg(Color::DarkGray));
-        frame.render_widget(search_widget, search_area);
+    let job = match app.workflows.nav.current() {
+        ViewLevel::Logs {
+            job_id,
+            job_status,
+            job_conclusion,
+            ..
+        } => Some(LogJob {
+            id: *job_id,
+            status: *job_status,
+            conclusion: *job_conclusion,
+        }),
+        _ => None,
+    };
+    LogViewer {
+        state: &app.workflows.log,
+        jobs: &app.workflows.jobs,
+        job,
+        tab: Tab::Workflows,
     }
+    .render(frame, app, area)
 }
 
 /// Draw the Console tab with error messages.
 fn draw_console_tab(frame: &mut Frame, app: &mut App, area: Rect) {
+    let usage = app.usage.recent(chrono::Local::now().date_naive());
+    let area = if usage.iter().all(|day| day.requests == 0) {
+        area
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(4)])
+            .split(area);
+        draw_usage(frame, &usage, chunks[1]);
+        chunks[0]
+    };
+    let stats = cache::stats::snapshot();
+    let area = if stats.is_empty() {
+        area
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(stats.len() as u16 + 2),
+            ])
+            .split(area);
+        draw_cache_stats(frame, &stats, chunks[1]);
+        chunks[0]
+    };
+    let mut timings = app
+        .github_client
+        .as_ref()
+        .map(|client| client.endpoint_timings())
+        .unwrap_or_default();
+    timings.truncate(CONSOLE_ENDPOINTS);
+    let area = if timings.is_empty() {
+        area
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(timings.len() as u16 + 2),
+            ])
+            .split(area);
+        draw_endpoint_timings(frame, &timings, chunks[1]);
+        chunks[0]
+    };
     let block = Block::default().borders(Borders::ALL).title(" Console ");
 
     if app.console_messages.is_empty() {
@@ -611,138 +722,1397 @@ fn draw_console_tab(frame: &mut Frame, app: &mut App, area: Rect) {
             .iter()
             .rev()
             .map(|msg| {
-                let (icon, color) = match msg.level {
-                    ConsoleLevel::Error => ("❌", Color::Red),
-                    ConsoleLevel::Warn => ("⚠️", Color::Yellow),
-                    ConsoleLevel::Info => ("ℹ️", Color::Cyan),
+                let (icon, color) = match (msg.kind, msg.level) {
+                    (ConsoleKind::RunFailed, _) => ("🔴", Color::Red),
+                    (_, ConsoleLevel::Error) => ("❌", Color::Red),
+                    (_, ConsoleLevel::Warn) => ("⚠️", Color::Yellow),
+                    (_, ConsoleLevel::Info) => ("ℹ️", Color::Cyan),
                 };
 
                 let time = list::format_relative_time(&msg.timestamp);
 
-                ListItem::new(Line::from(vec![
+                let mut spans = vec![
                     Span::raw(format!("{} ", icon)),
                     Span::styled(time, Style::default().fg(Color::DarkGray)),
                     Span::raw(" "),
                     Span::styled(msg.message.clone(), Style::default().fg(color)),
-                ]))
+                ];
+                if msg.target.is_some() {
+                    spans.push(Span::styled(
+                        "  ↵ open",
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
         let list_widget = List::new(items)
             .block(block)
-            .highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(list::highlight_style(app.config.theme))
             .highlight_symbol("> ");
 
         frame.render_stateful_widget(list_widget, area, &mut app.console_list_state);
     }
 }
 
+/// Draw requests and log downloads per day, oldest first, ending today.
+fn draw_usage(frame: &mut Frame, usage: &[DayUsage], area: Rect) {
+    let line = |label: &str, values: Vec<u64>, color: Color| {
+        let today = values.last().copied().unwrap_or(0);
+        let total: u64 = values.iter().sum();
+        Line::from(vec![
+            Span::styled(format!(" {:<9}", label), Style::default().fg(Color::Cyan)),
+            Span::styled(list::sparkline(&values), Style::default().fg(color)),
+            Span::raw(format!("  {:>5} today", today)),
+            Span::styled(
+                format!("  {:>6} in {} days", total, values.len()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])
+    };
+    let lines = vec![
+        line(
+            "requests",
+            usage.iter().map(|day| day.requests).collect(),
+            Color::Green,
+        ),
+        line(
+            "logs",
+            usage.iter().map(|day| day.log_downloads).collect(),
+            Color::Yellow,
+        ),
+    ];
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Usage "));
+    frame.render_widget(paragraph, area);
+}
+
+/// Draw cache hits, misses, and stale serves per kind of data since startup.
+fn draw_cache_stats(frame: &mut Frame, stats: &[(String, CacheCounts)], area: Rect) {
+    let width = stats.iter().map(|(kind, _)| kind.len()).max().unwrap_or(0);
+    let lines: Vec<Line> = stats
+        .iter()
+        .map(|(kind, counts)| {
+            let rate = counts
+                .hit_rate()
+                .map(|rate| format!("{:>3}%", rate))
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}", kind),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("  {:>5} hits", counts.hits),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::styled(
+                    format!("  {:>5} misses", counts.misses),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::styled(
+                    format!("  {:>5} stale", counts.stale),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(format!("  {}", rate), Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Cache "));
+    frame.render_widget(paragraph, area);
+}
+
+/// Draw request latency percentiles of the slowest endpoints.
+fn draw_endpoint_timings(frame: &mut Frame, timings: &[EndpointTiming], area: Rect) {
+    let width = timings.iter().map(|t| t.endpoint.len()).max().unwrap_or(0);
+    let lines: Vec<Line> = timings
+        .iter()
+        .map(|timing| {
+            let color = if timing.is_slow() {
+                Color::Red
+            } else if timing.p95 >= SLOW_THRESHOLD {
+                Color::Yellow
+            } else {
+                Color::Cyan
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}", timing.endpoint),
+                    Style::default().fg(color),
+                ),
+                Span::raw(format!(
+                    "  p50 {:>6}ms  p95 {:>6}ms",
+                    timing.p50.as_millis(),
+                    timing.p95.as_millis()
+                )),
+                Span::styled(
+                    format!("  {:>4} requests", timing.count),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect();
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Requests "));
+    frame.render_widget(paragraph, area);
+}
+
 /// Draw the status bar with keybinding hints and rate limit.
 fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect) {
-    let in_logs = (app.active_tab == Tab::Workflows
-        && matches!(app.workflows.nav.current(), ViewLevel::Logs { .. }))
-        || (app.active_tab == Tab::Runners
-            && matches!(app.runners.nav.current(), RunnersViewLevel::Logs { .. }));
-
-    let mut hints = if in_logs {
-        vec![
-            Span::raw(" ↑↓←→ "),
-            Span::styled("Scroll", Style::default().fg(Color::DarkGray)),
-            Span::raw("  PgUp/Dn "),
-            Span::styled("Page", Style::default().fg(Color::DarkGray)),
-            Span::raw("  Home/End "),
-            Span::styled("Jump", Style::default().fg(Color::DarkGray)),
-            Span::raw("  Esc "),
-            Span::styled("Back", Style::default().fg(Color::DarkGray)),
-            Span::raw("  r "),
-            Span::styled("Refresh", Style::default().fg(Color::DarkGray)),
-            Span::raw("  ? "),
-            Span::styled("Help", Style::default().fg(Color::DarkGray)),
-            Span::raw("  q "),
-            Span::styled("Quit", Style::default().fg(Color::DarkGray)),
-        ]
-    } else {
-        vec![
-            Span::raw(" ↑↓ "),
-            Span::styled("Navigate", Style::default().fg(Color::DarkGray)),
-            Span::raw("  ↵ "),
-            Span::styled("Select", Style::default().fg(Color::DarkGray)),
-            Span::raw("  Esc "),
-            Span::styled("Back", Style::default().fg(Color::DarkGray)),
-            Span::raw("  Tab "),
-            Span::styled("Switch", Style::default().fg(Color::DarkGray)),
-            Span::raw("  r "),
-            Span::styled("Refresh", Style::default().fg(Color::DarkGray)),
-            Span::raw("  ? "),
-            Span::styled("Help", Style::default().fg(Color::DarkGray)),
-            Span::raw("  q "),
-            Span::styled("Quit", Style::default().fg(Color::DarkGray)),
-        ]
-    };
+    let context = context_segment(app);
+
+    let mut trailing = Vec::new();
+    if app.is_revalidating() {
+        trailing.push(Span::styled(
+            "  Refreshing…",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(note) = app.missing_scope_note() {
+        trailing.push(Span::styled(
+            format!("  ⚠ {}", note),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
 
     // Add rate limit info on the right if available
     if let Some(client) = &app.github_client {
-        let rate = client.rate_limit();
-        let rate_color = if rate.remaining < 100 {
+        trailing.push(rate_limit_span(client, chrono::Utc::now()));
+    }
+
+    // Drop the view's least important actions until the hints fit,
+    // keeping the global ones
+    let reserved = spans_width(&context) + spans_width(&trailing);
+    let mut actions = app.available_actions();
+    let mut hints = action_hints(app, actions.iter().chain(Action::GLOBAL.iter()));
+    while !actions.is_empty() && reserved + spans_width(&hints) > area.width as usize {
+        actions.pop();
+        hints = action_hints(app, actions.iter().chain(Action::GLOBAL.iter()));
+    }
+
+    let mut spans = context;
+    spans.extend(hints);
+    spans.extend(trailing);
+    let status = Paragraph::new(Line::from(spans));
+    frame.render_widget(status, area);
+}
+
+/// Remaining API requests, with the session's usage, the time until the
+/// limit resets, and when it runs out first at the session's request rate.
+fn rate_limit_span(client: &GitHubClient, now: DateTime<Utc>) -> Span<'static> {
+    let rate = client.rate_limit();
+    let label = if client.is_authenticated() {
+        "API"
+    } else {
+        "API (no token)"
+    };
+    let mut text = format!("  {}: {}/{}", label, rate.remaining, rate.limit);
+    let (used, elapsed) = client.session_usage();
+    if used > 0 {
+        text.push_str(&format!(" · {} used", used));
+    }
+
+    let resets_in = (rate.reset as i64 - now.timestamp()).max(0);
+    let mut exhausted = None;
+    if rate.limit > 0 && resets_in > 0 {
+        text.push_str(&format!(
+            " · resets in {}",
+            format_until(chrono::Duration::seconds(resets_in))
+        ));
+        exhausted = budget::exhausted_in(
+            rate.remaining,
+            used,
+            elapsed,
+            Duration::from_secs(resets_in as u64),
+        );
+        if let Some(left) = exhausted {
+            text.push_str(&format!(
+                " · out in ~{}",
+                format_until(chrono::Duration::seconds(left.as_secs() as i64))
+            ));
+        }
+    }
+
+    // Thresholds scale with the limit, which is far lower without a token,
+    // and warn early when the session's rate would run out before the reset
+    let color =
+        if rate.remaining < rate.limit / 50 || exhausted.is_some_and(|left| left < RATE_WARNING) {
             Color::Red
-        } else if rate.remaining < 500 {
+        } else if rate.remaining < rate.limit / 10 || exhausted.is_some() {
             Color::Yellow
         } else {
             Color::DarkGray
         };
-        hints.push(Span::styled(
-            format!("  API: {}/{}", rate.remaining, rate.limit),
-            Style::default().fg(rate_color),
-        ));
-    }
+    Span::styled(text, Style::default().fg(color))
+}
 
-    let status = Paragraph::new(Line::from(hints));
-    frame.render_widget(status, area);
+/// Key and label spans for a sequence of actions, greying out those the token can't perform.
+fn action_hints<'a>(app: &App, actions: impl Iterator<Item = &'a Action>) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    for (i, action) in actions.enumerate() {
+        let gap = if i == 0 { " " } else { "  " };
+        let (key_style, label_style) = if app.action_allowed(*action) {
+            (Style::default(), Style::default().fg(Color::DarkGray))
+        } else {
+            let disabled = Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::CROSSED_OUT);
+            (disabled, disabled)
+        };
+        spans.push(Span::raw(gap));
+        spans.push(Span::styled(format!("{} ", action.key()), key_style));
+        spans.push(Span::styled(action.label(), label_style));
+    }
+    spans
 }
 
-/// Draw the help overlay.
-fn draw_help_overlay(frame: &mut Frame) {
-    let area = frame.area();
+/// Total display width of a list of spans.
+fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|span| span.content.chars().count()).sum()
+}
 
-    // Create a centered popup
-    let popup_width = 55;
-    let popup_height = 24;
-    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
-    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+/// Status bar segment showing the current owner/repo, branch, and run.
+fn context_segment(app: &App) -> Vec<Span<'static>> {
+    let Some(context) = app.nav_context() else {
+        return Vec::new();
+    };
+    let location = match (context.owner, context.repo) {
+        (Some(owner), Some(repo)) => format!("{}/{}", owner, repo),
+        (Some(owner), None) => owner,
+        _ => return Vec::new(),
+    };
 
-    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+    let mut spans = vec![Span::styled(
+        format!(" {}", location),
+        Style::default().fg(Color::Cyan),
+    )];
+    if let Some(branch) = context.branch {
+        spans.push(Span::styled(
+            format!(" ⎇ {}", branch),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(run_number) = context.run_number {
+        spans.push(Span::styled(
+            format!(" #{}", run_number),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    spans.push(Span::styled(" │", Style::default().fg(Color::DarkGray)));
+    spans
+}
 
-    // Clear the area behind the popup
+/// Ask before re-running a run with debug logging.
+fn draw_rerun_confirm(frame: &mut Frame, rerun: &PendingRerun) {
+    let area = frame.area();
+    let message = format!(" Re-run #{} with debug logging? ", rerun.run_number);
+    let popup_width = (message.chars().count() as u16 + 2).min(area.width);
+    let popup_height = 4.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
     frame.render_widget(Clear, popup_area);
 
-    let help_text = vec![
-        Line::from(vec![Span::styled(
-            "Keyboard Shortcuts",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ↑/↓ or j/k    ", Style::default().fg(Color::Cyan)),
-            Span::raw("Navigate list / scroll logs"),
-        ]),
+    let lines = vec![
+        Line::from(message),
         Line::from(vec![
-            Span::styled("  ←/→ or h/l    ", Style::default().fg(Color::Cyan)),
-            Span::raw("Horizontal scroll (logs)"),
+            Span::styled(" y ", Style::default().fg(Color::Cyan)),
+            Span::raw("Re-run  "),
+            Span::styled("any key ", Style::default().fg(Color::Cyan)),
+            Span::raw("Cancel"),
         ]),
+    ];
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(" Debug re-run ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Offer to restore the session that crashed.
+fn draw_restore_offer(frame: &mut Frame) {
+    let area = frame.area();
+    let message = " jolt quit unexpectedly. Restore the previous session? ";
+    let popup_width = (message.chars().count() as u16 + 2).min(area.width);
+    let popup_height = 4.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(message),
         Line::from(vec![
-            Span::styled("  Enter         ", Style::default().fg(Color::Cyan)),
-            Span::raw("Select / drill down"),
+            Span::styled(" Enter ", Style::default().fg(Color::Cyan)),
+            Span::raw("Restore  "),
+            Span::styled("any key ", Style::default().fg(Color::Cyan)),
+            Span::raw("Start fresh"),
         ]),
-        Line::from(vec![
-            Span::styled("  Esc           ", Style::default().fg(Color::Cyan)),
+    ];
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(" Crash recovery ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Offer to open the workflows of the current directory's repository.
+fn draw_repo_offer(frame: &mut Frame, owner: &str, repo: &str) {
+    let area = frame.area();
+    let message = format!(" Open the workflows of {}/{}? ", owner, repo);
+    let popup_width = (message.chars().count() as u16 + 2).min(area.width);
+    let popup_height = 4.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(message),
+        Line::from(vec![
+            Span::styled(" Enter ", Style::default().fg(Color::Cyan)),
+            Span::raw("Open  "),
+            Span::styled("any key ", Style::default().fg(Color::Cyan)),
+            Span::raw("Dismiss"),
+        ]),
+    ];
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Current repository ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the flaky jobs report for the current workflow.
+fn draw_flaky_report(frame: &mut Frame, report: &FlakyReport) {
+    let area = frame.area();
+    let popup_width = 50.min(area.width);
+    let popup_height = (report.jobs.len().clamp(1, 15) as u16 + 4).min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(" Passed on retry in the last {} runs", report.runs),
+        Style::default().fg(Color::DarkGray),
+    ))];
+    lines.push(Line::from(""));
+    if report.jobs.is_empty() {
+        lines.push(Line::from(" No flaky jobs"));
+    }
+    for job in report.jobs.iter().take(15) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:>3}×  ", job.runs),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::raw(job.name.clone()),
+        ]));
+    }
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" 🎲 Flaky jobs ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the latest lines of the application log, newest at the bottom.
+fn draw_debug_log(frame: &mut Frame) {
+    let area = frame.area();
+    let popup_width = area.width.saturating_sub(4);
+    let popup_height = area.height.saturating_sub(4);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let shown = popup_height.saturating_sub(2) as usize;
+    let tail = logging::tail();
+    let lines: Vec<Line> = tail[tail.len().saturating_sub(shown)..]
+        .iter()
+        .map(|line| {
+            let color = if line.contains(" ERROR ") {
+                Color::Red
+            } else if line.contains(" WARN ") {
+                Color::Yellow
+            } else {
+                Color::Gray
+            };
+            Line::from(Span::styled(line.clone(), Style::default().fg(color)))
+        })
+        .collect();
+    let location = cache::logs_dir()
+        .map(|dir| format!(" · {} ", dir.display()))
+        .unwrap_or_default();
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" Debug log{}", location))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw unfinished runs by status, and the runs each waiting run is likely queued behind.
+fn draw_run_queue(frame: &mut Frame, queue: &RunQueue) {
+    let area = frame.area();
+    let popup_width = 80.min(area.width);
+    let popup_height = (queue.blocked.len().clamp(1, 15) as u16 + 4).min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let now = chrono::Utc::now();
+    let count = |label: &str, count: usize, color: Color| {
+        Span::styled(format!(" {} {} ", count, label), Style::default().fg(color))
+    };
+    let mut lines = vec![Line::from(vec![
+        count("in progress", queue.in_progress, Color::Cyan),
+        count("queued", queue.queued, Color::Yellow),
+        count("waiting", queue.waiting, Color::Magenta),
+        count("pending", queue.pending, Color::Red),
+    ])];
+    lines.push(Line::from(""));
+    if queue.is_empty() {
+        lines.push(Line::from(" No unfinished runs"));
+    } else if queue.blocked.is_empty() {
+        lines.push(Line::from(" No runs waiting to start"));
+    }
+    for blocked in queue.blocked.iter().take(15) {
+        let run = &blocked.run;
+        let reason = match (&blocked.blocker, run.status) {
+            (Some(blocker), _) => format!("behind #{} in progress", blocker.run_number),
+            (None, RunStatus::Pending) => "held by a concurrency group".to_string(),
+            (None, _) => "waiting for a runner".to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:>7}  ", format_until(now - run.created_at)),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(format!(
+                "{} #{}",
+                run.name.as_deref().unwrap_or("Run"),
+                run.run_number
+            )),
+            Span::styled(
+                format!("  {}", run.head_branch.as_deref().unwrap_or("")),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(format!("  {}", reason), Style::default().fg(Color::Magenta)),
+        ]));
+    }
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" 🚦 Run queue · {} ", queue.scope))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the capabilities added since the last version run.
+fn draw_whats_new(frame: &mut Frame, releases: &[Release]) {
+    let features: Vec<&Feature> = releases.iter().flat_map(|r| &r.features).collect();
+    let area = frame.area();
+    let popup_width = 80.min(area.width);
+    let popup_height = (features.len() as u16 + 5).min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![Line::from("")];
+    for feature in features {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:>8}  ", feature.keys),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                feature.title.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", feature.description),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Press any key to dismiss",
+        Style::default().fg(Color::DarkGray),
+    )));
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" What's new in jolt {} ", CURRENT_VERSION))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw upcoming scheduled runs of favorite workflows, soonest first.
+fn draw_schedule_overview(frame: &mut Frame, upcoming: &[UpcomingRun]) {
+    let area = frame.area();
+    let popup_width = 80.min(area.width);
+    let popup_height = (upcoming.len().clamp(1, 15) as u16 + 4).min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let now = chrono::Utc::now();
+    let mut lines = vec![Line::from(Span::styled(
+        " Next cron runs of favorite workflows (UTC)",
+        Style::default().fg(Color::DarkGray),
+    ))];
+    lines.push(Line::from(""));
+    if upcoming.is_empty() {
+        lines.push(Line::from(" No scheduled favorite workflows"));
+    }
+    for run in upcoming.iter().take(15) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:>7}  ", format_until(run.at - now)),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                format!("{}  ", run.at.format("%a %H:%M")),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::raw(run.workflow.clone()),
+            Span::styled(
+                format!("  {}/{}", run.owner, run.repo),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" ⏰ Schedule ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw a repository's Actions cache entries with their total size.
+fn draw_caches_popup(frame: &mut Frame, view: &mut CachesView, theme: Theme) {
+    let area = frame.area();
+    let popup_width = 90.min(area.width);
+    let popup_height = 24.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(" Actions caches · {}/{} ", view.owner, view.repo))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+
+    let total = view.total_bytes();
+    let header = match view.caches.selected_item() {
+        Some(cache) if view.confirm_delete => Line::from(Span::styled(
+            format!(" Delete {}? y to confirm", cache.key),
+            Style::default().fg(Color::Red),
+        )),
+        _ => {
+            let color = if total >= CACHE_LIMIT_BYTES * 9 / 10 {
+                Color::Red
+            } else {
+                Color::DarkGray
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        " {} of {} used by {} entries",
+                        format_size(total),
+                        format_size(CACHE_LIMIT_BYTES),
+                        view.caches.visible_len()
+                    ),
+                    Style::default().fg(color),
+                ),
+                Span::styled("  ·  x delete", Style::default().fg(Color::DarkGray)),
+            ])
+        }
+    };
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+    list::render_actions_caches(frame, &mut view.caches, theme, chunks[1]);
+}
+
+/// Draw the check runs of a run's commit, from Actions and other apps.
+fn draw_checks_popup(frame: &mut Frame, view: &mut ChecksView, theme: Theme) {
+    let area = frame.area();
+    let popup_width = 80.min(area.width);
+    let popup_height = 24.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let short_sha = &view.sha[..view.sha.len().min(7)];
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(
+            " Checks · {}/{} @ {} ",
+            view.owner, view.repo, short_sha
+        ))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+
+    let checks = view.checks.sorted_items();
+    let other = checks.iter().filter(|c| !is_actions_check(c)).count();
+    let header = Line::from(vec![
+        Span::styled(
+            format!(" {} checks, {} from other apps", checks.len(), other),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(
+            "  ·  ↵ open in browser",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+    list::render_check_runs(frame, &mut view.checks, theme, chunks[1]);
+}
+
+/// Draw a run's log archive: its files, or the file being read.
+/// Returns where the file's text was drawn, if one is open.
+fn draw_log_archive_popup(
+    frame: &mut Frame,
+    view: &mut LogArchiveView,
+    theme: Theme,
+) -> Option<Rect> {
+    let area = frame.area();
+    let popup_width = 100.min(area.width);
+    let popup_height = 30.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let title = match &view.open {
+        Some(file) => format!(
+            " {} [{}/{}] · Esc files ",
+            file.name,
+            (file.scroll + 1).min(file.lines.len()),
+            file.lines.len()
+        ),
+        None => format!(
+            " Log archive · {}/{} #{} ",
+            view.owner, view.repo, view.run_number
+        ),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    if let Some(file) = &view.open {
+        let lines: Vec<Line> = file
+            .lines
+            .iter()
+            .skip(file.scroll)
+            .take(inner.height as usize)
+            .map(|line| Line::raw(line.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+        return Some(inner);
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+    let header = Line::from(Span::styled(
+        format!(
+            " {} files, kept in the cache after GitHub expires them  ·  ↵ read",
+            view.files.visible_len()
+        ),
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+    list::render_archive_files(frame, &mut view.files, theme, chunks[1]);
+    None
+}
+
+/// Draw the summary a job published, rendered from Markdown.
+fn draw_job_summary_popup(frame: &mut Frame, view: &mut JobSummaryView) {
+    let area = frame.area();
+    let popup_width = 100.min(area.width);
+    let popup_height = 30.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(" Summary · {} ", view.job_name))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    if view.markdown.is_empty() {
+        let text = Paragraph::new("This job published no summary")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        frame.render_widget(text, inner);
+        return;
+    }
+    let summary =
+        Paragraph::new(markdown::markdown_lines(&view.markdown)).wrap(Wrap { trim: false });
+    // Scroll no further than the last page of the summary as wrapped
+    view.max_scroll = summary
+        .line_count(inner.width)
+        .saturating_sub(inner.height as usize);
+    view.scroll = view.scroll.min(view.max_scroll);
+    frame.render_widget(summary.scroll((view.scroll as u16, 0)), inner);
+}
+
+/// Draw the branch picker, with the text typed to narrow it.
+fn draw_branch_picker(
+    frame: &mut Frame,
+    picker: &mut BranchPicker,
+    current: Option<&str>,
+    theme: Theme,
+) {
+    let area = frame.area();
+    let popup_width = 60.min(area.width);
+    let popup_height = 20.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(
+            " Branches · {}/{}{} ",
+            picker.owner,
+            picker.repo,
+            if picker.loading { " (loading…)" } else { "" }
+        ))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let query = Line::from(vec![
+        Span::styled(" Branch: ", Style::default().fg(Color::Cyan)),
+        Span::raw(format!("{}▏", picker.query)),
+    ]);
+    frame.render_widget(Paragraph::new(query), chunks[0]);
+    list::render_branches(frame, &mut picker.branches, current, theme, chunks[1]);
+    let hint = Line::from(Span::styled(
+        " ↵ show runs on branch  Del all branches  Esc cancel",
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(hint), chunks[2]);
+}
+
+/// Draw the secrets and variables available to a repository.
+fn draw_secrets_popup(frame: &mut Frame, view: &mut SecretsView, theme: Theme) {
+    let area = frame.area();
+    let popup_width = 90.min(area.width);
+    let popup_height = 24.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(
+            " Secrets & variables · {}/{} ",
+            view.owner, view.repo
+        ))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(view.notes.len() as u16),
+            Constraint::Min(1),
+        ])
+        .split(inner);
+
+    let notes: Vec<Line> = view
+        .notes
+        .iter()
+        .map(|note| {
+            Line::from(Span::styled(
+                format!(" {}", note),
+                Style::default().fg(Color::Yellow),
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(notes), chunks[0]);
+    list::render_config_entries(frame, &mut view.entries, theme, chunks[1]);
+}
+
+/// Draw the Actions permissions and policy of a repository and its organization.
+fn draw_policy_popup(frame: &mut Frame, view: &PolicyView) {
+    let mut lines = Vec::new();
+    for section in &view.sections {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            format!(" {}", section.title),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for setting in &section.settings {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("   {:<22}", setting.name),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(setting.value.clone()),
+            ]));
+        }
+        for note in &section.notes {
+            lines.push(Line::from(Span::styled(
+                format!("   {}", note),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+    }
+
+    let area = frame.area();
+    let popup_width = 90.min(area.width);
+    let popup_height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" Actions policy · {}/{} ", view.owner, view.repo))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the picker of recently viewed runs and jobs.
+fn draw_recent_picker(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let popup_width = 70.min(area.width);
+    let popup_height = (app.recent.len() as u16 + 2).clamp(3, 20).min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let items: Vec<ListItem> = app
+        .recent
+        .iter()
+        .map(|item| {
+            let (icon, color) = match item.job.as_ref().and_then(|job| job.conclusion) {
+                Some(RunConclusion::Success) => ("✓", Color::Green),
+                Some(RunConclusion::Failure) => ("✗", Color::Red),
+                Some(_) => ("•", Color::DarkGray),
+                None => (" ", Color::DarkGray),
+            };
+            let mut spans = vec![
+                Span::styled(format!(" {} ", icon), Style::default().fg(color)),
+                Span::styled(
+                    format!("{}/{} ", item.owner, item.repo),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(format!("{} #{}", item.workflow_name, item.run_number)),
+            ];
+            if let Some(job) = &item.job {
+                spans.push(Span::styled(" › ", Style::default().fg(Color::DarkGray)));
+                spans.push(Span::raw(job.name.clone()));
+            }
+            spans.push(Span::styled(
+                format!("  {}", list::format_relative_time(&item.viewed_at)),
+                Style::default().fg(Color::DarkGray),
+            ));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(" Recent ")
+                .title_style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(app.recent_selected));
+    frame.render_stateful_widget(list, popup_area, &mut state);
+}
+
+/// Draw the Workspaces picker, with the name being typed when saving.
+fn draw_workspace_picker(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let popup_width = 60.min(area.width);
+    let popup_height = (app.workspaces.len() as u16 + 4)
+        .clamp(5, 20)
+        .min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Workspaces ")
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    if app.workspaces.is_empty() {
+        frame.render_widget(
+            Paragraph::new(" No saved workspaces").style(Style::default().fg(Color::DarkGray)),
+            chunks[0],
+        );
+    } else {
+        let items: Vec<ListItem> = app
+            .workspaces
+            .iter()
+            .map(|workspace| {
+                let location = workspace
+                    .workflows_nav
+                    .breadcrumbs()
+                    .last()
+                    .map(|node| node.label.clone())
+                    .unwrap_or_default();
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!(" {}", workspace.name)),
+                    Span::styled(
+                        format!("  {} · {}", workspace.active_tab.title(), location),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("  {}", list::format_relative_time(&workspace.saved_at)),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]))
+            })
+            .collect();
+        let list =
+            List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(app.workspace_selected));
+        frame.render_stateful_widget(list, chunks[0], &mut state);
+    }
+
+    let footer = match &app.workspace_name {
+        Some(name) => Line::from(vec![
+            Span::styled(" Name: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}▏", name)),
+        ]),
+        None => Line::from(Span::styled(
+            " ↵ restore  s save current  x delete",
+            Style::default().fg(Color::DarkGray),
+        )),
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+/// Draw the input for a pasted GitHub Actions URL.
+fn draw_permalink_input(frame: &mut Frame, input: &str) {
+    let area = frame.area();
+    let popup_width = 70.min(area.width);
+    let popup_height = 4.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    // Keep the end of a long URL, where the job and line are, in view
+    let visible = popup_width.saturating_sub(5) as usize;
+    let skip = input.chars().count().saturating_sub(visible);
+    let shown: String = input.chars().skip(skip).collect();
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(" URL: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}▏", shown)),
+        ]),
+        Line::from(Span::styled(
+            " Paste a run, job, or log line link  ↵ open  Esc cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Open permalink ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the bulk action picker for the marked runs or jobs.
+fn draw_bulk_menu(frame: &mut Frame, app: &App) {
+    let Some((_, _, items)) = app.marked_items() else {
+        return;
+    };
+    let actions = BulkAction::available(&items);
+    let area = frame.area();
+    let popup_width = 34;
+    let popup_height = actions.len() as u16 + 2;
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width.min(area.width),
+        popup_height.min(area.height),
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let lines: Vec<Line> = actions
+        .iter()
+        .map(|action| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {}  ", action.key()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(action.label()),
+            ])
+        })
+        .collect();
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} marked ", items.describe()))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Ask before applying a bulk action to marked items.
+fn draw_bulk_confirm(frame: &mut Frame, bulk: &PendingBulk) {
+    let area = frame.area();
+    let message = format!(" {} ", bulk.action.prompt(&bulk.items));
+    let popup_width = (message.chars().count() as u16 + 2).max(24).min(area.width);
+    let popup_height = 4.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(message),
+        Line::from(vec![
+            Span::styled(" y ", Style::default().fg(Color::Cyan)),
+            Span::raw("Yes  "),
+            Span::styled("any key ", Style::default().fg(Color::Cyan)),
+            Span::raw("No"),
+        ]),
+    ];
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(format!(" {} ", bulk.action.label()))
+            .title_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the copy mode picker.
+fn draw_copy_menu(frame: &mut Frame) {
+    let area = frame.area();
+    let popup_width = 30;
+    let popup_height = CopyMode::ALL.len() as u16 + 2;
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width.min(area.width),
+        popup_height.min(area.height),
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let lines: Vec<Line> = CopyMode::ALL
+        .iter()
+        .map(|mode| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {}  ", mode.key()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(mode.label()),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Copy ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the format picker for exporting the current runs or jobs list.
+fn draw_export_menu(frame: &mut Frame) {
+    let area = frame.area();
+    let popup_width = 30;
+    let popup_height = ExportFormat::ALL.len() as u16 + 2;
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width.min(area.width),
+        popup_height.min(area.height),
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let lines: Vec<Line> = ExportFormat::ALL
+        .iter()
+        .map(|format| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {}  ", format.key()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(format.extension().to_uppercase()),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Export as ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Draw the help overlay.
+/// Draw the help overlay, with its shortcuts scrolled by `scroll` lines when
+/// they don't fit. The scroll is clamped to the last page.
+fn draw_help_overlay(frame: &mut Frame, scroll: &mut u16) {
+    let shortcuts = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  ↑/↓ or j/k    ", Style::default().fg(Color::Cyan)),
+            Span::raw("Navigate list / scroll logs"),
+        ]),
+        Line::from(vec![
+            Span::styled("  ←/→ or h/l    ", Style::default().fg(Color::Cyan)),
+            Span::raw("Horizontal scroll (logs)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Enter         ", Style::default().fg(Color::Cyan)),
+            Span::raw("Select / drill down"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Esc           ", Style::default().fg(Color::Cyan)),
             Span::raw("Go back / close help"),
         ]),
         Line::from(vec![
-            Span::styled("  Tab/1/2/3     ", Style::default().fg(Color::Cyan)),
+            Span::styled("  Tab/0-5       ", Style::default().fg(Color::Cyan)),
             Span::raw("Switch tabs"),
         ]),
         Line::from(vec![
@@ -755,7 +2125,7 @@ fn draw_help_overlay(frame: &mut Frame) {
         ]),
         Line::from(vec![
             Span::styled("  /             ", Style::default().fg(Color::Cyan)),
-            Span::raw("Search in logs"),
+            Span::raw("Filter lists / search logs"),
         ]),
         Line::from(vec![
             Span::styled("  n/N           ", Style::default().fg(Color::Cyan)),
@@ -765,6 +2135,10 @@ fn draw_help_overlay(frame: &mut Frame) {
             Span::styled("  r             ", Style::default().fg(Color::Cyan)),
             Span::raw("Refresh current view"),
         ]),
+        Line::from(vec![
+            Span::styled("  H             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Hard refresh, bypassing the cache"),
+        ]),
         Line::from(vec![
             Span::styled("  o             ", Style::default().fg(Color::Cyan)),
             Span::raw("Open in GitHub"),
@@ -773,6 +2147,142 @@ fn draw_help_overlay(frame: &mut Frame) {
             Span::styled("  f             ", Style::default().fg(Color::Cyan)),
             Span::raw("Toggle favorite"),
         ]),
+        Line::from(vec![
+            Span::styled("  p             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Open a pasted run/job/log line URL"),
+        ]),
+        Line::from(vec![
+            Span::styled("  a             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Select annotations (in logs)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  m             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Show only my runs (Runs lists)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  m, [/]        ", Style::default().fg(Color::Cyan)),
+            Span::raw("Bookmark log line, prev/next bookmark"),
+        ]),
+        Line::from(vec![
+            Span::styled("  s             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Pick a branch to show runs of (Runs lists)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  A             ", Style::default().fg(Color::Cyan)),
+            Span::raw("All branches / default branch (Runs lists)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  v / z         ", Style::default().fg(Color::Cyan)),
+            Span::raw("Group by event or matrix job / fold the group"),
+        ]),
+        Line::from(vec![
+            Span::styled("  V             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Show only failed matrix variants (Jobs)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  D             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Re-run with debug logging (Runs)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Space         ", Style::default().fg(Color::Cyan)),
+            Span::raw("Mark run/job for bulk actions"),
+        ]),
+        Line::from(vec![
+            Span::styled("  B             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Bulk actions on marked items"),
+        ]),
+        Line::from(vec![
+            Span::styled("  F             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Flaky jobs report (Runs)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  c             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Run queue and concurrency blocking"),
+        ]),
+        Line::from(vec![
+            Span::styled("  P             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Actions permissions and policy"),
+        ]),
+        Line::from(vec![
+            Span::styled("  K             ", Style::default().fg(Color::Cyan)),
+            Span::raw("All checks of the run's commit"),
+        ]),
+        Line::from(vec![
+            Span::styled("  L             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Browse the run's log archive"),
+        ]),
+        Line::from(vec![
+            Span::styled("  u             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Summary the job published"),
+        ]),
+        Line::from(vec![
+            Span::styled("  U             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Upcoming scheduled runs of favorites"),
+        ]),
+        Line::from(vec![
+            Span::styled("  e             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Environments and deployments (Workflows)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  C             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Actions caches of the repository"),
+        ]),
+        Line::from(vec![
+            Span::styled("  S             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Secrets and variables of the repository"),
+        ]),
+        Line::from(vec![
+            Span::styled("  d             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Runner diagnostic logs (Runners)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  x / X         ", Style::default().fg(Color::Cyan)),
+            Span::raw("Hide repository / show hidden ones (Runners tab)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  R             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Organization runner groups (Runners)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  y             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Copy log line, job/run URL, SHA, branch, runner"),
+        ]),
+        Line::from(vec![
+            Span::styled("  E             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Export runs or jobs as CSV/JSON"),
+        ]),
+        Line::from(vec![
+            Span::styled("  t             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Select failed tests (in logs)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  T             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Log timestamps: shown/hidden/elapsed"),
+        ]),
+        Line::from(vec![
+            Span::styled("  |             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Toggle run preview pane"),
+        ]),
+        Line::from(vec![
+            Span::styled("  ^r            ", Style::default().fg(Color::Cyan)),
+            Span::raw("Recently viewed runs and jobs"),
+        ]),
+        Line::from(vec![
+            Span::styled("  W             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Save and restore workspaces"),
+        ]),
+        Line::from(vec![
+            Span::styled("  b             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Breadcrumb path, 1-9/Enter jumps"),
+        ]),
+        Line::from(vec![
+            Span::styled("  ,             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Settings"),
+        ]),
+        Line::from(vec![
+            Span::styled("  ~             ", Style::default().fg(Color::Cyan)),
+            Span::raw("Debug log (tail of the application log)"),
+        ]),
         Line::from(vec![
             Span::styled("  ?             ", Style::default().fg(Color::Cyan)),
             Span::raw("Show/hide this help"),
@@ -782,28 +2292,62 @@ fn draw_help_overlay(frame: &mut Frame) {
             Span::raw("Quit"),
         ]),
         Line::from(""),
-        Line::from(vec![
-            Span::styled("Press ", Style::default().fg(Color::DarkGray)),
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
-            Span::styled(" or ", Style::default().fg(Color::DarkGray)),
-            Span::styled("?", Style::default().fg(Color::Yellow)),
-            Span::styled(" to close", Style::default().fg(Color::DarkGray)),
-        ]),
     ];
+    let area = frame.area();
 
-    let help_paragraph = Paragraph::new(help_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
-                .title(" Help ")
-                .title_style(
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                ),
-        )
-        .alignment(Alignment::Left);
+    // Create a centered popup, with a line above and below the shortcuts
+    let popup_width = 67.min(area.width);
+    let popup_height = (shortcuts.len() as u16 + 4).min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
 
-    frame.render_widget(help_paragraph, popup_area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Help ")
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let max_scroll = (shortcuts.len() as u16).saturating_sub(chunks[1].height);
+    *scroll = (*scroll).min(max_scroll);
+
+    let header = Line::from(vec![Span::styled(
+        "Keyboard Shortcuts",
+        Style::default().add_modifier(Modifier::BOLD),
+    )]);
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+    frame.render_widget(Paragraph::new(shortcuts).scroll((*scroll, 0)), chunks[1]);
+
+    let mut footer = vec![
+        Span::styled("Press ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled(" or ", Style::default().fg(Color::DarkGray)),
+        Span::styled("?", Style::default().fg(Color::Yellow)),
+        Span::styled(" to close", Style::default().fg(Color::DarkGray)),
+    ];
+    if max_scroll > 0 {
+        footer.push(Span::styled(
+            format!("  ↑/↓ scroll ({}/{})", *scroll, max_scroll),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(footer)), chunks[2]);
 }
@@ -0,0 +1,64 @@
+// Settings overlay rendering.
+// Shows editable runtime settings as a centered popup.
+
+use ratatui::{prelude::*, widgets::*};
+
+use crate::app::App;
+use crate::config::SettingsField;
+
+/// Draw the settings overlay.
+pub fn draw_settings_overlay(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+
+    let popup_width = 50;
+    let popup_height = SettingsField::ALL.len() as u16 + 6;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(
+        popup_x,
+        popup_y,
+        popup_width.min(area.width),
+        popup_height.min(area.height),
+    );
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![Line::from("")];
+    for (i, field) in SettingsField::ALL.iter().enumerate() {
+        let selected = i == app.settings_selected;
+        let marker = if selected { "> " } else { "  " };
+        let label_style = if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{:<24}", field.label()), label_style),
+            Span::styled("◀ ", Style::default().fg(Color::DarkGray)),
+            Span::styled(field.value(&app.config), Style::default().fg(Color::Yellow)),
+            Span::styled(" ▶", Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  ←/→ ", Style::default().fg(Color::Yellow)),
+        Span::styled("Change  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+        Span::styled("Close", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Settings ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+
+    frame.render_widget(paragraph, popup_area);
+}
@@ -0,0 +1,697 @@
+// Rendering tests against ratatui's TestBackend.
+// Views are drawn from fixture state and compared with text snapshots of the buffer.
+
+use std::collections::{HashMap, HashSet};
+
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+
+use crate::app::{App, PersistedState, Tab};
+use crate::config::Config;
+use crate::error::ViewError;
+use crate::github::{
+    GitHubClient, Job, Owner, OwnerType, RunConclusion, RunStatus, Runner, RunnerStatus, Workflow,
+    WorkflowRun, WorkflowState,
+};
+use crate::keymap::Action;
+use crate::state::history::JobHistory;
+use crate::state::log_view::LogViewState;
+use crate::state::utilization::RunnerUtilization;
+use crate::state::{LoadingState, RunnerRow, SelectableList, ViewLevel};
+
+use super::caps::{LogText, TermCaps};
+use super::list;
+
+/// Buffer rows as text, with trailing spaces trimmed.
+fn buffer_lines(buffer: &Buffer) -> Vec<String> {
+    let area = buffer.area;
+    (0..area.height)
+        .map(|y| {
+            let row: String = (0..area.width).map(|x| buffer[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Render with `draw` into a buffer of the given size.
+fn render(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(draw).unwrap();
+    buffer_lines(terminal.backend().buffer())
+}
+
+/// App with default settings, no token, and nothing loaded.
+fn app() -> App {
+    let persisted = PersistedState {
+        active_tab: Tab::Workflows,
+        ..PersistedState::default()
+    };
+    App::with_state(
+        persisted,
+        Config::default(),
+        None,
+        None,
+        TermCaps::from_env(|_| None),
+    )
+}
+
+fn runner(name: &str, status: RunnerStatus, busy: bool) -> Runner {
+    Runner {
+        id: 0,
+        name: name.to_string(),
+        os: "Linux".to_string(),
+        status,
+        busy,
+        labels: Vec::new(),
+    }
+}
+
+fn workflow(id: u64, name: &str) -> Workflow {
+    Workflow {
+        id,
+        name: name.to_string(),
+        path: format!(".github/workflows/{}.yml", name.to_lowercase()),
+        state: WorkflowState::Active,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+fn run(run_number: u64, conclusion: RunConclusion) -> WorkflowRun {
+    let created_at = chrono::Utc::now() - chrono::Duration::hours(2);
+    WorkflowRun {
+        id: run_number,
+        name: Some("CI".to_string()),
+        run_number,
+        run_attempt: None,
+        status: RunStatus::Completed,
+        conclusion: Some(conclusion),
+        workflow_id: 1,
+        head_branch: Some("main".to_string()),
+        head_sha: String::new(),
+        event: "push".to_string(),
+        created_at,
+        updated_at: created_at + chrono::Duration::seconds(90),
+        run_started_at: Some(created_at),
+        actor: None,
+        triggering_actor: None,
+        html_url: String::new(),
+        pull_requests: Vec::new(),
+    }
+}
+
+fn job(id: u64, name: &str, conclusion: RunConclusion) -> Job {
+    let started_at = chrono::Utc::now() - chrono::Duration::hours(1);
+    Job {
+        id,
+        run_id: 1,
+        run_attempt: None,
+        head_sha: String::new(),
+        head_branch: None,
+        name: name.to_string(),
+        status: RunStatus::Completed,
+        conclusion: Some(conclusion),
+        created_at: None,
+        started_at: Some(started_at),
+        completed_at: Some(started_at + chrono::Duration::seconds(75)),
+        html_url: String::new(),
+        steps: Vec::new(),
+        runner_name: None,
+        labels: Vec::new(),
+    }
+}
+
+/// List holding `data` as its loading state.
+fn list_in<T>(data: LoadingState<Vec<T>>) -> SelectableList<T> {
+    let mut list = SelectableList::new();
+    match data {
+        LoadingState::Loaded(items) => {
+            let count = items.len() as u64;
+            list.set_loaded(items, count);
+        }
+        LoadingState::Loading => list.set_loading(),
+        LoadingState::Error(e) => list.set_error(e),
+        LoadingState::Idle => {}
+    }
+    list
+}
+
+/// Every loading state of a list, with `rows` as the loaded one.
+fn states<T>(rows: Vec<T>) -> [LoadingState<Vec<T>>; 5] {
+    [
+        LoadingState::Idle,
+        LoadingState::Loading,
+        LoadingState::Error(ViewError::from("Not Found".to_string())),
+        LoadingState::Loaded(Vec::new()),
+        LoadingState::Loaded(rows),
+    ]
+}
+
+/// Runners list drawn in the given loading state.
+fn render_runners(data: LoadingState<Vec<RunnerRow>>) -> Vec<String> {
+    let mut runners = list_in(data);
+    let favorites = ["o/r/mac-mini".to_string()].into_iter().collect();
+    render(40, 5, |frame| {
+        let area = frame.area();
+        list::render_runners_list(
+            frame,
+            &mut runners,
+            &favorites,
+            "o/r",
+            &HashMap::new(),
+            &RunnerUtilization::default(),
+            Config::default().theme,
+            area,
+        )
+    })
+}
+
+#[test]
+fn test_runners_list_states() {
+    assert_eq!(
+        render_runners(LoadingState::Idle),
+        ["           Press Enter to load", "", "", "", ""]
+    );
+    assert_eq!(
+        render_runners(LoadingState::Loading),
+        ["          ⏳  Loading runners...", "", "", "", ""]
+    );
+    assert_eq!(
+        render_runners(LoadingState::Error(ViewError::from(
+            "Not Found".to_string()
+        ))),
+        ["              ❌  Not Found", "", "", "", ""]
+    );
+    assert_eq!(
+        render_runners(LoadingState::Loaded(Vec::new())),
+        ["            No runners found", "", "", "", ""]
+    );
+    let rows = vec![
+        RunnerRow::Runner(runner("mac-mini", RunnerStatus::Online, true)),
+        RunnerRow::Runner(runner("linux-01", RunnerStatus::Offline, false)),
+    ];
+    // Wide emoji take two cells, the second of which renders as a space here
+    assert_eq!(
+        render_runners(LoadingState::Loaded(rows)),
+        [
+            "┌ Runners ─────────────────────────────┐",
+            "│> ⭐  🟢  mac-mini (busy)  Linux        │",
+            "│     ⚫  linux-01  Linux               │",
+            "│                                      │",
+            "└──────────────────────────────────────┘",
+        ]
+    );
+}
+
+/// Workflows list drawn in the given loading state.
+fn render_workflows(data: LoadingState<Vec<Workflow>>) -> Vec<String> {
+    let mut workflows = list_in(data);
+    let favorites = ["o/r/1".to_string()].into_iter().collect();
+    let latest_runs = [(1, run(12, RunConclusion::Failure))].into_iter().collect();
+    render(50, 5, |frame| {
+        let area = frame.area();
+        list::render_workflows_list(
+            frame,
+            &mut workflows,
+            &favorites,
+            list::WorkflowBadges {
+                schedules: &HashMap::new(),
+                latest_runs: &latest_runs,
+            },
+            "o/r",
+            Config::default().theme,
+            area,
+        )
+    })
+}
+
+/// Runs list drawn in the given loading state.
+fn render_runs(data: LoadingState<Vec<WorkflowRun>>) -> Vec<String> {
+    let mut runs = list_in(data);
+    render(50, 5, |frame| {
+        let area = frame.area();
+        list::render_runs_list(
+            frame,
+            &mut runs,
+            list::RunsListOptions {
+                mine_only: false,
+                branch: None,
+                grouped: None,
+                ignored: 0,
+            },
+            &HashSet::new(),
+            Config::default().theme,
+            area,
+        )
+    })
+}
+
+/// Jobs list drawn in the given loading state.
+fn render_jobs(data: LoadingState<Vec<Job>>) -> Vec<String> {
+    let mut jobs = list_in(data);
+    render(50, 5, |frame| {
+        let area = frame.area();
+        list::render_jobs_list(
+            frame,
+            &mut jobs,
+            list::JobsListOptions {
+                grouped: None,
+                failed_only: false,
+            },
+            &JobHistory::default(),
+            &HashSet::new(),
+            Config::default().theme,
+            area,
+        )
+    })
+}
+
+#[test]
+fn test_workflows_list_states() {
+    let [idle, loading, error, empty, loaded] =
+        states(vec![workflow(1, "CI"), workflow(2, "Release")]).map(render_workflows);
+    assert_eq!(idle[0], "                Press Enter to load");
+    assert_eq!(loading[0], "              ⏳  Loading workflows...");
+    assert_eq!(error[0], "                   ❌  Not Found");
+    assert_eq!(empty[0], "          No workflows in this repository");
+    // Wide emoji take two cells, the second of which renders as a space here
+    assert_eq!(
+        loaded,
+        [
+            "┌ Workflows ─────────────────────────────────────┐",
+            "│> ⭐  CI  ci.yml  ❌  main · 2h ago               │",
+            "│     Release  release.yml                       │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn test_runs_list_states() {
+    let [idle, loading, error, empty, loaded] = states(vec![
+        run(12, RunConclusion::Failure),
+        run(11, RunConclusion::Success),
+    ])
+    .map(render_runs);
+    assert_eq!(idle[0], "                Press Enter to load");
+    assert_eq!(loading[0], "            ⏳  Loading workflow runs...");
+    assert_eq!(error[0], "                   ❌  Not Found");
+    assert_eq!(empty[0], "              No workflow runs found");
+    assert_eq!(
+        loaded,
+        [
+            "┌ Workflow Runs ─────────────────────────────────┐",
+            "│> ❌  #12  2h ago  ⏱ 1m 30s  main                │",
+            "│  ✅  #11  2h ago  ⏱ 1m 30s  main                │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn test_jobs_list_states() {
+    let [idle, loading, error, empty, loaded] = states(vec![
+        job(1, "build", RunConclusion::Success),
+        job(2, "test", RunConclusion::Failure),
+    ])
+    .map(render_jobs);
+    assert_eq!(idle[0], "                Press Enter to load");
+    assert_eq!(loading[0], "                ⏳  Loading jobs...");
+    assert_eq!(error[0], "                   ❌  Not Found");
+    assert_eq!(empty[0], "                No jobs in this run");
+    assert_eq!(
+        loaded,
+        [
+            "┌ Jobs ──────────────────────────────────────────┐",
+            "│> ✅  build  1m 15s                              │",
+            "│  ❌  test  1m 15s                               │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]
+    );
+}
+
+/// Matrix headers take rows of their own, so check that the scroll offset
+/// kept between frames is an item position and scrolls back to the same rows.
+#[test]
+fn test_jobs_list_offset_skips_headers() {
+    let mut jobs = list_in(LoadingState::Loaded(vec![
+        job(1, "build (a)", RunConclusion::Success),
+        job(2, "build (b)", RunConclusion::Success),
+        job(3, "test (a)", RunConclusion::Success),
+        job(4, "test (b)", RunConclusion::Failure),
+    ]));
+    jobs.list_state.select(Some(3));
+    let folded = HashSet::new();
+    let mut draw = || {
+        render(40, 5, |frame| {
+            let area = frame.area();
+            list::render_jobs_list(
+                frame,
+                &mut jobs,
+                list::JobsListOptions {
+                    grouped: Some(&folded),
+                    failed_only: false,
+                },
+                &JobHistory::default(),
+                &HashSet::new(),
+                Config::default().theme,
+                area,
+            )
+        })
+    };
+    let first = draw();
+    let second = draw();
+    assert!(first[1].contains("test") && !first[1].contains('>'));
+    assert!(first[3].contains("> "));
+    assert_eq!(first, second);
+    assert_eq!(jobs.list_state.offset(), 2);
+}
+
+/// The help overlay's height is set by hand, so check that adding a shortcut
+/// didn't push the closing hint out of the popup.
+#[test]
+fn test_help_overlay_fits() {
+    let mut app = app();
+    app.show_help = true;
+    let mut rows = Vec::new();
+    // The shortcuts that don't fit are scrolled to
+    for scroll in [0, u16::MAX] {
+        app.help_scroll = scroll;
+        let lines = render(80, 50, |frame| super::draw(frame, &mut app));
+        let top = lines
+            .iter()
+            .position(|line| line.contains("┌ Help"))
+            .unwrap();
+        let bottom = lines.iter().rposition(|line| line.contains("└──")).unwrap();
+        let hint = lines
+            .iter()
+            .position(|line| line.contains("Press Esc or ? to close"))
+            .unwrap();
+        assert!(top < hint && hint < bottom);
+        assert!(lines[top + 1].contains("Keyboard Shortcuts"));
+
+        // Shortcut rows are listed in order, each key next to what it does, and
+        // none is cut off by the popup's right border
+        let left = lines[top].chars().position(|c| c == '┌').unwrap();
+        let width = lines[top].chars().skip(left).count() - 2;
+        for line in &lines[top + 1..bottom] {
+            let row: String = line.chars().skip(left + 1).take(width).collect();
+            let row = row.trim_end().to_string();
+            assert!(row.chars().count() < width, "{:?} is cut off", row);
+            if !rows.contains(&row) {
+                rows.push(row);
+            }
+        }
+    }
+    assert!(
+        app.help_scroll < u16::MAX,
+        "scroll is clamped to the last page"
+    );
+    let expected = [
+        "  ↑/↓ or j/k    Navigate list / scroll logs",
+        "  Enter         Select / drill down",
+        "  Esc           Go back / close help",
+        "  r             Refresh current view",
+        "  H             Hard refresh, bypassing the cache",
+        "  Space         Mark run/job for bulk actions",
+        "  B             Bulk actions on marked items",
+        "  ?             Show/hide this help",
+        "  q             Quit",
+    ];
+    let mut rest = rows.iter();
+    for row in expected {
+        assert!(
+            rest.any(|line| *line == row),
+            "{:?} missing from {:?}",
+            row,
+            rows
+        );
+    }
+}
+
+/// Without a token, the status bar says so and shows the lower rate limit.
+#[test]
+fn test_status_bar_without_token() {
+    let client = GitHubClient::anonymous(&Config::default().http).unwrap();
+    let mut app = App::with_state(
+        PersistedState::default(),
+        Config::default(),
+        Some(client),
+        None,
+        TermCaps::from_env(|_| None),
+    );
+    assert!(!app.action_allowed(Action::DebugRerun));
+    let lines = render(200, 10, |frame| super::draw(frame, &mut app));
+    let status = lines.last().unwrap();
+    assert!(status.contains("⚠ No token: public repositories only"));
+    assert!(status.contains("API (no token): 0/0"));
+}
+
+/// Navigate the Workflows tab to the log of job 3 in run #7 of o/r.
+fn open_logs(app: &mut App) {
+    for level in [
+        ViewLevel::Repositories {
+            owner: "o".to_string(),
+        },
+        ViewLevel::Workflows {
+            owner: "o".to_string(),
+            repo: "r".to_string(),
+        },
+        ViewLevel::Runs {
+            owner: "o".to_string(),
+            repo: "r".to_string(),
+            workflow_id: 1,
+            workflow_name: "CI".to_string(),
+        },
+        ViewLevel::Jobs {
+            owner: "o".to_string(),
+            repo: "r".to_string(),
+            workflow_id: 1,
+            run_id: 2,
+            run_number: 7,
+        },
+        ViewLevel::Logs {
+            owner: "o".to_string(),
+            repo: "r".to_string(),
+            workflow_id: 1,
+            run_id: 2,
+            job_id: 3,
+            job_name: "build".to_string(),
+            job_status: RunStatus::Completed,
+            job_conclusion: None,
+        },
+    ] {
+        app.workflows.nav.push(level);
+    }
+}
+
+#[test]
+fn test_log_viewer_with_search() {
+    let mut app = app();
+    open_logs(&mut app);
+    app.workflows.set_logs(
+        "2024-05-01T10:00:00.0000000Z Syncing repository\n\
+         2024-05-01T10:00:01.0000000Z error: mismatched types\n\
+         2024-05-01T10:00:02.0000000Z Done"
+            .to_string(),
+    );
+    app.search_active = true;
+    app.search_query = "error".to_string();
+    app.search_matches = vec![1];
+    let lines = render(70, 12, |frame| super::draw(frame, &mut app));
+    assert_eq!(
+        lines,
+        [
+            " jolt",
+            " Home  │  Runners  │  Workflows  │  Inbox  │  Console  │  Live",
+            "──────────────────────────────────────────────────────────────────────",
+            "Owners > o > r > CI > #7 > build",
+            "",
+            "──────────────────────────────────────────────────────────────────────",
+            "┌ Logs [1-2/3] Match 1/1 · timestamps ───────────────────────────────┐",
+            "│     1 │ 2024-05-01T10:00:00.0000000Z Syncing repository            │",
+            "│     2 │ 2024-05-01T10:00:01.0000000Z error: mismatched types       │",
+            "└────────────────────────────────────────────────────────────────────┘",
+            "/error█",
+            " o/r #7 │ ↵ Search  Esc Cancel  , Settings  ? Help  q Quit",
+        ]
+    );
+}
+
+/// A log scrolled into its middle shows the visible range in the title,
+/// marks bookmarked lines, and highlights the current search match.
+#[test]
+fn test_log_viewer_scrolled_range() {
+    let mut app = app();
+    open_logs(&mut app);
+    app.workflows.set_logs(
+        (1..=8)
+            .map(|i| format!("2024-05-01T10:00:0{}.0000000Z step {}", i, i))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    app.workflows.log.scroll_y = 2;
+    let mut view = LogViewState::default();
+    view.toggle_bookmark(3);
+    app.log_views.insert(3, view);
+    app.search_query = "step 5".to_string();
+    app.search_matches = vec![4];
+    let mut terminal = Terminal::new(TestBackend::new(70, 13)).unwrap();
+    terminal.draw(|frame| super::draw(frame, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    assert_eq!(
+        buffer_lines(buffer)[6..12],
+        [
+            "┌ Logs [3-6/8] Match 1/1 · timestamps ───────────────────────────────┐",
+            "│     3 │ 2024-05-01T10:00:03.0000000Z step 3                        │",
+            "│     4 ● 2024-05-01T10:00:04.0000000Z step 4                        │",
+            "│     5 │ 2024-05-01T10:00:05.0000000Z step 5                        │",
+            "│     6 │ 2024-05-01T10:00:06.0000000Z step 6                        │",
+            "└────────────────────────────────────────────────────────────────────┘",
+        ]
+    );
+    // "step 5" on the match's row is highlighted, the row above isn't
+    assert_eq!(buffer[(39, 9)].bg, Color::Yellow);
+    assert_eq!(buffer[(39, 8)].bg, Color::Reset);
+}
+
+#[test]
+fn test_runs_list_duration_and_actor() {
+    let created_at = chrono::Utc::now() - chrono::Duration::days(3);
+    let user = |login: &str| Owner {
+        id: 0,
+        login: login.to_string(),
+        owner_type: OwnerType::User,
+        avatar_url: None,
+    };
+    let run = WorkflowRun {
+        id: 1,
+        name: Some("CI".to_string()),
+        run_number: 42,
+        run_attempt: Some(2),
+        status: RunStatus::Completed,
+        conclusion: Some(RunConclusion::Failure),
+        workflow_id: 1,
+        head_branch: Some("main".to_string()),
+        head_sha: String::new(),
+        event: "push".to_string(),
+        created_at,
+        updated_at: created_at + chrono::Duration::seconds(312),
+        run_started_at: Some(created_at + chrono::Duration::seconds(60)),
+        actor: Some(user("alice")),
+        triggering_actor: Some(user("bob")),
+        html_url: String::new(),
+        pull_requests: Vec::new(),
+    };
+    let mut runs = SelectableList::new();
+    runs.set_loaded(vec![run], 1);
+    let lines = render(70, 3, |frame| {
+        let area = frame.area();
+        list::render_runs_list(
+            frame,
+            &mut runs,
+            list::RunsListOptions {
+                mine_only: false,
+                branch: None,
+                grouped: None,
+                ignored: 0,
+            },
+            &HashSet::new(),
+            Config::default().theme,
+            area,
+        )
+    });
+    assert_eq!(
+        lines[1],
+        "│> ❌  #42  3d ago  ⏱ 4m 12s  main  @alice (re-run by @bob)           │"
+    );
+}
+
+/// Status icons differ in width (wide emoji, a symbol that drops its emoji
+/// selector, plain characters in ASCII mode), so check that the run number
+/// starts in the same column on every row with and without ASCII mode.
+#[test]
+fn test_runs_list_columns_align_with_wide_and_ascii_glyphs() {
+    let created_at = chrono::Utc::now();
+    let statuses = [
+        (RunStatus::Completed, Some(RunConclusion::Success)),
+        (RunStatus::Completed, Some(RunConclusion::Skipped)),
+        (RunStatus::Completed, Some(RunConclusion::Cancelled)),
+        (RunStatus::InProgress, None),
+        (RunStatus::Queued, None),
+        (RunStatus::Completed, Some(RunConclusion::TimedOut)),
+    ];
+    let runs: Vec<WorkflowRun> = statuses
+        .into_iter()
+        .enumerate()
+        .map(|(i, (status, conclusion))| WorkflowRun {
+            id: i as u64,
+            name: None,
+            run_number: 10 + i as u64,
+            run_attempt: None,
+            status,
+            conclusion,
+            workflow_id: 1,
+            head_branch: None,
+            head_sha: String::new(),
+            event: "push".to_string(),
+            created_at,
+            updated_at: created_at,
+            run_started_at: None,
+            actor: None,
+            triggering_actor: None,
+            html_url: String::new(),
+            pull_requests: Vec::new(),
+        })
+        .collect();
+    let ascii = TermCaps {
+        ascii: true,
+        ..TermCaps::default()
+    };
+    for caps in [TermCaps::default(), ascii] {
+        let mut list = SelectableList::new();
+        list.set_loaded(runs.clone(), runs.len() as u64);
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                list::render_runs_list(
+                    frame,
+                    &mut list,
+                    list::RunsListOptions {
+                        mine_only: false,
+                        branch: None,
+                        grouped: None,
+                        ignored: 0,
+                    },
+                    &HashSet::new(),
+                    Config::default().theme,
+                    area,
+                );
+                caps.degrade(frame.buffer_mut(), &LogText::default());
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let columns: Vec<Option<u16>> = (1..=runs.len() as u16)
+            .map(|y| (0..40).find(|&x| buffer[(x, y)].symbol() == "#"))
+            .collect();
+        assert!(
+            columns.iter().all(|column| *column == Some(6)),
+            "{:?}: {:?}",
+            caps,
+            buffer_lines(buffer)
+        );
+        // The succeeded run is drawn with a wide emoji unless in ASCII mode
+        let first = &buffer_lines(buffer)[1];
+        assert!(
+            first.contains(if caps.ascii { "ok" } else { "✅" }),
+            "{}",
+            first
+        );
+    }
+}
@@ -7,7 +7,14 @@ use crate::app::{App, Tab};
 
 /// Draw the tab bar at the top of the screen.
 pub fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
-    let tabs = [Tab::Runners, Tab::Workflows, Tab::Console];
+    let tabs = [
+        Tab::Home,
+        Tab::Runners,
+        Tab::Workflows,
+        Tab::Inbox,
+        Tab::Console,
+        Tab::Live,
+    ];
 
     let tab_titles: Vec<Line> = tabs
         .iter()
@@ -0,0 +1,242 @@
+// Progress of a run followed by `jolt watch`.
+// Turns successive snapshots of a run's jobs into progress lines for the terminal.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::github::{Job, RunConclusion, RunStatus, WorkflowRun};
+use crate::state::log_time::format_elapsed;
+use crate::state::test_report::strip_timestamp;
+use crate::state::workflows::first_error_line;
+
+/// Log lines printed for a failed job, starting a few lines before its first error.
+const FAILURE_EXCERPT_LINES: usize = 20;
+
+/// Lines of context kept above a failed job's first error.
+const FAILURE_CONTEXT_LINES: usize = 5;
+
+/// What has been printed about a job so far.
+#[derive(Debug, Default)]
+struct Reported {
+    started: bool,
+    steps: HashSet<u64>,
+    completed: bool,
+}
+
+/// Progress printed so far for a watched run.
+#[derive(Debug, Default)]
+pub struct Progress {
+    jobs: HashMap<u64, Reported>,
+}
+
+impl Progress {
+    /// Lines for what changed in `jobs` since the last update: jobs starting,
+    /// steps finishing, and jobs finishing.
+    pub fn update(&mut self, jobs: &[Job]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for job in jobs {
+            let reported = self.jobs.entry(job.id).or_default();
+            if job.status == RunStatus::Queued || job.status == RunStatus::Waiting {
+                continue;
+            }
+            if !reported.started {
+                reported.started = true;
+                lines.push(format!("▶ {}", job.name));
+            }
+            for step in &job.steps {
+                if step.status != RunStatus::Completed || !reported.steps.insert(step.number) {
+                    continue;
+                }
+                let took = match (step.started_at, step.completed_at) {
+                    (Some(start), Some(end)) => format!(" ({})", format_elapsed(end - start)),
+                    _ => String::new(),
+                };
+                lines.push(format!(
+                    "  {} {} › {}{}",
+                    conclusion_icon(step.conclusion),
+                    job.name,
+                    step.name,
+                    took
+                ));
+            }
+            if job.status == RunStatus::Completed && !reported.completed {
+                reported.completed = true;
+                let took = match (job.started_at, job.completed_at) {
+                    (Some(start), Some(end)) => format!(" in {}", format_elapsed(end - start)),
+                    _ => String::new(),
+                };
+                lines.push(format!(
+                    "{} {} {}{}",
+                    conclusion_icon(job.conclusion),
+                    job.name,
+                    conclusion_label(job.conclusion),
+                    took
+                ));
+            }
+        }
+        lines
+    }
+}
+
+/// Whether a job failed, so its log is worth printing.
+pub fn is_failed(job: &Job) -> bool {
+    matches!(
+        job.conclusion,
+        Some(RunConclusion::Failure | RunConclusion::TimedOut | RunConclusion::StartupFailure)
+    )
+}
+
+/// Lines of a failed job's log around its first error, or its last lines
+/// when no error is recognized, without timestamps.
+pub fn failure_excerpt(log: &str) -> Vec<&str> {
+    let lines: Vec<&str> = log.lines().map(strip_timestamp).collect();
+    let start = match first_error_line(log) {
+        Some(line) => line.saturating_sub(FAILURE_CONTEXT_LINES),
+        None => lines.len().saturating_sub(FAILURE_EXCERPT_LINES),
+    };
+    lines
+        .into_iter()
+        .skip(start)
+        .take(FAILURE_EXCERPT_LINES)
+        .collect()
+}
+
+/// Whether a finished run counts as passing for the exit status.
+pub fn run_passed(run: &WorkflowRun) -> bool {
+    matches!(
+        run.conclusion,
+        Some(RunConclusion::Success | RunConclusion::Skipped | RunConclusion::Neutral)
+    )
+}
+
+/// Final line for a finished run (e.g., "❌ CI #42 failed").
+pub fn summary(run: &WorkflowRun) -> String {
+    format!(
+        "{} {} #{} {}",
+        conclusion_icon(run.conclusion),
+        run.name.as_deref().unwrap_or("Run"),
+        run.run_number,
+        conclusion_label(run.conclusion)
+    )
+}
+
+fn conclusion_icon(conclusion: Option<RunConclusion>) -> &'static str {
+    match conclusion {
+        Some(RunConclusion::Success) => "✅",
+        Some(RunConclusion::Failure | RunConclusion::TimedOut | RunConclusion::StartupFailure) => {
+            "❌"
+        }
+        Some(RunConclusion::Cancelled) => "⚪",
+        Some(RunConclusion::Skipped) => "⏭️",
+        _ => "❓",
+    }
+}
+
+/// Conclusion in words (e.g., "timed out").
+fn conclusion_label(conclusion: Option<RunConclusion>) -> &'static str {
+    match conclusion {
+        Some(RunConclusion::Success) => "succeeded",
+        Some(RunConclusion::Failure) => "failed",
+        Some(RunConclusion::Cancelled) => "cancelled",
+        Some(RunConclusion::Skipped) => "skipped",
+        Some(RunConclusion::TimedOut) => "timed out",
+        Some(RunConclusion::ActionRequired) => "needs action",
+        Some(RunConclusion::Neutral) => "neutral",
+        Some(RunConclusion::Stale) => "stale",
+        Some(RunConclusion::StartupFailure) => "failed to start",
+        Some(RunConclusion::Unknown) | None => "finished",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::Step;
+
+    fn step(number: u64, status: RunStatus, conclusion: Option<RunConclusion>) -> Step {
+        Step {
+            name: format!("step {}", number),
+            status,
+            conclusion,
+            number,
+            started_at: None,
+            completed_at: None,
+        }
+    }
+
+    fn job(status: RunStatus, conclusion: Option<RunConclusion>, steps: Vec<Step>) -> Job {
+        Job {
+            id: 1,
+            run_id: 1,
+            run_attempt: None,
+            head_sha: String::new(),
+            head_branch: None,
+            name: "build".to_string(),
+            status,
+            conclusion,
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps,
+            runner_name: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_progress_reports_each_change_once() {
+        let mut progress = Progress::default();
+        assert!(
+            progress
+                .update(&[job(RunStatus::Queued, None, Vec::new())])
+                .is_empty()
+        );
+
+        let running = job(
+            RunStatus::InProgress,
+            None,
+            vec![
+                step(1, RunStatus::Completed, Some(RunConclusion::Success)),
+                step(2, RunStatus::InProgress, None),
+            ],
+        );
+        assert_eq!(
+            progress.update(std::slice::from_ref(&running)),
+            ["▶ build", "  ✅ build › step 1"]
+        );
+        assert!(progress.update(&[running]).is_empty());
+
+        let failed = job(
+            RunStatus::Completed,
+            Some(RunConclusion::TimedOut),
+            vec![
+                step(1, RunStatus::Completed, Some(RunConclusion::Success)),
+                step(2, RunStatus::Completed, Some(RunConclusion::Failure)),
+            ],
+        );
+        assert_eq!(
+            progress.update(&[failed]),
+            ["  ❌ build › step 2", "❌ build timed out"]
+        );
+    }
+
+    #[test]
+    fn test_failure_excerpt() {
+        let log: String = (1..=30)
+            .map(|i| {
+                if i == 12 {
+                    "2024-01-01T00:00:00.0000000Z ##[error]boom\n".to_string()
+                } else {
+                    format!("2024-01-01T00:00:00.0000000Z line {}\n", i)
+                }
+            })
+            .collect();
+        let excerpt = failure_excerpt(&log);
+        assert_eq!(excerpt.len(), FAILURE_EXCERPT_LINES);
+        assert_eq!(excerpt[0], "line 7");
+        assert_eq!(excerpt[5], "##[error]boom");
+
+        let excerpt = failure_excerpt("a\nb\n");
+        assert_eq!(excerpt, ["a", "b"]);
+    }
+}